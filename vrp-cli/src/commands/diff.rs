@@ -0,0 +1,80 @@
+use super::*;
+use std::io::BufReader;
+use std::process;
+use vrp_cli::extensions::diff::get_diff_serialized;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const ORIGINAL_SOLUTION_ARG_NAME: &str = "original-solution";
+const NEW_SOLUTION_ARG_NAME: &str = "new-solution";
+const OUT_FORMAT_ARG_NAME: &str = "out-format";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_diff_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("diff")
+        .about("Provides the way to compare two solutions of the same problem")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(ORIGINAL_SOLUTION_ARG_NAME)
+                .help("Sets original solution file")
+                .short("a")
+                .long(ORIGINAL_SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(NEW_SOLUTION_ARG_NAME)
+                .help("Sets new solution file")
+                .short("b")
+                .long(NEW_SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_FORMAT_ARG_NAME)
+                .help("Specifies output format")
+                .short("f")
+                .long(OUT_FORMAT_ARG_NAME)
+                .required(false)
+                .possible_values(&["json", "text"])
+                .default_value("text")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for result output")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_diff(matches: &ArgMatches) {
+    let open =
+        |arg_name: &str, description: &str| BufReader::new(open_file(matches.value_of(arg_name).unwrap(), description));
+
+    let original_file = open(ORIGINAL_SOLUTION_ARG_NAME, "original solution");
+    let new_file = open(NEW_SOLUTION_ARG_NAME, "new solution");
+    let is_text = matches.value_of(OUT_FORMAT_ARG_NAME).map_or(true, |format| format == "text");
+
+    match get_diff_serialized(original_file, new_file, is_text) {
+        Ok(result) => {
+            let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let mut out_buffer = create_write_buffer(out_result);
+            if let Err(err) = out_buffer.write_all(result.as_bytes()) {
+                eprintln!("Cannot write result: '{}'", err);
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("Cannot compare solutions: '{}'", err);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+use std::io::BufReader;
+use std::process;
+use vrp_cli::extensions::explain::get_explanation_serialized;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "problem-file";
+const SOLUTION_ARG_NAME: &str = "solution-file";
+const MATRIX_ARG_NAME: &str = "matrix";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_explain_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("explain")
+        .about("Provides the way to explain why jobs are unassigned in existing solution")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets input file which contains a VRP definition")
+                .short("p")
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SOLUTION_ARG_NAME)
+                .help("Sets solution file with unassigned jobs")
+                .short("s")
+                .long(SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short("m")
+                .long(MATRIX_ARG_NAME)
+                .multiple(true)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for result output")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_explain(matches: &ArgMatches) {
+    let open =
+        |arg_name: &str, description: &str| BufReader::new(open_file(matches.value_of(arg_name).unwrap(), description));
+
+    let problem_file = open(PROBLEM_ARG_NAME, "problem");
+    let solution_file = open(SOLUTION_ARG_NAME, "solution");
+    let matrix_files = matches
+        .values_of(MATRIX_ARG_NAME)
+        .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect());
+
+    match get_explanation_serialized(problem_file, solution_file, matrix_files) {
+        Ok(result) => {
+            let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let mut out_buffer = create_write_buffer(out_result);
+            if let Err(err) = out_buffer.write_all(result.as_bytes()) {
+                eprintln!("Cannot write result: '{}'", err);
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("Cannot explain solution: '{}'", err);
+            process::exit(1);
+        }
+    }
+}
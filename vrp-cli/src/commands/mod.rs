@@ -1,8 +1,15 @@
 use clap::{App, Arg, ArgMatches, Values};
 
+pub mod accept;
+pub mod analyze;
 pub mod check;
+pub mod diff;
+pub mod explain;
 pub mod generate;
 pub mod import;
+pub mod replan;
+pub mod report;
+pub mod serve;
 pub mod solve;
 
 use std::fs::File;
@@ -0,0 +1,61 @@
+use super::*;
+use std::io::BufReader;
+use std::process;
+use vrp_pragmatic::format::problem::{apply_progress, deserialize_problem, deserialize_progress, serialize_problem};
+
+pub const PROBLEM_ARG_NAME: &str = "PROBLEM";
+pub const PROGRESS_ARG_NAME: &str = "progress";
+pub const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_replan_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("replan")
+        .about("Creates a problem for the remaining part of the plan which is already in progress")
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets the problem file in pragmatic format to use. New jobs should be already added to the plan")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROGRESS_ARG_NAME)
+                .help("Sets the file with vehicles progress: their current location, time and served jobs")
+                .short("p")
+                .long(PROGRESS_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for result output")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_replan(matches: &ArgMatches) {
+    let problem_file = open_file(matches.value_of(PROBLEM_ARG_NAME).unwrap(), "problem");
+    let progress_file = open_file(matches.value_of(PROGRESS_ARG_NAME).unwrap(), "progress");
+
+    let result = deserialize_problem(BufReader::new(problem_file))
+        .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\n"))
+        .and_then(|problem| {
+            deserialize_progress(BufReader::new(progress_file)).and_then(|progress| apply_progress(problem, &progress))
+        });
+
+    match result {
+        Ok(problem) => {
+            let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let out_buffer = create_write_buffer(out_result);
+            if let Err(err) = serialize_problem(out_buffer, &problem) {
+                eprintln!("Cannot serialize result problem: '{}'", err);
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("Cannot replan problem: '{}'", err);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use super::*;
+use std::io::BufReader;
+use std::process;
+use vrp_cli::extensions::report::get_report_serialized;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "problem-file";
+const SOLUTION_ARG_NAME: &str = "solution-file";
+const OUT_FORMAT_ARG_NAME: &str = "out-format";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_report_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("report")
+        .about("Renders a self-contained html or svg report with tour map and timeline of existing solution")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets input file which contains a VRP definition")
+                .short("p")
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SOLUTION_ARG_NAME)
+                .help("Sets solution file to render")
+                .short("s")
+                .long(SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_FORMAT_ARG_NAME)
+                .help("Specifies output format")
+                .short("f")
+                .long(OUT_FORMAT_ARG_NAME)
+                .required(false)
+                .possible_values(&["html", "svg"])
+                .default_value("html")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for result output")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_report(matches: &ArgMatches) {
+    let open =
+        |arg_name: &str, description: &str| BufReader::new(open_file(matches.value_of(arg_name).unwrap(), description));
+
+    let problem_file = open(PROBLEM_ARG_NAME, "problem");
+    let solution_file = open(SOLUTION_ARG_NAME, "solution");
+    let is_svg = matches.value_of(OUT_FORMAT_ARG_NAME).map_or(false, |format| format == "svg");
+
+    match get_report_serialized(problem_file, solution_file, is_svg) {
+        Ok(result) => {
+            let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let mut out_buffer = create_write_buffer(out_result);
+            if let Err(err) = out_buffer.write_all(result.as_bytes()) {
+                eprintln!("Cannot write result: '{}'", err);
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("Cannot render report: '{}'", err);
+            process::exit(1);
+        }
+    }
+}
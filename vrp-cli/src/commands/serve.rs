@@ -0,0 +1,112 @@
+use super::*;
+use std::process;
+use std::time::Duration;
+use vrp_cli::extensions::serve::{run_server, ServerConfig};
+
+const HOST_ARG_NAME: &str = "host";
+const PORT_ARG_NAME: &str = "port";
+const WORKERS_ARG_NAME: &str = "workers";
+const MAX_CONNECTIONS_ARG_NAME: &str = "max-connections";
+const MAX_BODY_SIZE_ARG_NAME: &str = "max-body-size";
+const RESULT_TTL_ARG_NAME: &str = "result-ttl";
+const MAX_QUEUE_SIZE_ARG_NAME: &str = "max-queue-size";
+const IO_TIMEOUT_ARG_NAME: &str = "io-timeout";
+
+pub fn get_serve_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("serve")
+        .about("Runs http server which exposes solver functionality as a json api")
+        .arg(
+            Arg::with_name(HOST_ARG_NAME)
+                .help("Specifies host to listen on")
+                .long(HOST_ARG_NAME)
+                .required(false)
+                .default_value("127.0.0.1")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(PORT_ARG_NAME)
+                .help("Specifies port to listen on")
+                .short("p")
+                .long(PORT_ARG_NAME)
+                .required(false)
+                .default_value("8000")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(WORKERS_ARG_NAME)
+                .help("Specifies amount of problems solved in parallel")
+                .short("w")
+                .long(WORKERS_ARG_NAME)
+                .required(false)
+                .default_value("1")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(MAX_CONNECTIONS_ARG_NAME)
+                .help("Specifies max amount of connections handled at the same time")
+                .long(MAX_CONNECTIONS_ARG_NAME)
+                .required(false)
+                .default_value("64")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(MAX_BODY_SIZE_ARG_NAME)
+                .help("Specifies max size of request body in megabytes")
+                .long(MAX_BODY_SIZE_ARG_NAME)
+                .required(false)
+                .default_value("8")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(RESULT_TTL_ARG_NAME)
+                .help("Specifies time in seconds to keep results of finished jobs")
+                .long(RESULT_TTL_ARG_NAME)
+                .required(false)
+                .default_value("3600")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(MAX_QUEUE_SIZE_ARG_NAME)
+                .help("Specifies max amount of jobs waiting for a free worker")
+                .long(MAX_QUEUE_SIZE_ARG_NAME)
+                .required(false)
+                .default_value("128")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(IO_TIMEOUT_ARG_NAME)
+                .help("Specifies timeout in seconds for reading request and writing response")
+                .long(IO_TIMEOUT_ARG_NAME)
+                .required(false)
+                .default_value("30")
+                .takes_value(true),
+        )
+}
+
+pub fn run_serve(matches: &ArgMatches) {
+    let host = matches.value_of(HOST_ARG_NAME).unwrap();
+    let port = parse_int_value::<u16>(matches, PORT_ARG_NAME, "port").unwrap();
+    let workers = parse_int_value::<usize>(matches, WORKERS_ARG_NAME, "workers").unwrap();
+    let max_connections = parse_int_value::<usize>(matches, MAX_CONNECTIONS_ARG_NAME, "max connections").unwrap();
+    let max_body_size = parse_int_value::<usize>(matches, MAX_BODY_SIZE_ARG_NAME, "max body size").unwrap();
+    let result_ttl = parse_int_value::<u64>(matches, RESULT_TTL_ARG_NAME, "result ttl").unwrap();
+    let max_queue_size = parse_int_value::<usize>(matches, MAX_QUEUE_SIZE_ARG_NAME, "max queue size").unwrap();
+    let io_timeout = parse_int_value::<u64>(matches, IO_TIMEOUT_ARG_NAME, "io timeout").unwrap();
+
+    let config = ServerConfig {
+        address: format!("{}:{}", host, port),
+        workers,
+        max_connections: max_connections.max(1),
+        max_body_size: max_body_size * 1024 * 1024,
+        max_queue_size: max_queue_size.max(1),
+        io_timeout: Duration::from_secs(io_timeout.max(1)),
+        result_ttl: Duration::from_secs(result_ttl),
+    };
+
+    println!("listening on http://{} with {} worker(s)", config.address, config.workers);
+
+    if let Err(err) = run_server(&config) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
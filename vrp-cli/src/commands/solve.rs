@@ -1,15 +1,19 @@
 use super::*;
 
-use std::collections::HashMap;
+use memmap2::Mmap;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
-use std::io::{BufReader, BufWriter, Write};
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
 use std::process;
 use std::sync::Arc;
 use vrp_cli::extensions::check::check_pragmatic_solution;
 use vrp_cli::extensions::solve::config::create_builder_from_config_file;
 use vrp_cli::{get_errors_serialized, get_locations_serialized};
 use vrp_core::models::{Problem, Solution};
+use vrp_core::solver::objectives::{has_stability_objective, set_stability_reference};
 use vrp_core::solver::{Builder, Metrics, Telemetry, TelemetryMode};
+use vrp_pragmatic::format::problem::{deserialize_binary_matrix, is_binary_matrix, BinaryMatrix, ReaderOptions};
+use vrp_pragmatic::format::FormatError;
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
@@ -18,14 +22,20 @@ const GENERATIONS_ARG_NAME: &str = "max-generations";
 const TIME_ARG_NAME: &str = "max-time";
 const COST_VARIATION_ARG_NAME: &str = "cost-variation";
 const GEO_JSON_ARG_NAME: &str = "geo-json";
+const CSV_ARG_NAME: &str = "csv";
 
 const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
+const REFERENCE_SOLUTION_ARG_NAME: &str = "reference-solution";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
 const GET_LOCATIONS_ARG_NAME: &str = "get-locations";
 const CONFIG_ARG_NAME: &str = "config";
 const LOG_ARG_NAME: &str = "log";
 const CHECK_ARG_NAME: &str = "check";
 const RANDOM_SEED_NAME: &str = "seed";
+const DECOMPOSE_ARG_NAME: &str = "decompose";
+const JOB_INDEX_THRESHOLD_ARG_NAME: &str = "job-index-threshold";
+const MAX_JOB_NEIGHBOURS_ARG_NAME: &str = "max-job-neighbours";
+const COMPACT_MATRICES_ARG_NAME: &str = "compact-matrices";
 
 #[allow(clippy::type_complexity)]
 struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, String>>);
@@ -41,6 +51,7 @@ struct SolutionWriter(
             Option<Metrics>,
             BufWriter<Box<dyn Write>>,
             Option<BufWriter<Box<dyn Write>>>,
+            Option<BufWriter<Box<dyn Write>>>,
         ) -> Result<(), String>,
     >,
 );
@@ -65,7 +76,7 @@ fn add_scientific(formats: &mut FormatMap) {
                     BufReader::new(problem).read_solomon()
                 })),
                 InitSolutionReader(Box::new(|file, problem| read_init_solomon(BufReader::new(file), problem))),
-                SolutionWriter(Box::new(|_, solution, _, writer, _| solution.write_solomon(writer))),
+                SolutionWriter(Box::new(|_, solution, _, writer, _, _| solution.write_solomon(writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         );
@@ -77,14 +88,14 @@ fn add_scientific(formats: &mut FormatMap) {
                     BufReader::new(problem).read_lilim()
                 })),
                 InitSolutionReader(Box::new(|_file, _problem| unimplemented!())),
-                SolutionWriter(Box::new(|_, solution, _, writer, _| solution.write_lilim(writer))),
+                SolutionWriter(Box::new(|_, solution, _, writer, _, _| solution.write_lilim(writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         );
     }
 }
 
-fn add_pragmatic(formats: &mut FormatMap) {
+fn add_pragmatic(formats: &mut FormatMap, reader_options: ReaderOptions) {
     use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
     use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
     use vrp_pragmatic::format::solution::PragmaticSolution;
@@ -92,19 +103,30 @@ fn add_pragmatic(formats: &mut FormatMap) {
     formats.insert(
         "pragmatic",
         (
-            ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                if let Some(matrices) = matrices {
-                    let matrices = matrices.into_iter().map(BufReader::new).collect();
-                    (BufReader::new(problem), matrices).read_pragmatic()
-                } else {
-                    BufReader::new(problem).read_pragmatic()
+            ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
+                let to_error = |errors: Vec<FormatError>| {
+                    errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n")
+                };
+
+                match matrices {
+                    Some(matrices) if has_binary_matrices(&matrices)? => {
+                        let problem = deserialize_problem(BufReader::new(problem)).map_err(to_error)?;
+                        let matrices = read_binary_matrices(matrices).map_err(to_error)?;
+                        (problem, matrices).read_pragmatic_with_options(&reader_options)
+                    }
+                    Some(matrices) => {
+                        let matrices = matrices.into_iter().map(BufReader::new).collect();
+                        (BufReader::new(problem), matrices).read_pragmatic_with_options(&reader_options)
+                    }
+                    None => BufReader::new(problem).read_pragmatic_with_options(&reader_options),
                 }
-                .map_err(|errors| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n"))
+                .map_err(to_error)
             })),
             InitSolutionReader(Box::new(|file, problem| read_init_pragmatic(BufReader::new(file), problem))),
-            SolutionWriter(Box::new(|problem, solution, metrics, default_writer, geojson_writer| {
+            SolutionWriter(Box::new(|problem, solution, metrics, default_writer, geojson_writer, csv_writer| {
                 geojson_writer
                     .map_or(Ok(()), |geojson_writer| solution.write_geo_json(problem, geojson_writer))
+                    .and_then(|_| csv_writer.map_or(Ok(()), |csv_writer| solution.write_csv(problem, csv_writer)))
                     .and_then(|_| {
                         if let Some(metrics) = metrics {
                             (solution, metrics).write_pragmatic_json(problem, default_writer)
@@ -124,11 +146,11 @@ fn add_pragmatic(formats: &mut FormatMap) {
     );
 }
 
-fn get_formats<'a>() -> FormatMap<'a> {
+fn get_formats<'a>(reader_options: ReaderOptions) -> FormatMap<'a> {
     let mut formats = FormatMap::default();
 
     add_scientific(&mut formats);
-    add_pragmatic(&mut formats);
+    add_pragmatic(&mut formats, reader_options);
 
     formats
 }
@@ -176,6 +198,13 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(REFERENCE_SOLUTION_ARG_NAME)
+                .help("Specifies path to file with reference solution used by plan stability objective")
+                .long(REFERENCE_SOLUTION_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name(MATRIX_ARG_NAME)
                 .help("Specifies path to file with routing matrix")
@@ -208,6 +237,13 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(CSV_ARG_NAME)
+                .help("Specifies path to solution output in csv format with one row per activity")
+                .long(CSV_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name(CONFIG_ARG_NAME)
                 .help("Specifies path to algorithm configuration file")
@@ -237,11 +273,51 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(DECOMPOSE_ARG_NAME)
+                .help("Specifies max amount of jobs in sub-problem to solve huge problems using decomposition")
+                .long(DECOMPOSE_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(JOB_INDEX_THRESHOLD_ARG_NAME)
+                .help("Specifies max amount of jobs for which full job neighbourhood index is built, no limit by default (pragmatic only)")
+                .long(JOB_INDEX_THRESHOLD_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(MAX_JOB_NEIGHBOURS_ARG_NAME)
+                .help("Specifies amount of nearest neighbours kept in job index above threshold (pragmatic only)")
+                .long(MAX_JOB_NEIGHBOURS_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(COMPACT_MATRICES_ARG_NAME)
+                .help("Specifies whether json routing matrices should be stored in compact form (pragmatic only)")
+                .long(COMPACT_MATRICES_ARG_NAME)
+                .required(false)
+                .takes_value(false),
+        )
 }
 
 /// Runs solver commands.
 pub fn run_solve(matches: &ArgMatches) {
-    let formats = get_formats();
+    let default_options = ReaderOptions::default();
+    let reader_options = ReaderOptions {
+        full_job_index_threshold: parse_int_value::<usize>(
+            matches,
+            JOB_INDEX_THRESHOLD_ARG_NAME,
+            "job index threshold",
+        )
+        .or(default_options.full_job_index_threshold),
+        max_job_neighbours: parse_int_value::<usize>(matches, MAX_JOB_NEIGHBOURS_ARG_NAME, "max job neighbours")
+            .unwrap_or(default_options.max_job_neighbours),
+        compact_matrices: matches.is_present(COMPACT_MATRICES_ARG_NAME),
+    };
+    let formats = get_formats(reader_options);
 
     // required
     let problem_path = matches.value_of(PROBLEM_ARG_NAME).unwrap();
@@ -269,17 +345,33 @@ pub fn run_solve(matches: &ArgMatches) {
         }
     });
     let init_solution = matches.value_of(INIT_SOLUTION_ARG_NAME).map(|path| open_file(path, "init solution"));
+    let reference_solution =
+        matches.value_of(REFERENCE_SOLUTION_ARG_NAME).map(|path| open_file(path, "reference solution"));
     let config = matches.value_of(CONFIG_ARG_NAME).map(|path| open_file(path, "config"));
     let matrix_files = get_matrix_files(matches);
+
+    if is_check_requested && matrix_files.as_ref().map_or(false, |files| has_binary_matrices(files).unwrap_or(false)) {
+        eprintln!("cannot check solution: feasibility check is not supported with binary routing matrices");
+        process::exit(1);
+    }
+
+    if matches.is_present(CSV_ARG_NAME) && problem_format != "pragmatic" {
+        eprintln!("cannot write csv: csv output is supported only for pragmatic format");
+        process::exit(1);
+    }
+
     let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
     let out_geojson = matches.value_of(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
+    let out_csv = matches.value_of(CSV_ARG_NAME).map(|path| create_file(path, "out csv"));
     let is_get_locations_set = matches.is_present(GET_LOCATIONS_ARG_NAME);
     let seed = parse_int_value::<u64>(matches, RANDOM_SEED_NAME, "seed");
+    let decompose = parse_int_value::<usize>(matches, DECOMPOSE_ARG_NAME, "decompose");
 
     match formats.get(problem_format) {
         Some((problem_reader, init_reader, solution_writer, locations_writer)) => {
             let out_buffer = create_write_buffer(out_result);
             let geo_buffer = out_geojson.map(|geojson| create_write_buffer(Some(geojson)));
+            let csv_buffer = out_csv.map(|csv| create_write_buffer(Some(csv)));
 
             if is_get_locations_set {
                 locations_writer.0(problem_file, out_buffer).unwrap_or_else(|err| {
@@ -300,6 +392,17 @@ pub fn run_solve(matches: &ArgMatches) {
                                 .unwrap()
                         });
 
+                        if let Some(file) = reference_solution {
+                            init_reader.0(file, problem.clone())
+                                .and_then(|solution| set_stability_reference(problem.as_ref(), &solution))
+                                .unwrap_or_else(|err| {
+                                    eprintln!("cannot use reference solution '{}'", err);
+                                    process::exit(1);
+                                });
+                        } else if has_stability_objective(problem.as_ref()) {
+                            eprintln!("warning: plan stability objective has no effect without reference solution");
+                        }
+
                         let builder = if let Some(config) = config {
                             create_builder_from_config_file(problem.clone(), BufReader::new(config)).unwrap_or_else(
                                 |err| {
@@ -317,6 +420,7 @@ pub fn run_solve(matches: &ArgMatches) {
                         };
 
                         let (solution, _, metrics) = builder
+                            .with_decomposition(decompose)
                             .with_init_solutions(solutions)
                             .build()
                             .and_then(|solver| solver.solve())
@@ -325,7 +429,7 @@ pub fn run_solve(matches: &ArgMatches) {
                                 process::exit(1);
                             });
 
-                        solution_writer.0(&problem, solution, metrics, out_buffer, geo_buffer).unwrap();
+                        solution_writer.0(&problem, solution, metrics, out_buffer, geo_buffer, csv_buffer).unwrap();
 
                         if is_check_requested {
                             check_solution(matches);
@@ -345,6 +449,45 @@ pub fn run_solve(matches: &ArgMatches) {
     }
 }
 
+/// Checks whether routing matrices are specified in binary format. Mixing formats is not allowed.
+fn has_binary_matrices(matrices: &[File]) -> Result<bool, String> {
+    let is_binary = matrices
+        .iter()
+        .map(|mut file| {
+            let mut magic = [0_u8; 4];
+            let length = file.read(&mut magic).map_err(|err| err.to_string())?;
+            file.seek(SeekFrom::Start(0)).map_err(|err| err.to_string())?;
+
+            Ok(is_binary_matrix(&magic[..length]))
+        })
+        .collect::<Result<HashSet<_>, String>>()?;
+
+    if is_binary.len() > 1 {
+        Err("cannot mix json and binary routing matrices".to_string())
+    } else {
+        Ok(is_binary.contains(&true))
+    }
+}
+
+/// Reads routing matrices in binary format using memory mapped files.
+fn read_binary_matrices(matrices: Vec<File>) -> Result<Vec<BinaryMatrix>, Vec<FormatError>> {
+    matrices
+        .iter()
+        .map(|file| {
+            // NOTE file is expected to be unchanged while solver is running
+            let mmap = unsafe { Mmap::map(file) }.map_err(|err| {
+                vec![FormatError::new(
+                    "E0001".to_string(),
+                    "cannot deserialize matrix".to_string(),
+                    format!("cannot map binary matrix file: '{}'", err),
+                )]
+            })?;
+
+            deserialize_binary_matrix(mmap)
+        })
+        .collect()
+}
+
 fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
     matches
         .values_of(MATRIX_ARG_NAME)
@@ -361,9 +504,7 @@ fn check_solution(matches: &ArgMatches) {
         .map(|path| BufReader::new(open_file(path, "solution")))
         .expect("cannot read solution");
 
-    let matrix_files = matches
-        .values_of(MATRIX_ARG_NAME)
-        .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect());
+    let matrix_files = get_matrix_files(matches).map(|matrices| matrices.into_iter().map(BufReader::new).collect());
 
     let result = check_pragmatic_solution(problem_file, solution_file, matrix_files);
 
@@ -0,0 +1,49 @@
+//! A helper module which contains functionality to evaluate acceptance of new jobs into existing solution.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/accept/accept_test.rs"]
+mod accept_test;
+
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, Job, PragmaticProblem};
+use vrp_pragmatic::format::solution::{evaluate_job_acceptance, read_init_solution};
+use vrp_pragmatic::format::FormatError;
+
+/// Evaluates the cheapest insertion of new jobs into existing pragmatic solution and returns
+/// result serialized as json. Original problem should not contain new jobs.
+pub fn get_acceptance_serialized<F: Read>(
+    problem_reader: BufReader<F>,
+    solution_reader: BufReader<F>,
+    jobs_reader: BufReader<F>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+) -> Result<String, String> {
+    let mut problem = deserialize_problem(problem_reader)
+        .map_err(|errs| format!("cannot read problem: '{}'", FormatError::format_many(&errs, ",")))?;
+
+    let jobs: Vec<Job> =
+        serde_json::from_reader(jobs_reader).map_err(|err| format!("cannot read new jobs: '{}'", err))?;
+    let job_ids = jobs.iter().map(|job| job.id.clone()).collect::<Vec<_>>();
+    problem.plan.jobs.extend(jobs);
+
+    let matrices = matrices_readers
+        .map(|matrices| {
+            matrices
+                .into_iter()
+                .map(deserialize_matrix)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|errs| format!("cannot read matrix: '{}'", FormatError::format_many(&errs, ",")))
+        })
+        .transpose()?;
+
+    let core_problem = Arc::new(
+        (problem, matrices)
+            .read_pragmatic()
+            .map_err(|errs| format!("cannot read pragmatic problem: '{}'", FormatError::format_many(&errs, ",")))?,
+    );
+
+    let solution = read_init_solution(solution_reader, core_problem.clone())?;
+    let acceptance = evaluate_job_acceptance(core_problem, solution, job_ids.as_slice())?;
+
+    serde_json::to_string_pretty(&acceptance).map_err(|err| err.to_string())
+}
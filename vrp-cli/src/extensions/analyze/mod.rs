@@ -0,0 +1,34 @@
+//! A helper module which contains functionality to calculate key performance indicators of existing solution.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/analyze_test.rs"]
+mod analyze_test;
+
+use std::io::{BufReader, BufWriter, Read};
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::{analyze_solution, deserialize_solution, serialize_analytics_as_csv};
+use vrp_pragmatic::format::FormatError;
+
+/// Calculates key performance indicators of pragmatic solution and returns them serialized as json
+/// or as csv with one row per tour.
+pub fn get_analytics_serialized<F: Read>(
+    problem_reader: BufReader<F>,
+    solution_reader: BufReader<F>,
+    is_csv: bool,
+) -> Result<String, String> {
+    let problem = deserialize_problem(problem_reader)
+        .map_err(|errs| format!("cannot read problem: '{}'", FormatError::format_many(&errs, ",")))?;
+    let solution = deserialize_solution(solution_reader).map_err(|err| format!("cannot read solution: '{}'", err))?;
+
+    let analytics = analyze_solution(&problem, &solution)?;
+
+    if is_csv {
+        let mut buffer = String::new();
+        let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+        serialize_analytics_as_csv(writer, &analytics).map_err(|err| err.to_string())?;
+
+        Ok(buffer)
+    } else {
+        serde_json::to_string_pretty(&analytics).map_err(|err| err.to_string())
+    }
+}
@@ -0,0 +1,28 @@
+//! A helper module which contains functionality to compare two solutions of the same problem.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/diff/diff_test.rs"]
+mod diff_test;
+
+use std::io::{BufReader, Read};
+use vrp_pragmatic::format::solution::{compare_solutions, deserialize_solution};
+
+/// Compares two pragmatic solutions and returns their difference serialized as json or as
+/// human readable text.
+pub fn get_diff_serialized<F: Read>(
+    original_reader: BufReader<F>,
+    new_reader: BufReader<F>,
+    is_text: bool,
+) -> Result<String, String> {
+    let original =
+        deserialize_solution(original_reader).map_err(|err| format!("cannot read original solution: '{}'", err))?;
+    let new = deserialize_solution(new_reader).map_err(|err| format!("cannot read new solution: '{}'", err))?;
+
+    let diff = compare_solutions(&original, &new);
+
+    if is_text {
+        Ok(diff.to_string())
+    } else {
+        serde_json::to_string_pretty(&diff).map_err(|err| err.to_string())
+    }
+}
@@ -0,0 +1,55 @@
+//! A helper module which contains functionality to explain why jobs are unassigned in existing solution.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/explain/explain_test.rs"]
+mod explain_test;
+
+use std::io::{BufReader, BufWriter, Read};
+use std::sync::Arc;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem};
+use vrp_pragmatic::format::solution::{
+    deserialize_solution, explain_unassigned_jobs, read_init_solution, serialize_solution,
+};
+use vrp_pragmatic::format::FormatError;
+
+/// Enriches unassigned jobs of pragmatic solution with per vehicle shift explanations and returns
+/// solution serialized as json.
+pub fn get_explanation_serialized<F: Read>(
+    problem_reader: BufReader<F>,
+    mut solution_reader: BufReader<F>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+) -> Result<String, String> {
+    let problem = deserialize_problem(problem_reader)
+        .map_err(|errs| format!("cannot read problem: '{}'", FormatError::format_many(&errs, ",")))?;
+
+    let matrices = matrices_readers
+        .map(|matrices| {
+            matrices
+                .into_iter()
+                .map(deserialize_matrix)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|errs| format!("cannot read matrix: '{}'", FormatError::format_many(&errs, ",")))
+        })
+        .transpose()?;
+
+    let core_problem = Arc::new(
+        (problem, matrices)
+            .read_pragmatic()
+            .map_err(|errs| format!("cannot read pragmatic problem: '{}'", FormatError::format_many(&errs, ",")))?,
+    );
+
+    let mut solution_content = String::new();
+    solution_reader.read_to_string(&mut solution_content).map_err(|err| format!("cannot read solution: '{}'", err))?;
+
+    let solution = deserialize_solution(BufReader::new(solution_content.as_bytes()))
+        .map_err(|err| format!("cannot read solution: '{}'", err))?;
+    let core_solution = read_init_solution(BufReader::new(solution_content.as_bytes()), core_problem.clone())?;
+
+    let solution = explain_unassigned_jobs(core_problem, core_solution, solution);
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    serialize_solution(writer, &solution).map_err(|err| err.to_string())?;
+
+    Ok(buffer)
+}
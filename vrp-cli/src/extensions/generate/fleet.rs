@@ -30,11 +30,14 @@ pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize)
                 capacity: get_random_item(capacities.as_slice(), &rnd).expect("cannot find any capacity").clone(),
                 skills: get_random_item(skills.as_slice(), &rnd).expect("cannot find any skills").clone(),
                 limits: get_random_item(limits.as_slice(), &rnd).expect("cannot find any limits").clone(),
+                compartments: None,
+                named_capacity: None,
+                battery: None,
             }
         })
         .collect();
 
-    Ok(Fleet { vehicles, profiles })
+    Ok(Fleet { vehicles, profiles, dimensions: None })
 }
 
 fn get_from_vehicle<F, T>(problem_proto: &Problem, func: F) -> Vec<T>
@@ -52,7 +55,7 @@ fn get_vehicle_shifts(problem_proto: &Problem) -> Vec<Vec<VehicleShift>> {
     get_from_vehicle(problem_proto, |vehicle| vehicle.shifts.clone())
 }
 
-fn get_vehicle_capacities(problem_proto: &Problem) -> Vec<Vec<i32>> {
+fn get_vehicle_capacities(problem_proto: &Problem) -> Vec<Vec<f64>> {
     get_from_vehicle(problem_proto, |vehicle| vehicle.capacity.clone())
 }
 
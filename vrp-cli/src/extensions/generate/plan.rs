@@ -36,6 +36,7 @@ pub(crate) fn generate_plan(
                             location: get_location_fn(&rnd),
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
+                            vehicle_times: None,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -45,6 +46,7 @@ pub(crate) fn generate_plan(
                     },
 
                     tag: None,
+                    named_demand: None,
                 })
                 .collect::<Vec<_>>()
         })
@@ -65,7 +67,11 @@ pub(crate) fn generate_plan(
                 replacements: generate_tasks(&job_proto.replacements, false),
                 services: generate_tasks(&job_proto.services, true),
                 priority: job_proto.priority,
+                value: job_proto.value,
                 skills: job_proto.skills.clone(),
+                order: None,
+                split: None,
+                goods: None,
             }
         })
         .collect();
@@ -153,7 +159,7 @@ fn get_plan_time_windows(plan: &Plan) -> Vec<Vec<Vec<String>>> {
     get_plan_places(&plan).flat_map(|job_place| job_place.times.iter()).cloned().collect()
 }
 
-fn get_plan_demands(plan: &Plan) -> Vec<Vec<i32>> {
+fn get_plan_demands(plan: &Plan) -> Vec<Vec<f64>> {
     plan.jobs
         .iter()
         .flat_map(|job| get_job_tasks(job))
@@ -14,7 +14,7 @@ mod actual {
     use std::collections::{HashMap, HashSet};
     use std::error::Error;
     use std::io::{BufReader, Read};
-    use std::ops::Deref;
+    use std::str::FromStr;
     use vrp_pragmatic::format::problem::*;
     use vrp_pragmatic::format::{FormatError, Location};
 
@@ -24,10 +24,16 @@ mod actual {
         id: String,
         lat: f64,
         lng: f64,
-        demand: i32,
+        demand: String,
         duration: usize,
         tw_start: Option<String>,
         tw_end: Option<String>,
+        #[serde(rename = "TYPE", default)]
+        activity_type: Option<String>,
+        #[serde(default)]
+        skills: Option<String>,
+        #[serde(default)]
+        priority: Option<i32>,
     }
 
     #[derive(Debug, Deserialize)]
@@ -36,47 +42,201 @@ mod actual {
         id: String,
         lat: f64,
         lng: f64,
-        capacity: i32,
+        capacity: String,
         tw_start: String,
-        tw_end: String,
+        tw_end: Option<String>,
         amount: usize,
         profile: String,
+        #[serde(default)]
+        end_lat: Option<f64>,
+        #[serde(default)]
+        end_lng: Option<f64>,
+        #[serde(default)]
+        fixed_cost: Option<f64>,
+        #[serde(default)]
+        distance_cost: Option<f64>,
+        #[serde(default)]
+        time_cost: Option<f64>,
+        #[serde(default)]
+        skills: Option<String>,
+        #[serde(default)]
+        break_tw_start: Option<String>,
+        #[serde(default)]
+        break_tw_end: Option<String>,
+        #[serde(default)]
+        break_duration: Option<usize>,
+    }
+
+    /// Specifies job activity type modeled by a single csv row.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum ActivityType {
+        Pickup,
+        Delivery,
+        Service,
+    }
+
+    /// A job task read from a single csv row.
+    struct CsvTask {
+        line: u64,
+        activity_type: ActivityType,
+        task: JobTask,
+        skills: Option<Vec<String>>,
+        priority: Option<i32>,
     }
 
-    fn read_csv_entries<T, R: Read>(reader: BufReader<R>) -> Result<Vec<T>, Box<dyn Error>>
+    /// Reads csv entries together with line numbers they are defined at.
+    fn read_csv_entries<T, R: Read>(reader: BufReader<R>) -> Result<Vec<(u64, T)>, Box<dyn Error>>
     where
         for<'de> T: Deserialize<'de>,
     {
         let mut reader = csv::Reader::from_reader(reader);
+        let headers = reader.headers()?.clone();
         let mut entries = vec![];
 
-        for entry in reader.deserialize() {
-            entries.push(entry?);
+        for record in reader.records() {
+            let record = record?;
+            let line = record.position().map_or(0, |position| position.line());
+            entries.push((line, record.deserialize(Some(&headers))?));
         }
 
         Ok(entries)
     }
 
-    fn parse_tw(start: Option<String>, end: Option<String>) -> Option<Vec<String>> {
+    fn create_row_error(line: u64, message: String) -> Box<dyn Error> {
+        format!("line {}: {}", line, message).into()
+    }
+
+    /// Parses space separated list of values.
+    fn parse_list<T: FromStr>(line: u64, column: &str, value: &str) -> Result<Vec<T>, Box<dyn Error>> {
+        value
+            .split_whitespace()
+            .map(|item| {
+                item.parse::<T>()
+                    .map_err(|_| create_row_error(line, format!("cannot parse '{}' in {} column", item, column)))
+            })
+            .collect()
+    }
+
+    fn parse_skills(skills: &Option<String>) -> Option<Vec<String>> {
+        skills
+            .as_ref()
+            .map(|skills| skills.split_whitespace().map(|skill| skill.to_string()).collect::<Vec<_>>())
+            .filter(|skills| !skills.is_empty())
+    }
+
+    /// Parses time windows defined as space separated lists of starts and ends.
+    fn parse_time_windows(
+        line: u64,
+        start: &Option<String>,
+        end: &Option<String>,
+    ) -> Result<Option<Vec<Vec<String>>>, Box<dyn Error>> {
         match (start, end) {
-            (Some(start), Some(end)) => Some(vec![start, end]),
-            _ => None,
+            (Some(start), Some(end)) => {
+                let starts = parse_list::<String>(line, "TW_START", start)?;
+                let ends = parse_list::<String>(line, "TW_END", end)?;
+
+                if starts.len() != ends.len() {
+                    return Err(create_row_error(
+                        line,
+                        format!("TW_START has {} values, but TW_END has {}", starts.len(), ends.len()),
+                    ));
+                }
+
+                Ok(Some(starts.into_iter().zip(ends).map(|(start, end)| vec![start, end]).collect()))
+            }
+            (None, None) => Ok(None),
+            _ => Err(create_row_error(line, "both TW_START and TW_END should be specified".to_string())),
         }
     }
 
-    fn read_jobs<R: Read>(reader: BufReader<R>) -> Result<Vec<Job>, Box<dyn Error>> {
-        let get_task = |job: &CsvJob| JobTask {
-            places: vec![JobPlace {
-                location: Location::Coordinate { lat: job.lat, lng: job.lng },
-                duration: job.duration as f64 * 60.,
-                times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
-            }],
-            demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
-            tag: None,
+    fn read_task(line: u64, job: &CsvJob) -> Result<CsvTask, Box<dyn Error>> {
+        let demand = parse_list::<f64>(line, "DEMAND", job.demand.as_str())?;
+
+        let inferred_type = match (demand.iter().any(|&value| value > 0.), demand.iter().any(|&value| value < 0.)) {
+            (true, true) => return Err(create_row_error(line, "DEMAND has values with different sign".to_string())),
+            (true, false) => Some(ActivityType::Pickup),
+            (false, true) => Some(ActivityType::Delivery),
+            (false, false) => None,
+        };
+
+        let activity_type = match (job.activity_type.as_deref(), inferred_type) {
+            (None, inferred_type) => inferred_type.unwrap_or(ActivityType::Service),
+            (Some(value), inferred_type) => {
+                let activity_type = match value {
+                    "pickup" => ActivityType::Pickup,
+                    "delivery" => ActivityType::Delivery,
+                    "service" => ActivityType::Service,
+                    _ => {
+                        return Err(create_row_error(
+                            line,
+                            format!("unknown TYPE '{}', expected pickup, delivery or service", value),
+                        ))
+                    }
+                };
+
+                if activity_type == ActivityType::Service && inferred_type.is_some() {
+                    return Err(create_row_error(line, "service cannot have non-zero DEMAND".to_string()));
+                }
+
+                activity_type
+            }
         };
 
-        let get_tasks = |jobs: &Vec<&CsvJob>, filter: Box<dyn Fn(&CsvJob) -> bool>| {
-            let tasks = jobs.iter().filter(|j| filter.deref()(j)).map(|job| get_task(job)).collect::<Vec<_>>();
+        Ok(CsvTask {
+            line,
+            activity_type,
+            task: JobTask {
+                places: vec![JobPlace {
+                    location: Location::Coordinate { lat: job.lat, lng: job.lng },
+                    duration: job.duration as f64 * 60.,
+                    times: parse_time_windows(line, &job.tw_start, &job.tw_end)?,
+                    vehicle_times: None,
+                }],
+                demand: if demand.iter().any(|&value| value != 0.) {
+                    Some(demand.iter().map(|value| value.abs()).collect())
+                } else {
+                    None
+                },
+                tag: None,
+                named_demand: None,
+            },
+            skills: parse_skills(&job.skills),
+            priority: job.priority,
+        })
+    }
+
+    /// Returns a job property which should be the same in all job rows, if specified.
+    fn get_job_property<T: PartialEq>(
+        job_id: &str,
+        column: &str,
+        tasks: &[CsvTask],
+        property_fn: impl Fn(&CsvTask) -> Option<T>,
+    ) -> Result<Option<T>, Box<dyn Error>> {
+        let mut values = tasks.iter().filter_map(|task| property_fn(task).map(|value| (task.line, value)));
+
+        let first = match values.next() {
+            Some((_, value)) => value,
+            None => return Ok(None),
+        };
+
+        if let Some((line, _)) = values.find(|(_, value)| *value != first) {
+            return Err(create_row_error(line, format!("job '{}' has different {} values", job_id, column)));
+        }
+
+        Ok(Some(first))
+    }
+
+    fn create_job(job_id: String, tasks: Vec<CsvTask>) -> Result<Job, Box<dyn Error>> {
+        let skills = get_job_property(&job_id, "SKILLS", tasks.as_slice(), |task| task.skills.clone())?;
+        let priority = get_job_property(&job_id, "PRIORITY", tasks.as_slice(), |task| task.priority)?;
+
+        let get_tasks = |activity_type: ActivityType| {
+            let tasks = tasks
+                .iter()
+                .filter(|task| task.activity_type == activity_type)
+                .map(|task| task.task.clone())
+                .collect::<Vec<_>>();
+
             if tasks.is_empty() {
                 None
             } else {
@@ -84,57 +244,125 @@ mod actual {
             }
         };
 
-        let jobs = read_csv_entries::<CsvJob, _>(reader)?
-            .iter()
-            .fold(HashMap::new(), |mut acc, job| {
-                acc.entry(&job.id).or_insert_with(Vec::new).push(job);
-                acc
-            })
+        Ok(Job {
+            pickups: get_tasks(ActivityType::Pickup),
+            deliveries: get_tasks(ActivityType::Delivery),
+            replacements: None,
+            services: get_tasks(ActivityType::Service),
+            priority,
+            value: None,
+            skills,
+            order: None,
+            split: None,
+            goods: None,
+            id: job_id,
+        })
+    }
+
+    fn read_jobs<R: Read>(reader: BufReader<R>) -> Result<Vec<Job>, Box<dyn Error>> {
+        let mut job_ids = vec![];
+        let mut job_tasks = HashMap::<String, Vec<CsvTask>>::new();
+
+        for (line, job) in read_csv_entries::<CsvJob, _>(reader)? {
+            let task = read_task(line, &job)?;
+            job_tasks
+                .entry(job.id.clone())
+                .or_insert_with(|| {
+                    job_ids.push(job.id.clone());
+                    Vec::new()
+                })
+                .push(task);
+        }
+
+        job_ids
             .into_iter()
-            .map(|(job_id, tasks)| Job {
-                id: job_id.clone(),
-                pickups: get_tasks(&tasks, Box::new(|j| j.demand > 0)),
-                deliveries: get_tasks(&tasks, Box::new(|j| j.demand < 0)),
-                replacements: None,
-                services: get_tasks(&tasks, Box::new(|j| j.demand == 0)),
-                priority: None,
-                skills: None,
+            .map(|job_id| {
+                let tasks = job_tasks.remove(&job_id).unwrap_or_default();
+                create_job(job_id, tasks)
             })
-            .collect();
+            .collect()
+    }
+
+    fn read_break(line: u64, vehicle: &CsvVehicle) -> Result<Option<VehicleBreak>, Box<dyn Error>> {
+        match (&vehicle.break_tw_start, &vehicle.break_tw_end, vehicle.break_duration) {
+            (Some(start), Some(end), Some(duration)) => Ok(Some(VehicleBreak {
+                time: VehicleBreakTime::TimeWindow(vec![start.clone(), end.clone()]),
+                duration: duration as f64 * 60.,
+                locations: None,
+            })),
+            (None, None, None) => Ok(None),
+            _ => Err(create_row_error(
+                line,
+                "BREAK_TW_START, BREAK_TW_END and BREAK_DURATION should be specified together".to_string(),
+            )),
+        }
+    }
+
+    fn read_shift_end(
+        line: u64,
+        vehicle: &CsvVehicle,
+        depot_location: &Location,
+    ) -> Result<Option<ShiftEnd>, Box<dyn Error>> {
+        let location = match (vehicle.end_lat, vehicle.end_lng) {
+            (Some(lat), Some(lng)) => Some(Location::Coordinate { lat, lng }),
+            (None, None) => None,
+            _ => return Err(create_row_error(line, "both END_LAT and END_LNG should be specified".to_string())),
+        };
 
-        Ok(jobs)
+        match (vehicle.tw_end.clone(), location) {
+            (Some(latest), location) => Ok(Some(ShiftEnd {
+                earliest: None,
+                latest,
+                location: location.unwrap_or_else(|| depot_location.clone()),
+            })),
+            (None, None) => Ok(None),
+            (None, Some(_)) => Err(create_row_error(line, "TW_END should be specified for shift end".to_string())),
+        }
     }
 
     fn read_vehicles<R: Read>(reader: BufReader<R>) -> Result<Vec<VehicleType>, Box<dyn Error>> {
-        let vehicles = read_csv_entries::<CsvVehicle, _>(reader)?
+        read_csv_entries::<CsvVehicle, _>(reader)?
             .into_iter()
-            .map(|vehicle| {
+            .map(|(line, vehicle)| {
                 let depot_location = Location::Coordinate { lat: vehicle.lat, lng: vehicle.lng };
+                let capacity = parse_list::<f64>(line, "CAPACITY", vehicle.capacity.as_str())?;
+
+                if capacity.is_empty() {
+                    return Err(create_row_error(line, "CAPACITY should have at least one value".to_string()));
+                }
 
-                VehicleType {
+                let end = read_shift_end(line, &vehicle, &depot_location)?;
+                let breaks = read_break(line, &vehicle)?.map(|vehicle_break| vec![vehicle_break]);
+
+                Ok(VehicleType {
                     type_id: vehicle.id.clone(),
-                    vehicle_ids: (1..vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
-                    profile: vehicle.profile,
-                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                    vehicle_ids: (1..=vehicle.amount).map(|seq| format!("{}_{}", vehicle.id, seq)).collect(),
+                    profile: vehicle.profile.clone(),
+                    costs: VehicleCosts {
+                        fixed: Some(vehicle.fixed_cost.unwrap_or(25.)),
+                        distance: vehicle.distance_cost.unwrap_or(0.0002),
+                        time: vehicle.time_cost.unwrap_or(0.005),
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
-                            earliest: vehicle.tw_start,
+                            earliest: vehicle.tw_start.clone(),
                             latest: None,
-                            location: depot_location.clone(),
+                            location: depot_location,
                         },
-                        end: Some(ShiftEnd { earliest: None, latest: vehicle.tw_end, location: depot_location }),
+                        end,
                         depots: None,
-                        breaks: None,
+                        breaks,
                         reloads: None,
                     }],
-                    capacity: vec![vehicle.capacity],
-                    skills: None,
+                    capacity,
+                    skills: parse_skills(&vehicle.skills),
                     limits: None,
-                }
+                    compartments: None,
+                    named_capacity: None,
+                    battery: None,
+                })
             })
-            .collect();
-
-        Ok(vehicles)
+            .collect()
     }
 
     fn create_format_error(entity: &str, error: Box<dyn Error>) -> FormatError {
@@ -163,6 +391,7 @@ mod actual {
                     .into_iter()
                     .map(|p| Profile { name: p.clone(), profile_type: p, speed: None })
                     .collect(),
+                dimensions: None,
             },
             objectives: None,
             config: None,
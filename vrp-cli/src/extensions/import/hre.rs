@@ -90,7 +90,7 @@ mod models {
         /// Job places.
         pub places: JobPlaces,
         /// Job demand.
-        pub demand: Vec<i32>,
+        pub demand: Vec<f64>,
         /// Job priority, bigger value - less important.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub priority: Option<i32>,
@@ -110,7 +110,7 @@ mod models {
         /// Sub job duration (service time).
         pub duration: f64,
         /// Sub job demand.
-        pub demand: Vec<i32>,
+        pub demand: Vec<f64>,
         /// An tag which will be propagated back within corresponding activity in solution.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub tag: Option<String>,
@@ -256,7 +256,7 @@ mod models {
         /// Vehicle shifts.
         pub shifts: Vec<VehicleShift>,
         /// Vehicle capacity.
-        pub capacity: Vec<i32>,
+        pub capacity: Vec<f64>,
         /// Vehicle amount.
         pub amount: i32,
 
@@ -333,9 +333,11 @@ mod deserialize {
                 location: to_pragmatic_loc(&place.location),
                 duration: place.duration,
                 times: place.times.clone(),
+                vehicle_times: None,
             }],
             demand: Some(job.demand.clone()),
             tag: place.tag.clone(),
+            named_demand: None,
         };
 
         let multi_job_place_mapper = |places: &Vec<models::MultiJobPlace>| {
@@ -350,9 +352,11 @@ mod deserialize {
                                 location: to_pragmatic_loc(&place.location),
                                 duration: place.duration,
                                 times: place.times.clone(),
+                                vehicle_times: None,
                             }],
                             demand: Some(place.demand.clone()),
                             tag: place.tag.clone(),
+                            named_demand: None,
                         })
                         .collect(),
                 )
@@ -371,7 +375,11 @@ mod deserialize {
                         replacements: None,
                         services: None,
                         priority: job.priority.as_ref().copied(),
+                        value: None,
                         skills: job.skills.clone(),
+                        order: None,
+                        split: None,
+                        goods: None,
                     },
                     models::JobVariant::Multi(job) => Job {
                         id: job.id.clone(),
@@ -380,7 +388,11 @@ mod deserialize {
                         replacements: None,
                         services: None,
                         priority: job.priority.as_ref().copied(),
+                        value: None,
                         skills: job.skills.clone(),
+                        order: None,
+                        split: None,
+                        goods: None,
                     },
                 })
                 .collect(),
@@ -466,7 +478,14 @@ mod deserialize {
                         max_distance: l.max_distance,
                         shift_time: l.shift_time,
                         allowed_areas: None,
+                        forbidden_areas: None,
+                        area_costs: None,
+                        max_jobs: None,
+                        max_stops: None,
                     }),
+                    compartments: None,
+                    named_capacity: None,
+                    battery: None,
                 })
                 .collect(),
             profiles: fleet
@@ -474,6 +493,7 @@ mod deserialize {
                 .iter()
                 .map(|p| Profile { name: p.name.clone(), profile_type: p.profile_type.clone(), speed: None })
                 .collect(),
+            dimensions: None,
         })
     }
 
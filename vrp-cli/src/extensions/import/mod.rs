@@ -15,7 +15,10 @@ pub fn import_problem<R: Read>(input_format: &str, readers: Option<Vec<BufReader
         ("csv", Some(mut readers)) if readers.len() == 2 => {
             let jobs = readers.swap_remove(0);
             let vehicles = readers.swap_remove(0);
-            read_csv_problem(jobs, vehicles).map_err(|err| format!("cannot read csv: {}", err))
+            read_csv_problem(jobs, vehicles).map_err(|err| {
+                let details = err.details.as_ref().map_or_else(String::new, |details| format!(" {}", details));
+                format!("cannot read csv: {}{}", err, details)
+            })
         }
         ("csv", _) => Err("csv format expects two files with jobs and vehicles as an input".to_string()),
         ("hre", Some(mut readers)) if readers.len() == 1 => {
@@ -1,9 +1,21 @@
 //! Module provides various helper functionality.
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod accept;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod analyze;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod check;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod diff;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod explain;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod generate;
 
 pub mod import;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod report;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod serve;
 pub mod solve;
@@ -0,0 +1,33 @@
+//! A helper module which contains functionality to render a visual report of existing solution.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/report/report_test.rs"]
+mod report_test;
+
+use std::io::{BufReader, BufWriter, Read};
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::{deserialize_solution, serialize_solution_as_html, serialize_solution_as_svg};
+use vrp_pragmatic::format::FormatError;
+
+/// Renders pragmatic solution as a self-contained html page or svg image with tour map and timeline.
+pub fn get_report_serialized<F: Read>(
+    problem_reader: BufReader<F>,
+    solution_reader: BufReader<F>,
+    is_svg: bool,
+) -> Result<String, String> {
+    let problem = deserialize_problem(problem_reader)
+        .map_err(|errs| format!("cannot read problem: '{}'", FormatError::format_many(&errs, ",")))?;
+    let solution = deserialize_solution(solution_reader).map_err(|err| format!("cannot read solution: '{}'", err))?;
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    if is_svg {
+        serialize_solution_as_svg(writer, &problem, &solution)
+    } else {
+        serialize_solution_as_html(writer, &problem, &solution)
+    }
+    .map_err(|err| err.to_string())?;
+
+    Ok(buffer)
+}
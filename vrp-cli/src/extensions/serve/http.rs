@@ -0,0 +1,113 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/serve/http_test.rs"]
+mod http_test;
+
+use std::io::{BufRead, Read, Write};
+
+/// Represents HTTP request.
+pub struct Request {
+    /// Request method.
+    pub method: String,
+    /// Request path without query string.
+    pub path: String,
+    /// Request body.
+    pub body: Vec<u8>,
+}
+
+/// Represents HTTP response with json body.
+pub struct Response {
+    /// Status code.
+    pub status: u16,
+    /// Response body.
+    pub body: String,
+}
+
+impl Response {
+    /// Creates a new response with json body.
+    pub fn json(status: u16, body: String) -> Self {
+        Self { status, body }
+    }
+
+    /// Creates a new response with error message.
+    pub fn error(status: u16, message: &str) -> Self {
+        Self { status, body: serde_json::json!({ "error": message }).to_string() }
+    }
+}
+
+/// Reads HTTP request. Only requests with body length specified by `Content-Length` are supported,
+/// body cannot be larger than `max_body_size` bytes.
+pub fn read_request<R: BufRead>(reader: &mut R, max_body_size: usize) -> Result<Request, String> {
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(|err| err.to_string())?;
+
+    let mut parts = line.split_whitespace();
+    let (method, target) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(method), Some(target), Some(_)) => (method.to_string(), target),
+        _ => return Err(format!("invalid request line: '{}'", line.trim())),
+    };
+    let path = target.split('?').next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).map_err(|err| err.to_string())? == 0 {
+            break;
+        }
+
+        let header = line.trim();
+        if header.is_empty() {
+            break;
+        }
+
+        let mut parts = header.splitn(2, ':');
+        if let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length =
+                    value.trim().parse::<usize>().map_err(|err| format!("invalid content length: {}", err))?;
+            }
+        }
+    }
+
+    if content_length > max_body_size {
+        return Err(format!("request body is too large: {} bytes, max is {} bytes", content_length, max_body_size));
+    }
+
+    // NOTE body is read incrementally, so memory is allocated only for received data
+    let mut body = Vec::new();
+    reader.take(content_length as u64).read_to_end(&mut body).map_err(|err| err.to_string())?;
+
+    if body.len() != content_length {
+        return Err(format!("unexpected end of request body: {} of {} bytes", body.len(), content_length));
+    }
+
+    Ok(Request { method, path, body })
+}
+
+/// Writes HTTP response and closes connection.
+pub fn write_response<W: Write>(writer: &mut W, response: &Response) -> Result<(), String> {
+    write!(
+        writer,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        get_reason_phrase(response.status),
+        response.body.len(),
+        response.body
+    )
+    .and_then(|_| writer.flush())
+    .map_err(|err| err.to_string())
+}
+
+fn get_reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        410 => "Gone",
+        422 => "Unprocessable Entity",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Unknown",
+    }
+}
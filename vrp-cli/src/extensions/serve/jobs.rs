@@ -0,0 +1,221 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/serve/jobs_test.rs"]
+mod jobs_test;
+
+use serde::Serialize;
+use std::any::Any;
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use vrp_core::construction::Quota;
+
+/// Specifies status of submitted job.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    /// Job is waiting for a free worker.
+    Queued,
+    /// Job is being processed.
+    Running,
+    /// Job is processed successfully.
+    Completed,
+    /// Job is processed with error.
+    Failed,
+    /// Job is cancelled.
+    Cancelled,
+}
+
+/// Keeps state of submitted job.
+#[derive(Clone, Debug)]
+pub struct JobState {
+    /// Job status.
+    pub status: JobStatus,
+    /// Job result, available once job is completed or failed. Solution is shared to avoid copying it.
+    pub result: Option<Result<Arc<String>, String>>,
+}
+
+/// A task to be run by worker. Given quota is reached when task is cancelled.
+pub type JobTask = Box<dyn FnOnce(Arc<dyn Quota + Send + Sync>) -> Result<String, String> + Send>;
+
+struct JobEntry {
+    state: JobState,
+    is_cancelled: Arc<AtomicBool>,
+    finished_at: Option<Instant>,
+}
+
+type JobMap = Arc<Mutex<HashMap<String, JobEntry>>>;
+
+/// A quota which is reached when job is cancelled.
+struct CancellationQuota {
+    is_cancelled: Arc<AtomicBool>,
+}
+
+impl Quota for CancellationQuota {
+    fn is_reached(&self) -> bool {
+        self.is_cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// A pool of workers which processes submitted jobs in background. Finished jobs are kept
+/// for specified time and removed afterwards.
+pub struct WorkerPool {
+    jobs: JobMap,
+    result_ttl: Duration,
+    sender: Mutex<Option<SyncSender<(String, JobTask)>>>,
+    counter: AtomicUsize,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl WorkerPool {
+    /// Creates a new instance of `WorkerPool` with given amount of workers, max amount of queued
+    /// jobs and time to keep finished jobs.
+    pub fn new(workers: usize, max_queue_size: usize, result_ttl: Duration) -> Self {
+        let jobs: JobMap = Default::default();
+        let (sender, receiver) = sync_channel::<(String, JobTask)>(max_queue_size.max(1));
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..workers.max(1))
+            .map(|_| {
+                let jobs = jobs.clone();
+                let receiver = receiver.clone();
+                thread::spawn(move || run_worker(jobs, receiver))
+            })
+            .collect();
+
+        Self { jobs, result_ttl, sender: Mutex::new(Some(sender)), counter: AtomicUsize::new(0), workers }
+    }
+
+    /// Submits a new job and returns its id. Returns error when job queue is full.
+    pub fn submit(&self, task: JobTask) -> Result<String, String> {
+        let id = (self.counter.fetch_add(1, Ordering::Relaxed) + 1).to_string();
+
+        {
+            let mut jobs = self.jobs.lock().unwrap();
+            self.remove_expired(&mut jobs);
+            jobs.insert(
+                id.clone(),
+                JobEntry {
+                    state: JobState { status: JobStatus::Queued, result: None },
+                    is_cancelled: Arc::new(AtomicBool::new(false)),
+                    finished_at: None,
+                },
+            );
+        }
+
+        let sender = self.sender.lock().unwrap();
+        let result = sender.as_ref().ok_or_else(|| "worker pool is stopped".to_string()).and_then(|sender| {
+            sender.try_send((id.clone(), task)).map_err(|err| match err {
+                TrySendError::Full(_) => "job queue is full".to_string(),
+                TrySendError::Disconnected(_) => "worker pool is stopped".to_string(),
+            })
+        });
+
+        if result.is_err() {
+            self.jobs.lock().unwrap().remove(&id);
+        }
+
+        result.map(|_| id)
+    }
+
+    /// Returns status of the job with given id.
+    pub fn get_status(&self, id: &str) -> Option<JobStatus> {
+        let mut jobs = self.jobs.lock().unwrap();
+        self.remove_expired(&mut jobs);
+
+        jobs.get(id).map(|entry| entry.state.status.clone())
+    }
+
+    /// Returns state of the job with given id.
+    pub fn get(&self, id: &str) -> Option<JobState> {
+        let mut jobs = self.jobs.lock().unwrap();
+        self.remove_expired(&mut jobs);
+
+        jobs.get(id).map(|entry| entry.state.clone())
+    }
+
+    /// Cancels queued or running job. Finished job is removed from the pool.
+    /// Returns job status after the operation.
+    pub fn cancel(&self, id: &str) -> Option<JobStatus> {
+        let mut jobs = self.jobs.lock().unwrap();
+        let entry = jobs.get_mut(id)?;
+
+        match entry.state.status {
+            JobStatus::Queued => {
+                entry.is_cancelled.store(true, Ordering::Relaxed);
+                entry.state.status = JobStatus::Cancelled;
+                entry.finished_at = Some(Instant::now());
+                Some(JobStatus::Cancelled)
+            }
+            // NOTE running job is marked as cancelled once solver reacts on quota
+            JobStatus::Running => {
+                entry.is_cancelled.store(true, Ordering::Relaxed);
+                Some(JobStatus::Running)
+            }
+            _ => jobs.remove(id).map(|entry| entry.state.status),
+        }
+    }
+
+    fn remove_expired(&self, jobs: &mut HashMap<String, JobEntry>) {
+        let result_ttl = self.result_ttl;
+        jobs.retain(|_, entry| entry.finished_at.map_or(true, |finished_at| finished_at.elapsed() < result_ttl));
+    }
+}
+
+impl Drop for WorkerPool {
+    fn drop(&mut self) {
+        self.sender.lock().unwrap().take();
+        self.workers.drain(0..).for_each(|worker| {
+            let _ = worker.join();
+        });
+    }
+}
+
+fn run_worker(jobs: JobMap, receiver: Arc<Mutex<Receiver<(String, JobTask)>>>) {
+    loop {
+        let message = receiver.lock().unwrap().recv();
+        let (id, task) = match message {
+            Ok(message) => message,
+            Err(_) => break,
+        };
+
+        let is_cancelled = {
+            let mut jobs = jobs.lock().unwrap();
+            match jobs.get_mut(&id) {
+                Some(entry) if entry.state.status == JobStatus::Queued => {
+                    entry.state.status = JobStatus::Running;
+                    entry.is_cancelled.clone()
+                }
+                _ => continue,
+            }
+        };
+
+        let quota: Arc<dyn Quota + Send + Sync> = Arc::new(CancellationQuota { is_cancelled: is_cancelled.clone() });
+        let result = panic::catch_unwind(AssertUnwindSafe(|| task(quota)))
+            .unwrap_or_else(|err| Err(get_panic_message(err.as_ref())));
+
+        if let Some(entry) = jobs.lock().unwrap().get_mut(&id) {
+            entry.finished_at = Some(Instant::now());
+            entry.state = if is_cancelled.load(Ordering::Relaxed) {
+                JobState { status: JobStatus::Cancelled, result: None }
+            } else {
+                let status = if result.is_ok() { JobStatus::Completed } else { JobStatus::Failed };
+                JobState { status, result: Some(result.map(Arc::new)) }
+            };
+        }
+    }
+}
+
+/// Returns a message of caught panic.
+pub(crate) fn get_panic_message(err: &(dyn Any + Send)) -> String {
+    let message = err
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| err.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown error".to_string());
+
+    format!("fatal: {}", message)
+}
@@ -0,0 +1,246 @@
+//! Provides a simple HTTP server which exposes solver functionality as a json api.
+//!
+//! The following endpoints are supported:
+//!
+//! - `POST /solve` submits a problem in `pragmatic` format for solving and returns job id
+//! - `GET /jobs/{id}` returns job status
+//! - `GET /jobs/{id}/result` returns solution once job is completed
+//! - `DELETE /jobs/{id}` cancels queued or running job, removes finished job
+//! - `POST /check` checks solution feasibility
+//! - `POST /locations` returns unique locations of the problem
+//! - `POST /convert/{format}` converts problem from given format to `pragmatic`
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/serve/serve_test.rs"]
+mod serve_test;
+
+mod http;
+pub use self::http::*;
+
+mod jobs;
+pub use self::jobs::*;
+
+use crate::extensions::import::import_problem;
+use crate::extensions::solve::config::Config;
+use crate::{get_locations_serialized, get_solution_serialized_with_quota};
+use serde::Deserialize;
+use std::io::{BufReader, BufWriter};
+use std::net::{TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use vrp_pragmatic::checker::CheckerContext;
+use vrp_pragmatic::format::problem::{serialize_problem, Matrix, PragmaticProblem, Problem};
+use vrp_pragmatic::format::solution::Solution;
+use vrp_pragmatic::format::FormatError;
+
+/// Specifies server configuration.
+pub struct ServerConfig {
+    /// An address to listen on, e.g. `127.0.0.1:8000`.
+    pub address: String,
+    /// Amount of workers which solve problems in parallel.
+    pub workers: usize,
+    /// Max amount of connections handled at the same time, extra ones are rejected.
+    pub max_connections: usize,
+    /// Max size of request body in bytes.
+    pub max_body_size: usize,
+    /// Max amount of jobs waiting for a free worker, extra ones are rejected.
+    pub max_queue_size: usize,
+    /// Timeout for reading request and writing response.
+    pub io_timeout: Duration,
+    /// Time to keep results of finished jobs.
+    pub result_ttl: Duration,
+}
+
+#[derive(Deserialize)]
+struct SolveRequest {
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    config: Option<Config>,
+}
+
+#[derive(Deserialize)]
+struct CheckRequest {
+    problem: Problem,
+    solution: Solution,
+    matrices: Option<Vec<Matrix>>,
+}
+
+#[derive(Deserialize)]
+struct ConvertRequest {
+    inputs: Vec<String>,
+}
+
+/// Runs server which listens for incoming connections until process is terminated.
+pub fn run_server(config: &ServerConfig) -> Result<(), String> {
+    let listener = TcpListener::bind(config.address.as_str())
+        .map_err(|err| format!("cannot listen on '{}': '{}'", config.address, err))?;
+    let pool = Arc::new(WorkerPool::new(config.workers, config.max_queue_size, config.result_ttl));
+    let connections = Arc::new(AtomicUsize::new(0));
+    let max_body_size = config.max_body_size;
+    let io_timeout = config.io_timeout;
+
+    listener.incoming().for_each(|stream| match stream {
+        Ok(stream) => {
+            if connections.fetch_add(1, Ordering::SeqCst) >= config.max_connections {
+                connections.fetch_sub(1, Ordering::SeqCst);
+                let mut writer = &stream;
+                let _ = write_response(&mut writer, &Response::error(503, "too many connections"));
+                return;
+            }
+
+            // NOTE without timeouts, slow or idle clients keep connection slots forever
+            if let Err(err) =
+                stream.set_read_timeout(Some(io_timeout)).and_then(|_| stream.set_write_timeout(Some(io_timeout)))
+            {
+                connections.fetch_sub(1, Ordering::SeqCst);
+                eprintln!("cannot set connection timeouts: '{}'", err);
+                return;
+            }
+
+            let pool = pool.clone();
+            let connections = connections.clone();
+            thread::spawn(move || {
+                handle_connection(pool.as_ref(), stream, max_body_size);
+                connections.fetch_sub(1, Ordering::SeqCst);
+            });
+        }
+        Err(err) => eprintln!("cannot accept connection: '{}'", err),
+    });
+
+    Ok(())
+}
+
+/// Handles request and returns response.
+pub fn handle_request(pool: &WorkerPool, request: &Request) -> Response {
+    let segments = request.path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("POST", ["solve"]) => submit_solve(pool, request.body.as_slice()),
+        ("GET", ["jobs", id]) => get_job_status(pool, id),
+        ("GET", ["jobs", id, "result"]) => get_job_result(pool, id),
+        ("DELETE", ["jobs", id]) => cancel_job(pool, id),
+        ("POST", ["check"]) => check_solution(request.body.as_slice()),
+        ("POST", ["locations"]) => get_locations(request.body.as_slice()),
+        ("POST", ["convert", format]) => convert_problem(format, request.body.as_slice()),
+        (_, ["solve"]) | (_, ["jobs", _]) | (_, ["jobs", _, "result"]) | (_, ["check"]) | (_, ["locations"]) => {
+            Response::error(405, "method is not allowed")
+        }
+        _ => Response::error(404, format!("unknown path: '{}'", request.path).as_str()),
+    }
+}
+
+fn handle_connection(pool: &WorkerPool, stream: TcpStream, max_body_size: usize) {
+    let mut reader = BufReader::new(&stream);
+    let response = match read_request(&mut reader, max_body_size) {
+        Ok(request) => panic::catch_unwind(AssertUnwindSafe(|| handle_request(pool, &request)))
+            .unwrap_or_else(|err| Response::error(500, get_panic_message(err.as_ref()).as_str())),
+        Err(err) => Response::error(400, err.as_str()),
+    };
+
+    let mut writer = &stream;
+    if let Err(err) = write_response(&mut writer, &response) {
+        eprintln!("cannot write response: '{}'", err);
+    }
+}
+
+fn submit_solve(pool: &WorkerPool, body: &[u8]) -> Response {
+    let request = match serde_json::from_slice::<SolveRequest>(body) {
+        Ok(request) => request,
+        Err(err) => return Response::error(400, format!("cannot read solve request: '{}'", err).as_str()),
+    };
+
+    let problem = match (request.problem, request.matrices).read_pragmatic() {
+        Ok(problem) => Arc::new(problem),
+        Err(errors) => return get_format_errors_response(&errors),
+    };
+    let config = request.config.unwrap_or_default();
+
+    match pool.submit(Box::new(move |quota| get_solution_serialized_with_quota(problem, config, Some(quota)))) {
+        Ok(id) => Response::json(202, serde_json::json!({ "id": id, "status": JobStatus::Queued }).to_string()),
+        Err(err) => Response::error(503, err.as_str()),
+    }
+}
+
+fn get_job_status(pool: &WorkerPool, id: &str) -> Response {
+    match pool.get_status(id) {
+        Some(status) => Response::json(200, serde_json::json!({ "id": id, "status": status }).to_string()),
+        None => get_unknown_job_response(id),
+    }
+}
+
+fn get_job_result(pool: &WorkerPool, id: &str) -> Response {
+    match pool.get(id) {
+        Some(JobState { result: Some(Ok(solution)), .. }) => Response::json(200, solution.as_ref().clone()),
+        Some(JobState { result: Some(Err(err)), .. }) => Response::error(500, err.as_str()),
+        Some(JobState { status: JobStatus::Cancelled, .. }) => Response::error(410, "job is cancelled"),
+        Some(state) => Response::json(202, serde_json::json!({ "id": id, "status": state.status }).to_string()),
+        None => get_unknown_job_response(id),
+    }
+}
+
+fn cancel_job(pool: &WorkerPool, id: &str) -> Response {
+    match pool.cancel(id) {
+        Some(status) => Response::json(200, serde_json::json!({ "id": id, "status": status }).to_string()),
+        None => get_unknown_job_response(id),
+    }
+}
+
+fn check_solution(body: &[u8]) -> Response {
+    let request = match serde_json::from_slice::<CheckRequest>(body) {
+        Ok(request) => request,
+        Err(err) => return Response::error(400, format!("cannot read check request: '{}'", err).as_str()),
+    };
+
+    let core_problem = match (request.problem.clone(), request.matrices.clone()).read_pragmatic() {
+        Ok(problem) => Arc::new(problem),
+        Err(errors) => return get_format_errors_response(&errors),
+    };
+
+    match CheckerContext::new(core_problem, request.problem, request.matrices, request.solution).check() {
+        Ok(_) => Response::json(200, serde_json::json!({ "feasible": true }).to_string()),
+        Err(err) => Response::json(200, serde_json::json!({ "feasible": false, "error": err }).to_string()),
+    }
+}
+
+fn get_locations(body: &[u8]) -> Response {
+    let result = serde_json::from_slice::<Problem>(body)
+        .map_err(|err| format!("cannot read problem: '{}'", err))
+        .and_then(|problem| get_locations_serialized(&problem));
+
+    match result {
+        Ok(locations) => Response::json(200, locations),
+        Err(err) => Response::error(400, err.as_str()),
+    }
+}
+
+fn convert_problem(format: &str, body: &[u8]) -> Response {
+    let result = serde_json::from_slice::<ConvertRequest>(body)
+        .map_err(|err| format!("cannot read convert request: '{}'", err))
+        .and_then(|request| {
+            let readers = request.inputs.iter().map(|input| BufReader::new(input.as_bytes())).collect();
+            import_problem(format, Some(readers))
+        })
+        .and_then(|problem| {
+            let mut buffer = String::new();
+            let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+            serialize_problem(writer, &problem).map_err(|err| err.to_string())?;
+
+            Ok(buffer)
+        });
+
+    match result {
+        Ok(problem) => Response::json(200, problem),
+        Err(err) => Response::error(400, err.as_str()),
+    }
+}
+
+fn get_format_errors_response(errors: &[FormatError]) -> Response {
+    Response::json(400, serde_json::json!({ "errors": errors }).to_string())
+}
+
+fn get_unknown_job_response(id: &str) -> Response {
+    Response::error(404, format!("unknown job: '{}'", id).as_str())
+}
@@ -177,6 +177,12 @@ pub enum RecreateMethod {
     Perturbation { weight: usize, probability: f64, min: f64, max: f64 },
     #[serde(rename(deserialize = "regret"))]
     Regret { weight: usize, start: usize, end: usize },
+    /// Clarke-Wright savings method.
+    #[serde(rename(deserialize = "savings"))]
+    Savings { weight: usize, neighbours: usize },
+    /// Sweep method.
+    #[serde(rename(deserialize = "sweep"))]
+    Sweep { weight: usize },
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -378,6 +384,8 @@ fn create_recreate_method(method: &RecreateMethod) -> (Box<dyn Recreate + Send +
         RecreateMethod::Perturbation { weight, probability, min, max } => {
             (Box::new(RecreateWithPerturbation::new(*probability, *min, *max)), *weight)
         }
+        RecreateMethod::Savings { weight, neighbours } => (Box::new(RecreateWithSavings::new(*neighbours)), *weight),
+        RecreateMethod::Sweep { weight } => (Box::new(RecreateWithSweep::default()), *weight),
     }
 }
 
@@ -36,6 +36,7 @@ use crate::extensions::import::import_problem;
 use crate::extensions::solve::config::{create_builder_from_config, Config};
 use std::io::{BufReader, BufWriter};
 use std::sync::Arc;
+use vrp_core::construction::Quota;
 use vrp_core::models::Problem as CoreProblem;
 use vrp_pragmatic::format::problem::{serialize_problem, PragmaticProblem, Problem};
 use vrp_pragmatic::format::solution::PragmaticSolution;
@@ -237,7 +238,17 @@ pub fn get_locations_serialized(problem: &Problem) -> Result<String, String> {
 
 /// Gets solution serialized in json.
 pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Result<String, String> {
+    get_solution_serialized_with_quota(problem, config, None)
+}
+
+/// Gets solution serialized in json. Solver is interrupted when given quota is reached.
+pub fn get_solution_serialized_with_quota(
+    problem: Arc<CoreProblem>,
+    config: Config,
+    quota: Option<Arc<dyn Quota + Send + Sync>>,
+) -> Result<String, String> {
     let (solution, _, metrics) = create_builder_from_config(problem.clone(), &config)
+        .map(|builder| builder.with_quota(quota))
         .and_then(|builder| builder.build())
         .and_then(|solver| solver.solve())
         .map_err(|err| {
@@ -10,7 +10,14 @@ fn main() {
 
 #[cfg(not(target_arch = "wasm32"))]
 mod cli {
+    use super::commands::accept::{get_accept_app, run_accept};
+    use super::commands::analyze::{get_analyze_app, run_analyze};
+    use super::commands::diff::{get_diff_app, run_diff};
+    use super::commands::explain::{get_explain_app, run_explain};
     use super::commands::import::{get_import_app, run_import};
+    use super::commands::replan::{get_replan_app, run_replan};
+    use super::commands::report::{get_report_app, run_report};
+    use super::commands::serve::{get_serve_app, run_serve};
     use super::commands::solve::{get_solve_app, run_solve};
     use crate::commands::check::{get_check_app, run_check};
     use crate::commands::generate::{get_generate_app, run_generate};
@@ -26,6 +33,13 @@ mod cli {
             .subcommand(get_import_app())
             .subcommand(get_check_app())
             .subcommand(get_generate_app())
+            .subcommand(get_serve_app())
+            .subcommand(get_replan_app())
+            .subcommand(get_accept_app())
+            .subcommand(get_explain_app())
+            .subcommand(get_diff_app())
+            .subcommand(get_analyze_app())
+            .subcommand(get_report_app())
             .get_matches();
 
         match matches.subcommand() {
@@ -33,6 +47,13 @@ mod cli {
             ("import", Some(import_matches)) => run_import(import_matches),
             ("check", Some(check_matches)) => run_check(check_matches),
             ("generate", Some(generate_matches)) => run_generate(generate_matches),
+            ("serve", Some(serve_matches)) => run_serve(serve_matches),
+            ("replan", Some(replan_matches)) => run_replan(replan_matches),
+            ("accept", Some(accept_matches)) => run_accept(accept_matches),
+            ("explain", Some(explain_matches)) => run_explain(explain_matches),
+            ("diff", Some(diff_matches)) => run_diff(diff_matches),
+            ("analyze", Some(analyze_matches)) => run_analyze(analyze_matches),
+            ("report", Some(report_matches)) => run_report(report_matches),
             ("", None) => {
                 eprintln!("No subcommand was used. Use -h to print help information.");
                 process::exit(1);
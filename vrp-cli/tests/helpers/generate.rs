@@ -1,3 +1,7 @@
+use crate::extensions::solve::config::Config;
+use crate::get_solution_serialized;
+use std::io::BufWriter;
+use std::sync::Arc;
 use vrp_pragmatic::format::problem::*;
 use vrp_pragmatic::format::Location;
 
@@ -9,16 +13,20 @@ pub fn create_empty_job() -> Job {
         replacements: None,
         services: None,
         priority: None,
+        value: None,
         skills: None,
+        order: None,
+        split: None,
+        goods: None,
     }
 }
 
 pub fn create_empty_job_task() -> JobTask {
-    JobTask { places: vec![], demand: None, tag: None }
+    JobTask { places: vec![], demand: None, tag: None, named_demand: None }
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None }
+    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None, vehicle_times: None }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -38,9 +46,12 @@ pub fn create_test_vehicle_type() -> VehicleType {
             breaks: None,
             reloads: None,
         }],
-        capacity: vec![10],
+        capacity: vec![10.],
         skills: None,
         limits: None,
+        compartments: None,
+        named_capacity: None,
+        battery: None,
     }
 }
 
@@ -60,9 +71,42 @@ pub fn create_test_job(lat: f64, lng: f64) -> Job {
                 times: Some(vec![create_test_time_window()]),
                 ..create_empty_job_place()
             }],
-            demand: Some(vec![1]),
+            demand: Some(vec![1.]),
             ..create_empty_job_task()
         }]),
         ..create_empty_job()
     }
 }
+
+pub fn create_test_job_with_id(id: &str, lat: f64, demand: f64) -> Job {
+    let mut job = Job { id: id.to_string(), ..create_test_job(lat, 0.) };
+    job.pickups.iter_mut().flat_map(|tasks| tasks.iter_mut()).for_each(|task| task.demand = Some(vec![demand]));
+
+    job
+}
+
+pub fn create_test_problem(jobs: Vec<Job>) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: None },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            profiles: vec![create_test_vehicle_profile()],
+            dimensions: None,
+        },
+        objectives: None,
+        config: None,
+    }
+}
+
+pub fn solve_test_problem(problem: &Problem) -> Result<(String, String), String> {
+    let solution = get_solution_serialized(
+        Arc::new(problem.clone().read_pragmatic().map_err(|_| "cannot read problem")?),
+        Config::default(),
+    )?;
+
+    let mut problem_buffer = String::new();
+    let writer = unsafe { BufWriter::new(problem_buffer.as_mut_vec()) };
+    serialize_problem(writer, problem).map_err(|err| err.to_string())?;
+
+    Ok((problem_buffer, solution))
+}
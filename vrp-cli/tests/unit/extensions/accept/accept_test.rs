@@ -0,0 +1,38 @@
+use super::*;
+use crate::helpers::generate::*;
+
+fn get_acceptance(jobs: &str) -> Result<serde_json::Value, String> {
+    let problem = create_test_problem(vec![create_test_job_with_id("job1", 1., 1.)]);
+    let (problem, solution) = solve_test_problem(&problem)?;
+
+    get_acceptance_serialized(
+        BufReader::new(problem.as_bytes()),
+        BufReader::new(solution.as_bytes()),
+        BufReader::new(jobs.as_bytes()),
+        None,
+    )
+    .map(|result| serde_json::from_str(result.as_str()).unwrap())
+}
+
+#[test]
+fn can_evaluate_new_jobs() {
+    let jobs = vec![create_test_job_with_id("job2", 1.001, 1.), create_test_job_with_id("job3", 1.001, 100.)];
+    let jobs = serde_json::to_string(&jobs).unwrap();
+
+    let result = get_acceptance(jobs.as_str()).expect("cannot evaluate acceptance");
+
+    assert_eq!(result[0]["jobId"], "job2");
+    assert_eq!(result[0]["feasible"], true);
+    assert_eq!(result[0]["insertion"]["vehicleId"], "vehicle_1");
+    assert_eq!(result[0]["insertion"]["activities"][0]["type"], "pickup");
+    assert_eq!(result[1]["jobId"], "job3");
+    assert_eq!(result[1]["feasible"], false);
+    assert_eq!(result[1]["reasons"][0]["code"], 3);
+}
+
+#[test]
+fn can_propagate_invalid_jobs_error() {
+    let result = get_acceptance("[{}]");
+
+    assert!(result.unwrap_err().starts_with("cannot read new jobs"));
+}
@@ -0,0 +1,37 @@
+use super::*;
+use crate::helpers::generate::*;
+
+fn get_test_data() -> (String, String) {
+    let problem =
+        create_test_problem(vec![create_test_job_with_id("job1", 1., 1.), create_test_job_with_id("job2", 1.001, 1.)]);
+
+    solve_test_problem(&problem).expect("cannot solve problem")
+}
+
+#[test]
+fn can_analyze_solution_as_json() {
+    let (problem, solution) = get_test_data();
+
+    let result =
+        get_analytics_serialized(BufReader::new(problem.as_bytes()), BufReader::new(solution.as_bytes()), false)
+            .expect("cannot analyze solution");
+
+    let result: serde_json::Value = serde_json::from_str(result.as_str()).unwrap();
+    assert_eq!(result["tours"][0]["vehicleId"], "vehicle_1");
+    assert_eq!(result["tours"][0]["jobs"], 2);
+    assert_eq!(result["balance"]["jobs"]["mean"], 2.);
+}
+
+#[test]
+fn can_analyze_solution_as_csv() {
+    let (problem, solution) = get_test_data();
+
+    let result =
+        get_analytics_serialized(BufReader::new(problem.as_bytes()), BufReader::new(solution.as_bytes()), true)
+            .expect("cannot analyze solution");
+
+    let lines = result.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("vehicle_id,shift_index,jobs,"));
+    assert!(lines[1].starts_with("vehicle_1,0,2,"));
+}
@@ -0,0 +1,36 @@
+use super::*;
+use crate::helpers::generate::*;
+
+fn get_test_solution(job_lats: Vec<f64>) -> String {
+    let jobs = job_lats
+        .into_iter()
+        .enumerate()
+        .map(|(idx, lat)| create_test_job_with_id(format!("job{}", idx + 1).as_str(), lat, 1.))
+        .collect();
+
+    solve_test_problem(&create_test_problem(jobs)).expect("cannot solve problem").1
+}
+
+#[test]
+fn can_compare_solutions() {
+    let original = get_test_solution(vec![1.]);
+    let new = get_test_solution(vec![1., 1.001]);
+
+    let json = get_diff_serialized(BufReader::new(original.as_bytes()), BufReader::new(new.as_bytes()), false)
+        .expect("cannot compare solutions");
+    let text = get_diff_serialized(BufReader::new(original.as_bytes()), BufReader::new(new.as_bytes()), true)
+        .expect("cannot compare solutions");
+
+    let json: serde_json::Value = serde_json::from_str(json.as_str()).unwrap();
+    assert_eq!(json["tours"][0]["vehicleId"], "vehicle_1");
+    assert_eq!(json["tours"][0]["status"], "changed");
+    assert_eq!(json["tours"][0]["addedJobs"][0], "job2");
+    assert!(text.contains("added jobs: job2"));
+}
+
+#[test]
+fn can_propagate_invalid_solution_error() {
+    let result = get_diff_serialized(BufReader::new("{}".as_bytes()), BufReader::new("{}".as_bytes()), false);
+
+    assert!(result.unwrap_err().starts_with("cannot read original solution"));
+}
@@ -0,0 +1,23 @@
+use super::*;
+use crate::helpers::generate::*;
+
+#[test]
+fn can_explain_unassigned_jobs() {
+    let problem = create_test_problem(vec![
+        create_test_job_with_id("job1", 1., 1.),
+        create_test_job_with_id("job2", 1.001, 100.),
+    ]);
+    let (problem, solution) = solve_test_problem(&problem).expect("cannot solve problem");
+
+    let result =
+        get_explanation_serialized(BufReader::new(problem.as_bytes()), BufReader::new(solution.as_bytes()), None)
+            .expect("cannot explain solution");
+
+    let result: serde_json::Value = serde_json::from_str(result.as_str()).unwrap();
+    let unassigned = &result["unassigned"];
+    assert_eq!(unassigned.as_array().map(|jobs| jobs.len()), Some(1));
+    assert_eq!(unassigned[0]["jobId"], "job2");
+    assert_eq!(unassigned[0]["details"][0]["vehicleId"], "vehicle_1");
+    assert_eq!(unassigned[0]["details"][0]["shiftIndex"], 0);
+    assert_eq!(unassigned[0]["details"][0]["reasons"][0]["code"], 3);
+}
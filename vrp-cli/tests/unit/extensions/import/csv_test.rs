@@ -24,6 +24,28 @@ vehicle2,52.4959,13.3539,50,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,20,truck
     assert_eq!(result.fleet.vehicles.len(), 2);
 }
 
+#[test]
+fn can_create_vehicle_ids_from_type_id_and_amount() {
+    let jobs_csv = "ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END\njob1,52.5,13.4,1,3,,\n";
+    let vehicles_csv = r"
+ID,LAT,LNG,CAPACITY,TW_START,TW_END,AMOUNT,PROFILE
+vehicle1,52.4664,13.4023,40,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,1,car
+vehicle2,52.4959,13.3539,50,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,3,car
+";
+
+    let result = read_csv_problem(BufReader::new(jobs_csv.as_bytes()), BufReader::new(vehicles_csv.as_bytes()))
+        .expect("cannot read csv");
+
+    let vehicle_ids = result.fleet.vehicles.iter().map(|vehicle| vehicle.vehicle_ids.clone()).collect::<Vec<_>>();
+    assert_eq!(
+        vehicle_ids,
+        vec![
+            vec!["vehicle1_1".to_string()],
+            vec!["vehicle2_1".to_string(), "vehicle2_2".to_string(), "vehicle2_3".to_string()]
+        ]
+    );
+}
+
 #[test]
 fn can_propagate_format_error() {
     let invalid_jobs = r"
@@ -46,7 +68,115 @@ job2,52.5165,13.3808,3,,
             .err()
             .expect("Should return error!");
 
-    assert_eq!(result, "cannot read csv: E0000, cause: 'cannot read jobs', action: 'check jobs definition'.");
+    assert!(result.starts_with("cannot read csv: E0000, cause: 'cannot read jobs', action: 'check jobs definition'. "));
+    assert!(result.contains("line: 4"));
+}
+
+#[test]
+fn can_read_csv_problem_with_extended_columns() {
+    let jobs_csv = r"
+ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END,TYPE,SKILLS,PRIORITY
+job1,52.52599,13.45413,2 1,5,2020-07-04T08:00:00Z 2020-07-04T14:00:00Z,2020-07-04T10:00:00Z 2020-07-04T16:00:00Z,,fridge,2
+job2,52.5225,13.4095,1 1,3,,,pickup,,
+job2,52.5165,13.3808,1 1,3,,,delivery,,
+job3,52.5316,13.3884,0 0,5,,,,,
+";
+    let vehicles_csv = r"
+ID,LAT,LNG,CAPACITY,TW_START,TW_END,AMOUNT,PROFILE,END_LAT,END_LNG,FIXED_COST,DISTANCE_COST,TIME_COST,SKILLS,BREAK_TW_START,BREAK_TW_END,BREAK_DURATION
+vehicle1,52.4664,13.4023,40 10,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,10,car,52.5,13.4,10,0.001,0.002,fridge,2020-07-04T12:00:00Z,2020-07-04T14:00:00Z,30
+vehicle2,52.4959,13.3539,50 20,2020-07-04T08:00:00Z,,20,truck,,,,,,,,,
+";
+
+    let result = read_csv_problem(BufReader::new(jobs_csv.as_bytes()), BufReader::new(vehicles_csv.as_bytes()))
+        .expect("cannot read csv");
+
+    let get_job = |id: &str| result.plan.jobs.iter().find(|job| job.id == id).expect("cannot find job");
+    let job1 = get_job("job1");
+    assert_eq!(job1.skills, Some(vec!["fridge".to_string()]));
+    assert_eq!(job1.priority, Some(2));
+    let job1_task = &job1.pickups.as_ref().unwrap()[0];
+    assert_eq!(job1_task.demand, Some(vec![2., 1.]));
+    assert_eq!(job1_task.places[0].times.as_ref().map(|times| times.len()), Some(2));
+    let job2 = get_job("job2");
+    assert_eq!(job2.pickups.as_ref().map(|tasks| tasks.len()), Some(1));
+    assert_eq!(job2.deliveries.as_ref().map(|tasks| tasks.len()), Some(1));
+    assert_eq!(job2.deliveries.as_ref().unwrap()[0].demand, Some(vec![1., 1.]));
+    let job3 = get_job("job3");
+    assert!(job3.services.is_some());
+    assert!(job3.services.as_ref().unwrap()[0].demand.is_none());
+
+    let vehicle1 = &result.fleet.vehicles[0];
+    assert_eq!(vehicle1.capacity, vec![40., 10.]);
+    assert_eq!(vehicle1.costs.fixed, Some(10.));
+    assert_eq!(vehicle1.costs.distance, 0.001);
+    assert_eq!(vehicle1.costs.time, 0.002);
+    assert_eq!(vehicle1.skills, Some(vec!["fridge".to_string()]));
+    assert_eq!(vehicle1.shifts[0].breaks.as_ref().map(|breaks| breaks[0].duration), Some(1800.));
+    assert_eq!(vehicle1.shifts[0].end.as_ref().map(|end| end.location.to_lat_lng()), Some((52.5, 13.4)));
+    let vehicle2 = &result.fleet.vehicles[1];
+    assert!(vehicle2.shifts[0].end.is_none());
+    assert!(vehicle2.shifts[0].breaks.is_none());
+    assert_eq!(vehicle2.costs.fixed, Some(25.));
+}
+
+parameterized_test! {can_report_invalid_job_row, (job_row, expected), {
+    can_report_invalid_job_row_impl(job_row, expected);
+}}
+
+can_report_invalid_job_row! {
+    case01: ("job1,52.5,13.4,1 -1,3,,,,", "line 3: DEMAND has values with different sign"),
+    case02: ("job1,52.5,13.4,1 a,3,,,,", "line 3: cannot parse 'a' in DEMAND column"),
+    case03: ("job1,52.5,13.4,1,3,,,service,", "line 3: service cannot have non-zero DEMAND"),
+    case04: ("job1,52.5,13.4,1,3,,,replacement,", "line 3: unknown TYPE 'replacement', expected pickup, delivery or service"),
+    case05: ("job1,52.5,13.4,0,3,2020-07-04T08:00:00Z,,,", "line 3: both TW_START and TW_END should be specified"),
+    case06: (
+        "job1,52.5,13.4,0,3,2020-07-04T08:00:00Z 2020-07-04T10:00:00Z,2020-07-04T12:00:00Z,,",
+        "line 3: TW_START has 2 values, but TW_END has 1"
+    ),
+    case07: ("job1,52.5,13.4,1,3,,,,1\njob1,52.5,13.4,-1,3,,,,2", "line 4: job 'job1' has different PRIORITY values"),
+}
+
+fn can_report_invalid_job_row_impl(job_row: &str, expected: &str) {
+    let jobs_csv = format!("\nID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END,TYPE,PRIORITY\n{}\n", job_row);
+
+    let result = read_csv_problem(BufReader::new(jobs_csv.as_bytes()), BufReader::new("".as_bytes()))
+        .err()
+        .expect("Should return error!");
+
+    assert_eq!(result.cause, "cannot read jobs");
+    assert_eq!(result.details, Some(expected.to_string()));
+}
+
+parameterized_test! {can_report_invalid_vehicle_row, (vehicle_row, expected), {
+    can_report_invalid_vehicle_row_impl(vehicle_row, expected);
+}}
+
+can_report_invalid_vehicle_row! {
+    case01: ("v1,52.4,13.4,,2020-07-04T08:00:00Z,,1,car,,,,,", "line 3: CAPACITY should have at least one value"),
+    case02: ("v1,52.4,13.4,10,2020-07-04T08:00:00Z,,1,car,52.5,13.4,,,", "line 3: TW_END should be specified for shift end"),
+    case03: (
+        "v1,52.4,13.4,10,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,1,car,52.5,,,,",
+        "line 3: both END_LAT and END_LNG should be specified"
+    ),
+    case04: (
+        "v1,52.4,13.4,10,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,1,car,,,2020-07-04T12:00:00Z,,30",
+        "line 3: BREAK_TW_START, BREAK_TW_END and BREAK_DURATION should be specified together"
+    ),
+}
+
+fn can_report_invalid_vehicle_row_impl(vehicle_row: &str, expected: &str) {
+    let jobs_csv = "ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END\njob1,52.5,13.4,1,3,,\n";
+    let vehicles_csv = format!(
+        "\nID,LAT,LNG,CAPACITY,TW_START,TW_END,AMOUNT,PROFILE,END_LAT,END_LNG,BREAK_TW_START,BREAK_TW_END,BREAK_DURATION\n{}\n",
+        vehicle_row
+    );
+
+    let result = read_csv_problem(BufReader::new(jobs_csv.as_bytes()), BufReader::new(vehicles_csv.as_bytes()))
+        .err()
+        .expect("Should return error!");
+
+    assert_eq!(result.cause, "cannot read vehicles");
+    assert_eq!(result.details, Some(expected.to_string()));
 }
 
 parameterized_test! {can_handle_invalid_input_amount, input_size, {
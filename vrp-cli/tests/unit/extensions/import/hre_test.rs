@@ -188,7 +188,7 @@ fn can_import_hre_problem() {
     assert_eq!(problem.fleet.vehicles.len(), 1);
     let vehicle = problem.fleet.vehicles.first().unwrap();
     assert_eq!(vehicle.vehicle_ids, vec!["vehicle_1"]);
-    assert_eq!(vehicle.capacity, vec![10]);
+    assert_eq!(vehicle.capacity, vec![10.]);
     assert_eq!(vehicle.profile, "normal_car");
 
     assert!(vehicle.limits.is_some());
@@ -218,7 +218,7 @@ fn can_write_hre_problem() {
                             times: Some(vec![create_test_time_window()]),
                             ..create_empty_job_place()
                         }],
-                        demand: Some(vec![1]),
+                        demand: Some(vec![1.]),
                         ..create_empty_job_task()
                     }]),
                     skills: Some(vec!["skill1".to_string()]),
@@ -232,7 +232,7 @@ fn can_write_hre_problem() {
                             times: Some(vec![create_test_time_window()]),
                             ..create_empty_job_place()
                         }],
-                        demand: Some(vec![1]),
+                        demand: Some(vec![1.]),
                         ..create_empty_job_task()
                     }]),
                     ..create_empty_job()
@@ -245,7 +245,7 @@ fn can_write_hre_problem() {
                             times: Some(vec![create_test_time_window()]),
                             ..create_empty_job_place()
                         }],
-                        demand: Some(vec![1]),
+                        demand: Some(vec![1.]),
                         ..create_empty_job_task()
                     }]),
                     deliveries: Some(vec![JobTask {
@@ -254,7 +254,7 @@ fn can_write_hre_problem() {
                             times: Some(vec![create_test_time_window()]),
                             ..create_empty_job_place()
                         }],
-                        demand: Some(vec![1]),
+                        demand: Some(vec![1.]),
                         ..create_empty_job_task()
                     }]),
                     ..create_empty_job()
@@ -268,7 +268,7 @@ fn can_write_hre_problem() {
                                 times: Some(vec![create_test_time_window()]),
                                 ..create_empty_job_place()
                             }],
-                            demand: Some(vec![1]),
+                            demand: Some(vec![1.]),
                             ..create_empty_job_task()
                         },
                         JobTask {
@@ -277,7 +277,7 @@ fn can_write_hre_problem() {
                                 times: Some(vec![create_test_time_window()]),
                                 ..create_empty_job_place()
                             }],
-                            demand: Some(vec![1]),
+                            demand: Some(vec![1.]),
                             ..create_empty_job_task()
                         },
                     ]),
@@ -287,7 +287,7 @@ fn can_write_hre_problem() {
                             times: Some(vec![create_test_time_window()]),
                             ..create_empty_job_place()
                         }],
-                        demand: Some(vec![1]),
+                        demand: Some(vec![1.]),
                         ..create_empty_job_task()
                     }]),
                     ..create_empty_job()
@@ -347,15 +347,23 @@ fn can_write_hre_problem() {
                     }]),
                     reloads: None,
                 }],
-                capacity: vec![10],
+                capacity: vec![10.],
                 skills: Some(vec!["skill1".to_string()]),
                 limits: Some(VehicleLimits {
                     max_distance: Some(10000.),
                     shift_time: Some(14400.),
                     allowed_areas: None,
+                    forbidden_areas: None,
+                    area_costs: None,
+                    max_jobs: None,
+                    max_stops: None,
                 }),
+                compartments: None,
+                named_capacity: None,
+                battery: None,
             }],
             profiles: vec![Profile { name: "normal_car".to_string(), profile_type: "car".to_string(), speed: None }],
+            dimensions: None,
         },
         objectives: None,
         config: None,
@@ -0,0 +1,41 @@
+use super::*;
+use crate::helpers::generate::*;
+
+fn get_test_data() -> (String, String) {
+    let problem =
+        create_test_problem(vec![create_test_job_with_id("job1", 1., 1.), create_test_job_with_id("job2", 1.001, 1.)]);
+
+    solve_test_problem(&problem).expect("cannot solve problem")
+}
+
+#[test]
+fn can_render_report_as_html() {
+    let (problem, solution) = get_test_data();
+
+    let result = get_report_serialized(BufReader::new(problem.as_bytes()), BufReader::new(solution.as_bytes()), false)
+        .expect("cannot render report");
+
+    assert!(result.starts_with("<!DOCTYPE html>"));
+    assert!(result.contains("<polyline"));
+    assert!(result.contains("vehicle_1"));
+}
+
+#[test]
+fn can_render_report_as_svg() {
+    let (problem, solution) = get_test_data();
+
+    let result = get_report_serialized(BufReader::new(problem.as_bytes()), BufReader::new(solution.as_bytes()), true)
+        .expect("cannot render report");
+
+    assert!(result.starts_with("<svg"));
+    assert!(result.trim_end().ends_with("</svg>"));
+}
+
+#[test]
+fn can_return_error_for_invalid_solution() {
+    let (problem, _) = get_test_data();
+
+    let result = get_report_serialized(BufReader::new(problem.as_bytes()), BufReader::new("{}".as_bytes()), true);
+
+    assert!(result.err().map_or(false, |err| err.starts_with("cannot read solution")));
+}
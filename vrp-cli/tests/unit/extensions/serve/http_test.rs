@@ -0,0 +1,54 @@
+use super::*;
+use std::io::BufReader;
+
+#[test]
+fn can_read_request_with_body() {
+    let data = "POST /solve?debug=true HTTP/1.1\r\nHost: localhost\r\ncontent-length: 4\r\n\r\n{}{}";
+
+    let request = read_request(&mut BufReader::new(data.as_bytes()), 16).unwrap();
+
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/solve");
+    assert_eq!(request.body, b"{}{}".to_vec());
+}
+
+#[test]
+fn can_read_request_without_body() {
+    let data = "GET /jobs/1 HTTP/1.1\r\nHost: localhost\r\n\r\n";
+
+    let request = read_request(&mut BufReader::new(data.as_bytes()), 16).unwrap();
+
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.path, "/jobs/1");
+    assert!(request.body.is_empty());
+}
+
+parameterized_test! {can_reject_invalid_request, data, {
+    can_reject_invalid_request_impl(data);
+}}
+
+can_reject_invalid_request! {
+    case01: "GET\r\n\r\n",
+    case02: "POST /solve HTTP/1.1\r\nContent-Length: abc\r\n\r\n",
+    case03: "POST /solve HTTP/1.1\r\nContent-Length: 10\r\n\r\n{}",
+    case04: "POST /solve HTTP/1.1\r\nContent-Length: 17\r\n\r\n{\"problem\":\"abcd\"}",
+}
+
+fn can_reject_invalid_request_impl(data: &str) {
+    let result = read_request(&mut BufReader::new(data.as_bytes()), 16);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_write_response() {
+    let mut buffer = Vec::new();
+
+    write_response(&mut buffer, &Response::error(404, "unknown path")).unwrap();
+
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "HTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\nContent-Length: 24\r\nConnection: close\r\n\r\n\
+         {\"error\":\"unknown path\"}"
+    );
+}
@@ -0,0 +1,104 @@
+use super::*;
+use std::sync::mpsc::channel;
+use std::time::{Duration, Instant};
+
+fn wait_for_status(pool: &WorkerPool, id: &str, status: JobStatus) -> JobState {
+    let start = Instant::now();
+    loop {
+        let state = pool.get(id).unwrap();
+        if state.status == status || start.elapsed() > Duration::from_secs(10) {
+            return state;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn can_run_submitted_jobs() {
+    let pool = WorkerPool::new(2, 16, Duration::from_secs(60));
+
+    let ok_id = pool.submit(Box::new(|_| Ok("solution".to_string()))).unwrap();
+    let err_id = pool.submit(Box::new(|_| Err("error".to_string()))).unwrap();
+
+    let ok_state = wait_for_status(&pool, &ok_id, JobStatus::Completed);
+    let err_state = wait_for_status(&pool, &err_id, JobStatus::Failed);
+
+    assert_eq!(ok_state.status, JobStatus::Completed);
+    assert_eq!(ok_state.result, Some(Ok(Arc::new("solution".to_string()))));
+    assert_eq!(err_state.status, JobStatus::Failed);
+    assert_eq!(err_state.result, Some(Err("error".to_string())));
+    assert!(pool.get("unknown").is_none());
+}
+
+#[test]
+fn can_cancel_running_and_queued_jobs() {
+    let pool = WorkerPool::new(1, 16, Duration::from_secs(60));
+    let (sender, receiver) = channel();
+
+    let running_id = pool
+        .submit(Box::new(move |quota| {
+            sender.send(()).unwrap();
+            while !quota.is_reached() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            Ok("solution".to_string())
+        }))
+        .unwrap();
+    let queued_id = pool.submit(Box::new(|_| Ok("solution".to_string()))).unwrap();
+    receiver.recv().unwrap();
+
+    assert_eq!(pool.cancel(&queued_id), Some(JobStatus::Cancelled));
+    assert_eq!(pool.cancel(&running_id), Some(JobStatus::Running));
+
+    let state = wait_for_status(&pool, &running_id, JobStatus::Cancelled);
+    assert_eq!(state.status, JobStatus::Cancelled);
+    assert!(state.result.is_none());
+    assert_eq!(pool.get(&queued_id).unwrap().status, JobStatus::Cancelled);
+}
+
+#[test]
+fn can_remove_finished_job_on_cancel() {
+    let pool = WorkerPool::new(1, 16, Duration::from_secs(60));
+    let id = pool.submit(Box::new(|_| Ok("solution".to_string()))).unwrap();
+    wait_for_status(&pool, &id, JobStatus::Completed);
+
+    assert_eq!(pool.cancel(&id), Some(JobStatus::Completed));
+    assert!(pool.get(&id).is_none());
+    assert!(pool.cancel(&id).is_none());
+}
+
+#[test]
+fn can_remove_expired_jobs() {
+    let pool = WorkerPool::new(1, 16, Duration::from_millis(100));
+    let id = pool.submit(Box::new(|_| Ok("solution".to_string()))).unwrap();
+    wait_for_status(&pool, &id, JobStatus::Completed);
+
+    assert_eq!(pool.get_status(&id), Some(JobStatus::Completed));
+    thread::sleep(Duration::from_millis(200));
+    assert!(pool.get_status(&id).is_none());
+}
+
+#[test]
+fn can_reject_job_when_queue_is_full() {
+    let pool = WorkerPool::new(1, 1, Duration::from_secs(60));
+    let (sender, receiver) = channel();
+
+    let running_id = pool
+        .submit(Box::new(move |quota| {
+            sender.send(()).unwrap();
+            while !quota.is_reached() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            Ok("solution".to_string())
+        }))
+        .unwrap();
+    receiver.recv().unwrap();
+    let queued_id = pool.submit(Box::new(|_| Ok("solution".to_string()))).unwrap();
+
+    let result = pool.submit(Box::new(|_| Ok("solution".to_string())));
+
+    assert_eq!(result, Err("job queue is full".to_string()));
+    assert_eq!(pool.get("3").map(|state| state.status), None);
+    assert_eq!(pool.get_status(&queued_id), Some(JobStatus::Queued));
+    pool.cancel(&running_id);
+}
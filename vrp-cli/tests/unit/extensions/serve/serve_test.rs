@@ -0,0 +1,88 @@
+use super::*;
+use crate::helpers::generate::{create_test_job, create_test_vehicle_type};
+use std::time::{Duration, Instant};
+use vrp_pragmatic::format::problem::{Fleet, Plan, Profile};
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_test_job(1., 0.)], relations: None },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            profiles: vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }],
+            dimensions: None,
+        },
+        objectives: None,
+        config: None,
+    }
+}
+
+fn create_request(method: &str, path: &str, body: String) -> Request {
+    Request { method: method.to_string(), path: path.to_string(), body: body.into_bytes() }
+}
+
+fn get_json(response: &Response) -> serde_json::Value {
+    serde_json::from_str(response.body.as_str()).unwrap()
+}
+
+parameterized_test! {can_handle_unknown_request, (method, path, expected_status), {
+    can_handle_unknown_request_impl(method, path, expected_status);
+}}
+
+can_handle_unknown_request! {
+    case01: ("GET", "/unknown", 404),
+    case02: ("GET", "/solve", 405),
+    case03: ("GET", "/jobs/42", 404),
+    case04: ("POST", "/solve", 400),
+}
+
+fn can_handle_unknown_request_impl(method: &str, path: &str, expected_status: u16) {
+    let pool = WorkerPool::new(1, 16, Duration::from_secs(60));
+
+    let response = handle_request(&pool, &create_request(method, path, "{}".to_string()));
+
+    assert_eq!(response.status, expected_status);
+}
+
+#[test]
+fn can_get_locations() {
+    let pool = WorkerPool::new(1, 16, Duration::from_secs(60));
+    let body = serde_json::to_string(&create_test_problem()).unwrap();
+
+    let response = handle_request(&pool, &create_request("POST", "/locations", body));
+
+    assert_eq!(response.status, 200);
+    assert_eq!(get_json(&response).as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn can_solve_and_check_problem() {
+    let pool = WorkerPool::new(1, 16, Duration::from_secs(60));
+    let problem = serde_json::to_value(&create_test_problem()).unwrap();
+    let body = serde_json::json!({ "problem": problem, "config": { "termination": { "maxGenerations": 10 } } });
+
+    let response = handle_request(&pool, &create_request("POST", "/solve", body.to_string()));
+    assert_eq!(response.status, 202);
+    let id = get_json(&response)["id"].as_str().unwrap().to_string();
+
+    let start = Instant::now();
+    let response = loop {
+        let response =
+            handle_request(&pool, &create_request("GET", format!("/jobs/{}/result", id).as_str(), "".into()));
+        if response.status != 202 || start.elapsed() > Duration::from_secs(30) {
+            break response;
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+    assert_eq!(response.status, 200);
+    let solution = get_json(&response);
+    assert_eq!(solution["tours"].as_array().unwrap().len(), 1);
+
+    let body = serde_json::json!({ "problem": problem, "solution": solution });
+    let response = handle_request(&pool, &create_request("POST", "/check", body.to_string()));
+    assert_eq!(response.status, 200);
+    assert_eq!(get_json(&response)["feasible"], true);
+
+    let response = handle_request(&pool, &create_request("DELETE", format!("/jobs/{}", id).as_str(), "".into()));
+    assert_eq!(response.status, 200);
+    assert_eq!(get_json(&response)["status"], "completed");
+}
@@ -65,3 +65,24 @@ fn can_create_default_config() {
     assert!(config.termination.is_none());
     assert!(config.telemetry.is_none());
 }
+
+#[test]
+fn can_create_builder_with_classical_initial_methods() {
+    let config = r#"{
+        "population": {
+            "initial": {
+                "size": 2,
+                "methods": [
+                    { "weight": 1, "type": "savings", "neighbours": 16 },
+                    { "weight": 1, "type": "sweep" }
+                ]
+            }
+        }
+    }"#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    let builder = create_builder_from_config(create_example_problem(), &config).unwrap();
+
+    assert_eq!(builder.config.population.initial.size, 2);
+    assert_eq!(builder.config.population.initial.methods.len(), 2);
+}
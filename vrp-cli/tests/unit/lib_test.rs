@@ -6,7 +6,7 @@ use vrp_pragmatic::format::problem::{Fleet, Plan, Profile};
 fn can_get_locations_serialized() {
     let problem = Problem {
         plan: Plan { jobs: vec![create_test_job(1., 1.), create_test_job(1., 0.)], relations: None },
-        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![], dimensions: None },
         objectives: None,
         config: None,
     };
@@ -23,6 +23,7 @@ fn can_get_solution_serialized() {
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
             profiles: vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }],
+            dimensions: None,
         },
         objectives: None,
         config: None,
@@ -4,7 +4,7 @@ mod area_test;
 
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
-use crate::models::common::Location;
+use crate::models::common::{Cost, Location};
 use crate::models::problem::{Actor, Job, Single};
 use crate::utils::compare_floats;
 use std::cmp::Ordering;
@@ -12,23 +12,62 @@ use std::ops::Deref;
 use std::slice::Iter;
 use std::sync::Arc;
 
-/// An area where actor is allowed to serve jobs.
+/// An area defined by polygon which can have holes.
+#[derive(Clone)]
 pub struct Area {
     /// An area priority, bigger value - less important.
     pub priority: Option<usize>,
     /// An area outer shape.
     pub outer_shape: Vec<(f64, f64)>,
+    /// An area inner shapes (holes). Locations inside them are considered as outside of area.
+    pub inner_shapes: Vec<Vec<(f64, f64)>>,
+}
+
+impl Area {
+    /// Checks whether given location is inside area.
+    pub fn contains(&self, location: &(f64, f64)) -> bool {
+        is_location_in_area(location, self.outer_shape.as_slice())
+            && !self.inner_shapes.iter().any(|shape| is_location_in_area(location, shape.as_slice()))
+    }
+}
+
+/// Specifies areas which restrict where actor can serve jobs or affect cost of serving them.
+#[derive(Clone, Default)]
+pub struct ActorAreas {
+    /// Areas where actor is allowed to serve jobs. No restrictions when empty.
+    pub allowed: Vec<Area>,
+    /// Areas where actor is not allowed to serve jobs.
+    pub forbidden: Vec<Area>,
+    /// Areas with extra cost for each job served inside them.
+    pub costs: Vec<(Area, Cost)>,
+}
+
+impl ActorAreas {
+    /// Checks whether actor can serve job at given location.
+    pub fn can_serve(&self, location: &(f64, f64)) -> bool {
+        (self.allowed.is_empty() || self.allowed.iter().any(|area| area.contains(location)))
+            && !self.forbidden.iter().any(|area| area.contains(location))
+    }
+
+    /// Returns total extra cost of serving job at given location.
+    pub fn get_cost(&self, location: &(f64, f64)) -> Cost {
+        self.costs.iter().filter(|(area, _)| area.contains(location)).map(|(_, cost)| *cost).sum()
+    }
 }
 
 /// A function which returns operating areas for given actor.
-pub type AreaResolver = Arc<dyn Fn(&Actor) -> Option<&Vec<Area>> + Sync + Send>;
+pub type AreaResolver = Arc<dyn Fn(&Actor) -> Option<&ActorAreas> + Sync + Send>;
 /// A function which returns actual coordinate for given location.
 pub type LocationResolver = Arc<dyn Fn(Location) -> (f64, f64) + Sync + Send>;
 
-/// An area module provides way to restrict given actor to work in specific areas only.
+/// An area module provides way to restrict given actor to work in specific areas only, to forbid
+/// some areas for it or to apply an extra cost for serving jobs in specific areas. The extra cost
+/// is kept in route state, so it is a part of route cost.
 pub struct AreaModule {
     constraints: Vec<ConstraintVariant>,
     keys: Vec<i32>,
+    area_resolver: AreaResolver,
+    location_resolver: LocationResolver,
 }
 
 impl AreaModule {
@@ -47,21 +86,41 @@ impl AreaModule {
                     code,
                 })),
                 ConstraintVariant::SoftActivity(Arc::new(AreaSoftActivityConstraint {
-                    area_resolver,
-                    location_resolver,
+                    area_resolver: area_resolver.clone(),
+                    location_resolver: location_resolver.clone(),
                 })),
             ],
-            keys: vec![],
+            keys: vec![AREA_COST_KEY],
+            area_resolver,
+            location_resolver,
         }
     }
 }
 
 impl ConstraintModule for AreaModule {
-    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
 
-    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let areas = self.area_resolver.deref()(&ctx.route.actor).filter(|areas| !areas.costs.is_empty());
 
-    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+        if let Some(areas) = areas {
+            let cost = ctx
+                .route
+                .tour
+                .all_activities()
+                .filter(|activity| activity.job.is_some())
+                .map(|activity| areas.get_cost(&self.location_resolver.deref()(activity.place.location)))
+                .sum::<Cost>();
+
+            ctx.state_mut().put_route_state(AREA_COST_KEY, cost);
+        }
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx))
+    }
 
     fn state_keys(&self) -> Iter<i32> {
         self.keys.iter()
@@ -82,11 +141,8 @@ impl HardRouteConstraint for AreaHardRouteConstraint {
     fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
         if let Some(areas) = self.area_resolver.deref()(&ctx.route.actor) {
             let can_serve = match job {
-                Job::Single(job) => find_allowed_area_for_job(job, areas, &self.location_resolver).is_some(),
-                Job::Multi(job) => job
-                    .jobs
-                    .iter()
-                    .all(|single| find_allowed_area_for_job(single, areas, &self.location_resolver).is_some()),
+                Job::Single(job) => can_serve_job(job, areas, &self.location_resolver),
+                Job::Multi(job) => job.jobs.iter().all(|single| can_serve_job(single, areas, &self.location_resolver)),
             };
 
             if !can_serve {
@@ -111,9 +167,7 @@ impl HardActivityConstraint for AreaHardActivityConstraint {
     ) -> Option<ActivityConstraintViolation> {
         if let Some(areas) = self.area_resolver.deref()(&route_ctx.route.actor) {
             let location = self.location_resolver.deref()(activity_ctx.target.place.location);
-            let can_serve = areas.iter().any(|area| is_location_in_area(&location, area.outer_shape.as_slice()));
-
-            if !can_serve {
+            if !areas.can_serve(&location) {
                 // NOTE do not stop job insertion evaluation if it has multiple locations
                 let stopped = activity_ctx
                     .target
@@ -136,54 +190,46 @@ struct AreaSoftActivityConstraint {
 
 impl SoftActivityConstraint for AreaSoftActivityConstraint {
     fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
-        let location = activity_ctx.target.place.location;
-
-        self.area_resolver.deref()(&route_ctx.route.actor)
-            .and_then(|areas| find_allowed_area_for_location(location, areas, &self.location_resolver))
-            .and_then(|area| area.priority)
-            .map(|priority| {
-                let route_cost = route_ctx.get_route_cost();
-                let penalty = if compare_floats(route_cost, 0.) == Ordering::Equal { 1E9 } else { route_cost * 2. };
-
-                (priority - 1) as f64 * penalty
-            })
-            .unwrap_or(0.)
-    }
-}
+        self.area_resolver.deref()(&route_ctx.route.actor).map_or(0., |areas| {
+            let location = self.location_resolver.deref()(activity_ctx.target.place.location);
 
-fn find_allowed_area_for_location<'a>(
-    location: Location,
-    areas: &'a [Area],
-    location_resolver: &LocationResolver,
-) -> Option<&'a Area> {
-    let location = location_resolver.deref()(location);
-    areas.iter().find(|area| is_location_in_area(&location, area.outer_shape.as_slice()))
+            let priority_cost = areas
+                .allowed
+                .iter()
+                .find(|area| area.contains(&location))
+                .and_then(|area| area.priority)
+                .map(|priority| {
+                    let route_cost = route_ctx.get_route_cost();
+                    let penalty = if compare_floats(route_cost, 0.) == Ordering::Equal { 1E9 } else { route_cost * 2. };
+
+                    (priority - 1) as f64 * penalty
+                })
+                .unwrap_or(0.);
+
+            priority_cost + areas.get_cost(&location)
+        })
+    }
 }
 
-fn find_allowed_area_for_job<'a>(
-    job: &Single,
-    areas: &'a [Area],
-    location_resolver: &LocationResolver,
-) -> Option<&'a Area> {
+fn can_serve_job(job: &Single, areas: &ActorAreas, location_resolver: &LocationResolver) -> bool {
     job.places
         .iter()
         .filter_map(|place| place.location)
-        .filter_map(|location| find_allowed_area_for_location(location, areas, location_resolver))
-        .next()
+        .any(|location| areas.can_serve(&location_resolver.deref()(location)))
 }
 
 /// Checks whether given location is inside area using ray casting algorithm.
 /// Location is interpreted as 2D point, area - as 2D polygon.
-fn is_location_in_area(location: &(f64, f64), outer_shape: &[(f64, f64)]) -> bool {
+fn is_location_in_area(location: &(f64, f64), shape: &[(f64, f64)]) -> bool {
     let &(x, y) = location;
 
     let mut is_inside = false;
     let mut i = 0;
-    let mut j = outer_shape.len() - 1;
+    let mut j = shape.len() - 1;
 
-    while i < outer_shape.len() {
-        let &(ix, iy) = outer_shape.get(i).unwrap();
-        let &(jx, jy) = outer_shape.get(j).unwrap();
+    while i < shape.len() {
+        let &(ix, iy) = shape.get(i).unwrap();
+        let &(jx, jy) = shape.get(j).unwrap();
 
         if ((ix > x) != (jx > x)) && (y < (jy - iy) * (x - ix) / (jx - ix) + iy) {
             is_inside = !is_inside;
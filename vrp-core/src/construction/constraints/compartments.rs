@@ -0,0 +1,336 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/compartments_test.rs"]
+mod compartments_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::{Demand, DemandDimension, Dimensions, Load, ValueDimension};
+use crate::models::problem::{Job, Single};
+use crate::models::solution::Activity;
+use hashbrown::{HashMap, HashSet};
+use std::ops::{Add, Sub};
+use std::slice::Iter;
+use std::sync::Arc;
+
+const COMPARTMENTS_DIMENSION_KEY: &str = "compartments";
+const GOODS_DIMENSION_KEY: &str = "goods";
+
+/// Specifies a vehicle compartment which can hold goods of only one type at a time.
+#[derive(Clone)]
+pub struct Compartment<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    /// Compartment capacity.
+    pub capacity: T,
+    /// Goods types which can be loaded into the compartment.
+    pub goods: HashSet<String>,
+}
+
+/// A trait to get or set vehicle's compartments.
+pub trait CompartmentDimension<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    /// Sets compartments.
+    fn set_compartments(&mut self, compartments: Vec<Compartment<T>>) -> &mut Self;
+    /// Gets compartments.
+    fn get_compartments(&self) -> Option<&Vec<Compartment<T>>>;
+}
+
+/// A trait to get or set job's goods type.
+pub trait GoodsDimension {
+    /// Sets goods type.
+    fn set_goods(&mut self, goods: &str) -> &mut Self;
+    /// Gets goods type.
+    fn get_goods(&self) -> Option<&String>;
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> CompartmentDimension<T> for Dimensions {
+    fn set_compartments(&mut self, compartments: Vec<Compartment<T>>) -> &mut Self {
+        self.set_value(COMPARTMENTS_DIMENSION_KEY, compartments);
+        self
+    }
+
+    fn get_compartments(&self) -> Option<&Vec<Compartment<T>>> {
+        self.get_value(COMPARTMENTS_DIMENSION_KEY)
+    }
+}
+
+impl GoodsDimension for Dimensions {
+    fn set_goods(&mut self, goods: &str) -> &mut Self {
+        self.set_value(GOODS_DIMENSION_KEY, goods.to_string());
+        self
+    }
+
+    fn get_goods(&self) -> Option<&String> {
+        self.get_value(GOODS_DIMENSION_KEY)
+    }
+}
+
+/// Keeps load of each goods type at specific activity within its reload interval.
+struct GoodsLoads<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    current: HashMap<String, T>,
+    max_past: HashMap<String, T>,
+    max_future: HashMap<String, T>,
+    /// Max load of each goods type within the whole interval.
+    peaks: Arc<HashMap<String, T>>,
+}
+
+/// A module which ensures that goods are loaded into compatible vehicle compartments and each
+/// compartment holds only one goods type within a trip. Vehicle capacity itself is still checked
+/// by `CapacityConstraintModule` which has to be added to the pipeline before this module.
+///
+/// Vehicles without compartments and jobs without goods type are not restricted.
+pub struct CompartmentConstraintModule<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> CompartmentConstraintModule<T> {
+    /// Creates a new instance of `CompartmentConstraintModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            state_keys: vec![COMPARTMENT_LOADS_KEY],
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(CompartmentHardRouteConstraint::<T> {
+                    code,
+                    phantom: Default::default(),
+                })),
+                ConstraintVariant::HardActivity(Arc::new(CompartmentHardActivityConstraint::<T> {
+                    code,
+                    phantom: Default::default(),
+                })),
+            ],
+            phantom: Default::default(),
+        }
+    }
+
+    fn recalculate_states(&self, ctx: &mut RouteContext) {
+        if get_compartments::<T>(&ctx.route.actor.vehicle.dimens).is_none() {
+            return;
+        }
+
+        let intervals = ctx
+            .state
+            .get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)
+            .cloned()
+            .unwrap_or_else(|| vec![(0, ctx.route.tour.total() - 1)]);
+
+        let (route, state) = ctx.as_mut();
+
+        intervals.into_iter().for_each(|(start_idx, end_idx)| {
+            let activities = route.tour.activities_slice(start_idx, end_idx);
+
+            // static deliveries are loaded at the begin of interval
+            let start = activities.iter().fold(HashMap::<String, T>::new(), |mut acc, activity| {
+                if let Some((goods, demand)) = get_goods_demand::<T>(activity) {
+                    add_load(&mut acc, goods, demand.delivery.0);
+                }
+                acc
+            });
+
+            let (currents, max_pasts) = activities.iter().fold(
+                (Vec::with_capacity(activities.len()), Vec::<HashMap<String, T>>::with_capacity(activities.len())),
+                |(mut currents, mut max_pasts), activity| {
+                    let mut current = currents.last().cloned().unwrap_or_else(|| start.clone());
+                    if let Some((goods, demand)) = get_goods_demand::<T>(activity) {
+                        add_load(&mut current, goods, demand.change());
+                    }
+
+                    let mut max_past = max_pasts.last().cloned().unwrap_or_default();
+                    merge_max(&mut max_past, &current);
+
+                    currents.push(current);
+                    max_pasts.push(max_past);
+
+                    (currents, max_pasts)
+                },
+            );
+
+            let max_futures = currents.iter().rev().fold(Vec::with_capacity(currents.len()), |mut acc, current| {
+                let mut max_future: HashMap<String, T> = acc.last().cloned().unwrap_or_default();
+                merge_max(&mut max_future, current);
+                acc.push(max_future);
+                acc
+            });
+
+            let peaks = Arc::new(max_pasts.last().cloned().unwrap_or_default());
+
+            activities
+                .iter()
+                .zip(currents.into_iter().zip(max_pasts.into_iter()).zip(max_futures.into_iter().rev()))
+                .for_each(|(activity, ((current, max_past), max_future))| {
+                    state.put_activity_state(
+                        COMPARTMENT_LOADS_KEY,
+                        activity,
+                        GoodsLoads { current, max_past, max_future, peaks: peaks.clone() },
+                    );
+                });
+        });
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> ConstraintModule for CompartmentConstraintModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.recalculate_states(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.recalculate_states(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.recalculate_states(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Checks that vehicle has compartments compatible with job's goods.
+struct CompartmentHardRouteConstraint<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    code: i32,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> HardRouteConstraint for CompartmentHardRouteConstraint<T> {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let compartments = get_compartments::<T>(&ctx.route.actor.vehicle.dimens)?;
+
+        let is_compatible = |single: &Arc<Single>| {
+            single.dimens.get_goods().map_or(true, |goods| compartments.iter().any(|c| c.goods.contains(goods)))
+        };
+
+        let is_compatible = match job {
+            Job::Single(single) => is_compatible(single),
+            Job::Multi(multi) => multi.jobs.iter().all(is_compatible),
+        };
+
+        if is_compatible {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+/// Checks that goods loads can be distributed between compartments after insertion.
+struct CompartmentHardActivityConstraint<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    code: i32,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> HardActivityConstraint
+    for CompartmentHardActivityConstraint<T>
+{
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let compartments = get_compartments::<T>(&route_ctx.route.actor.vehicle.dimens)?;
+        let (goods, demand) = get_goods_demand::<T>(activity_ctx.target)?;
+
+        let loads = route_ctx.state.get_activity_state::<GoodsLoads<T>>(COMPARTMENT_LOADS_KEY, activity_ctx.prev);
+        let get_load = |loads: Option<&HashMap<String, T>>| loads.and_then(|loads| loads.get(goods)).cloned();
+
+        let mut peaks = loads.map_or_else(HashMap::new, |loads| loads.peaks.as_ref().clone());
+        let mut peak = peaks.get(goods).cloned().unwrap_or_default();
+
+        // static deliveries increase load from the begin of interval till the target activity
+        if demand.delivery.0.is_not_empty() {
+            let past = get_load(loads.map(|loads| &loads.max_past)).unwrap_or_default();
+            peak = peak.max_load(past + demand.delivery.0);
+        }
+
+        // pickups increase load from the target activity till the end of interval
+        let change = demand.change();
+        if change.is_not_empty() {
+            let future = get_load(loads.map(|loads| &loads.max_future)).unwrap_or_default();
+            let current = get_load(loads.map(|loads| &loads.current)).unwrap_or_default();
+            peak = peak.max_load(future + change).max_load(current + change);
+        }
+
+        peaks.insert(goods.clone(), peak);
+        let peaks = peaks.into_iter().collect::<Vec<_>>();
+
+        if can_load_into_compartments(compartments.as_slice(), peaks.as_slice()) {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+/// Checks whether given loads of goods can be distributed between compartments, so that each
+/// compartment holds only one goods type which it is compatible with.
+pub fn can_load_into_compartments<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+    compartments: &[Compartment<T>],
+    loads: &[(String, T)],
+) -> bool {
+    fn assign<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+        compartments: &[Compartment<T>],
+        loads: &[(&String, T)],
+        assigned: &mut Vec<T>,
+    ) -> bool {
+        let is_done = loads.iter().zip(assigned.iter()).all(|((_, load), capacity)| capacity.can_fit(load));
+
+        match compartments.split_first() {
+            _ if is_done => true,
+            Some((compartment, rest)) => {
+                (0..loads.len()).any(|idx| {
+                    let (goods, load) = loads[idx];
+                    if !compartment.goods.contains(goods) || assigned[idx].can_fit(&load) {
+                        return false;
+                    }
+
+                    let previous = assigned[idx];
+                    assigned[idx] = previous + compartment.capacity;
+                    let result = assign(rest, loads, assigned);
+                    assigned[idx] = previous;
+
+                    result
+                }) || assign(rest, loads, assigned)
+            }
+            None => false,
+        }
+    }
+
+    let loads =
+        loads.iter().filter(|(_, load)| load.is_not_empty()).map(|(goods, load)| (goods, *load)).collect::<Vec<_>>();
+    let mut assigned = vec![T::default(); loads.len()];
+
+    assign(compartments, loads.as_slice(), &mut assigned)
+}
+
+fn get_compartments<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+    dimens: &Dimensions,
+) -> Option<&Vec<Compartment<T>>> {
+    CompartmentDimension::<T>::get_compartments(dimens)
+}
+
+fn get_goods_demand<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+    activity: &Activity,
+) -> Option<(&String, &Demand<T>)> {
+    activity.job.as_ref().and_then(|job| job.dimens.get_goods().zip(job.dimens.get_demand()))
+}
+
+fn add_load<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+    loads: &mut HashMap<String, T>,
+    goods: &str,
+    load: T,
+) {
+    let current = loads.get(goods).cloned().unwrap_or_default();
+    loads.insert(goods.to_string(), current + load);
+}
+
+fn merge_max<T: Load + Add<Output = T> + Sub<Output = T> + 'static>(
+    target: &mut HashMap<String, T>,
+    source: &HashMap<String, T>,
+) {
+    source.iter().for_each(|(goods, load)| {
+        let max = target.get(goods).map_or(*load, |max| max.max_load(*load));
+        target.insert(goods.clone(), max);
+    });
+}
@@ -72,6 +72,19 @@ pub const MAX_FUTURE_CAPACITY_KEY: i32 = 12;
 pub const MAX_PAST_CAPACITY_KEY: i32 = 13;
 /// A key which tracks reload intervals.
 pub const RELOAD_INTERVALS_KEY: i32 = 14;
+/// A key which tracks loads of goods types for compartments.
+pub const COMPARTMENT_LOADS_KEY: i32 = 15;
+
+/// A key which tracks extra cost of serving jobs inside cost areas.
+pub const AREA_COST_KEY: i32 = 30;
+
+/// A key which tracks state of charge of vehicle battery.
+pub const BATTERY_CHARGE_KEY: i32 = 40;
+/// A key which tracks whether charging stations have to be offered for insertion.
+pub const CHARGING_REQUIRED_KEY: i32 = 41;
+
+/// A key which tracks amount of stops with jobs in the tour.
+pub const TOUR_STOPS_KEY: i32 = 50;
 
 mod pipeline;
 pub use self::pipeline::*;
@@ -85,6 +98,9 @@ pub use self::transport::*;
 mod capacity;
 pub use self::capacity::*;
 
+mod compartments;
+pub use self::compartments::*;
+
 mod locking;
 pub use self::locking::*;
 
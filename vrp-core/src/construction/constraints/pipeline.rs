@@ -171,6 +171,17 @@ impl ConstraintPipeline {
         self.hard_route_constraints.iter().find_map(|c| c.evaluate_job(solution_ctx, route_ctx, job))
     }
 
+    /// Checks all hard route constraints.
+    /// Returns results of all failed constraints.
+    pub fn evaluate_hard_route_violations(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Vec<RouteConstraintViolation> {
+        self.hard_route_constraints.iter().filter_map(|c| c.evaluate_job(solution_ctx, route_ctx, job)).collect()
+    }
+
     /// Checks whether all activity route constraints are fulfilled.
     /// Returns result of first failed constraint or empty value.
     pub fn evaluate_hard_activity(
@@ -181,6 +192,16 @@ impl ConstraintPipeline {
         self.hard_activity_constraints.iter().find_map(|c| c.evaluate_activity(route_ctx, activity_ctx))
     }
 
+    /// Checks all hard activity constraints.
+    /// Returns results of all failed constraints.
+    pub fn evaluate_hard_activity_violations(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Vec<ActivityConstraintViolation> {
+        self.hard_activity_constraints.iter().filter_map(|c| c.evaluate_activity(route_ctx, activity_ctx)).collect()
+    }
+
     /// Checks soft route constraints and aggregates associated actual and penalty costs.
     pub fn evaluate_soft_route(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> Cost {
         self.soft_route_constraints.iter().map(|c| c.estimate_job(solution_ctx, route_ctx, job)).sum()
@@ -151,35 +151,57 @@ impl TransportConstraintModule {
     }
 
     fn reschedule_departure(&self, ctx: &mut RouteContext) {
-        if let Some((last_departure_time, new_departure_time)) = self.analyze_departures(ctx) {
-            if new_departure_time > last_departure_time {
-                let mut start = ctx.route_mut().tour.get_mut(0).unwrap();
-                start.schedule.departure = new_departure_time;
-                self.update_route_schedules(ctx);
-                self.update_route_states(ctx);
+        let earliest_departure_time = ctx.route.actor.detail.time.start;
+        let last_departure_time = ctx.route.tour.start().unwrap().schedule.departure;
+
+        // NOTE start from the earliest possible departure to be able to move it back when needed
+        if last_departure_time > earliest_departure_time {
+            self.set_departure(ctx, earliest_departure_time);
+        }
+
+        if let Some(new_departure_time) = self.analyze_departures(ctx) {
+            if new_departure_time > earliest_departure_time {
+                self.set_departure(ctx, new_departure_time);
             }
         }
     }
 
-    fn analyze_departures(&self, ctx: &RouteContext) -> Option<(Timestamp, Timestamp)> {
-        if let Some(first) = ctx.route.tour.get(1) {
-            let start = ctx.route.tour.start().unwrap();
-            let last_departure_time = start.schedule.departure;
-            let start_to_first = self.transport.duration(
-                ctx.route.actor.vehicle.profile,
-                start.place.location,
-                first.place.location,
-                last_departure_time,
-            );
-
-            let latest_allowed_departure =
-                ctx.route.actor.detail.start.as_ref().and_then(|s| s.time.latest).unwrap_or(std::f64::MAX);
-
-            let new_departure_time =
-                last_departure_time.max(first.place.time.start - start_to_first).min(latest_allowed_departure);
-            return Some((last_departure_time, new_departure_time));
+    fn set_departure(&self, ctx: &mut RouteContext, departure_time: Timestamp) {
+        ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = departure_time;
+        self.update_route_schedules(ctx);
+        self.update_route_states(ctx);
+    }
+
+    /// Returns the latest departure time which eliminates waiting time in the tour, if possible,
+    /// without violating time windows of its activities.
+    fn analyze_departures(&self, ctx: &RouteContext) -> Option<Timestamp> {
+        if !ctx.route.tour.has_jobs() {
+            return None;
         }
-        None
+
+        let start = ctx.route.tour.start().unwrap();
+        let last_departure_time = start.schedule.departure;
+
+        // NOTE shifting departure by some time delays an activity only by the part which
+        // is not absorbed by waiting at previous activities.
+        let (total_waiting, max_shift) =
+            ctx.route.tour.all_activities().skip(1).fold((0., std::f64::MAX), |(waiting, max_shift), a| {
+                let max_shift = max_shift.min(a.place.time.end - a.schedule.arrival + waiting);
+                let waiting = waiting + (a.place.time.start - a.schedule.arrival).max(0.);
+
+                (waiting, max_shift)
+            });
+
+        let latest_allowed_departure =
+            ctx.route.actor.detail.start.as_ref().and_then(|s| s.time.latest).unwrap_or(std::f64::MAX);
+
+        // NOTE waiting at the first activity can always be removed as it was done before
+        let first_waiting = ctx.route.tour.get(1).map_or(0., |a| (a.place.time.start - a.schedule.arrival).max(0.));
+        let shift = total_waiting.min(max_shift).max(first_waiting);
+
+        let new_departure_time = (last_departure_time + shift).min(latest_allowed_departure);
+
+        Some(new_departure_time.max(last_departure_time))
     }
 
     fn update_statistics(&self, ctx: &mut RouteContext) {
@@ -2,7 +2,7 @@
 #[path = "../../../tests/unit/construction/heuristics/context_test.rs"]
 mod context_test;
 
-use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
+use crate::construction::constraints::{AREA_COST_KEY, TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
 use crate::construction::heuristics::factories::*;
 use crate::models::common::Cost;
 use crate::models::problem::*;
@@ -210,7 +210,11 @@ impl RouteContext {
         let distance = self.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
         let duration = self.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
 
-        get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+        let area_cost = self.state.get_route_state::<f64>(AREA_COST_KEY).cloned().unwrap_or(0.);
+
+        get_cost(&actor.vehicle.costs, distance, duration)
+            + get_cost(&actor.driver.costs, distance, duration)
+            + area_cost
     }
 
     /// Unwraps given `RouteContext` as pair of mutable references.
@@ -364,6 +368,11 @@ impl RegistryContext {
         self.registry.next().map(move |actor| self.index[&actor].clone())
     }
 
+    /// Returns routes of all available actors.
+    pub fn available<'a>(&'a self) -> impl Iterator<Item = RouteContext> + 'a {
+        self.registry.available().map(move |actor| self.index[&actor].clone())
+    }
+
     /// Sets this route as used.
     /// Returns whether the route was already marked as used in the registry.
     pub fn use_route(&mut self, route: &RouteContext) -> bool {
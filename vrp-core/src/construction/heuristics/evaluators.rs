@@ -15,6 +15,8 @@ use crate::models::solution::{Activity, Place};
 pub enum InsertionPosition {
     /// Job can be inserted anywhere in the route.
     Any,
+    /// Job can be inserted only to the beginning of the route.
+    First,
     /// Job can be inserted only to the end of the route.
     Last,
 }
@@ -193,6 +195,13 @@ fn analyze_insertion_in_route(
                 analyze_insertion_in_route_leg(constraint, route_ctx, leg, single, target, out)
             })
         }
+        InsertionPosition::First => {
+            if let Some(leg) = route_ctx.route.tour.legs().nth(init.index) {
+                analyze_insertion_in_route_leg(constraint, route_ctx, leg, single, target, init)
+            } else {
+                Ok(init)
+            }
+        }
         InsertionPosition::Last => {
             if let Some(last_leg) = route_ctx.route.tour.legs().last() {
                 analyze_insertion_in_route_leg(constraint, route_ctx, last_leg, single, target, init)
@@ -183,7 +183,7 @@ impl InsertionHeuristic {
         while !ctx.solution.required.is_empty() && !quota.as_ref().map_or(false, |q| q.is_reached()) {
             let jobs = job_selector.select(&mut ctx).collect::<Vec<Job>>();
             let result = job_reducer.reduce(&ctx, jobs, self.insertion_position);
-            insert(result, &mut ctx);
+            apply_insertion(result, &mut ctx);
         }
 
         finalize_ctx(&mut ctx);
@@ -235,7 +235,8 @@ fn finalize_ctx(ctx: &mut InsertionContext) {
     ctx.problem.constraint.accept_solution_state(&mut ctx.solution);
 }
 
-fn insert(result: InsertionResult, ctx: &mut InsertionContext) {
+/// Applies insertion result to the insertion context.
+pub(crate) fn apply_insertion(result: InsertionResult, ctx: &mut InsertionContext) {
     match result {
         InsertionResult::Success(success) => {
             let is_new_route = ctx.solution.registry.use_route(&success.context);
@@ -199,6 +199,121 @@ impl TransportCost for TimeAgnosticMatrixTransportCost {
     }
 }
 
+/// Provides read access to routing matrix values kept in a compact form.
+pub trait MatrixValues: Send + Sync {
+    /// Returns value at given index. Negative value means that location is not reachable.
+    fn value(&self, index: usize) -> f64;
+
+    /// Returns total amount of values.
+    fn len(&self) -> usize;
+
+    /// Returns true if there are no values.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Keeps values as unsigned integers: `u32::MAX` is used to mark unreachable locations.
+impl MatrixValues for Vec<u32> {
+    fn value(&self, index: usize) -> f64 {
+        match self[index] {
+            u32::MAX => -1.,
+            value => value as f64,
+        }
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+/// Keeps values as single precision floats.
+impl MatrixValues for Vec<f32> {
+    fn value(&self, index: usize) -> f64 {
+        self[index] as f64
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+/// Contains compact time agnostic matrix routing data for specific profile.
+pub struct CompactMatrixData {
+    /// A routing profile.
+    pub profile: Profile,
+    /// Travel durations. When not set, distances are used as durations.
+    pub durations: Option<Arc<dyn MatrixValues>>,
+    /// Travel distances.
+    pub distances: Arc<dyn MatrixValues>,
+}
+
+impl CompactMatrixData {
+    /// Creates `CompactMatrixData` with separate durations and distances.
+    pub fn new(profile: Profile, durations: Arc<dyn MatrixValues>, distances: Arc<dyn MatrixValues>) -> Self {
+        Self { profile, durations: Some(durations), distances }
+    }
+
+    /// Creates `CompactMatrixData` which uses distances as durations.
+    pub fn new_distance_only(profile: Profile, distances: Arc<dyn MatrixValues>) -> Self {
+        Self { profile, durations: None, distances }
+    }
+}
+
+/// Creates time agnostic transport costs from compact routing matrices. Matrix values can be shared
+/// between profiles or between durations and distances.
+pub fn create_compact_matrix_transport_cost(
+    costs: Vec<CompactMatrixData>,
+) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+    if costs.is_empty() {
+        return Err("no matrix data found".to_string());
+    }
+
+    let mut costs = costs;
+    costs.sort_by_key(|data| data.profile);
+
+    if (0..).zip(costs.iter().map(|c| c.profile)).any(|(a, b)| a != b) {
+        return Err("duplicate profiles can be passed only for time aware routing".to_string());
+    }
+
+    let size = (costs.first().unwrap().distances.len() as f64).sqrt().round() as usize;
+
+    if costs.iter().any(|matrix| matrix.distances.len() != size * size) {
+        return Err("distance lengths don't match".to_string());
+    }
+
+    if costs.iter().filter_map(|matrix| matrix.durations.as_ref()).any(|durations| durations.len() != size * size) {
+        return Err("duration lengths don't match".to_string());
+    }
+
+    let (durations, distances) = costs.into_iter().fold((vec![], vec![]), |mut acc, data| {
+        let distances = data.distances;
+        acc.0.push(data.durations.unwrap_or_else(|| distances.clone()));
+        acc.1.push(distances);
+
+        acc
+    });
+
+    Ok(Arc::new(CompactMatrixTransportCost { durations, distances, size }))
+}
+
+/// A time agnostic matrix routing costs which keeps values in a compact form.
+struct CompactMatrixTransportCost {
+    durations: Vec<Arc<dyn MatrixValues>>,
+    distances: Vec<Arc<dyn MatrixValues>>,
+    size: usize,
+}
+
+impl TransportCost for CompactMatrixTransportCost {
+    fn duration(&self, profile: Profile, from: Location, to: Location, _: Timestamp) -> Duration {
+        self.durations.get(profile as usize).unwrap().value(from * self.size + to)
+    }
+
+    fn distance(&self, profile: Profile, from: Location, to: Location, _: Timestamp) -> Distance {
+        self.distances.get(profile as usize).unwrap().value(from * self.size + to)
+    }
+}
+
 /// A time aware matrix costs.
 struct TimeAwareMatrixTransportCost {
     costs: HashMap<Profile, (Vec<u64>, Vec<MatrixData>)>,
@@ -4,9 +4,12 @@ mod jobs_test;
 
 use crate::models::common::*;
 use crate::models::problem::{Costs, Fleet, TransportCost};
+use crate::utils::{compare_floats, parallel_collect};
 use hashbrown::HashMap;
 use std::cell::UnsafeCell;
+use std::cmp::Ordering;
 use std::cmp::Ordering::Less;
+use std::collections::BinaryHeap;
 use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Weak};
 
@@ -183,12 +186,30 @@ type JobIndex = HashMap<Job, (Vec<(Job, Cost)>, HashMap<Job, Cost>, Cost)>;
 pub struct Jobs {
     jobs: Vec<Job>,
     index: HashMap<Profile, JobIndex>,
+    costs: HashMap<Profile, Costs>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
 }
 
 impl Jobs {
     /// Creates a new [`Jobs`].
     pub fn new(fleet: &Fleet, jobs: Vec<Job>, transport: &Arc<dyn TransportCost + Send + Sync>) -> Jobs {
-        Jobs { jobs: jobs.clone(), index: create_index(fleet, jobs, transport) }
+        Self::new_with_neighbours(fleet, jobs, transport, None)
+    }
+
+    /// Creates a new [`Jobs`] keeping only `max_neighbours` nearest jobs in neighborhood of each job.
+    /// This reduces memory footprint of job index for big problems from quadratic to linear. Costs
+    /// between jobs which are not kept in the index are calculated on demand. Please note, that time
+    /// needed to build the index is still quadratic as costs between all jobs have to be evaluated.
+    pub fn new_with_neighbours(
+        fleet: &Fleet,
+        jobs: Vec<Job>,
+        transport: &Arc<dyn TransportCost + Send + Sync>,
+        max_neighbours: Option<usize>,
+    ) -> Jobs {
+        let costs = get_avg_profile_costs(fleet);
+        let index = create_index(fleet, &costs, jobs.clone(), transport, max_neighbours);
+
+        Jobs { jobs, index, costs, transport: transport.clone() }
     }
 
     /// Returns all jobs in original order.
@@ -209,7 +230,11 @@ impl Jobs {
 
     /// Returns cost distance between two jobs.
     pub fn distance(&self, profile: Profile, from: &Job, to: &Job, _: Timestamp) -> Cost {
-        *self.index.get(&profile).unwrap().get(from).unwrap().1.get(to).unwrap()
+        let costs = &self.index.get(&profile).unwrap().get(from).unwrap().1;
+
+        costs.get(to).cloned().unwrap_or_else(|| {
+            get_cost_between_jobs(profile, self.costs.get(&profile).unwrap(), &self.transport, from, to)
+        })
     }
 
     /// Returns job rank as relative cost from any vehicle's start position.
@@ -259,11 +284,11 @@ const UNREACHABLE_COST: Cost = std::f32::MAX as f64;
 /// Creates job index.
 fn create_index(
     fleet: &Fleet,
+    avg_profile_costs: &HashMap<Profile, Costs>,
     jobs: Vec<Job>,
     transport: &Arc<dyn TransportCost + Send + Sync>,
+    max_neighbours: Option<usize>,
 ) -> HashMap<Profile, JobIndex> {
-    let avg_profile_costs = get_avg_profile_costs(fleet);
-
     fleet.profiles.iter().cloned().fold(HashMap::new(), |mut acc, profile| {
         let avg_costs = avg_profile_costs.get(&profile).unwrap();
         // get all possible start positions for given profile
@@ -277,32 +302,87 @@ fn create_index(
             .collect();
 
         // create job index
-        let item = jobs.iter().cloned().fold(HashMap::new(), |mut acc, job| {
-            let mut sorted_job_costs: Vec<(Job, Cost)> = jobs
+        let item = parallel_collect(&jobs, |job| {
+            let job_costs = jobs
                 .iter()
-                .filter(|j| **j != job)
-                .map(|j| (j.clone(), get_cost_between_jobs(profile, avg_costs, transport, &job, j)))
-                .collect();
-            sorted_job_costs.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Less));
+                .enumerate()
+                .filter(|(_, j)| *j != job)
+                .map(|(idx, j)| (idx, get_cost_between_jobs(profile, avg_costs, transport, job, j)));
+
+            let sorted_job_costs: Vec<(Job, Cost)> = match max_neighbours {
+                Some(max_neighbours) => get_nearest_neighbours(job_costs, max_neighbours),
+                None => {
+                    let mut job_costs = job_costs.collect::<Vec<_>>();
+                    job_costs.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Less));
+                    job_costs
+                }
+            }
+            .into_iter()
+            .map(|(idx, cost)| (jobs[idx].clone(), cost))
+            .collect();
 
             let fleet_costs = starts
                 .iter()
                 .cloned()
-                .map(|s| get_cost_between_job_and_location(profile, avg_costs, transport, &job, s))
+                .map(|s| get_cost_between_job_and_location(profile, avg_costs, transport, job, s))
                 .min_by(|a, b| a.partial_cmp(b).unwrap_or(Less))
                 .unwrap_or(DEFAULT_COST);
 
             let job_costs_map = sorted_job_costs.iter().cloned().collect::<HashMap<_, _>>();
 
-            acc.insert(job, (sorted_job_costs, job_costs_map, fleet_costs));
-            acc
-        });
+            (job.clone(), (sorted_job_costs, job_costs_map, fleet_costs))
+        })
+        .into_iter()
+        .collect::<HashMap<_, _>>();
 
         acc.insert(profile, item);
         acc
     })
 }
 
+/// Keeps `max_neighbours` job indices with the lowest costs using bounded max-heap, so that
+/// neither all costs are stored nor fully sorted. Ties are resolved by job index as stable sort does.
+fn get_nearest_neighbours<I>(job_costs: I, max_neighbours: usize) -> Vec<(usize, Cost)>
+where
+    I: Iterator<Item = (usize, Cost)>,
+{
+    let mut heap = BinaryHeap::with_capacity(max_neighbours + 1);
+
+    job_costs.for_each(|(idx, cost)| {
+        heap.push(JobCost { idx, cost });
+        if heap.len() > max_neighbours {
+            heap.pop();
+        }
+    });
+
+    heap.into_sorted_vec().into_iter().map(|job_cost| (job_cost.idx, job_cost.cost)).collect()
+}
+
+struct JobCost {
+    idx: usize,
+    cost: Cost,
+}
+
+impl PartialEq for JobCost {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for JobCost {}
+
+impl PartialOrd for JobCost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JobCost {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_floats(self.cost, other.cost).then_with(|| self.idx.cmp(&other.idx))
+    }
+}
+
 #[inline(always)]
 fn get_cost_between_locations(
     profile: Profile,
@@ -5,7 +5,7 @@ use crate::solver::evolution::EvolutionConfig;
 use crate::solver::mutation::*;
 use crate::solver::selection::Selection;
 use crate::solver::termination::*;
-use crate::solver::{Solver, Telemetry};
+use crate::solver::{Decomposition, Solver, Telemetry};
 use crate::utils::{DefaultRandom, TimeQuota};
 use std::sync::Arc;
 
@@ -56,6 +56,12 @@ pub struct Builder {
     /// A randomization seed
     pub seed: Option<u64>,
 
+    /// A max amount of jobs in sub-problem when decomposition is used.
+    pub decomposition: Option<usize>,
+
+    /// A custom quota which can interrupt evolution, e.g. on external cancellation.
+    pub quota: Option<Arc<dyn Quota + Send + Sync>>,
+
     /// An evolution configuration..
     pub config: EvolutionConfig,
 }
@@ -68,6 +74,8 @@ impl Builder {
             max_time: None,
             cost_variation: None,
             seed: None,
+            decomposition: None,
+            quota: None,
             config: EvolutionConfig::new(problem),
         }
     }
@@ -170,6 +178,27 @@ impl Builder {
         self
     }
 
+    /// Sets max amount of jobs in sub-problem to enable cluster-first decomposition for huge problems.
+    /// Sub-problems are solved in parallel within half of termination limits and their merged solution
+    /// is used as initial one for global refinement. Default is None.
+    pub fn with_decomposition(mut self, max_jobs: Option<usize>) -> Self {
+        if let Some(max_jobs) = max_jobs {
+            self.config.telemetry.log(format!("configured to use decomposition with max jobs: {}", max_jobs).as_str());
+        }
+        self.decomposition = max_jobs;
+        self
+    }
+
+    /// Sets a custom quota which stops evolution when reached, e.g. to cancel solver from another
+    /// thread. It is combined with max time quota when both are set. Default is None.
+    pub fn with_quota(mut self, quota: Option<Arc<dyn Quota + Send + Sync>>) -> Self {
+        if quota.is_some() {
+            self.config.telemetry.log("configured to use custom quota");
+        }
+        self.quota = quota;
+        self
+    }
+
     /// Builds [`Solver`](./struct.Solver.html) instance.
     pub fn build(self) -> Result<Solver, String> {
         let problem = self.config.problem.clone();
@@ -214,8 +243,33 @@ impl Builder {
             };
 
         let mut config = self.config;
+
+        if let Some(max_jobs) = self.decomposition {
+            let (max_generations, max_time) = match (self.max_generations, self.max_time, self.cost_variation) {
+                (None, None, None) => (Some(1500), Some(150)),
+                _ => (self.max_generations.map(|limit| limit / 2), self.max_time.map(|limit| limit / 2)),
+            };
+
+            config.population.initial.size = 1;
+            config.population.initial.methods = vec![(
+                Box::new(Decomposition::new(
+                    max_jobs,
+                    max_generations,
+                    max_time,
+                    self.seed,
+                    config.telemetry.get_logger(),
+                )),
+                1,
+            )];
+        }
+
         config.termination = Arc::new(CompositeTermination::new(criterias));
-        config.quota = quota;
+        config.quota = match (quota, self.quota) {
+            (Some(time_quota), Some(custom_quota)) => {
+                Some(Arc::new(CompositeQuota { quotas: vec![time_quota, custom_quota] }))
+            }
+            (time_quota, custom_quota) => time_quota.or(custom_quota),
+        };
 
         config.random = Arc::new(if let Some(seed) = self.seed {
             config.telemetry.log(format!("configured to use seed: {}", seed).as_str());
@@ -231,3 +285,14 @@ impl Builder {
 fn create_time_quota(limit: usize) -> Option<Arc<dyn Quota + Sync + Send>> {
     Some(Arc::new(TimeQuota::new(limit as f64)))
 }
+
+/// A quota which is reached when any of its inner quotas is reached.
+struct CompositeQuota {
+    quotas: Vec<Arc<dyn Quota + Send + Sync>>,
+}
+
+impl Quota for CompositeQuota {
+    fn is_reached(&self) -> bool {
+        self.quotas.iter().any(|quota| quota.is_reached())
+    }
+}
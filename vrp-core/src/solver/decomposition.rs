@@ -0,0 +1,425 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/decomposition_test.rs"]
+mod decomposition_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::{IdDimension, Location};
+use crate::models::problem::{Actor, Fleet, Job, Jobs, Vehicle};
+use crate::models::solution::{Registry, Route};
+use crate::models::{Problem, Solution};
+use crate::solver::mutation::{get_job_location, Recreate, RecreateWithCheapest};
+use crate::solver::{Builder, InfoLogger, RefinementContext};
+use crate::utils::{compare_floats, get_cpus, parallel_collect, Random};
+use hashbrown::{HashMap, HashSet};
+use std::ops::Deref;
+use std::sync::Arc;
+
+type AffinityFn = Arc<dyn Fn(&Job) -> Option<String> + Sync + Send>;
+
+/// A wrapper around function which returns id of the vehicle the job is bound to, e.g. vehicle break.
+/// Such jobs are kept within the same sub-problem as their vehicle.
+pub struct JobVehicleAffinity {
+    affinity: AffinityFn,
+}
+
+impl JobVehicleAffinity {
+    /// Creates a new instance of `JobVehicleAffinity`.
+    pub fn new<F: 'static + Fn(&Job) -> Option<String> + Sync + Send>(affinity: F) -> Self {
+        Self { affinity: Arc::new(affinity) }
+    }
+
+    /// Returns vehicle id if job is bound to specific vehicle.
+    pub fn get_vehicle_id(&self, job: &Job) -> Option<String> {
+        self.affinity.deref()(job)
+    }
+}
+
+/// Builds a solution using cluster-first decomposition: jobs and vehicles are partitioned into
+/// sub-problems around spatially distant seed jobs, each sub-problem is solved independently
+/// in parallel and resulting routes are merged into one solution.
+pub struct Decomposition {
+    max_jobs: usize,
+    max_generations: Option<usize>,
+    max_time: Option<usize>,
+    seed: Option<u64>,
+    logger: Option<InfoLogger>,
+}
+
+impl Decomposition {
+    /// Creates a new instance of `Decomposition` which keeps sub-problems within `max_jobs` size and
+    /// solves each of them using given termination criteria. Time limit is shared by all sub-problems,
+    /// errors of sub-problem solvers are reported using optional logger.
+    pub fn new(
+        max_jobs: usize,
+        max_generations: Option<usize>,
+        max_time: Option<usize>,
+        seed: Option<u64>,
+        logger: Option<InfoLogger>,
+    ) -> Self {
+        Self { max_jobs: max_jobs.max(1), max_generations, max_time, seed, logger }
+    }
+}
+
+impl Recreate for Decomposition {
+    fn run(&self, refinement_ctx: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let problem = insertion_ctx.problem.clone();
+        let partitions = create_partitions(problem.as_ref(), self.max_jobs, insertion_ctx.random.as_ref());
+
+        if partitions.len() < 2 {
+            return RecreateWithCheapest::default().run(refinement_ctx, insertion_ctx);
+        }
+
+        let sub_problems = partitions
+            .into_iter()
+            .map(|(vehicles, jobs)| Arc::new(create_sub_problem(problem.as_ref(), vehicles, jobs)))
+            .collect::<Vec<_>>();
+
+        let max_time = get_sub_problem_time(self.max_time, sub_problems.len(), get_cpus());
+        let indices = (0..sub_problems.len()).collect::<Vec<_>>();
+        let solutions = parallel_collect(&indices, |&idx| {
+            Builder::new(sub_problems[idx].clone())
+                .with_max_generations(self.max_generations)
+                .with_max_time(max_time)
+                .with_seed(self.seed.map(|seed| seed + idx as u64))
+                .build()
+                .and_then(|solver| solver.solve())
+                .map(|(solution, _, _)| solution)
+        });
+
+        let solutions = solutions
+            .into_iter()
+            .zip(sub_problems.iter())
+            .enumerate()
+            .filter_map(|(idx, (solution, sub_problem))| match solution {
+                Ok(solution) => Some((solution, sub_problem.clone())),
+                Err(err) => {
+                    if let Some(logger) = &self.logger {
+                        logger.deref()(format!("cannot solve sub-problem {}: '{}'", idx, err).as_str());
+                    }
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        create_merged_context(refinement_ctx, problem, solutions, insertion_ctx.random)
+    }
+}
+
+type Partition = (Vec<Arc<Vehicle>>, Vec<Job>);
+
+/// Merges sub-problem solutions and inserts jobs which are not assigned to any route, e.g. jobs of
+/// failed sub-problems, using cheapest insertion. Jobs which cannot be inserted are reported as unassigned.
+fn create_merged_context(
+    refinement_ctx: &RefinementContext,
+    problem: Arc<Problem>,
+    solutions: Vec<(Solution, Arc<Problem>)>,
+    random: Arc<dyn Random + Send + Sync>,
+) -> InsertionContext {
+    let solution = merge_solutions(problem.as_ref(), solutions);
+    let mut insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), random);
+
+    let solution = &mut insertion_ctx.solution;
+    let assigned = solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+        .chain(solution.ignored.iter().cloned())
+        .collect::<HashSet<_>>();
+
+    solution.unassigned.clear();
+    solution.required = problem.jobs.all().filter(|job| !assigned.contains(job)).collect();
+
+    RecreateWithCheapest::default().run(refinement_ctx, insertion_ctx)
+}
+
+/// Returns time limit for each sub-problem which keeps total time needed to solve all of them
+/// within given time budget taking into account that only `parallelism` of them are solved at once.
+fn get_sub_problem_time(max_time: Option<usize>, sub_problems: usize, parallelism: usize) -> Option<usize> {
+    max_time.map(|max_time| {
+        let parallelism = parallelism.max(1);
+        let rounds = ((sub_problems + parallelism - 1) / parallelism).max(1);
+
+        (max_time / rounds).max(1)
+    })
+}
+
+/// Splits jobs and vehicles into partitions with no more than `max_jobs` jobs bound to locations.
+fn create_partitions(problem: &Problem, max_jobs: usize, random: &(dyn Random + Send + Sync)) -> Vec<Partition> {
+    let fleet = problem.fleet.as_ref();
+    let affinity = problem.extras.get("job_vehicle_affinity").and_then(|a| a.downcast_ref::<JobVehicleAffinity>());
+
+    let (bound_jobs, free_jobs): (Vec<_>, Vec<_>) = problem.jobs.all().partition(|job| {
+        affinity.and_then(|affinity| affinity.get_vehicle_id(job)).is_some()
+            || problem.locks.iter().any(|lock| lock.details.iter().any(|detail| detail.jobs.contains(job)))
+    });
+
+    let (located_jobs, unlocated_jobs): (Vec<_>, Vec<_>) =
+        free_jobs.into_iter().map(|job| (get_job_location(&job), job)).partition(|(location, _)| location.is_some());
+    let located_jobs = located_jobs.into_iter().map(|(location, job)| (location.unwrap(), job)).collect::<Vec<_>>();
+
+    let total_partitions = (located_jobs.len() + max_jobs - 1) / max_jobs;
+    let total_partitions = total_partitions.min(fleet.vehicles.len());
+
+    if total_partitions < 2 {
+        return vec![];
+    }
+
+    let profile = fleet.profiles.first().cloned().unwrap_or_default();
+    let distance = |from: Location, to: Location| problem.transport.distance(profile, from, to, 0.);
+    let seeds = select_seeds(&located_jobs, total_partitions, random, &distance);
+
+    let mut partitions = assign_jobs(located_jobs, &seeds, max_jobs, &distance);
+    let vehicle_partitions = assign_vehicles(fleet, &seeds, &partitions, &distance);
+
+    vehicle_partitions.iter().enumerate().for_each(|(vehicle_idx, &partition_idx)| {
+        partitions[partition_idx].0.push(fleet.vehicles[vehicle_idx].clone());
+    });
+
+    let get_smallest_partition =
+        |partitions: &[Partition]| (0..partitions.len()).min_by_key(|&idx| partitions[idx].1.len()).unwrap_or(0);
+
+    unlocated_jobs.into_iter().for_each(|(_, job)| {
+        let partition_idx = get_smallest_partition(&partitions);
+        partitions[partition_idx].1.push(job);
+    });
+
+    let get_vehicle_partition = |is_vehicle: &dyn Fn(&Vehicle) -> bool| {
+        fleet.vehicles.iter().position(|vehicle| is_vehicle(vehicle)).map(|idx| vehicle_partitions[idx])
+    };
+
+    let mut lock_partitions = HashMap::<usize, usize>::new();
+    bound_jobs.into_iter().for_each(|job| {
+        let vehicle_partition = affinity
+            .and_then(|affinity| affinity.get_vehicle_id(&job))
+            .and_then(|vehicle_id| get_vehicle_partition(&|vehicle| vehicle.dimens.get_id() == Some(&vehicle_id)));
+
+        let partition_idx = vehicle_partition
+            .or_else(|| {
+                // NOTE keep all jobs of the same lock together with the vehicle it is locked to
+                let lock_idx = problem
+                    .locks
+                    .iter()
+                    .position(|lock| lock.details.iter().any(|detail| detail.jobs.contains(&job)))?;
+
+                Some(*lock_partitions.entry(lock_idx).or_insert_with(|| {
+                    let lock = &problem.locks[lock_idx];
+                    fleet
+                        .actors
+                        .iter()
+                        .filter(|actor| lock.condition.deref()(actor.as_ref()))
+                        .filter_map(|actor| {
+                            get_vehicle_partition(&|vehicle| std::ptr::eq(vehicle, actor.vehicle.as_ref()))
+                        })
+                        .next()
+                        .unwrap_or_else(|| get_smallest_partition(&partitions))
+                }))
+            })
+            .unwrap_or_else(|| get_smallest_partition(&partitions));
+
+        partitions[partition_idx].1.push(job);
+    });
+
+    // NOTE jobs of partitions without vehicles are inserted after merging sub-problem solutions
+    partitions.into_iter().filter(|(vehicles, jobs)| !vehicles.is_empty() && !jobs.is_empty()).collect()
+}
+
+/// Selects spatially distant seed jobs using farthest first traversal.
+fn select_seeds(
+    jobs: &[(Location, Job)],
+    total: usize,
+    random: &(dyn Random + Send + Sync),
+    distance: &dyn Fn(Location, Location) -> f64,
+) -> Vec<Location> {
+    let first = jobs[random.uniform_int(0, jobs.len() as i32 - 1) as usize].0;
+    let mut seeds = vec![first];
+    let mut min_distances = jobs.iter().map(|(location, _)| distance(first, *location)).collect::<Vec<_>>();
+
+    while seeds.len() < total {
+        let (next_idx, _) =
+            min_distances.iter().enumerate().max_by(|(_, a), (_, b)| compare_floats(**a, **b)).expect("empty jobs");
+        let next = jobs[next_idx].0;
+
+        jobs.iter().zip(min_distances.iter_mut()).for_each(|((location, _), min_distance)| {
+            *min_distance = min_distance.min(distance(next, *location));
+        });
+
+        seeds.push(next);
+    }
+
+    seeds
+}
+
+/// Assigns jobs to their nearest seeds keeping partition size within `max_jobs`.
+fn assign_jobs(
+    jobs: Vec<(Location, Job)>,
+    seeds: &[Location],
+    max_jobs: usize,
+    distance: &dyn Fn(Location, Location) -> f64,
+) -> Vec<Partition> {
+    let capacity = ((jobs.len() + seeds.len() - 1) / seeds.len()).min(max_jobs).max(1);
+
+    let mut jobs = jobs
+        .into_iter()
+        .map(|(location, job)| {
+            let mut seed_distances =
+                seeds.iter().enumerate().map(|(idx, seed)| (idx, distance(*seed, location))).collect::<Vec<_>>();
+            seed_distances.sort_by(|(_, a), (_, b)| compare_floats(*a, *b));
+
+            (seed_distances, job)
+        })
+        .collect::<Vec<_>>();
+
+    // NOTE jobs close to their seeds are assigned first
+    jobs.sort_by(|(a, _), (b, _)| compare_floats(a[0].1, b[0].1));
+
+    jobs.into_iter().fold(seeds.iter().map(|_| (vec![], vec![])).collect(), |mut partitions, (distances, job)| {
+        let partition_idx = distances
+            .iter()
+            .map(|(idx, _)| *idx)
+            .find(|idx| partitions[*idx].1.len() < capacity)
+            .unwrap_or(distances[0].0);
+
+        partitions[partition_idx].1.push(job);
+
+        partitions
+    })
+}
+
+/// Assigns vehicles to partitions proportionally to partition sizes preferring partitions with nearest seeds.
+/// Each partition gets at least one vehicle when fleet is big enough. Returns partition index for each fleet vehicle.
+fn assign_vehicles(
+    fleet: &Fleet,
+    seeds: &[Location],
+    partitions: &[Partition],
+    distance: &dyn Fn(Location, Location) -> f64,
+) -> Vec<usize> {
+    let total_vehicles = fleet.vehicles.len();
+    let total_jobs = partitions.iter().map(|(_, jobs)| jobs.len()).sum::<usize>().max(1);
+
+    // NOTE reserve one vehicle per partition, so quotas never exceed fleet size
+    let reserved = partitions.len().min(total_vehicles);
+    let spare = total_vehicles - reserved;
+    let mut quotas = partitions
+        .iter()
+        .enumerate()
+        .map(|(idx, (_, jobs))| {
+            let base = if idx < reserved { 1 } else { 0 };
+            base + spare * jobs.len() / total_jobs
+        })
+        .collect::<Vec<_>>();
+
+    // NOTE distribute remaining vehicles across the biggest partitions
+    let mut order = (0..partitions.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| partitions[*b].1.len().cmp(&partitions[*a].1.len()));
+    let assigned = quotas.iter().sum::<usize>();
+    (assigned..total_vehicles).zip(order.iter().cycle()).for_each(|(_, idx)| quotas[*idx] += 1);
+
+    fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| {
+            let start = vehicle.details.iter().filter_map(|detail| detail.start.as_ref()).map(|s| s.location).next();
+            let mut distances = seeds
+                .iter()
+                .enumerate()
+                .map(|(idx, seed)| (idx, start.map_or(0., |start| distance(start, *seed))))
+                .collect::<Vec<_>>();
+            distances.sort_by(|(_, a), (_, b)| compare_floats(*a, *b));
+
+            let partition_idx = distances
+                .iter()
+                .map(|(idx, _)| *idx)
+                .find(|idx| quotas[*idx] > 0)
+                .or_else(|| (0..quotas.len()).max_by_key(|idx| quotas[*idx]))
+                .unwrap_or(0);
+
+            quotas[partition_idx] = quotas[partition_idx].saturating_sub(1);
+
+            partition_idx
+        })
+        .collect()
+}
+
+/// Creates a sub-problem which shares all properties of original problem except fleet, jobs and locks.
+fn create_sub_problem(problem: &Problem, vehicles: Vec<Arc<Vehicle>>, jobs: Vec<Job>) -> Problem {
+    let groups =
+        get_actor_keys(problem.fleet.actors.iter()).into_iter().fold(HashMap::new(), |mut acc, (actor_key, actor)| {
+            let group =
+                problem.fleet.groups.iter().find(|(_, actors)| actors.contains(&actor)).map(|(group, _)| *group);
+            acc.insert(actor_key, group.unwrap_or_default());
+            acc
+        });
+
+    let fleet = Arc::new(Fleet::new(
+        problem.fleet.drivers.clone(),
+        vehicles,
+        Box::new(move |actors| {
+            let actor_groups = get_actor_keys(actors.iter())
+                .into_iter()
+                .map(|(actor_key, actor)| (actor, groups.get(&actor_key).cloned().unwrap_or_default()))
+                .collect::<HashMap<_, _>>();
+
+            Box::new(move |actor| actor_groups.get(actor).cloned().unwrap_or_default())
+        }),
+    ));
+
+    let job_set = jobs.iter().cloned().collect::<HashSet<_>>();
+    let locks = problem
+        .locks
+        .iter()
+        .filter(|lock| lock.details.iter().flat_map(|detail| detail.jobs.iter()).all(|job| job_set.contains(job)))
+        .cloned()
+        .collect();
+
+    Problem {
+        fleet: fleet.clone(),
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &problem.transport)),
+        locks,
+        constraint: problem.constraint.clone(),
+        activity: problem.activity.clone(),
+        transport: problem.transport.clone(),
+        objective: problem.objective.clone(),
+        extras: problem.extras.clone(),
+    }
+}
+
+/// Merges sub-problem solutions into a solution of original problem.
+fn merge_solutions(problem: &Problem, solutions: Vec<(Solution, Arc<Problem>)>) -> Solution {
+    let actors = get_actor_keys(problem.fleet.actors.iter()).into_iter().collect::<HashMap<_, _>>();
+    let mut registry = Registry::new(problem.fleet.as_ref());
+
+    let (routes, unassigned) = solutions.into_iter().fold((vec![], vec![]), |mut acc, (solution, sub_problem)| {
+        let sub_actors = get_actor_keys(sub_problem.fleet.actors.iter())
+            .into_iter()
+            .map(|(actor_key, actor)| (actor, actor_key))
+            .collect::<HashMap<_, _>>();
+
+        acc.0.extend(solution.routes.into_iter().filter_map(|route| {
+            let actor = sub_actors.get(&route.actor).and_then(|actor_key| actors.get(actor_key))?.clone();
+            registry.use_actor(&actor);
+
+            Some(Route { actor, tour: route.tour })
+        }));
+        acc.1.extend(solution.unassigned);
+
+        acc
+    });
+
+    Solution { registry, routes, unassigned, extras: problem.extras.clone() }
+}
+
+/// Returns actors with their keys which are the same for actors created from the same vehicle detail.
+fn get_actor_keys<'a>(actors: impl Iterator<Item = &'a Arc<Actor>>) -> Vec<((usize, usize), Arc<Actor>)> {
+    let mut ordinals = HashMap::<usize, usize>::new();
+
+    actors
+        .map(|actor| {
+            let vehicle_key = actor.vehicle.as_ref() as *const Vehicle as usize;
+            let ordinal = ordinals.entry(vehicle_key).or_insert(0);
+            let actor_key = (vehicle_key, *ordinal);
+            *ordinal += 1;
+
+            (actor_key, actor.clone())
+        })
+        .collect()
+}
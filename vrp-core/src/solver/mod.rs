@@ -108,6 +108,9 @@ pub mod termination;
 mod builder;
 pub use self::builder::Builder;
 
+mod decomposition;
+pub use self::decomposition::{Decomposition, JobVehicleAffinity};
+
 mod evolution;
 use self::evolution::{EvolutionConfig, EvolutionSimulator};
 
@@ -115,7 +118,7 @@ mod population;
 pub use self::population::DominancePopulation;
 
 mod telemetry;
-pub use self::telemetry::{Metrics, Telemetry, TelemetryMode};
+pub use self::telemetry::{InfoLogger, Metrics, Telemetry, TelemetryMode};
 
 use std::cmp::Ordering;
 
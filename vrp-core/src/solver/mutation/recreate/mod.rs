@@ -30,7 +30,14 @@ pub use self::recreate_with_skip_best::RecreateWithSkipBest;
 mod recreate_with_regret;
 pub use self::recreate_with_regret::RecreateWithRegret;
 
-use crate::models::common::SingleDimLoad;
+mod recreate_with_savings;
+pub use self::recreate_with_savings::RecreateWithSavings;
+
+mod recreate_with_sweep;
+pub use self::recreate_with_sweep::RecreateWithSweep;
+
+use crate::models::common::{Location, Profile, SingleDimLoad};
+use crate::models::problem::Job;
 use crate::models::Problem;
 use std::sync::Arc;
 
@@ -71,3 +78,23 @@ impl Recreate for CompositeRecreate {
         self.recreates.get(index).unwrap().run(refinement_ctx, insertion_ctx)
     }
 }
+
+/// Returns the first known location of the job.
+pub(crate) fn get_job_location(job: &Job) -> Option<Location> {
+    match job {
+        Job::Single(single) => single.places.iter().filter_map(|place| place.location).next(),
+        Job::Multi(multi) => {
+            multi.jobs.iter().flat_map(|job| job.places.iter()).filter_map(|place| place.location).next()
+        }
+    }
+}
+
+/// Returns profile and start location of the first vehicle with start which is used as depot by
+/// classical construction heuristics.
+fn get_depot(problem: &Problem) -> Option<(Profile, Location)> {
+    problem
+        .fleet
+        .actors
+        .iter()
+        .find_map(|actor| actor.detail.start.as_ref().map(|start| (actor.vehicle.profile, start.location)))
+}
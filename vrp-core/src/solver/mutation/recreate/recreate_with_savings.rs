@@ -0,0 +1,205 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/recreate/recreate_with_savings_test.rs"]
+mod recreate_with_savings_test;
+
+use super::{get_depot, get_job_location};
+use crate::construction::heuristics::*;
+use crate::construction::Quota;
+use crate::models::common::{Cost, Location};
+use crate::models::problem::{Actor, Job};
+use crate::solver::mutation::{Recreate, RecreateWithCheapest};
+use crate::solver::RefinementContext;
+use crate::utils::compare_floats;
+use hashbrown::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Keeps savings between jobs sorted in descending order.
+struct Savings {
+    /// Savings of serving other job right after the given one.
+    append: HashMap<Job, Vec<(Cost, Job)>>,
+    /// Savings of serving other job right before the given one.
+    prepend: HashMap<Job, Vec<(Cost, Job)>>,
+    /// All job pairs.
+    pairs: Vec<(Cost, Job, Job)>,
+}
+
+/// A recreate method which builds routes using sequential variant of Clarke-Wright savings
+/// algorithm: each route is started from a pair of jobs with the highest saving and extended at
+/// both ends with jobs which give the highest saving while constraints allow it.
+///
+/// Savings are calculated relative to the start location of the first vehicle and only within
+/// neighbourhood of each job. Route shape parameter is chosen randomly. Jobs which are not routed
+/// by savings are inserted using cheapest insertion.
+pub struct RecreateWithSavings {
+    neighbours: usize,
+}
+
+impl RecreateWithSavings {
+    /// Creates a new instance of `RecreateWithSavings` which considers savings between a job
+    /// and up to `neighbours` nearest jobs.
+    pub fn new(neighbours: usize) -> Self {
+        Self { neighbours }
+    }
+}
+
+impl Default for RecreateWithSavings {
+    fn default() -> Self {
+        Self::new(32)
+    }
+}
+
+impl Recreate for RecreateWithSavings {
+    fn run(&self, refinement_ctx: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = insertion_ctx;
+
+        let solution = &mut insertion_ctx.solution;
+        solution.required.extend(solution.unassigned.drain().map(|(job, _)| job));
+        insertion_ctx.problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+
+        if let Some(savings) = create_savings(&insertion_ctx, self.neighbours) {
+            build_routes(&mut insertion_ctx, &savings, &refinement_ctx.quota);
+        }
+
+        RecreateWithCheapest::default().run(refinement_ctx, insertion_ctx)
+    }
+}
+
+fn create_savings(insertion_ctx: &InsertionContext, neighbours: usize) -> Option<Savings> {
+    let problem = insertion_ctx.problem.as_ref();
+    let (profile, depot) = get_depot(problem)?;
+
+    let lambda = insertion_ctx.random.uniform_real(0.8, 1.2);
+    let distance = |from: Location, to: Location| problem.transport.distance(profile, from, to, 0.);
+
+    let locations = insertion_ctx
+        .solution
+        .required
+        .iter()
+        .filter_map(|job| get_job_location(job).map(|location| (job.clone(), location)))
+        .collect::<HashMap<_, _>>();
+
+    let mut pairs = locations
+        .iter()
+        .flat_map(|(job, &location)| {
+            let locations = &locations;
+            problem
+                .jobs
+                .neighbors(profile, job, 0.)
+                .filter(move |(other, _)| other != job && locations.contains_key(other))
+                .take(neighbours)
+                .map(move |(other, _)| {
+                    let other_location = *locations.get(other).unwrap();
+                    let saving = distance(location, depot) + distance(depot, other_location)
+                        - lambda * distance(location, other_location);
+
+                    (saving, job.clone(), other.clone())
+                })
+        })
+        .filter(|(saving, _, _)| *saving > 0.)
+        .collect::<Vec<_>>();
+
+    pairs.sort_by(|(a, _, _), (b, _, _)| compare_floats(*b, *a));
+
+    let (append, prepend) = pairs.iter().fold(
+        (HashMap::<Job, Vec<_>>::new(), HashMap::<Job, Vec<_>>::new()),
+        |(mut append, mut prepend), (saving, first, second)| {
+            append.entry(first.clone()).or_default().push((*saving, second.clone()));
+            prepend.entry(second.clone()).or_default().push((*saving, first.clone()));
+
+            (append, prepend)
+        },
+    );
+
+    Some(Savings { append, prepend, pairs })
+}
+
+fn build_routes(insertion_ctx: &mut InsertionContext, savings: &Savings, quota: &Option<Arc<dyn Quota + Send + Sync>>) {
+    let is_quota_reached = || quota.as_ref().map_or(false, |q| q.is_reached());
+    let mut routed = HashSet::new();
+
+    for (_, first, second) in savings.pairs.iter() {
+        if is_quota_reached() || insertion_ctx.solution.registry.next().next().is_none() {
+            break;
+        }
+
+        if routed.contains(first) || routed.contains(second) {
+            continue;
+        }
+
+        // NOTE seed job is not retried to start another route even if insertion fails
+        routed.insert(first.clone());
+
+        let init = InsertionResult::make_failure();
+        let result = insertion_ctx.solution.registry.next().fold(init, |acc, route_ctx| {
+            evaluate_job_insertion_in_route(first, insertion_ctx, &route_ctx, InsertionPosition::Any, Some(acc))
+        });
+
+        if let InsertionResult::Success(success) = result {
+            let actor = success.context.route.actor.clone();
+            apply_insertion(InsertionResult::Success(success), insertion_ctx);
+            extend_route(insertion_ctx, savings, &mut routed, actor, first.clone(), &is_quota_reached);
+        }
+    }
+}
+
+/// Extends route with jobs from both ends while it is feasible.
+fn extend_route(
+    insertion_ctx: &mut InsertionContext,
+    savings: &Savings,
+    routed: &mut HashSet<Job>,
+    actor: Arc<Actor>,
+    seed: Job,
+    is_quota_reached: &dyn Fn() -> bool,
+) {
+    let mut tried = HashSet::new();
+    let (mut head, mut tail) = (seed.clone(), seed);
+
+    while !is_quota_reached() {
+        let route_ctx = match insertion_ctx.solution.routes.iter().find(|route_ctx| route_ctx.route.actor == actor) {
+            Some(route_ctx) => route_ctx,
+            None => break,
+        };
+
+        let (position, job) = match (
+            get_candidate(&savings.append, &tail, routed, &tried),
+            get_candidate(&savings.prepend, &head, routed, &tried),
+        ) {
+            (Some((append_saving, append)), Some((prepend_saving, prepend))) => {
+                if append_saving >= prepend_saving {
+                    (InsertionPosition::Last, append)
+                } else {
+                    (InsertionPosition::First, prepend)
+                }
+            }
+            (Some((_, append)), None) => (InsertionPosition::Last, append),
+            (None, Some((_, prepend))) => (InsertionPosition::First, prepend),
+            (None, None) => break,
+        };
+
+        let result = evaluate_job_insertion_in_route(&job, insertion_ctx, route_ctx, position, None);
+
+        if let InsertionResult::Success(_) = result {
+            apply_insertion(result, insertion_ctx);
+            routed.insert(job.clone());
+
+            match position {
+                InsertionPosition::First => head = job,
+                _ => tail = job,
+            }
+        } else {
+            tried.insert(job);
+        }
+    }
+}
+
+fn get_candidate(
+    savings: &HashMap<Job, Vec<(Cost, Job)>>,
+    job: &Job,
+    routed: &HashSet<Job>,
+    tried: &HashSet<Job>,
+) -> Option<(Cost, Job)> {
+    savings
+        .get(job)
+        .and_then(|candidates| candidates.iter().find(|(_, other)| !routed.contains(other) && !tried.contains(other)))
+        .cloned()
+}
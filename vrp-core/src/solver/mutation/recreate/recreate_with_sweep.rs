@@ -0,0 +1,186 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/recreate/recreate_with_sweep_test.rs"]
+mod recreate_with_sweep_test;
+
+use super::{get_depot, get_job_location};
+use crate::construction::heuristics::*;
+use crate::models::common::{Distance, Location};
+use crate::models::problem::Job;
+use crate::solver::mutation::{Recreate, RecreateWithCheapest};
+use crate::solver::RefinementContext;
+use crate::utils::compare_floats;
+use std::f64::consts::PI;
+
+/// Returns the first job from required jobs which are kept in sweep order.
+struct SweepJobSelector {}
+
+impl JobSelector for SweepJobSelector {
+    fn select<'a>(&'a self, ctx: &'a mut InsertionContext) -> Box<dyn Iterator<Item = Job> + 'a> {
+        Box::new(ctx.solution.required.iter().take(1).cloned())
+    }
+}
+
+/// Tries to insert job into the current (last) route and opens a new one only if it is not possible.
+struct SweepJobMapReducer {}
+
+impl JobMapReducer for SweepJobMapReducer {
+    fn reduce<'a>(
+        &'a self,
+        ctx: &'a InsertionContext,
+        jobs: Vec<Job>,
+        insertion_position: InsertionPosition,
+    ) -> InsertionResult {
+        let job = match jobs.first() {
+            Some(job) => job,
+            None => return InsertionResult::make_failure(),
+        };
+
+        let current = ctx
+            .solution
+            .routes
+            .last()
+            .map(|route_ctx| evaluate_job_insertion_in_route(job, ctx, route_ctx, insertion_position, None));
+
+        match current {
+            Some(InsertionResult::Success(success)) => InsertionResult::Success(success),
+            current => {
+                let init = current.unwrap_or_else(|| InsertionResult::make_failure_with_code(-1, Some(job.clone())));
+                ctx.solution.registry.next().fold(init, |acc, route_ctx| {
+                    evaluate_job_insertion_in_route(job, ctx, &route_ctx, insertion_position, Some(acc))
+                })
+            }
+        }
+    }
+}
+
+/// A recreate method which builds routes using sweep algorithm: jobs are ordered by polar angle
+/// around the depot and inserted one by one into the current route until it is not possible,
+/// then the next route is opened. Start angle and sweep direction are chosen randomly.
+///
+/// Locations have no coordinates, so polar angles are estimated from routing distances using
+/// the law of cosines. The method works best when distances are close to euclidean.
+pub struct RecreateWithSweep {
+    job_selector: Box<dyn JobSelector + Send + Sync>,
+    job_reducer: Box<dyn JobMapReducer + Send + Sync>,
+}
+
+impl Default for RecreateWithSweep {
+    fn default() -> Self {
+        Self { job_selector: Box::new(SweepJobSelector {}), job_reducer: Box::new(SweepJobMapReducer {}) }
+    }
+}
+
+impl Recreate for RecreateWithSweep {
+    fn run(&self, refinement_ctx: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        let mut insertion_ctx = insertion_ctx;
+        sort_jobs_by_angle(&mut insertion_ctx);
+
+        let insertion_ctx = InsertionHeuristic::default().process(
+            self.job_selector.as_ref(),
+            self.job_reducer.as_ref(),
+            insertion_ctx,
+            &refinement_ctx.quota,
+        );
+
+        // NOTE jobs which do not fit neither into current nor into a new route are tried with all routes
+        if insertion_ctx.solution.unassigned.is_empty() {
+            insertion_ctx
+        } else {
+            RecreateWithCheapest::default().run(refinement_ctx, insertion_ctx)
+        }
+    }
+}
+
+fn sort_jobs_by_angle(insertion_ctx: &mut InsertionContext) {
+    let solution = &mut insertion_ctx.solution;
+    solution.required.extend(solution.unassigned.drain().map(|(job, _)| job));
+
+    let (profile, depot) = match get_depot(insertion_ctx.problem.as_ref()) {
+        Some(depot) => depot,
+        None => return,
+    };
+
+    let transport = insertion_ctx.problem.transport.as_ref();
+    let distance = |from: Location, to: Location| {
+        (transport.distance(profile, from, to, 0.) + transport.distance(profile, to, from, 0.)) / 2.
+    };
+
+    let locations = solution.required.iter().map(get_job_location).collect::<Vec<_>>();
+    let angles = get_sweep_angles(depot, locations.as_slice(), distance);
+
+    let offset = insertion_ctx.random.uniform_real(0., 2. * PI);
+    let is_clockwise = insertion_ctx.random.is_head_not_tails();
+
+    let mut jobs = solution
+        .required
+        .drain(0..)
+        .zip(angles.into_iter().map(|angle| {
+            angle.map_or(std::f64::MAX, |angle| {
+                let angle = (angle - offset).rem_euclid(2. * PI);
+                if is_clockwise {
+                    2. * PI - angle
+                } else {
+                    angle
+                }
+            })
+        }))
+        .collect::<Vec<_>>();
+
+    jobs.sort_by(|(_, a), (_, b)| compare_floats(*a, *b));
+
+    solution.required.extend(jobs.into_iter().map(|(job, _)| job));
+}
+
+/// Estimates polar angles of locations around the depot using distances only. The farthest
+/// location defines zero angle, the location closest to be orthogonal to it defines direction.
+fn get_sweep_angles<F>(depot: Location, locations: &[Option<Location>], distance: F) -> Vec<Option<f64>>
+where
+    F: Fn(Location, Location) -> Distance,
+{
+    let radius = |location: Location| distance(depot, location);
+    let get_angle = |a: Location, b: Location| {
+        let (ra, rb) = (radius(a), radius(b));
+        if ra <= 0. || rb <= 0. {
+            return 0.;
+        }
+
+        let ab = distance(a, b);
+        ((ra * ra + rb * rb - ab * ab) / (2. * ra * rb)).max(-1.).min(1.).acos()
+    };
+
+    let located = locations.iter().filter_map(|location| *location);
+
+    let first = match located.clone().max_by(|&a, &b| compare_floats(radius(a), radius(b))) {
+        Some(first) => first,
+        None => return locations.iter().map(|_| None).collect(),
+    };
+
+    let second = located
+        .min_by(|&a, &b| compare_floats((get_angle(first, a) - PI / 2.).abs(), (get_angle(first, b) - PI / 2.).abs()))
+        .map(|second| (second, get_angle(first, second)))
+        .filter(|(_, angle)| *angle > std::f64::EPSILON && *angle < PI - std::f64::EPSILON);
+
+    locations
+        .iter()
+        .map(|location| {
+            location.map(|location| {
+                let angle = get_angle(first, location);
+
+                match second {
+                    Some((second, second_angle)) if angle > 0. && angle < PI => {
+                        let actual = get_angle(second, location);
+                        let counter_clockwise = (second_angle - angle).abs();
+                        let clockwise = (second_angle + angle).min(2. * PI - second_angle - angle);
+
+                        if (actual - counter_clockwise).abs() <= (actual - clockwise).abs() {
+                            angle
+                        } else {
+                            2. * PI - angle
+                        }
+                    }
+                    _ => angle,
+                }
+            })
+        })
+        .collect()
+}
@@ -8,6 +8,9 @@ const BALANCE_ACTIVITY_KEY: i32 = 21;
 const BALANCE_DISTANCE_KEY: i32 = 22;
 const BALANCE_DURATION_KEY: i32 = 23;
 
+mod plan_stability;
+pub use self::plan_stability::{has_stability_objective, set_stability_reference, PlanStability, StabilityReference};
+
 mod total_routes;
 pub use self::total_routes::TotalRoutes;
 
@@ -17,5 +20,11 @@ pub use self::total_transport_cost::TotalTransportCost;
 mod total_unassigned_jobs;
 pub use self::total_unassigned_jobs::TotalUnassignedJobs;
 
+mod total_value;
+pub use self::total_value::{JobValueFn, TotalValue};
+
+mod tour_rank;
+pub use self::tour_rank::{JobRankFn, TourRank};
+
 mod work_balance;
 pub use self::work_balance::WorkBalance;
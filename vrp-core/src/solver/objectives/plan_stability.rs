@@ -0,0 +1,251 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/plan_stability_test.rs"]
+mod plan_stability_test;
+
+use super::*;
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::Cost;
+use crate::models::problem::{Actor, Job, TargetConstraint, TargetObjective};
+use crate::models::solution::{Activity, Route};
+use crate::models::{Problem, Solution};
+use crate::utils::compare_floats;
+use std::collections::{HashMap, HashSet};
+use std::slice::Iter;
+use std::sync::{Arc, RwLock};
+
+/// Keeps job assignments of a reference solution: actor and job position in its tour.
+/// Reference can be set after problem is created, e.g. when it is read from existing solution.
+#[derive(Default)]
+pub struct StabilityReference {
+    assignments: RwLock<HashMap<Job, (Arc<Actor>, usize)>>,
+}
+
+impl StabilityReference {
+    /// Sets routes of the solution as a new reference.
+    pub fn set_routes(&self, routes: &[Route]) {
+        let assignments = routes
+            .iter()
+            .flat_map(|route| {
+                get_job_sequence(route)
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(position, job)| (job, (route.actor.clone(), position)))
+            })
+            .collect();
+
+        *self.assignments.write().unwrap() = assignments;
+    }
+
+    fn get(&self, job: &Job) -> Option<(Arc<Actor>, usize)> {
+        self.assignments.read().unwrap().get(job).cloned()
+    }
+
+    /// Returns reference position of the job if it is assigned to the same actor in reference solution.
+    fn get_position(&self, actor: &Arc<Actor>, activity: &Activity) -> Option<usize> {
+        activity.retrieve_job().and_then(|job| self.get(&job)).and_then(|(reference_actor, position)| {
+            if reference_actor == *actor {
+                Some(position)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Sets solution as a reference for plan stability objective. Returns error if problem
+/// has no plan stability objective defined.
+pub fn set_stability_reference(problem: &Problem, solution: &Solution) -> Result<(), String> {
+    let reference =
+        get_stability_reference(problem).ok_or_else(|| "problem has no plan stability objective".to_string())?;
+
+    reference.set_routes(solution.routes.as_slice());
+
+    Ok(())
+}
+
+/// Checks whether problem has plan stability objective defined.
+pub fn has_stability_objective(problem: &Problem) -> bool {
+    get_stability_reference(problem).is_some()
+}
+
+fn get_stability_reference(problem: &Problem) -> Option<&StabilityReference> {
+    problem.extras.get("stability_reference").and_then(|reference| reference.downcast_ref::<StabilityReference>())
+}
+
+/// An objective function which minimizes deviation from a reference solution: amount of jobs
+/// assigned to a different actor and amount of job order violations within the same actor.
+pub struct PlanStability {
+    reference: Arc<StabilityReference>,
+    reassignment_weight: f64,
+    reorder_weight: f64,
+}
+
+impl PlanStability {
+    /// Creates _(constraint, objective)_ type pair which minimizes deviation from a reference solution.
+    /// Constraint penalizes insertions which move jobs to another actor or change their order.
+    /// When objective has priority over cost, the penalty is scaled from solution cost, so that
+    /// deviation outweighs any cost saving. Otherwise, weighted deviation is used as is, so that
+    /// it only breaks ties between insertions of similar cost.
+    pub fn new_minimized(
+        reference: Arc<StabilityReference>,
+        reassignment_weight: f64,
+        reorder_weight: f64,
+        has_priority_over_cost: bool,
+    ) -> (TargetConstraint, TargetObjective) {
+        let constraint = PlanStabilityModule {
+            constraints: vec![
+                ConstraintVariant::SoftRoute(Arc::new(PlanStabilitySoftRouteConstraint {
+                    reference: reference.clone(),
+                    weight: reassignment_weight,
+                    has_priority_over_cost,
+                })),
+                ConstraintVariant::SoftActivity(Arc::new(PlanStabilitySoftActivityConstraint {
+                    reference: reference.clone(),
+                    weight: reorder_weight,
+                    has_priority_over_cost,
+                })),
+            ],
+            keys: vec![],
+        };
+
+        (Box::new(constraint), Box::new(Self { reference, reassignment_weight, reorder_weight }))
+    }
+
+    fn get_deviation(&self, route: &Route) -> f64 {
+        let jobs = get_job_sequence(route);
+
+        let reassignments =
+            jobs.iter().filter(|job| self.reference.get(job).map_or(false, |(actor, _)| actor != route.actor)).count();
+
+        let positions = jobs
+            .iter()
+            .filter_map(|job| {
+                self.reference
+                    .get(job)
+                    .and_then(|(actor, position)| if actor == route.actor { Some(position) } else { None })
+            })
+            .collect::<Vec<_>>();
+        let reorders = positions.windows(2).filter(|pair| pair[0] > pair[1]).count();
+
+        reassignments as f64 * self.reassignment_weight + reorders as f64 * self.reorder_weight
+    }
+}
+
+impl Objective for PlanStability {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(|rc| self.get_deviation(&rc.route)).sum()
+    }
+}
+
+struct PlanStabilityModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for PlanStabilityModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct PlanStabilitySoftRouteConstraint {
+    reference: Arc<StabilityReference>,
+    weight: f64,
+    has_priority_over_cost: bool,
+}
+
+impl SoftRouteConstraint for PlanStabilitySoftRouteConstraint {
+    fn estimate_job(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, job: &Job) -> f64 {
+        match self.reference.get(job) {
+            Some((actor, _)) if actor != route_ctx.route.actor => {
+                self.weight * get_deviation_cost(self.has_priority_over_cost, || solution_ctx.get_max_cost())
+            }
+            _ => 0.,
+        }
+    }
+}
+
+struct PlanStabilitySoftActivityConstraint {
+    reference: Arc<StabilityReference>,
+    weight: f64,
+    has_priority_over_cost: bool,
+}
+
+impl SoftActivityConstraint for PlanStabilitySoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let actor = &route_ctx.route.actor;
+        let target = if let Some(position) = self.reference.get_position(actor, activity_ctx.target) {
+            position
+        } else {
+            return 0.;
+        };
+
+        let tour = &route_ctx.route.tour;
+        let prev = (0..=activity_ctx.index)
+            .rev()
+            .filter_map(|idx| tour.get(idx))
+            .find_map(|activity| self.reference.get_position(actor, activity));
+        let next = (activity_ctx.index + 1..tour.total())
+            .filter_map(|idx| tour.get(idx))
+            .find_map(|activity| self.reference.get_position(actor, activity));
+
+        let violations = prev.map_or(0, |prev| if prev > target { 1 } else { 0 })
+            + next.map_or(0, |next| if target > next { 1 } else { 0 });
+
+        if violations == 0 {
+            0.
+        } else {
+            violations as f64
+                * self.weight
+                * get_deviation_cost(self.has_priority_over_cost, || route_ctx.get_route_cost())
+        }
+    }
+}
+
+/// Returns a cost of one unit of deviation.
+fn get_deviation_cost<F: Fn() -> Cost>(has_priority_over_cost: bool, cost_fn: F) -> Cost {
+    if has_priority_over_cost {
+        let cost = cost_fn();
+        if compare_floats(cost, 0.) == Ordering::Equal {
+            1E9
+        } else {
+            cost * 2.
+        }
+    } else {
+        1.
+    }
+}
+
+/// Returns jobs in the order of their first activity in the tour.
+fn get_job_sequence(route: &Route) -> Vec<Job> {
+    let mut visited = HashSet::new();
+
+    route
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.retrieve_job())
+        .filter(|job| visited.insert(job.clone()))
+        .collect()
+}
@@ -0,0 +1,168 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/total_value_test.rs"]
+mod total_value_test;
+
+use super::*;
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::problem::{ActivityCost, Job, TargetConstraint, TargetObjective, TransportCost};
+use crate::utils::compare_floats;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns value of the job.
+pub type JobValueFn = Arc<dyn Fn(&Job) -> f64 + Send + Sync>;
+
+/// An objective function which maximizes total value of served jobs.
+pub struct TotalValue {
+    value_func: JobValueFn,
+}
+
+impl TotalValue {
+    /// Creates _(constraint, objective)_ type pair which maximizes total value of served jobs.
+    /// Constraint makes insertion of more valuable jobs cheaper and rejects insertion of jobs
+    /// with positive value when serving them costs more than their value.
+    pub fn new_maximized(
+        value_func: JobValueFn,
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+    ) -> (TargetConstraint, TargetObjective) {
+        let constraint = TotalValueModule {
+            constraints: vec![
+                ConstraintVariant::SoftRoute(Arc::new(TotalValueSoftRouteConstraint {
+                    value_func: value_func.clone(),
+                })),
+                ConstraintVariant::HardActivity(Arc::new(TotalValueHardActivityConstraint {
+                    value_func: value_func.clone(),
+                    activity,
+                    transport,
+                    code,
+                })),
+            ],
+            keys: vec![],
+        };
+
+        (Box::new(constraint), Box::new(Self { value_func }))
+    }
+
+    fn get_total_value(&self, solution: &InsertionContext) -> f64 {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|rc| rc.route.tour.jobs())
+            .map(|job| self.value_func.deref()(&job))
+            .sum()
+    }
+}
+
+impl Objective for TotalValue {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(b), self.fitness(a))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        self.get_total_value(solution)
+    }
+}
+
+struct TotalValueModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for TotalValueModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TotalValueSoftRouteConstraint {
+    value_func: JobValueFn,
+}
+
+impl SoftRouteConstraint for TotalValueSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, _: &RouteContext, job: &Job) -> f64 {
+        -self.value_func.deref()(job)
+    }
+}
+
+/// Rejects activity insertion when its detour cost exceeds its share of the job value. As the share is
+/// the job value divided by amount of job activities, the job is served only when it pays off.
+struct TotalValueHardActivityConstraint {
+    value_func: JobValueFn,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    code: i32,
+}
+
+impl HardActivityConstraint for TotalValueHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let job = activity_ctx.target.retrieve_job()?;
+        let value = self.value_func.deref()(&job);
+        if compare_floats(value, 0.) != Ordering::Greater {
+            return None;
+        }
+
+        let activities = match &job {
+            Job::Single(_) => 1,
+            Job::Multi(multi) => multi.jobs.len(),
+        };
+
+        if compare_floats(self.get_detour_cost(route_ctx, activity_ctx), value / activities as f64) == Ordering::Greater
+        {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+impl TotalValueHardActivityConstraint {
+    fn get_detour_cost(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let actor = route_ctx.route.actor.as_ref();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let departure = prev.schedule.departure;
+        let prev_to_target = self.transport.cost(actor, prev.place.location, target.place.location, departure);
+        let arrival = departure
+            + self.transport.duration(actor.vehicle.profile, prev.place.location, target.place.location, departure);
+        let target_cost = self.activity.cost(actor, target, arrival);
+        let target_departure = arrival.max(target.place.time.start) + self.activity.duration(actor, target, arrival);
+
+        let (target_to_next, prev_to_next) = activity_ctx.next.map_or((0., 0.), |next| {
+            (
+                self.transport.cost(actor, target.place.location, next.place.location, target_departure),
+                self.transport.cost(actor, prev.place.location, next.place.location, departure),
+            )
+        });
+
+        let fixed_cost =
+            if route_ctx.route.tour.has_jobs() { 0. } else { actor.vehicle.costs.fixed + actor.driver.costs.fixed };
+
+        prev_to_target + target_cost + target_to_next - prev_to_next + fixed_cost
+    }
+}
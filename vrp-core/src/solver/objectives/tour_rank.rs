@@ -0,0 +1,116 @@
+use super::*;
+use crate::algorithms::nsga2::Objective;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::problem::{Job, TargetConstraint, TargetObjective};
+use crate::models::solution::{Activity, Tour};
+use crate::utils::compare_floats;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns rank of the job. Jobs with lower rank are preferred to be served earlier.
+pub type JobRankFn = Arc<dyn Fn(&Job) -> Option<i32> + Send + Sync>;
+
+/// An objective function which minimizes amount of job rank violations within tours.
+pub struct TourRank {
+    rank_func: JobRankFn,
+}
+
+impl TourRank {
+    /// Creates _(constraint, objective)_ type pair which minimizes amount of job rank violations.
+    /// Constraint penalizes insertions which violate job ranks.
+    pub fn new_minimized(rank_func: JobRankFn) -> (TargetConstraint, TargetObjective) {
+        let constraint = TourRankModule {
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(TourRankSoftActivityConstraint {
+                rank_func: rank_func.clone(),
+            }))],
+            keys: vec![],
+        };
+
+        (Box::new(constraint), Box::new(Self { rank_func }))
+    }
+
+    fn get_violations(&self, tour: &Tour) -> usize {
+        let ranks =
+            tour.all_activities().filter_map(|activity| get_rank(&self.rank_func, activity)).collect::<Vec<_>>();
+
+        ranks.windows(2).filter(|pair| pair[0] > pair[1]).count()
+    }
+}
+
+impl Objective for TourRank {
+    type Solution = InsertionContext;
+
+    fn total_order(&self, a: &Self::Solution, b: &Self::Solution) -> Ordering {
+        compare_floats(self.fitness(a), self.fitness(b))
+    }
+
+    fn distance(&self, a: &Self::Solution, b: &Self::Solution) -> f64 {
+        self.fitness(a) - self.fitness(b)
+    }
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        solution.solution.routes.iter().map(|rc| self.get_violations(&rc.route.tour)).sum::<usize>() as f64
+    }
+}
+
+struct TourRankModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl ConstraintModule for TourRankModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TourRankSoftActivityConstraint {
+    rank_func: JobRankFn,
+}
+
+impl SoftActivityConstraint for TourRankSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let target = if let Some(rank) = get_rank(&self.rank_func, activity_ctx.target) {
+            rank
+        } else {
+            return 0.;
+        };
+
+        let tour = &route_ctx.route.tour;
+        let prev = (0..=activity_ctx.index)
+            .rev()
+            .filter_map(|idx| tour.get(idx))
+            .find_map(|activity| get_rank(&self.rank_func, activity));
+        let next = (activity_ctx.index + 1..tour.total())
+            .filter_map(|idx| tour.get(idx))
+            .find_map(|activity| get_rank(&self.rank_func, activity));
+
+        let violations = prev.map_or(0, |prev| if prev > target { 1 } else { 0 })
+            + next.map_or(0, |next| if target > next { 1 } else { 0 });
+
+        if violations == 0 {
+            0.
+        } else {
+            let route_cost = route_ctx.get_route_cost();
+            let penalty = if compare_floats(route_cost, 0.) == Ordering::Equal { 1E9 } else { route_cost * 2. };
+
+            violations as f64 * penalty
+        }
+    }
+}
+
+fn get_rank(rank_func: &JobRankFn, activity: &Activity) -> Option<i32> {
+    activity.retrieve_job().and_then(|job| rank_func.deref()(&job))
+}
@@ -12,7 +12,7 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 /// A logger type which is called with various information regarding the work done by the VRP solver.
-pub type InfoLogger = Arc<dyn Fn(&str)>;
+pub type InfoLogger = Arc<dyn Fn(&str) + Send + Sync>;
 
 /// Encapsulates different measurements regarding algorithm evaluation.
 pub struct Metrics {
@@ -254,6 +254,15 @@ impl Telemetry {
         }
     }
 
+    /// Returns logger if logging is enabled.
+    pub fn get_logger(&self) -> Option<InfoLogger> {
+        match &self.mode {
+            TelemetryMode::OnlyLogging { logger, .. } => Some(logger.clone()),
+            TelemetryMode::All { logger, .. } => Some(logger.clone()),
+            _ => None,
+        }
+    }
+
     /// Writes log message.
     pub fn log(&self, message: &str) {
         match &self.mode {
@@ -6,7 +6,11 @@ use crate::helpers::models::solution::*;
 use crate::models::common::{Cost, ValueDimension};
 use crate::models::problem::Fleet;
 
-fn create_fleet(areas: Vec<Area>) -> Fleet {
+fn create_area(priority: Option<usize>, outer_shape: Vec<(f64, f64)>) -> Area {
+    Area { priority, outer_shape, inner_shapes: vec![] }
+}
+
+fn create_fleet(areas: ActorAreas) -> Fleet {
     let mut vehicle = test_vehicle_with_id("v1");
     vehicle.dimens.set_value("areas", areas);
 
@@ -19,7 +23,7 @@ fn create_fleet(areas: Vec<Area>) -> Fleet {
 
 fn create_area_constraint_pipeline() -> ConstraintPipeline {
     create_constraint_pipeline_with_module(Box::new(AreaModule::new(
-        Arc::new(move |actor| actor.vehicle.dimens.get_value::<Vec<Area>>("areas")),
+        Arc::new(move |actor| actor.vehicle.dimens.get_value::<ActorAreas>("areas")),
         Arc::new(|location| (location as f64, 0.)),
         2,
     )))
@@ -46,7 +50,10 @@ fn can_check_single_job_impl(
     activity_location: Location,
     expected: (Option<()>, Option<()>),
 ) {
-    let areas = vec![Area { priority: None, outer_shape: vec![(-5., -5.), (-5., 5.), (5., 5.), (5., -5.)] }];
+    let areas = ActorAreas {
+        allowed: vec![create_area(None, vec![(-5., -5.), (-5., 5.), (5., 5.), (5., -5.)])],
+        ..ActorAreas::default()
+    };
     let solution_ctx = create_empty_solution_context();
     let route_ctx = create_route_context_with_activities(&create_fleet(areas), vehicle_id, vec![]);
     let activity_ctx = ActivityContext {
@@ -82,7 +89,10 @@ can_check_multi_job! {
 }
 
 fn can_check_multi_job_impl(job_locations: Vec<Option<Location>>, expected: Option<()>) {
-    let areas = vec![Area { priority: None, outer_shape: vec![(-5., -5.), (-5., 5.), (5., 5.), (5., -5.)] }];
+    let areas = ActorAreas {
+        allowed: vec![create_area(None, vec![(-5., -5.), (-5., 5.), (5., 5.), (5., -5.)])],
+        ..ActorAreas::default()
+    };
     let solution_ctx = create_empty_solution_context();
     let route_ctx = create_route_context_with_activities(&create_fleet(areas), "v1", vec![]);
     let pipeline = create_area_constraint_pipeline();
@@ -96,6 +106,63 @@ fn can_check_multi_job_impl(job_locations: Vec<Option<Location>>, expected: Opti
     assert_eq!(route_result.map(|_| ()), expected);
 }
 
+parameterized_test! {can_check_forbidden_area, (job_locations, activity_location, expected), {
+    can_check_forbidden_area_impl(job_locations, activity_location, expected);
+}}
+
+can_check_forbidden_area! {
+    case01: (vec![Some(0)], 0, (Some(()), Some(()))),
+    case02: (vec![Some(10)], 10, (None, None)),
+    case03: (vec![Some(0), Some(10)], 0, (None, Some(()))),
+    case04: (vec![Some(0), Some(10)], 10, (None, None)),
+}
+
+fn can_check_forbidden_area_impl(
+    job_locations: Vec<Option<Location>>,
+    activity_location: Location,
+    expected: (Option<()>, Option<()>),
+) {
+    let areas = ActorAreas {
+        forbidden: vec![create_area(None, vec![(-5., -5.), (-5., 5.), (5., 5.), (5., -5.)])],
+        ..ActorAreas::default()
+    };
+    let solution_ctx = create_empty_solution_context();
+    let route_ctx = create_route_context_with_activities(&create_fleet(areas), "v1", vec![]);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: &test_activity_without_job(),
+        target: &test_activity_with_location(activity_location),
+        next: None,
+    };
+    let pipeline = create_area_constraint_pipeline();
+
+    let route_result = pipeline.evaluate_hard_route(
+        &solution_ctx,
+        &route_ctx,
+        &SingleBuilder::default()
+            .places(job_locations.into_iter().map(|l| (l, 10., vec![(0., 100.)])).collect())
+            .build_as_job_ref(),
+    );
+    let activity_result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(route_result.map(|_| ()), expected.0);
+    assert_eq!(activity_result.map(|_| ()), expected.1);
+}
+
+#[test]
+fn can_check_location_in_area_with_holes() {
+    let area = Area {
+        priority: None,
+        outer_shape: vec![(-10., -10.), (-10., 10.), (10., 10.), (10., -10.)],
+        inner_shapes: vec![vec![(-1., -1.), (-1., 1.), (1., 1.), (1., -1.)]],
+    };
+
+    assert_eq!(area.contains(&(5., 5.)), true);
+    assert_eq!(area.contains(&(0., 0.)), false);
+    assert_eq!(area.contains(&(0.5, -0.5)), false);
+    assert_eq!(area.contains(&(11., 0.)), false);
+}
+
 #[test]
 fn can_check_location_in_area() {
     let polygon = vec![(-1., -1.), (-1., 1.), (1., 1.), (1., -1.)];
@@ -152,9 +219,12 @@ can_estimate_activity_with_penalty! {
 }
 
 fn can_estimate_activity_with_penalty_impl(priority: Option<usize>, route_cost: Option<Cost>, expected_cost: Cost) {
-    let areas = vec![Area { priority, outer_shape: vec![(-1., -1.), (-1., 1.), (1., 1.), (1., -1.)] }];
+    let areas = ActorAreas {
+        allowed: vec![create_area(priority, vec![(-1., -1.), (-1., 1.), (1., 1.), (1., -1.)])],
+        ..ActorAreas::default()
+    };
     let area_constraint = AreaSoftActivityConstraint {
-        area_resolver: Arc::new(move |actor| actor.vehicle.dimens.get_value::<Vec<Area>>("areas")),
+        area_resolver: Arc::new(move |actor| actor.vehicle.dimens.get_value::<ActorAreas>("areas")),
         location_resolver: Arc::new(|location| (location as f64, 0.)),
     };
 
@@ -176,3 +246,61 @@ fn can_estimate_activity_with_penalty_impl(priority: Option<usize>, route_cost:
 
     assert_eq!(cost, expected_cost);
 }
+
+parameterized_test! {can_estimate_activity_with_area_cost, (location, expected), {
+    can_estimate_activity_with_area_cost_impl(location, expected);
+}}
+
+can_estimate_activity_with_area_cost! {
+    case01: (0, 15.),
+    case02: (3, 10.),
+    case03: (10, 0.),
+}
+
+fn can_estimate_activity_with_area_cost_impl(location: Location, expected_cost: Cost) {
+    let areas = ActorAreas {
+        costs: vec![
+            (create_area(None, vec![(-5., -5.), (-5., 5.), (5., 5.), (5., -5.)]), 10.),
+            (create_area(None, vec![(-1., -1.), (-1., 1.), (1., 1.), (1., -1.)]), 5.),
+        ],
+        ..ActorAreas::default()
+    };
+    let area_constraint = AreaSoftActivityConstraint {
+        area_resolver: Arc::new(move |actor| actor.vehicle.dimens.get_value::<ActorAreas>("areas")),
+        location_resolver: Arc::new(|location| (location as f64, 0.)),
+    };
+    let route_ctx = create_route_context_with_activities(&create_fleet(areas), "v1", vec![]);
+
+    let cost = area_constraint.estimate_activity(
+        &route_ctx,
+        &ActivityContext {
+            index: 0,
+            prev: &test_activity_with_location(1),
+            target: &test_activity_with_location(location),
+            next: None,
+        },
+    );
+
+    assert_eq!(cost, expected_cost);
+}
+
+#[test]
+fn can_include_area_cost_into_route_cost() {
+    let areas = ActorAreas {
+        costs: vec![(create_area(None, vec![(-5., -5.), (-5., 5.), (5., 5.), (5., -5.)]), 100.)],
+        ..ActorAreas::default()
+    };
+    let fleet = create_fleet(areas);
+    let pipeline = create_area_constraint_pipeline();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![test_activity_with_location(1), test_activity_with_location(2), test_activity_with_location(10)],
+    );
+    let original_cost = route_ctx.get_route_cost();
+
+    pipeline.accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state.get_route_state::<Cost>(AREA_COST_KEY).cloned(), Some(200.));
+    assert_eq!(route_ctx.get_route_cost(), original_cost + 200.);
+}
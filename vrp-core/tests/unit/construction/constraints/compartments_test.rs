@@ -0,0 +1,131 @@
+use super::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::constraints::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::SingleDimLoad;
+use crate::models::problem::Vehicle;
+
+fn create_compartments(compartments: Vec<(i32, Vec<&str>)>) -> Vec<Compartment<SingleDimLoad>> {
+    compartments
+        .into_iter()
+        .map(|(capacity, goods)| Compartment {
+            capacity: SingleDimLoad::new(capacity),
+            goods: goods.into_iter().map(|goods| goods.to_string()).collect(),
+        })
+        .collect()
+}
+
+fn create_test_vehicle(compartments: Vec<(i32, Vec<&str>)>) -> Vehicle {
+    let mut vehicle = VehicleBuilder::default().id("v1").capacity(10).build();
+    vehicle.dimens.set_compartments(create_compartments(compartments));
+
+    vehicle
+}
+
+fn create_goods_single(goods: &str, size: i32) -> Arc<Single> {
+    let mut single = test_single();
+    single.dimens.set_demand(create_simple_demand(size));
+    single.dimens.set_goods(goods);
+
+    Arc::new(single)
+}
+
+fn create_constraint_pipeline() -> ConstraintPipeline {
+    let mut constraint = create_constraint_pipeline_with_simple_capacity();
+    constraint.add_module(Box::new(CompartmentConstraintModule::<SingleDimLoad>::new(3)));
+
+    constraint
+}
+
+parameterized_test! {can_load_goods_into_compartments, (compartments, loads, expected), {
+    can_load_goods_into_compartments_impl(compartments, loads, expected);
+}}
+
+can_load_goods_into_compartments! {
+    case01: (vec![(5, vec!["a"]), (5, vec!["b"])], vec![("a", 5), ("b", 5)], true),
+    case02: (vec![(5, vec!["a"]), (5, vec!["b"])], vec![("a", 6)], false),
+    case03: (vec![(5, vec!["a", "b"]), (5, vec!["a", "b"])], vec![("a", 6)], true),
+    case04: (vec![(5, vec!["a", "b"]), (5, vec!["a", "b"])], vec![("a", 6), ("b", 1)], false),
+    case05: (vec![(3, vec!["a", "b"]), (5, vec!["a"])], vec![("a", 4), ("b", 3)], true),
+    case06: (vec![(5, vec!["a", "b"]), (3, vec!["a"])], vec![("a", 4), ("b", 3)], false),
+    case07: (vec![(5, vec!["a"])], vec![("c", 1)], false),
+    case08: (vec![(5, vec!["a"])], vec![("c", 0)], true),
+}
+
+fn can_load_goods_into_compartments_impl(compartments: Vec<(i32, Vec<&str>)>, loads: Vec<(&str, i32)>, expected: bool) {
+    let compartments = create_compartments(compartments);
+    let loads =
+        loads.into_iter().map(|(goods, load)| (goods.to_string(), SingleDimLoad::new(load))).collect::<Vec<_>>();
+
+    let result = can_load_into_compartments(compartments.as_slice(), loads.as_slice());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_goods_on_route, (goods, expected), {
+    can_evaluate_goods_on_route_impl(goods, expected);
+}}
+
+can_evaluate_goods_on_route! {
+    case01: ("a", None),
+    case02: ("c", Some(RouteConstraintViolation { code: 3 })),
+}
+
+fn can_evaluate_goods_on_route_impl(goods: &str, expected: Option<RouteConstraintViolation>) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_test_vehicle(vec![(5, vec!["a"]), (5, vec!["b"])]))
+        .build();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+    let job = Job::Single(create_goods_single(goods, -1));
+
+    let result = create_constraint_pipeline().evaluate_hard_route(&create_empty_solution_context(), &route_ctx, &job);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_goods_on_activity, (existing, neighbours, target, expected), {
+    can_evaluate_goods_on_activity_impl(existing, neighbours, target, expected);
+}}
+
+can_evaluate_goods_on_activity! {
+    case01: (vec![("a", -3), ("b", -3)], (1, 2), ("a", -2), None),
+    case02: (vec![("a", -3), ("b", -3)], (1, 2), ("a", -3), Some(ActivityConstraintViolation { code: 3, stopped: false })),
+    case03: (vec![("a", -3), ("b", -3)], (1, 2), ("b", -2), None),
+    case04: (vec![("a", -5)], (1, 2), ("b", -5), None),
+    case05: (vec![("a", 3), ("b", 3)], (2, 3), ("b", 3), Some(ActivityConstraintViolation { code: 3, stopped: false })),
+    case06: (vec![("a", -3), ("a", 3)], (1, 2), ("a", 2), None),
+    case07: (vec![("a", -3), ("a", 3)], (0, 1), ("a", 3), Some(ActivityConstraintViolation { code: 3, stopped: false })),
+}
+
+fn can_evaluate_goods_on_activity_impl(
+    existing: Vec<(&str, i32)>,
+    neighbours: (usize, usize),
+    target: (&str, i32),
+    expected: Option<ActivityConstraintViolation>,
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_test_vehicle(vec![(5, vec!["a", "b"]), (5, vec!["b"])]))
+        .build();
+    let mut route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        existing.into_iter().map(|(goods, size)| test_activity_with_job(create_goods_single(goods, size))).collect(),
+    );
+    let pipeline = create_constraint_pipeline();
+    pipeline.accept_route_state(&mut route_ctx);
+    let target = test_activity_with_job(create_goods_single(target.0, target.1));
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(neighbours.0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(neighbours.1),
+    };
+
+    let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
@@ -1,5 +1,7 @@
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::test_single;
 use crate::helpers::models::solution::{test_activity_without_job, test_actor};
 use crate::models::common::Cost;
 use crate::models::problem::Job;
@@ -37,6 +39,10 @@ struct TestHardActivityConstraint {
     violation: Option<ActivityConstraintViolation>,
 }
 
+struct TestHardRouteConstraint {
+    violation: Option<RouteConstraintViolation>,
+}
+
 struct TestSoftActivityConstraint {
     cost: Cost,
 }
@@ -51,6 +57,17 @@ impl HardActivityConstraint for TestHardActivityConstraint {
     }
 }
 
+impl HardRouteConstraint for TestHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        _solution_ctx: &SolutionContext,
+        _ctx: &RouteContext,
+        _job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        self.violation.clone()
+    }
+}
+
 impl SoftActivityConstraint for TestSoftActivityConstraint {
     fn estimate_activity(&self, _route_ctx: &RouteContext, _activity_ctx: &ActivityContext) -> Cost {
         self.cost
@@ -111,3 +128,25 @@ fn can_estimate_hard_activity_constraints() {
 
     assert_eq!(result, 12.0);
 }
+
+#[test]
+fn can_evaluate_all_hard_route_violations() {
+    let create_constraint = |code: Option<i32>| {
+        ConstraintVariant::HardRoute(Arc::new(TestHardRouteConstraint {
+            violation: code.map(|code| RouteConstraintViolation { code }),
+        }))
+    };
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(TestConstraintModule {
+        state_keys: vec![1],
+        constraints: vec![create_constraint(Some(1)), create_constraint(None), create_constraint(Some(3))],
+    }));
+
+    let result = pipeline.evaluate_hard_route_violations(
+        &create_empty_solution_context(),
+        &RouteContext::new(test_actor()),
+        &Job::Single(Arc::new(test_single())),
+    );
+
+    assert_eq!(result, vec![RouteConstraintViolation { code: 1 }, RouteConstraintViolation { code: 3 }]);
+}
@@ -150,6 +150,64 @@ mod timing {
         assert_eq!(route_ctx.route.tour.get(2).unwrap().schedule, Schedule { arrival: 35.0, departure: 60.0 });
     }
 
+    #[test]
+    fn can_reschedule_departure_forward() {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![VehicleBuilder::default().id("v1").build()])
+            .build();
+        let mut solution_ctx = SolutionContext {
+            routes: vec![create_route_context_with_activities(
+                &fleet,
+                "v1",
+                vec![
+                    ActivityBuilder::default()
+                        .place(Place { location: 10, duration: 5.0, time: TimeWindow { start: 20.0, end: 30.0 } })
+                        .build(),
+                    ActivityBuilder::default()
+                        .place(Place { location: 20, duration: 10.0, time: TimeWindow { start: 50.0, end: 100.0 } })
+                        .build(),
+                ],
+            )],
+            registry: RegistryContext::new(Registry::new(&fleet)),
+            ..create_empty_solution_context()
+        };
+
+        create_constraint_pipeline_with_transport().accept_solution_state(&mut solution_ctx);
+
+        let route_ctx = solution_ctx.routes.first().unwrap();
+        assert_eq!(route_ctx.route.tour.start().unwrap().schedule.departure, 20.0);
+        assert_eq!(route_ctx.route.tour.get(1).unwrap().schedule, Schedule { arrival: 30.0, departure: 35.0 });
+        assert_eq!(route_ctx.route.tour.get(2).unwrap().schedule, Schedule { arrival: 45.0, departure: 60.0 });
+    }
+
+    #[test]
+    fn can_reschedule_departure_back() {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![VehicleBuilder::default().id("v1").build()])
+            .build();
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![ActivityBuilder::default()
+                .place(Place { location: 10, duration: 5.0, time: TimeWindow { start: 10.0, end: 12.0 } })
+                .build()],
+        );
+        route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = 5.;
+        let mut solution_ctx = SolutionContext {
+            routes: vec![route_ctx],
+            registry: RegistryContext::new(Registry::new(&fleet)),
+            ..create_empty_solution_context()
+        };
+
+        create_constraint_pipeline_with_transport().accept_solution_state(&mut solution_ctx);
+
+        let route_ctx = solution_ctx.routes.first().unwrap();
+        assert_eq!(route_ctx.route.tour.start().unwrap().schedule.departure, 0.0);
+        assert_eq!(route_ctx.route.tour.get(1).unwrap().schedule, Schedule { arrival: 10.0, departure: 15.0 });
+    }
+
     #[test]
     fn can_calculate_soft_activity_cost_for_empty_tour() {
         let fleet = FleetBuilder::default()
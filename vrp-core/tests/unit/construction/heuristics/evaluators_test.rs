@@ -99,6 +99,35 @@ mod single {
         }
     }
 
+    parameterized_test! {can_insert_job_at_specific_position, (position, index), {
+        can_insert_job_at_specific_position_impl(position, index);
+    }}
+
+    can_insert_job_at_specific_position! {
+        case01: (InsertionPosition::Any, 1),
+        case02: (InsertionPosition::First, 0),
+        case03: (InsertionPosition::Last, 2),
+    }
+
+    fn can_insert_job_at_specific_position_impl(position: InsertionPosition, index: usize) {
+        let registry = create_test_registry();
+        let mut route_ctx = RouteContext::new(registry.next().next().unwrap());
+        route_ctx.route_mut().tour.insert_at(create_activity_at(5), 1).insert_at(create_activity_at(10), 2);
+        let routes = vec![route_ctx];
+        let constraint = create_constraint_pipeline_with_transport();
+        let ctx = create_insertion_context(registry, constraint, routes);
+        let job = Job::Single(test_single_with_location(Some(8)));
+
+        let result = evaluate_job_insertion(&job, &ctx, &AllRouteSelector::default(), position);
+
+        if let InsertionResult::Success(success) = result {
+            assert_eq!(success.activities.len(), 1);
+            assert_eq!(success.activities.first().unwrap().1, index);
+        } else {
+            unreachable!()
+        }
+    }
+
     parameterized_test! {can_insert_job_with_two_vehicles_and_various_time_constraints, (job_location, v1_end_location, v2_end_location, expected_used_vehicle, cost), {
         can_insert_job_with_two_vehicles_and_various_time_constraints_impl(job_location, v1_end_location, v2_end_location, expected_used_vehicle, cost);
     }}
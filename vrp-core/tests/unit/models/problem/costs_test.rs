@@ -80,3 +80,43 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance(0, 0, 1, 0.), 1.);
     assert_eq!(costs.distance(1, 0, 1, 0.), 5.);
 }
+
+#[test]
+fn can_use_compact_matrix_values() {
+    let distances: Arc<dyn MatrixValues> = Arc::new(vec![0_u32, 10, u32::MAX, 0]);
+    let durations: Arc<dyn MatrixValues> = Arc::new(vec![0_f32, 5.5, 7., 0.]);
+
+    let transport = create_compact_matrix_transport_cost(vec![
+        CompactMatrixData::new(0, durations, distances.clone()),
+        CompactMatrixData::new_distance_only(1, distances),
+    ])
+    .expect("cannot create transport costs");
+
+    assert_eq!(transport.distance(0, 0, 1, 0.), 10.);
+    assert_eq!(transport.distance(0, 1, 0, 0.), -1.);
+    assert_eq!(transport.duration(0, 0, 1, 0.), 5.5);
+    assert_eq!(transport.duration(0, 1, 0, 0.), 7.);
+    assert_eq!(transport.duration(1, 0, 1, 0.), 10.);
+    assert_eq!(transport.duration(1, 1, 0, 0.), -1.);
+}
+
+#[test]
+fn can_detect_compact_matrix_errors() {
+    let create = |profile: Profile, size: usize, durations: Option<usize>| CompactMatrixData {
+        profile,
+        durations: durations.map(|size| Arc::new(vec![0_u32; size]) as Arc<dyn MatrixValues>),
+        distances: Arc::new(vec![0_u32; size]),
+    };
+
+    let assert_error = |data: Vec<CompactMatrixData>, expected: &str| {
+        assert_eq!(create_compact_matrix_transport_cost(data).err(), Some(expected.to_string()));
+    };
+
+    assert_error(vec![], "no matrix data found");
+    assert_error(
+        vec![create(0, 4, None), create(0, 4, None)],
+        "duplicate profiles can be passed only for time aware routing",
+    );
+    assert_error(vec![create(0, 4, None), create(1, 9, None)], "distance lengths don't match");
+    assert_error(vec![create(0, 4, Some(9))], "duration lengths don't match");
+}
@@ -226,3 +226,39 @@ fn can_handle_negative_distances_durations_impl(transport_costs: Arc<dyn Transpo
             .all(|(_, cost)| { (*cost - UNREACHABLE_COST).abs() < std::f64::EPSILON }));
     }
 }
+
+parameterized_test! {returns_bounded_job_neighbours, (index, expected), {
+    returns_bounded_job_neighbours_impl(index, expected.iter().map(|s| s.to_string()).collect());
+}}
+
+returns_bounded_job_neighbours! {
+    case1: (0, vec!["s1", "s2"]),
+    case2: (2, vec!["s1", "s3"]),
+    case3: (4, vec!["s3", "s2"]),
+}
+
+fn returns_bounded_job_neighbours_impl(index: usize, expected: Vec<String>) {
+    let species = (0..5)
+        .map(|idx| SingleBuilder::default().id(format!("s{}", idx).as_str()).location(Some(idx)).build_as_job_ref())
+        .collect::<Vec<_>>();
+    let jobs =
+        Jobs::new_with_neighbours(&test_fleet(), species.clone(), &create_only_distance_transport_cost(), Some(2));
+
+    let result: Vec<String> =
+        jobs.neighbors(0, species.get(index).unwrap(), 0.0).map(|(j, _)| get_job_id(j).clone()).collect();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_get_distance_between_jobs_outside_of_bounded_index() {
+    let species = (0..5)
+        .map(|idx| SingleBuilder::default().id(format!("s{}", idx).as_str()).location(Some(idx)).build_as_job_ref())
+        .collect::<Vec<_>>();
+    let jobs =
+        Jobs::new_with_neighbours(&test_fleet(), species.clone(), &create_only_distance_transport_cost(), Some(1));
+
+    assert_eq!(jobs.neighbors(0, &species[0], 0.0).count(), 1);
+    assert_eq!(jobs.distance(0, &species[0], &species[1], 0.0), 1.);
+    assert_eq!(jobs.distance(0, &species[0], &species[4], 0.0), 4.);
+}
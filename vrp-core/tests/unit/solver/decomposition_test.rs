@@ -0,0 +1,151 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes};
+use crate::utils::DefaultRandom;
+
+fn create_problem_with_affinity(vehicle_id: &str) -> Problem {
+    let (problem, _) = generate_matrix_routes(8, 4, |data| (data.clone(), data));
+    let vehicle_id = vehicle_id.to_string();
+
+    let mut extras = crate::models::Extras::default();
+    extras.insert(
+        "job_vehicle_affinity".to_string(),
+        Arc::new(JobVehicleAffinity::new(move |job| {
+            if job.dimens().get_id().map(|id| id == "c0").unwrap_or(false) {
+                Some(vehicle_id.clone())
+            } else {
+                None
+            }
+        })),
+    );
+
+    Problem { extras: Arc::new(extras), ..problem }
+}
+
+parameterized_test! {can_create_partitions, (rows, cols, max_jobs, expected_partitions), {
+    can_create_partitions_impl(rows, cols, max_jobs, expected_partitions);
+}}
+
+can_create_partitions! {
+    case01: (8, 4, 8, 4),
+    case02: (8, 4, 16, 2),
+    case03: (8, 2, 4, 2),
+    case04: (8, 4, 32, 0),
+}
+
+fn can_create_partitions_impl(rows: usize, cols: usize, max_jobs: usize, expected_partitions: usize) {
+    let (problem, _) = generate_matrix_routes(rows, cols, |data| (data.clone(), data));
+
+    let partitions = create_partitions(&problem, max_jobs, &DefaultRandom::default());
+
+    assert_eq!(partitions.len(), expected_partitions);
+    if expected_partitions > 0 {
+        let jobs = partitions.iter().flat_map(|(_, jobs)| jobs.iter()).cloned().collect::<HashSet<_>>();
+        let vehicles = partitions.iter().map(|(vehicles, _)| vehicles.len()).sum::<usize>();
+        assert_eq!(jobs.len(), rows * cols);
+        assert_eq!(partitions.iter().map(|(_, jobs)| jobs.len()).sum::<usize>(), rows * cols);
+        assert_eq!(vehicles, cols);
+        assert!(partitions.iter().all(|(vehicles, jobs)| !vehicles.is_empty() && !jobs.is_empty()));
+    }
+}
+
+#[test]
+fn can_keep_jobs_with_affinity_in_partition_of_their_vehicle() {
+    let problem = create_problem_with_affinity("3");
+
+    let partitions = create_partitions(&problem, 8, &DefaultRandom::default());
+
+    let (vehicles, _) = partitions
+        .iter()
+        .find(|(_, jobs)| jobs.iter().any(|job| job.dimens().get_id().map(|id| id == "c0").unwrap_or(false)))
+        .expect("cannot find job");
+    assert!(vehicles.iter().any(|vehicle| vehicle.dimens.get_id().map(|id| id == "3").unwrap_or(false)));
+}
+
+#[test]
+fn can_solve_problem_using_decomposition() {
+    let (problem, _) = generate_matrix_routes(8, 4, |data| (data.clone(), data));
+    let problem = Arc::new(problem);
+
+    let (solution, _, _) = Builder::new(problem.clone())
+        .with_max_generations(Some(10))
+        .with_decomposition(Some(8))
+        .with_seed(Some(0))
+        .build()
+        .and_then(|solver| solver.solve())
+        .expect("cannot solve problem");
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.iter().map(|route| route.tour.job_count()).sum::<usize>(), 32);
+    assert!(solution.routes.iter().all(|route| problem.fleet.actors.contains(&route.actor)));
+}
+
+parameterized_test! {can_split_time_budget_across_sub_problems, (max_time, sub_problems, parallelism, expected), {
+    can_split_time_budget_across_sub_problems_impl(max_time, sub_problems, parallelism, expected);
+}}
+
+can_split_time_budget_across_sub_problems! {
+    case01: (Some(100), 4, 4, Some(100)),
+    case02: (Some(100), 4, 2, Some(50)),
+    case03: (Some(100), 5, 2, Some(33)),
+    case04: (Some(100), 3, 1, Some(33)),
+    case05: (Some(1), 4, 1, Some(1)),
+    case06: (None, 4, 1, None),
+}
+
+fn can_split_time_budget_across_sub_problems_impl(
+    max_time: Option<usize>,
+    sub_problems: usize,
+    parallelism: usize,
+    expected: Option<usize>,
+) {
+    assert_eq!(get_sub_problem_time(max_time, sub_problems, parallelism), expected);
+}
+
+#[test]
+fn can_assign_vehicles_to_uneven_partitions_within_fleet_size() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2"), test_vehicle_with_id("v3")])
+        .build();
+    let job = Job::Single(test_single_with_id("job"));
+    let partitions = [98, 1, 1].iter().map(|size| (vec![], vec![job.clone(); *size])).collect::<Vec<Partition>>();
+    let distance = |from: Location, to: Location| (if to > from { to - from } else { from - to }) as f64;
+
+    let mut vehicle_partitions = assign_vehicles(&fleet, &[0, 10, 20], partitions.as_slice(), &distance);
+
+    vehicle_partitions.sort();
+    assert_eq!(vehicle_partitions, vec![0, 1, 2]);
+}
+
+#[test]
+fn can_insert_jobs_of_failed_sub_problem_after_merge() {
+    let (problem, _) = generate_matrix_routes(8, 4, |data| (data.clone(), data));
+    let problem = Arc::new(problem);
+    let random = Arc::new(DefaultRandom::default());
+    let partitions = create_partitions(problem.as_ref(), 8, random.as_ref());
+    assert!(partitions.len() > 1);
+
+    // NOTE only first sub-problem is solved, all others are considered as failed
+    let (vehicles, jobs) = partitions.into_iter().next().unwrap();
+    let sub_problem = Arc::new(create_sub_problem(problem.as_ref(), vehicles, jobs));
+    let (solution, _, _) = Builder::new(sub_problem.clone())
+        .with_max_generations(Some(1))
+        .build()
+        .and_then(|solver| solver.solve())
+        .expect("cannot solve sub-problem");
+
+    let insertion_ctx = create_merged_context(
+        &create_default_refinement_ctx(problem.clone()),
+        problem.clone(),
+        vec![(solution, sub_problem)],
+        random,
+    );
+
+    assert!(insertion_ctx.solution.required.is_empty());
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(
+        insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(),
+        32
+    );
+}
@@ -0,0 +1,38 @@
+use super::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes};
+use crate::utils::DefaultRandom;
+
+fn create_insertion_ctx() -> InsertionContext {
+    let (problem, _) = generate_matrix_routes(8, 4, |data| (data.clone(), data));
+
+    InsertionContext::new(Arc::new(problem), Arc::new(DefaultRandom::default()))
+}
+
+#[test]
+fn can_create_savings_sorted_in_descending_order() {
+    let insertion_ctx = create_insertion_ctx();
+
+    let savings = create_savings(&insertion_ctx, 4).expect("no savings");
+
+    assert!(!savings.pairs.is_empty());
+    assert!(savings.pairs.len() <= 32 * 4);
+    assert!(savings.pairs.windows(2).all(|pair| pair[0].0 >= pair[1].0));
+    assert!(savings.pairs.iter().all(|(saving, first, second)| *saving > 0. && first != second));
+    assert!(savings
+        .append
+        .values()
+        .chain(savings.prepend.values())
+        .all(|candidates| { candidates.windows(2).all(|pair| pair[0].0 >= pair[1].0) }));
+}
+
+#[test]
+fn can_build_solution_using_savings() {
+    let insertion_ctx = create_insertion_ctx();
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let result = RecreateWithSavings::default().run(&refinement_ctx, insertion_ctx);
+
+    assert!(result.solution.required.is_empty());
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(), 32);
+}
@@ -0,0 +1,40 @@
+use super::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes};
+use crate::utils::DefaultRandom;
+use std::sync::Arc;
+
+fn get_distance(points: &[(f64, f64)]) -> impl Fn(Location, Location) -> Distance + '_ {
+    move |from, to| {
+        let ((x1, y1), (x2, y2)) = (points[from], points[to]);
+        ((x1 - x2) * (x1 - x2) + (y1 - y2) * (y1 - y2)).sqrt()
+    }
+}
+
+#[test]
+fn can_estimate_sweep_angles_from_distances() {
+    let points = vec![(0., 0.), (10., 0.), (0., 5.), (-5., 0.), (0., -5.), (3., 3.)];
+    let locations = vec![Some(1), Some(2), Some(3), Some(4), Some(5), None];
+
+    let angles = get_sweep_angles(0, locations.as_slice(), get_distance(points.as_slice()));
+
+    let expected = vec![Some(0.), Some(PI / 2.), Some(PI), Some(3. * PI / 2.), Some(PI / 4.), None];
+    assert_eq!(angles.len(), expected.len());
+    angles.iter().zip(expected.iter()).for_each(|(actual, expected)| match (actual, expected) {
+        (Some(actual), Some(expected)) => assert!((actual - expected).abs() < 1E-6),
+        (None, None) => {}
+        _ => unreachable!(),
+    });
+}
+
+#[test]
+fn can_build_solution_using_sweep() {
+    let (problem, _) = generate_matrix_routes(8, 4, |data| (data.clone(), data));
+    let problem = Arc::new(problem);
+    let insertion_ctx = InsertionContext::new(problem.clone(), Arc::new(DefaultRandom::default()));
+
+    let result = RecreateWithSweep::default().run(&create_default_refinement_ctx(problem), insertion_ctx);
+
+    assert!(result.solution.required.is_empty());
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(), 32);
+}
@@ -0,0 +1,142 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::problem::{Fleet, Single};
+
+fn create_fleet() -> Fleet {
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build()
+}
+
+fn create_route(fleet: &Fleet, vehicle: &str, jobs: &[&Arc<Single>]) -> Route {
+    create_route_with_activities(
+        fleet,
+        vehicle,
+        jobs.iter().map(|job| test_activity_with_job((*job).clone())).collect(),
+    )
+}
+
+fn create_reference(fleet: &Fleet, jobs: &[Arc<Single>]) -> Arc<StabilityReference> {
+    let reference = Arc::new(StabilityReference::default());
+    reference.set_routes(&[
+        create_route(fleet, "v1", &[&jobs[0], &jobs[1], &jobs[2]]),
+        create_route(fleet, "v2", &[&jobs[3]]),
+    ]);
+
+    reference
+}
+
+fn create_jobs() -> Vec<Arc<Single>> {
+    (1..=4).map(|idx| test_single_with_id(format!("job{}", idx).as_str())).collect()
+}
+
+parameterized_test! {can_calculate_deviation, (v1_jobs, v2_jobs, reassignment_weight, reorder_weight, expected), {
+    can_calculate_deviation_impl(v1_jobs, v2_jobs, reassignment_weight, reorder_weight, expected);
+}}
+
+can_calculate_deviation! {
+    case01: (vec![0, 1, 2], vec![3], 1., 1., 0.),
+    case02: (vec![1, 0, 2], vec![3], 1., 1., 1.),
+    case03: (vec![2, 1, 0], vec![3], 1., 2., 4.),
+    case04: (vec![0, 1], vec![2, 3], 1., 1., 1.),
+    case05: (vec![0, 1, 2, 3], vec![], 3., 1., 3.),
+    case06: (vec![3], vec![2, 1, 0], 2., 1., 8.),
+}
+
+fn can_calculate_deviation_impl(
+    v1_jobs: Vec<usize>,
+    v2_jobs: Vec<usize>,
+    reassignment_weight: f64,
+    reorder_weight: f64,
+    expected: f64,
+) {
+    let fleet = create_fleet();
+    let jobs = create_jobs();
+    let reference = create_reference(&fleet, &jobs);
+    let get_jobs = |indices: Vec<usize>| indices.into_iter().map(|idx| &jobs[idx]).collect::<Vec<_>>();
+    let objective = PlanStability { reference, reassignment_weight, reorder_weight };
+
+    let deviation = objective.get_deviation(&create_route(&fleet, "v1", get_jobs(v1_jobs).as_slice()))
+        + objective.get_deviation(&create_route(&fleet, "v2", get_jobs(v2_jobs).as_slice()));
+
+    assert_eq!(deviation, expected);
+}
+
+parameterized_test! {can_estimate_reassignment_and_reorder_insertions, has_priority_over_cost, {
+    can_estimate_reassignment_and_reorder_insertions_impl(has_priority_over_cost);
+}}
+
+can_estimate_reassignment_and_reorder_insertions! {
+    case01: true,
+    case02: false,
+}
+
+fn can_estimate_reassignment_and_reorder_insertions_impl(has_priority_over_cost: bool) {
+    let fleet = create_fleet();
+    let jobs = create_jobs();
+    let reference = create_reference(&fleet, &jobs);
+    let route_constraint =
+        PlanStabilitySoftRouteConstraint { reference: reference.clone(), weight: 3., has_priority_over_cost };
+    let activity_constraint = PlanStabilitySoftActivityConstraint { reference, weight: 2., has_priority_over_cost };
+    let solution_ctx = crate::helpers::models::domain::create_empty_solution_context();
+    let route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![test_activity_with_job(jobs[0].clone()), test_activity_with_job(jobs[2].clone())],
+    );
+    let target = test_activity_with_job(jobs[1].clone());
+    let get_activity_estimate = |index: usize| {
+        activity_constraint.estimate_activity(
+            &route_ctx,
+            &ActivityContext {
+                index,
+                prev: route_ctx.route.tour.get(index).unwrap(),
+                target: &target,
+                next: route_ctx.route.tour.get(index + 1),
+            },
+        )
+    };
+    // NOTE route and solution costs are zero in test contexts
+    let deviation_cost = if has_priority_over_cost { 1E9 } else { 1. };
+
+    assert_eq!(route_constraint.estimate_job(&solution_ctx, &route_ctx, &Job::Single(jobs[1].clone())), 0.);
+    assert_eq!(
+        route_constraint.estimate_job(&solution_ctx, &route_ctx, &Job::Single(jobs[3].clone())),
+        3. * deviation_cost
+    );
+    assert_eq!(get_activity_estimate(1), 0.);
+    assert_eq!(get_activity_estimate(0), 2. * deviation_cost);
+    assert_eq!(get_activity_estimate(2), 2. * deviation_cost);
+}
+
+#[test]
+fn can_ignore_jobs_without_reference() {
+    let fleet = create_fleet();
+    let jobs = create_jobs();
+    let objective = PlanStability {
+        reference: Arc::new(StabilityReference::default()),
+        reassignment_weight: 1.,
+        reorder_weight: 1.,
+    };
+
+    let deviation = objective.get_deviation(&create_route(&fleet, "v2", &[&jobs[2], &jobs[1], &jobs[0]]));
+
+    assert_eq!(deviation, 0.);
+}
+
+parameterized_test! {can_get_deviation_cost, (has_priority_over_cost, cost, expected), {
+    can_get_deviation_cost_impl(has_priority_over_cost, cost, expected);
+}}
+
+can_get_deviation_cost! {
+    case01: (true, 0., 1E9),
+    case02: (true, 100., 200.),
+    case03: (false, 0., 1.),
+    case04: (false, 100., 1.),
+}
+
+fn can_get_deviation_cost_impl(has_priority_over_cost: bool, cost: f64, expected: f64) {
+    assert_eq!(get_deviation_cost(has_priority_over_cost, || cost), expected);
+}
@@ -0,0 +1,58 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::ValueDimension;
+use crate::models::solution::Activity;
+
+fn create_target(location: usize, value: Option<f64>) -> Activity {
+    let mut single = test_single();
+    single.places[0].location = Some(location);
+    if let Some(value) = value {
+        single.dimens.set_value("value", value);
+    }
+
+    Activity { job: Some(Arc::new(single)), ..test_activity_with_location(location) }
+}
+
+parameterized_test! {can_reject_job_when_serving_costs_more_than_value, (location, value, expected), {
+    can_reject_job_when_serving_costs_more_than_value_impl(location, value, expected);
+}}
+
+can_reject_job_when_serving_costs_more_than_value! {
+    case01_on_the_way: (5, Some(1.), None),
+    case02_low_value: (20, Some(70.), Some(1)),
+    case03_high_value: (20, Some(90.), None),
+    case04_no_value: (20, None, None),
+}
+
+fn can_reject_job_when_serving_costs_more_than_value_impl(location: usize, value: Option<f64>, expected: Option<i32>) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![test_activity_with_location(10)]);
+    let target = create_target(location, value);
+    let (module, _) = TotalValue::new_maximized(
+        Arc::new(|job| job.dimens().get_value::<f64>("value").cloned().unwrap_or(0.)),
+        Arc::new(TestActivityCost::default()),
+        TestTransportCost::new_shared(),
+        1,
+    );
+    let constraint = module
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .next()
+        .unwrap();
+
+    let result = constraint.evaluate_activity(
+        &route_ctx,
+        &ActivityContext {
+            index: 0,
+            prev: route_ctx.route.tour.get(0).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(1),
+        },
+    );
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
@@ -12,6 +12,7 @@ pub fn check_assignment(ctx: &CheckerContext) -> Result<(), String> {
     check_vehicles(ctx)?;
     check_jobs_presence(ctx)?;
     check_jobs_match(ctx)?;
+    check_vehicle_times(ctx)?;
 
     Ok(())
 }
@@ -56,6 +57,7 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
 
     let all_jobs = ctx.problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect::<HashMap<_, _>>();
     let mut used_jobs = HashMap::<String, JobAssignment>::new();
+    let mut split_jobs = HashMap::<String, Vec<i32>>::new();
 
     ctx.solution.tours.iter().try_for_each(|tour| {
         tour.stops
@@ -64,6 +66,19 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
             .enumerate()
             .filter(|(_, activity)| activity_types.contains(&activity.activity_type.as_str()))
             .try_for_each(|(idx, activity)| {
+                if ctx.is_split_job(activity.job_id.as_str()) {
+                    let demand = activity
+                        .demand
+                        .as_ref()
+                        .map(|demand| ctx.get_load(demand.as_slice()))
+                        .ok_or_else(|| format!("Split job activity must have demand: '{}'", activity.job_id))?;
+                    let total = split_jobs.entry(activity.job_id.clone()).or_default();
+                    total.resize(total.len().max(demand.len()), 0);
+                    total.iter_mut().zip(demand.iter()).for_each(|(total, value)| *total += *value);
+
+                    return Ok(());
+                }
+
                 let tour_info = (tour.vehicle_id.clone(), tour.shift_index);
                 let asgn =
                     used_jobs.entry(activity.job_id.clone()).or_insert_with(|| new_assignment(tour_info.clone()));
@@ -133,8 +148,39 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
         Ok(())
     })?;
 
-    let all_used_job =
-        unique_unassigned_jobs.into_iter().chain(used_jobs.into_iter().map(|(id, _)| id)).collect::<Vec<_>>();
+    split_jobs.iter().try_for_each(|(id, served)| {
+        let job = all_jobs.get(id).ok_or_else(|| format!("Cannot find job with id {}", id))?;
+        let demand = job
+            .pickups
+            .iter()
+            .chain(job.deliveries.iter())
+            .flat_map(|tasks| tasks.iter())
+            .find_map(|task| task.demand.as_ref().map(|demand| ctx.get_load(demand.as_slice())))
+            .unwrap_or_default();
+        let is_partial = unique_unassigned_jobs.contains(id);
+
+        let is_valid = demand.len() >= served.len()
+            && demand.iter().enumerate().all(|(idx, &expected)| {
+                let served = served.get(idx).cloned().unwrap_or(0);
+                if is_partial {
+                    served <= expected
+                } else {
+                    served == expected
+                }
+            });
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(format!("Served demand of split job '{}' doesn't match: {:?} vs {:?}", id, demand, served))
+        }
+    })?;
+
+    let all_used_job = unique_unassigned_jobs
+        .into_iter()
+        .chain(used_jobs.into_iter().map(|(id, _)| id))
+        .chain(split_jobs.into_iter().map(|(id, _)| id))
+        .collect::<HashSet<_>>();
 
     if all_used_job.len() != all_jobs.len() {
         return Err(format!(
@@ -184,3 +230,53 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
 
     Ok(())
 }
+
+/// Checks that jobs are served within their vehicle specific time windows.
+fn check_vehicle_times(ctx: &CheckerContext) -> Result<(), String> {
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = ctx.get_vehicle(&tour.vehicle_id)?;
+        let is_applicable = |times: &JobVehicleTimes| {
+            times.type_ids.as_ref().map_or(false, |ids| ids.contains(&vehicle.type_id))
+                || times.profiles.as_ref().map_or(false, |profiles| profiles.contains(&vehicle.profile))
+        };
+
+        tour.stops.iter().try_for_each(|stop| {
+            stop.activities.iter().try_for_each(|activity| {
+                let activity_type = ctx.get_activity_type(tour, stop, activity)?;
+                let location = get_location(stop, activity);
+                let time = get_time_window(stop, activity);
+
+                let is_valid = ctx.visit_job(
+                    activity,
+                    &activity_type,
+                    |_, task| {
+                        task.places
+                            .iter()
+                            .filter(|place| place.location == location)
+                            .filter_map(|place| place.vehicle_times.as_ref().map(|times| (place, times)))
+                            .all(|(place, vehicle_times)| {
+                                let start = time.end - place.duration;
+                                vehicle_times.iter().filter(|times| is_applicable(times)).all(|times| {
+                                    times
+                                        .times
+                                        .iter()
+                                        .map(|tw| parse_time_window(tw))
+                                        .any(|tw| tw.start <= start && start <= tw.end)
+                                })
+                            })
+                    },
+                    || true,
+                )?;
+
+                if is_valid {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "vehicle specific time window violation, job '{}', vehicle id '{}', shift index: {}",
+                        activity.job_id, tour.vehicle_id, tour.shift_index
+                    ))
+                }
+            })
+        })
+    })
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/battery_test.rs"]
+mod battery_test;
+
+use super::*;
+
+/// Checks that vehicle battery is not depleted on any tour leg.
+///
+/// NOTE to ensure distance correctness, routing check should be performed first.
+pub fn check_battery(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let battery = if let Some(battery) = vehicle.battery.as_ref() {
+            battery
+        } else {
+            return Ok(());
+        };
+        let load_consumption = battery.load_consumption.unwrap_or(0.);
+        let initial = battery.initial.unwrap_or(battery.capacity);
+
+        (1..).zip(tour.stops.windows(2)).try_fold(initial, |charge, (idx, leg)| {
+            let (from, to) = match leg {
+                [from, to] => (from, to),
+                _ => unreachable!(),
+            };
+
+            let consumption = battery.consumption + load_consumption * from.load.first().cloned().unwrap_or(0.);
+            let charge = charge - (to.distance - from.distance) as f64 * consumption;
+
+            // NOTE stop distances are rounded, so one distance unit is tolerated
+            if charge < -consumption {
+                return Err(format!(
+                    "battery is depleted before stop {}, vehicle id '{}', shift index: {}",
+                    idx, tour.vehicle_id, tour.shift_index
+                ));
+            }
+
+            to.activities.iter().filter(|activity| activity.activity_type == "charging").try_fold(
+                charge,
+                |charge, activity| match context.get_activity_type(tour, to, activity)? {
+                    ActivityType::Charging(station) => {
+                        Ok((charge + station.rate * station.duration).min(battery.capacity))
+                    }
+                    _ => Err(format!("unexpected activity type at stop {} in tour '{}'", idx, tour.vehicle_id)),
+                },
+            )
+        })?;
+
+        Ok(())
+    })
+}
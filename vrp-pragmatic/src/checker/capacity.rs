@@ -3,7 +3,8 @@
 mod capacity_test;
 
 use super::*;
-use std::iter::once;
+use std::iter::{once, repeat};
+use vrp_core::construction::constraints::{can_load_into_compartments, Compartment};
 use vrp_core::models::common::{Load, MultiDimLoad};
 
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
@@ -11,7 +12,7 @@ use vrp_core::models::common::{Load, MultiDimLoad};
 /// * load change is correct
 pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), String> {
     context.solution.tours.iter().try_for_each(|tour| {
-        let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.clone());
+        let capacity = MultiDimLoad::new(context.get_load(context.get_vehicle(&tour.vehicle_id)?.capacity.as_slice()));
 
         let legs = (0_usize..)
             .zip(tour.stops.windows(2))
@@ -72,8 +73,8 @@ pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), String> {
                     )?;
 
                 let end_capacity = interval.iter().try_fold(start_delivery, |acc, (idx, (from, to))| {
-                    let from_load = MultiDimLoad::new(from.load.clone());
-                    let to_load = MultiDimLoad::new(to.load.clone());
+                    let from_load = MultiDimLoad::new(context.get_load(from.load.as_slice()));
+                    let to_load = MultiDimLoad::new(context.get_load(to.load.as_slice()));
 
                     if !capacity.can_fit(&from_load) || !capacity.can_fit(&to_load) {
                         return Err(format!("Load exceeds capacity in tour '{}'", tour.vehicle_id));
@@ -120,6 +121,101 @@ pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), String> {
     })
 }
 
+/// Checks that named load, if present, is consistent with positional one.
+pub fn check_named_load(context: &CheckerContext) -> Result<(), String> {
+    let dimensions = context.problem.fleet.dimensions.as_ref();
+
+    context.solution.tours.iter().try_for_each(|tour| {
+        tour.stops.iter().try_for_each(|stop| match (dimensions, stop.named_load.as_ref()) {
+            (_, None) => Ok(()),
+            (Some(dimensions), Some(named_load))
+                if named_load.len() == dimensions.len()
+                    && context.get_load(get_positional_load(dimensions, named_load).as_slice())
+                        == context.get_load(
+                            stop.load
+                                .iter()
+                                .cloned()
+                                .chain(repeat(0.))
+                                .take(dimensions.len())
+                                .collect::<Vec<_>>()
+                                .as_slice(),
+                        ) =>
+            {
+                Ok(())
+            }
+            _ => Err(format!("Named load does not match load at stop in tour '{}'", tour.vehicle_id)),
+        })
+    })
+}
+
+/// Checks that goods can be distributed between vehicle compartments within each trip.
+pub fn check_compartments(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let compartments = match context.get_vehicle(&tour.vehicle_id)?.compartments.as_ref() {
+            Some(compartments) if !compartments.is_empty() => compartments
+                .iter()
+                .map(|compartment| Compartment {
+                    capacity: MultiDimLoad::new(context.get_load(compartment.capacity.as_slice())),
+                    goods: compartment.goods.iter().cloned().collect(),
+                })
+                .collect::<Vec<_>>(),
+            _ => return Ok(()),
+        };
+
+        let activities = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+            .try_fold::<_, _, Result<_, String>>(vec![vec![]], |mut trips, (stop, activity)| {
+                if activity.activity_type == "reload" {
+                    trips.push(vec![]);
+                    return Ok(trips);
+                }
+
+                let goods = context.get_job_by_id(&activity.job_id).and_then(|job| job.goods.clone());
+                if let Some(goods) = goods {
+                    let activity_type = context.get_activity_type(tour, stop, activity)?;
+                    let demand = get_demand(context, activity, &activity_type)?;
+                    trips.last_mut().unwrap().push((goods, demand));
+                }
+
+                Ok(trips)
+            })?;
+
+        activities.into_iter().try_for_each(|trip| {
+            let start = trip.iter().fold(HashMap::<String, MultiDimLoad>::new(), |mut acc, (goods, demand)| {
+                if let (DemandType::StaticDelivery, demand) = demand {
+                    let load = acc.get(goods).cloned().unwrap_or_default();
+                    acc.insert(goods.clone(), load + *demand);
+                }
+                acc
+            });
+
+            let (_, peaks) = trip.iter().fold((start.clone(), start), |(mut current, mut peaks), (goods, demand)| {
+                let load = current.get(goods).cloned().unwrap_or_default();
+                let load = match demand {
+                    (DemandType::StaticDelivery, demand) | (DemandType::DynamicDelivery, demand) => load - *demand,
+                    (DemandType::StaticPickup, demand) | (DemandType::DynamicPickup, demand) => load + *demand,
+                    _ => load,
+                };
+
+                let peak = peaks.get(goods).map_or(load, |peak| peak.max_load(load));
+                current.insert(goods.clone(), load);
+                peaks.insert(goods.clone(), peak);
+
+                (current, peaks)
+            });
+
+            let peaks = peaks.into_iter().collect::<Vec<_>>();
+            if can_load_into_compartments(compartments.as_slice(), peaks.as_slice()) {
+                Ok(())
+            } else {
+                Err(format!("Goods cannot be distributed between compartments in tour '{}'", tour.vehicle_id))
+            }
+        })
+    })
+}
+
 enum DemandType {
     None,
     StaticPickup,
@@ -140,13 +236,26 @@ fn get_demand(
         |job, task| {
             let is_dynamic = job.pickups.as_ref().map_or(false, |p| !p.is_empty())
                 && job.deliveries.as_ref().map_or(false, |p| !p.is_empty());
-            let demand = task.demand.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new);
+            let demand = task
+                .demand
+                .as_ref()
+                .map_or_else(MultiDimLoad::default, |demand| MultiDimLoad::new(context.get_load(demand.as_slice())));
 
             (is_dynamic, demand)
         },
         || (false, MultiDimLoad::default()),
     )?;
 
+    // NOTE split job is served by several visits, each of them has its own demand
+    let demand = match activity_type {
+        ActivityType::Job(job) if context.is_split_job(job.id.as_str()) => activity
+            .demand
+            .as_ref()
+            .map(|demand| MultiDimLoad::new(context.get_load(demand.as_slice())))
+            .ok_or_else(|| format!("Split job activity must have demand: '{}'", activity.job_id))?,
+        _ => demand,
+    };
+
     let demand_type = match (is_dynamic, activity.activity_type.as_ref()) {
         (_, "replacement") => DemandType::StaticPickupDelivery,
         (true, "pickup") => DemandType::DynamicPickup,
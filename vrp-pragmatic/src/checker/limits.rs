@@ -3,10 +3,13 @@
 mod limits_test;
 
 use super::*;
+use std::collections::HashSet;
 
 /// Check that shift limits are not violated:
 /// * max shift time
 /// * max distance
+/// * allowed and forbidden areas
+/// * max jobs and stops per tour
 ///
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), String> {
@@ -31,6 +34,53 @@ pub fn check_limits(context: &CheckerContext) -> Result<(), String> {
                     ));
                 }
             }
+
+            if let Some(areas) = read_areas(limits) {
+                let job_id = tour
+                    .stops
+                    .iter()
+                    .flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+                    .filter(|(_, activity)| is_job_activity(activity))
+                    .find(|(stop, activity)| !areas.can_serve(&get_location(stop, activity).to_lat_lng()))
+                    .map(|(_, activity)| activity.job_id.clone());
+
+                if let Some(job_id) = job_id {
+                    return Err(format!(
+                        "area limit violation, job '{}' is served outside allowed or inside forbidden area, vehicle id '{}', shift index: {}",
+                        job_id, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
+            if let Some(max_jobs) = limits.max_jobs {
+                let jobs = tour
+                    .stops
+                    .iter()
+                    .flat_map(|stop| stop.activities.iter())
+                    .filter(|activity| is_job_activity(activity))
+                    .map(|activity| activity.job_id.as_str())
+                    .collect::<HashSet<_>>()
+                    .len();
+
+                if jobs > max_jobs {
+                    return Err(format!(
+                        "max jobs limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_jobs, jobs, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
+            if let Some(max_stops) = limits.max_stops {
+                let stops =
+                    tour.stops.iter().filter(|stop| stop.activities.iter().any(is_job_activity)).count();
+
+                if stops > max_stops {
+                    return Err(format!(
+                        "max stops limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_stops, stops, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
         }
 
         Ok(())
@@ -20,6 +20,7 @@ pub struct CheckerContext {
 
     job_map: HashMap<String, Job>,
     core_problem: Arc<CoreProblem>,
+    load_scale: f64,
 }
 
 /// Represents all possible activity types.
@@ -29,6 +30,7 @@ enum ActivityType {
     Depot(VehicleCargoPlace),
     Break(VehicleBreak),
     Reload(VehicleCargoPlace),
+    Charging(VehicleChargingStation),
 }
 
 impl CheckerContext {
@@ -39,23 +41,33 @@ impl CheckerContext {
         matrices: Option<Vec<Matrix>>,
         solution: Solution,
     ) -> Self {
+        let problem = apply_named_dimensions(problem);
         let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
+        let load_scale = get_load_scale(&problem);
 
-        Self { problem, matrices, solution, job_map, core_problem }
+        Self { problem, matrices, solution, job_map, core_problem, load_scale }
     }
 
     /// Performs solution check.
     pub fn check(&self) -> Result<(), String> {
         check_vehicle_load(&self)?;
+        check_compartments(&self)?;
+        check_named_load(&self)?;
         check_relations(&self)?;
         check_breaks(&self)?;
         check_assignment(&self)?;
         check_routing(&self)?;
         check_limits(&self)?;
+        check_battery(&self)?;
 
         Ok(())
     }
 
+    /// Converts decimal load values into fixed-point units used for exact comparison.
+    fn get_load(&self, values: &[f64]) -> Vec<i32> {
+        to_fixed_load(values, self.load_scale)
+    }
+
     /// Gets vehicle by its id.
     fn get_vehicle(&self, vehicle_id: &str) -> Result<&VehicleType, String> {
         self.problem
@@ -149,6 +161,14 @@ impl CheckerContext {
                 .and_then(|reload| reload.iter().find(|r| r.location == location && r.tag == activity.job_tag))
                 .map(|r| ActivityType::Reload(r.clone()))
                 .ok_or_else(|| format!("Cannot find reload for tour '{}'", tour.vehicle_id)),
+            "charging" => self
+                .get_vehicle(&tour.vehicle_id)?
+                .battery
+                .as_ref()
+                .and_then(|battery| battery.stations.as_ref())
+                .and_then(|stations| stations.iter().find(|s| s.location == location && s.tag == activity.job_tag))
+                .map(|s| ActivityType::Charging(s.clone()))
+                .ok_or_else(|| format!("Cannot find charging station for tour '{}'", tour.vehicle_id)),
             "depot" => shift
                 .depots
                 .as_ref()
@@ -163,6 +183,10 @@ impl CheckerContext {
         self.problem.plan.jobs.iter().find(|job| job.id == job_id)
     }
 
+    fn is_split_job(&self, job_id: &str) -> bool {
+        self.job_map.get(job_id).map_or(false, |job| get_split_task(&self.problem, job).is_some())
+    }
+
     fn visit_job<F1, F2, R>(
         &self,
         activity: &Activity,
@@ -240,11 +264,14 @@ mod assignment;
 use crate::checker::assignment::check_assignment;
 
 mod capacity;
-use crate::checker::capacity::check_vehicle_load;
+use crate::checker::capacity::{check_compartments, check_named_load, check_vehicle_load};
 
 mod limits;
 use crate::checker::limits::check_limits;
 
+mod battery;
+use crate::checker::battery::check_battery;
+
 mod breaks;
 use crate::checker::breaks::check_breaks;
 
@@ -7,7 +7,8 @@ use std::collections::HashSet;
 
 /// Checks relation rules.
 pub fn check_relations(context: &CheckerContext) -> Result<(), String> {
-    let reserved_ids = vec!["departure", "arrival", "break", "depot", "reload"].into_iter().collect::<HashSet<_>>();
+    let reserved_ids =
+        vec!["departure", "arrival", "break", "depot", "reload", "charging"].into_iter().collect::<HashSet<_>>();
 
     (0_usize..)
         .zip(context.problem.plan.relations.as_ref().map_or(vec![].iter(), |relations| relations.iter()))
@@ -84,7 +85,28 @@ pub fn check_relations(context: &CheckerContext) -> Result<(), String> {
             }
         })?;
 
-    Ok(())
+    check_tour_order(context)
+}
+
+/// Checks that jobs with first/last order are served at the beginning/end of the tour.
+fn check_tour_order(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let positions = get_activity_ids(tour)
+            .iter()
+            .filter_map(|job_id| context.get_job_by_id(job_id))
+            .map(|job| match job.order {
+                Some(JobOrder::Position(JobOrderPosition::First)) => 0,
+                Some(JobOrder::Position(JobOrderPosition::Last)) => 2,
+                _ => 1,
+            })
+            .collect::<Vec<_>>();
+
+        if positions.windows(2).any(|pair| pair[0] > pair[1]) {
+            Err(format!("Tour of '{}' does not follow job order", tour.vehicle_id))
+        } else {
+            Ok(())
+        }
+    })
 }
 
 fn get_tour_by_vehicle_id(vehicle_id: &str, shift_index: Option<usize>, solution: &Solution) -> Result<Tour, String> {
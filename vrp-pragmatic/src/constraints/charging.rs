@@ -0,0 +1,470 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/charging_test.rs"]
+mod charging_test;
+
+use crate::constraints::*;
+use std::cmp::Ordering;
+use std::ops::{Add, Sub};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Demand, DemandDimension, Load, ValueDimension};
+use vrp_core::models::problem::{Job, Single, TransportCost};
+use vrp_core::models::solution::{Activity, Route};
+use vrp_core::utils::compare_floats;
+
+/// Specifies electric vehicle battery.
+#[derive(Clone, Debug)]
+pub struct Battery {
+    /// Battery capacity.
+    pub capacity: f64,
+    /// Initial state of charge.
+    pub initial: f64,
+    /// Energy consumed per distance unit by empty vehicle.
+    pub consumption: f64,
+    /// Extra energy consumed per distance unit for each unit of load.
+    pub load_consumption: f64,
+}
+
+/// A module which tracks state of charge of vehicle battery along the route. Charging stations are
+/// offered for insertion to used vehicles only when some job cannot be served due to battery range,
+/// redundant charging activities are removed when all required jobs are processed.
+/// NOTE should be added before transport module as removal of charging activities changes route schedules.
+pub struct ChargingModule<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    code: i32,
+    conditional: ConditionalJobModule,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    energy: Arc<EnergyModel<T>>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> ChargingModule<T> {
+    /// Creates a new instance of `ChargingModule`. `load_value` maps load to the amount used to
+    /// estimate load dependent energy consumption.
+    pub fn new(
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        load_value: Arc<dyn Fn(&T) -> f64 + Send + Sync>,
+        code: i32,
+    ) -> Self {
+        let energy = Arc::new(EnergyModel { transport, load_value });
+
+        Self {
+            code,
+            conditional: ConditionalJobModule::new(Box::new(ConcreteJobContextTransition {
+                remove_required: |ctx, job| !is_required_job(ctx, job, true),
+                promote_required: |ctx, job| is_required_job(ctx, job, false),
+                remove_locked: |_, _| false,
+                promote_locked: |_, job| is_charging_job(job),
+            })),
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(ChargingHardRouteConstraint { code })),
+                ConstraintVariant::HardActivity(Arc::new(ChargingHardActivityConstraint {
+                    code,
+                    energy: energy.clone(),
+                })),
+                ConstraintVariant::SoftRoute(Arc::new(ChargingSoftRouteConstraint {})),
+            ],
+            keys: vec![BATTERY_CHARGE_KEY],
+            energy,
+        }
+    }
+
+    /// Removes charging activities which are not needed to complete the route.
+    fn remove_redundant_charging(&self, ctx: &mut SolutionContext) {
+        let mut extra_ignored = Vec::new();
+
+        ctx.routes.iter_mut().for_each(|route_ctx| {
+            let battery = if let Some(battery) = get_battery(&route_ctx.route) {
+                battery.clone()
+            } else {
+                return;
+            };
+
+            let indices = (0..)
+                .zip(route_ctx.route.tour.all_activities())
+                .filter_map(|(idx, activity)| as_charging_job(activity).map(|_| idx))
+                .collect::<Vec<_>>();
+
+            indices.into_iter().rev().for_each(|idx| {
+                let activities = (0..)
+                    .zip(route_ctx.route.tour.all_activities())
+                    .filter(|(activity_idx, _)| *activity_idx != idx)
+                    .map(|(_, activity)| activity)
+                    .collect::<Vec<_>>();
+
+                if self.energy.get_min_charge(&route_ctx.route, &battery, activities.as_slice()) >= 0. {
+                    let job = as_charging_job(route_ctx.route.tour.get(idx).unwrap()).unwrap().clone();
+                    extra_ignored.push(Job::Single(job));
+                    route_ctx.route_mut().tour.remove_activity_at(idx);
+                }
+            });
+        });
+
+        ctx.ignored.extend(extra_ignored.into_iter());
+    }
+
+    /// Marks charging stations as required once some job cannot be served due to battery range.
+    fn update_charging_requirement(&self, ctx: &mut SolutionContext) {
+        let has_range_failures = ctx.unassigned.iter().any(|(job, code)| *code == self.code && !is_charging_job(job));
+
+        let is_required = if ctx.required.is_empty() {
+            let has_stations =
+                ctx.routes.iter().any(|rc| rc.route.tour.all_activities().any(|a| as_charging_job(a).is_some()));
+            has_range_failures || has_stations
+        } else {
+            has_range_failures || is_charging_required(ctx)
+        };
+
+        ctx.state.insert(CHARGING_REQUIRED_KEY, Arc::new(is_required));
+    }
+
+    fn recalculate_states(&self, route_ctx: &mut RouteContext) {
+        let battery = if let Some(battery) = get_battery(&route_ctx.route) {
+            battery.clone()
+        } else {
+            return;
+        };
+
+        let (route, state) = route_ctx.as_mut();
+        let activities = route.tour.all_activities().collect::<Vec<_>>();
+        let charges = self.energy.get_charge_states(route, &battery, activities.as_slice());
+
+        activities.into_iter().zip(charges.into_iter()).for_each(|(activity, charge)| {
+            state.put_activity_state(BATTERY_CHARGE_KEY, activity, charge);
+        });
+    }
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> ConstraintModule for ChargingModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.recalculate_states(solution_ctx.routes.get_mut(route_index).unwrap());
+        self.update_charging_requirement(solution_ctx);
+        self.conditional.accept_solution_state(solution_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.recalculate_states(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        self.conditional.accept_solution_state(ctx);
+
+        if ctx.required.is_empty() {
+            // NOTE charging station which cannot be visited is not a reason to treat solution as worse one
+            let unassigned = ctx.unassigned.keys().filter(|job| is_charging_job(job)).cloned().collect::<Vec<_>>();
+            unassigned.iter().for_each(|job| {
+                ctx.unassigned.remove(job);
+            });
+            ctx.ignored.extend(unassigned.into_iter());
+
+            self.remove_redundant_charging(ctx);
+        }
+
+        self.update_charging_requirement(ctx);
+
+        ctx.routes.iter_mut().for_each(|route_ctx| self.recalculate_states(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Locks charging jobs to specific vehicles.
+struct ChargingHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for ChargingHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        job.as_single().filter(|single| is_charging_single(single)).and_then(|single| {
+            let vehicle_id = get_vehicle_id_from_job(single).unwrap();
+            let shift_index = get_shift_index(&single.dimens);
+
+            if is_correct_vehicle(&ctx.route, vehicle_id, shift_index) {
+                None
+            } else {
+                Some(RouteConstraintViolation { code: self.code })
+            }
+        })
+    }
+}
+
+/// Checks that battery is not depleted after activity insertion.
+struct ChargingHardActivityConstraint<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    code: i32,
+    energy: Arc<EnergyModel<T>>,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> HardActivityConstraint
+    for ChargingHardActivityConstraint<T>
+{
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        get_battery(&route_ctx.route).and_then(|battery| {
+            let is_feasible = self
+                .energy
+                .can_insert(route_ctx, battery, activity_ctx)
+                .unwrap_or_else(|| self.energy.can_insert_with_simulation(route_ctx, battery, activity_ctx));
+
+            if is_feasible {
+                None
+            } else {
+                Some(ActivityConstraintViolation { code: self.code, stopped: false })
+            }
+        })
+    }
+}
+
+/// Makes charging jobs preferable for insertion once they are offered, so they are inserted before
+/// jobs which cannot be served without charging. The bonus is derived from the route cost, so it
+/// outweighs a detour to the charging station unless the detour costs more than the route twice.
+struct ChargingSoftRouteConstraint {}
+
+impl SoftRouteConstraint for ChargingSoftRouteConstraint {
+    fn estimate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> f64 {
+        if is_charging_job(job) {
+            let route_cost = ctx.get_route_cost();
+            let bonus = if compare_floats(route_cost, 0.) == Ordering::Equal { 1E9 } else { route_cost * 2. };
+
+            -bonus
+        } else {
+            0.
+        }
+    }
+}
+
+/// Estimates energy consumption along the route.
+struct EnergyModel<T: Load + Add<Output = T> + Sub<Output = T> + 'static> {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    load_value: Arc<dyn Fn(&T) -> f64 + Send + Sync>,
+}
+
+/// Keeps state of charge of vehicle battery at specific activity.
+#[derive(Clone, Debug)]
+struct ChargeState {
+    /// State of charge on arrival.
+    arrival: f64,
+    /// State of charge on departure.
+    departure: f64,
+    /// Energy consumed per distance unit after departure.
+    rate: f64,
+    /// Minimum state of charge on arrival to this or any later activity.
+    min_future: f64,
+}
+
+impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> EnergyModel<T> {
+    /// Checks whether activity can be inserted using states of charge kept in route state.
+    /// Returns `None` if the check cannot be done without simulating the whole route.
+    fn can_insert(&self, route_ctx: &RouteContext, battery: &Battery, activity_ctx: &ActivityContext) -> Option<bool> {
+        // NOTE load dependent consumption changes energy needed for all legs of the trip
+        if battery.load_consumption != 0. && self.has_demand(activity_ctx.target) {
+            return None;
+        }
+
+        let state = &route_ctx.state;
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let prev_charge = state.get_activity_state::<ChargeState>(BATTERY_CHARGE_KEY, prev)?;
+
+        let profile = route_ctx.route.actor.vehicle.profile;
+        let departure = prev.schedule.departure;
+        let get_energy = |from: &Activity, to: &Activity| {
+            self.transport.distance(profile, from.place.location, to.place.location, departure) * prev_charge.rate
+        };
+
+        let arrival = prev_charge.departure - get_energy(prev, target);
+        if arrival < 0. {
+            return Some(false);
+        }
+
+        let departure_charge =
+            as_charging_job(target).map_or(arrival, |job| (arrival + get_charging_amount(job)).min(battery.capacity));
+
+        if let Some(next) = activity_ctx.next {
+            let next_charge = state.get_activity_state::<ChargeState>(BATTERY_CHARGE_KEY, next)?;
+            let change = departure_charge - get_energy(target, next) - next_charge.arrival;
+
+            // NOTE more energy on arrival to next activity cannot decrease state of charge later
+            Some(change >= 0. || next_charge.min_future + change >= 0.)
+        } else {
+            Some(true)
+        }
+    }
+
+    /// Checks whether activity can be inserted by simulating energy consumption along the whole route.
+    fn can_insert_with_simulation(
+        &self,
+        route_ctx: &RouteContext,
+        battery: &Battery,
+        activity_ctx: &ActivityContext,
+    ) -> bool {
+        let tour = &route_ctx.route.tour;
+        let activities = tour
+            .all_activities()
+            .take(activity_ctx.index + 1)
+            .chain(std::iter::once(activity_ctx.target))
+            .chain(tour.all_activities().skip(activity_ctx.index + 1))
+            .collect::<Vec<_>>();
+
+        self.get_min_charge(&route_ctx.route, battery, activities.as_slice()) >= 0.
+    }
+
+    /// Returns minimum state of charge observed at arrival to given activities.
+    fn get_min_charge(&self, route: &Route, battery: &Battery, activities: &[&Activity]) -> f64 {
+        self.get_charge_states(route, battery, activities).first().map_or(battery.initial, |state| state.min_future)
+    }
+
+    /// Returns state of charge at each of given activities.
+    fn get_charge_states(&self, route: &Route, battery: &Battery, activities: &[&Activity]) -> Vec<ChargeState> {
+        let loads = self.get_loads(battery, activities);
+        let profile = route.actor.vehicle.profile;
+
+        let mut states: Vec<ChargeState> = Vec::with_capacity(activities.len());
+        let mut arrival = battery.initial;
+        let mut departure = 0.;
+
+        activities.iter().zip(loads.iter()).enumerate().for_each(|(idx, (activity, load))| {
+            if let Some(prev) = states.last() {
+                let prev_activity = activities[idx - 1];
+                // NOTE inserted activity has no schedule yet, so previous departure is used
+                departure = prev_activity.schedule.departure.max(departure);
+
+                let distance =
+                    self.transport.distance(profile, prev_activity.place.location, activity.place.location, departure);
+                arrival = prev.departure - distance * prev.rate;
+            }
+
+            let charge = as_charging_job(activity)
+                .map_or(arrival, |job| (arrival + get_charging_amount(job)).min(battery.capacity));
+            let rate = battery.consumption + battery.load_consumption * load;
+
+            states.push(ChargeState { arrival, departure: charge, rate, min_future: arrival });
+        });
+
+        states.iter_mut().rev().fold(f64::MAX, |min, state| {
+            state.min_future = state.arrival.min(min);
+            state.min_future
+        });
+
+        states
+    }
+
+    fn has_demand(&self, activity: &Activity) -> bool {
+        activity.job.as_ref().and_then(|job| job.dimens.get_demand()).map_or(false, |demand: &Demand<T>| {
+            let value = self.load_value.as_ref();
+            value(&demand.pickup.0) != 0.
+                || value(&demand.pickup.1) != 0.
+                || value(&demand.delivery.0) != 0.
+                || value(&demand.delivery.1) != 0.
+        })
+    }
+
+    /// Returns load on departure from each activity.
+    fn get_loads(&self, battery: &Battery, activities: &[&Activity]) -> Vec<f64> {
+        if battery.load_consumption == 0. {
+            return vec![0.; activities.len()];
+        }
+
+        let get_demand = |activity: &Activity| {
+            activity.job.as_ref().and_then(|job| job.dimens.get_demand()).map(|demand: &Demand<T>| {
+                let value = self.load_value.as_ref();
+                (
+                    value(&demand.delivery.0),
+                    value(&demand.pickup.0),
+                    value(&demand.pickup.0) + value(&demand.pickup.1)
+                        - value(&demand.delivery.0)
+                        - value(&demand.delivery.1),
+                )
+            })
+        };
+
+        let is_reload = |activity: &Activity| {
+            activity
+                .job
+                .as_ref()
+                .and_then(|job| job.dimens.get_value::<String>("type"))
+                .map_or(false, |t| t == "reload")
+        };
+
+        // NOTE static deliveries are loaded at the start of each trip, static pickups are unloaded at its end
+        let mut loads = Vec::with_capacity(activities.len());
+        let mut start_idx = 0;
+        let mut acc = 0.;
+        while start_idx < activities.len() {
+            let end_idx = activities
+                .iter()
+                .enumerate()
+                .skip(start_idx + 1)
+                .find(|(_, activity)| is_reload(activity))
+                .map_or(activities.len(), |(idx, _)| idx);
+
+            let (start_delivery, end_pickup) = activities[start_idx..end_idx]
+                .iter()
+                .filter_map(|activity| get_demand(activity))
+                .fold((acc, 0.), |(delivery, pickup), demand| (delivery + demand.0, pickup + demand.1));
+
+            let current = activities[start_idx..end_idx].iter().fold(start_delivery, |current, activity| {
+                let current = current + get_demand(activity).map_or(0., |demand| demand.2);
+                loads.push(current);
+                current
+            });
+
+            acc = current - end_pickup;
+            start_idx = end_idx;
+        }
+
+        loads
+    }
+}
+
+//region Helpers
+
+fn get_battery(route: &Route) -> Option<&Battery> {
+    route.actor.vehicle.dimens.get_value::<Battery>("battery")
+}
+
+fn get_charging_amount(job: &Single) -> f64 {
+    let rate = *job.dimens.get_value::<f64>("charging_rate").unwrap();
+    let duration = job.places.first().unwrap().duration;
+
+    rate * duration
+}
+
+/// Marks charging job as required only if its vehicle is used and charging is needed.
+fn is_required_job(ctx: &SolutionContext, job: &Job, default: bool) -> bool {
+    match job {
+        Job::Single(job) if is_charging_single(job) => {
+            let vehicle_id = get_vehicle_id_from_job(job).unwrap();
+            let shift_index = get_shift_index(&job.dimens);
+            is_charging_required(ctx)
+                && ctx.routes.iter().any(|rc| is_correct_vehicle(&rc.route, vehicle_id, shift_index))
+        }
+        _ => default,
+    }
+}
+
+fn is_charging_required(ctx: &SolutionContext) -> bool {
+    ctx.state.get(&CHARGING_REQUIRED_KEY).and_then(|s| s.downcast_ref::<bool>()).cloned().unwrap_or(false)
+}
+
+fn is_charging_single(single: &Single) -> bool {
+    single.dimens.get_value::<String>("type").map_or(false, |t| t == "charging")
+}
+
+fn is_charging_job(job: &Job) -> bool {
+    job.as_single().map_or(false, |single| is_charging_single(single))
+}
+
+fn as_charging_job(activity: &Activity) -> Option<&Arc<Single>> {
+    as_single_job(activity, |job| is_charging_single(job))
+}
+
+//endregion
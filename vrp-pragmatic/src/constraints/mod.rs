@@ -16,7 +16,7 @@ fn get_shift_index(dimens: &Dimensions) -> usize {
     *dimens.get_value::<usize>("shift_index").unwrap()
 }
 
-fn get_vehicle_id_from_job(job: &Arc<Single>) -> Option<&String> {
+pub(crate) fn get_vehicle_id_from_job(job: &Arc<Single>) -> Option<&String> {
     job.dimens.get_value::<String>("vehicle_id")
 }
 
@@ -28,6 +28,9 @@ fn is_correct_vehicle(route: &Route, target_id: &str, target_shift: usize) -> bo
 mod breaks;
 pub use self::breaks::BreakModule;
 
+mod charging;
+pub use self::charging::{Battery, ChargingModule};
+
 mod priorities;
 pub use self::priorities::PriorityModule;
 
@@ -39,3 +42,12 @@ pub use self::reachable::ReachableModule;
 
 mod skills;
 pub use self::skills::SkillsModule;
+
+mod tour_order;
+pub use self::tour_order::{TourOrder, TourOrderModule};
+
+mod tour_size;
+pub use self::tour_size::{TourSize, TourSizeModule};
+
+mod vehicle_times;
+pub use self::vehicle_times::{VehicleTimes, VehicleTimesModule};
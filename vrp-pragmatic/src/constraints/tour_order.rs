@@ -0,0 +1,109 @@
+use crate::constraints::*;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::ValueDimension;
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::{Activity, Tour};
+
+/// Specifies job order within a tour.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TourOrder {
+    /// A job has to be served before any other job in a tour.
+    First,
+    /// A job has to be served after any other job in a tour.
+    Last,
+    /// A job is preferred to be served before jobs with bigger rank.
+    Rank(i32),
+}
+
+/// Keeps jobs with first/last order at the beginning/end of the tour.
+pub struct TourOrderModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl TourOrderModule {
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(TourOrderHardActivityConstraint { code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for TourOrderModule {
+    fn accept_insertion(&self, _solution_ctx: &mut SolutionContext, _route_index: usize, _job: &Job) {}
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TourOrderHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for TourOrderHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let target = get_position(activity_ctx.target)?;
+        let (prev, next) = get_neighbours(&route_ctx.route.tour, activity_ctx.index);
+
+        let prev = prev.and_then(get_position);
+        let next = next.and_then(get_position);
+
+        if prev.map_or(false, |prev| prev > target) {
+            // NOTE all further insertion positions will have the same or worse previous job
+            Some(ActivityConstraintViolation { code: self.code, stopped: true })
+        } else if next.map_or(false, |next| target > next) {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns nearest job activities around insertion index ignoring vehicle specific jobs such as breaks or reloads.
+fn get_neighbours(tour: &Tour, index: usize) -> (Option<&Activity>, Option<&Activity>) {
+    let is_ordered = |activity: &&Activity| activity.job.is_some() && !is_conditional(activity);
+
+    let prev = (0..=index).rev().filter_map(|idx| tour.get(idx)).find(is_ordered);
+    let next = (index + 1..tour.total()).filter_map(|idx| tour.get(idx)).find(is_ordered);
+
+    (prev, next)
+}
+
+/// Returns position group of the job activity: first, any or last.
+fn get_position(activity: &Activity) -> Option<usize> {
+    if activity.job.is_none() || is_conditional(activity) {
+        return None;
+    }
+
+    let order = activity.retrieve_job().and_then(|job| match job {
+        Job::Single(job) => job.dimens.get_value::<TourOrder>("order").cloned(),
+        Job::Multi(job) => job.dimens.get_value::<TourOrder>("order").cloned(),
+    });
+
+    Some(match order {
+        Some(TourOrder::First) => 0,
+        Some(TourOrder::Last) => 2,
+        _ => 1,
+    })
+}
+
+fn is_conditional(activity: &Activity) -> bool {
+    activity.job.as_ref().map_or(false, |job| get_vehicle_id_from_job(job).is_some())
+}
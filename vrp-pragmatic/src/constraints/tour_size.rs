@@ -0,0 +1,162 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/tour_size_test.rs"]
+mod tour_size_test;
+
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::ValueDimension;
+use vrp_core::models::problem::{Job, Single};
+use vrp_core::models::solution::{Activity, Route};
+
+/// Specifies tour size limits.
+#[derive(Clone, Debug, Default)]
+pub struct TourSize {
+    /// Max amount of jobs served in the tour.
+    pub max_jobs: Option<usize>,
+    /// Max amount of stops with jobs in the tour.
+    pub max_stops: Option<usize>,
+}
+
+/// A module which limits amount of jobs and stops per tour. Vehicle specific jobs, such as
+/// breaks or reloads, are not counted.
+pub struct TourSizeModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl TourSizeModule {
+    /// Creates a new instance of `TourSizeModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(TourSizeHardRouteConstraint { code })),
+                ConstraintVariant::HardActivity(Arc::new(TourSizeHardActivityConstraint { code })),
+            ],
+            keys: vec![TOUR_STOPS_KEY],
+        }
+    }
+}
+
+impl ConstraintModule for TourSizeModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        if get_tour_size(&ctx.route).and_then(|size| size.max_stops).is_some() {
+            let stops = count_stops(ctx.route.tour.all_activities());
+            ctx.state_mut().put_route_state(TOUR_STOPS_KEY, stops);
+        }
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Checks that amount of jobs in the tour does not exceed the limit.
+struct TourSizeHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for TourSizeHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let max_jobs = get_tour_size(&ctx.route).and_then(|size| size.max_jobs)?;
+
+        if is_vehicle_specific_job(job) {
+            return None;
+        }
+
+        let jobs = ctx.route.tour.jobs().filter(|job| !is_vehicle_specific_job(job)).count();
+
+        if jobs + 1 > max_jobs {
+            Some(RouteConstraintViolation { code: self.code })
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks that amount of stops with jobs in the tour does not exceed the limit.
+struct TourSizeHardActivityConstraint {
+    code: i32,
+}
+
+impl HardActivityConstraint for TourSizeHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let max_stops = get_tour_size(&route_ctx.route).and_then(|size| size.max_stops)?;
+
+        if activity_ctx.target.job.as_ref().map_or(true, |job| is_vehicle_specific_single(job)) {
+            return None;
+        }
+
+        let stops = route_ctx
+            .state
+            .get_route_state::<usize>(TOUR_STOPS_KEY)
+            .cloned()
+            .unwrap_or_else(|| count_stops(route_ctx.route.tour.all_activities()));
+
+        // NOTE an insertion adds at most two stops: a new one and one created by splitting the existing
+        if stops + 2 <= max_stops {
+            return None;
+        }
+
+        let tour = &route_ctx.route.tour;
+        let stops = count_stops(
+            tour.all_activities()
+                .take(activity_ctx.index + 1)
+                .chain(std::iter::once(activity_ctx.target))
+                .chain(tour.all_activities().skip(activity_ctx.index + 1)),
+        );
+
+        if stops > max_stops {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+//region Helpers
+
+fn get_tour_size(route: &Route) -> Option<&TourSize> {
+    route.actor.vehicle.dimens.get_value::<TourSize>("tour_size")
+}
+
+fn is_vehicle_specific_single(single: &Single) -> bool {
+    single.dimens.get_value::<String>("vehicle_id").is_some()
+}
+
+fn is_vehicle_specific_job(job: &Job) -> bool {
+    job.as_single().map_or(false, |single| is_vehicle_specific_single(single))
+}
+
+/// Counts groups of consecutive activities at the same location which have at least one job
+/// which is not vehicle specific.
+fn count_stops<'a>(activities: impl Iterator<Item = &'a Activity>) -> usize {
+    let (stops, _, has_job) = activities.fold((0, None, false), |(stops, location, has_job), activity| {
+        let is_job = activity.job.as_ref().map_or(false, |job| !is_vehicle_specific_single(job));
+
+        if location == Some(activity.place.location) {
+            (stops, location, has_job || is_job)
+        } else {
+            (stops + if has_job { 1 } else { 0 }, Some(activity.place.location), is_job)
+        }
+    });
+
+    stops + if has_job { 1 } else { 0 }
+}
+
+//endregion
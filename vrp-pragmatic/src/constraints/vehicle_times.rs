@@ -0,0 +1,185 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/vehicle_times_test.rs"]
+mod vehicle_times_test;
+
+use std::collections::HashSet;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Location, Profile, TimeWindow, Timestamp, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, TransportCost, Vehicle};
+use vrp_core::models::solution::Activity;
+
+/// Specifies job place time windows which are applied only for specific vehicles.
+#[derive(Clone)]
+pub struct VehicleTimes {
+    /// Vehicle type ids.
+    pub type_ids: HashSet<String>,
+    /// Vehicle profiles.
+    pub profiles: HashSet<Profile>,
+    /// Time windows.
+    pub times: Vec<TimeWindow>,
+}
+
+impl VehicleTimes {
+    fn is_applicable(&self, vehicle: &Vehicle) -> bool {
+        self.profiles.contains(&vehicle.profile)
+            || vehicle.dimens.get_value::<String>("type_id").map_or(false, |type_id| self.type_ids.contains(type_id))
+    }
+}
+
+/// A module which checks that vehicle starts serving job place within its vehicle specific time windows.
+/// Once activity is inserted, its time window is narrowed to the vehicle specific one, so
+/// other time related logic takes it into account. Narrowing always starts from the job's original
+/// time window, so it can be relaxed again when the tour changes.
+pub struct VehicleTimesModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl VehicleTimesModule {
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+    ) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(VehicleTimesHardActivityConstraint {
+                transport: transport.clone(),
+                code,
+            }))],
+            keys: vec![],
+            activity,
+            transport,
+        }
+    }
+
+    fn restrict_time_windows(&self, ctx: &mut RouteContext) {
+        let actor = ctx.route.actor.clone();
+        let start = ctx.route.tour.start().unwrap();
+        let route_departure = start.schedule.departure;
+        let init = (start.place.location, route_departure);
+
+        ctx.route_mut().tour.all_activities_mut().skip(1).fold(init, |(location, departure), activity| {
+            let arrival = departure
+                + self.transport.duration(actor.vehicle.profile, location, activity.place.location, departure);
+
+            let vehicle_times = get_vehicle_times(activity, &actor.vehicle);
+            if !vehicle_times.is_empty() {
+                let original = get_original_time_window(activity, route_departure);
+                let start = arrival.max(original.start);
+                // NOTE when vehicle is late for all vehicle specific time windows, use the first one
+                // anyway, so route state reflects the violation the same way as constraint does
+                activity.place.time = restrict_time_window(&original, vehicle_times.as_slice(), start)
+                    .or_else(|| restrict_time_window(&original, vehicle_times.as_slice(), original.start))
+                    .unwrap_or(original);
+            }
+
+            let departure =
+                arrival.max(activity.place.time.start) + self.activity.duration(actor.as_ref(), activity, arrival);
+
+            (activity.place.location, departure)
+        });
+    }
+}
+
+impl ConstraintModule for VehicleTimesModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _job: &Job) {
+        self.restrict_time_windows(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.restrict_time_windows(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.restrict_time_windows(route_ctx));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct VehicleTimesHardActivityConstraint {
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    code: i32,
+}
+
+impl HardActivityConstraint for VehicleTimesHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let vehicle = route_ctx.route.actor.vehicle.as_ref();
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let vehicle_times = get_vehicle_times(target, vehicle);
+        if vehicle_times.is_empty() {
+            return None;
+        }
+
+        let departure = prev.schedule.departure;
+        let arrival =
+            departure + self.transport.duration(vehicle.profile, prev.place.location, target.place.location, departure);
+        let start = arrival.max(target.place.time.start);
+
+        if restrict_time_window(&target.place.time, vehicle_times.as_slice(), start).is_some() {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+/// Returns vehicle specific times of activity's job place applicable for given vehicle.
+fn get_vehicle_times<'a>(activity: &'a Activity, vehicle: &Vehicle) -> Vec<&'a VehicleTimes> {
+    activity
+        .job
+        .as_ref()
+        .and_then(|job| job.dimens.get_value::<Vec<(Location, Vec<VehicleTimes>)>>("vehicle_times"))
+        .and_then(|places| places.iter().find(|(location, _)| *location == activity.place.location))
+        .map_or_else(Vec::new, |(_, times)| times.iter().filter(|times| times.is_applicable(vehicle)).collect())
+}
+
+/// Returns job's time window which activity's (possibly already narrowed) time window belongs to.
+fn get_original_time_window(activity: &Activity, route_departure: Timestamp) -> TimeWindow {
+    let time = &activity.place.time;
+
+    activity
+        .job
+        .as_ref()
+        .and_then(|job| {
+            job.places
+                .iter()
+                .filter(|place| place.location.map_or(true, |location| location == activity.place.location))
+                .flat_map(|place| place.times.iter())
+                .map(|span| span.to_time_window(route_departure))
+                .find(|tw| tw.start <= time.start && time.end <= tw.end)
+        })
+        .unwrap_or_else(|| time.clone())
+}
+
+/// Returns given time window narrowed by the first vehicle specific time windows which are not
+/// closed at given service start time, so vehicle can wait for them to open, or `None` if all are closed.
+fn restrict_time_window(time: &TimeWindow, vehicle_times: &[&VehicleTimes], start: Timestamp) -> Option<TimeWindow> {
+    vehicle_times.iter().try_fold(time.clone(), |time, vehicle_times| {
+        let start = start.max(time.start);
+
+        vehicle_times
+            .times
+            .iter()
+            .filter(|tw| tw.end >= start)
+            .map(|tw| TimeWindow::new(time.start.max(tw.start), time.end.min(tw.end)))
+            .find(|tw| tw.start <= tw.end)
+    })
+}
@@ -54,6 +54,10 @@ impl CoordIndex {
                     reloads.iter().for_each(|reload| index.add(&reload.location));
                 }
             });
+
+            if let Some(stations) = vehicle.battery.as_ref().and_then(|battery| battery.stations.as_ref()) {
+                stations.iter().for_each(|station| index.add(&station.location));
+            }
         });
 
         index
@@ -6,6 +6,7 @@ extern crate serde_json;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::BufWriter;
+use vrp_core::models::common::{Dimensions, ValueDimension};
 use vrp_core::models::problem::Job as CoreJob;
 use vrp_core::models::Problem as CoreProblem;
 
@@ -108,6 +109,12 @@ const LOCKING_CONSTRAINT_CODE: i32 = 7;
 const REACHABLE_CONSTRAINT_CODE: i32 = 8;
 const PRIORITY_CONSTRAINT_CODE: i32 = 9;
 const AREA_CONSTRAINT_CODE: i32 = 10;
+const VALUE_CONSTRAINT_CODE: i32 = 11;
+const VEHICLE_TIMES_CONSTRAINT_CODE: i32 = 12;
+const TOUR_ORDER_CONSTRAINT_CODE: i32 = 13;
+const COMPARTMENT_CONSTRAINT_CODE: i32 = 14;
+const CHARGING_CONSTRAINT_CODE: i32 = 15;
+const TOUR_SIZE_CONSTRAINT_CODE: i32 = 16;
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
@@ -121,6 +128,24 @@ pub(crate) fn get_job_index(problem: &CoreProblem) -> &JobIndex {
         .unwrap_or_else(|| panic!("cannot get job index!"))
 }
 
+/// A separator between job id and part number in id of the split job part.
+pub(crate) const SPLIT_PART_SEPARATOR: &str = "_split_";
+
+/// Returns an id of the split job part used in job index. Parts are numbered starting from one.
+pub(crate) fn get_split_job_id(job_id: &str, part: usize) -> String {
+    format!("{}{}{}", job_id, SPLIT_PART_SEPARATOR, part)
+}
+
+/// Returns all parts of the split job with given id.
+pub(crate) fn get_split_jobs<'a>(job_index: &'a JobIndex, job_id: &'a str) -> impl Iterator<Item = &'a CoreJob> + 'a {
+    (1..).map(move |part| job_index.get(&get_split_job_id(job_id, part))).take_while(|job| job.is_some()).flatten()
+}
+
+/// Checks whether given job dimensions belong to a part of the split job.
+pub(crate) fn is_split_job(dimens: &Dimensions) -> bool {
+    dimens.get_value::<bool>("split").cloned().unwrap_or(false)
+}
+
 /// Gets coord index from core problem definition.
 pub(crate) fn get_coord_index(problem: &CoreProblem) -> &CoordIndex {
     problem
@@ -0,0 +1,213 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/binary_matrix_test.rs"]
+mod binary_matrix_test;
+
+use crate::format::problem::Matrix;
+use crate::format::FormatError;
+use std::io::Write;
+use std::sync::Arc;
+use vrp_core::models::problem::MatrixValues;
+
+/// A magic bytes which start binary routing matrix.
+pub const BINARY_MATRIX_MAGIC: &[u8; 4] = b"VRPM";
+
+const BINARY_MATRIX_VERSION: u8 = 1;
+const HAS_DURATIONS_FLAG: u8 = 1;
+const HAS_UNREACHABLE_FLAG: u8 = 2;
+
+/// Specifies a type of values stored in binary routing matrix.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinaryValueType {
+    /// Unsigned 32 bit integer, `u32::MAX` marks unreachable location.
+    U32,
+    /// Single precision float, negative value marks unreachable location.
+    F32,
+}
+
+/// A time agnostic routing matrix stored in compact binary form.
+///
+/// Binary layout (all numbers are little endian):
+/// - magic bytes `VRPM`
+/// - version (u8), value type (u8: 0 - u32, 1 - f32), flags (u8: 1 - has durations, 2 - has unreachable), reserved (u8)
+/// - amount of locations (u32)
+/// - profile name length (u32) followed by utf8 bytes, zero length means no profile
+/// - durations (if flag is set) and distances as row-major square matrices
+///
+/// When durations are omitted, distances are used as durations.
+pub struct BinaryMatrix {
+    /// A name of profile.
+    pub profile: Option<String>,
+    /// Amount of locations.
+    pub size: usize,
+    /// Specifies whether matrix has unreachable locations.
+    pub has_unreachable: bool,
+    /// Travel durations.
+    pub durations: Option<Arc<dyn MatrixValues>>,
+    /// Travel distances.
+    pub distances: Arc<dyn MatrixValues>,
+}
+
+/// Checks whether given bytes start with binary matrix magic bytes.
+pub fn is_binary_matrix(bytes: &[u8]) -> bool {
+    bytes.starts_with(BINARY_MATRIX_MAGIC)
+}
+
+/// Deserializes routing matrix in binary format from bytes. Values are read lazily from the buffer,
+/// so it can be a memory mapped file.
+pub fn deserialize_binary_matrix<B>(bytes: B) -> Result<BinaryMatrix, Vec<FormatError>>
+where
+    B: AsRef<[u8]> + Send + Sync + 'static,
+{
+    let to_error = |cause: &str| {
+        vec![FormatError::new(
+            "E0001".to_string(),
+            "cannot deserialize matrix".to_string(),
+            format!("check binary matrix: '{}'", cause),
+        )]
+    };
+
+    let data = bytes.as_ref();
+    let read_u32 =
+        |offset: usize| data.get(offset..offset + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize);
+
+    if !is_binary_matrix(data) {
+        return Err(to_error("unknown magic bytes"));
+    }
+
+    let (version, value_type, flags) = match data.get(4..8) {
+        Some(header) => (header[0], header[1], header[2]),
+        None => return Err(to_error("unexpected end of header")),
+    };
+
+    if version != BINARY_MATRIX_VERSION {
+        return Err(to_error(format!("unsupported version: {}", version).as_str()));
+    }
+
+    let value_type = match value_type {
+        0 => BinaryValueType::U32,
+        1 => BinaryValueType::F32,
+        _ => return Err(to_error(format!("unsupported value type: {}", value_type).as_str())),
+    };
+
+    let size = read_u32(8).ok_or_else(|| to_error("unexpected end of header"))?;
+    let profile_len = read_u32(12).ok_or_else(|| to_error("unexpected end of header"))?;
+    let offset = 16_usize.checked_add(profile_len).ok_or_else(|| to_error("unexpected end of header"))?;
+    let profile = data.get(16..offset).ok_or_else(|| to_error("unexpected end of header"))?;
+    let profile = if profile.is_empty() {
+        None
+    } else {
+        Some(String::from_utf8(profile.to_vec()).map_err(|_| to_error("profile is not valid utf8"))?)
+    };
+
+    let has_durations = flags & HAS_DURATIONS_FLAG != 0;
+    let has_unreachable = flags & HAS_UNREACHABLE_FLAG != 0;
+
+    let matrices = if has_durations { 2 } else { 1 };
+
+    // NOTE header values are not trusted: guard against overflow on malformed or malicious input
+    let len = size.checked_mul(size).ok_or_else(|| to_error("amount of locations is too big"))?;
+    let expected_len = len
+        .checked_mul(matrices * 4)
+        .and_then(|values_len| values_len.checked_add(offset))
+        .ok_or_else(|| to_error("amount of locations is too big"))?;
+
+    if data.len() != expected_len {
+        return Err(to_error("data size does not match amount of locations"));
+    }
+
+    let bytes = Arc::new(bytes);
+    let create_values = |offset: usize| -> Arc<dyn MatrixValues> {
+        Arc::new(BinaryMatrixValues { bytes: bytes.clone(), offset, len, value_type })
+    };
+
+    let (durations, distances) = if has_durations {
+        (Some(create_values(offset)), create_values(offset + len * 4))
+    } else {
+        (None, create_values(offset))
+    };
+
+    Ok(BinaryMatrix { profile, size, has_unreachable, durations, distances })
+}
+
+/// Serializes routing matrix into binary format. Time aware matrices are not supported.
+pub fn serialize_binary_matrix<W: Write>(
+    matrix: &Matrix,
+    value_type: BinaryValueType,
+    writer: &mut W,
+) -> Result<(), String> {
+    if matrix.timestamp.is_some() {
+        return Err("binary matrix cannot be time aware".to_string());
+    }
+
+    if matrix.travel_times.len() != matrix.distances.len() {
+        return Err("distance and duration collections have different length".to_string());
+    }
+
+    let size = (matrix.distances.len() as f64).sqrt().round() as usize;
+    if size * size != matrix.distances.len() {
+        return Err("matrix is not square".to_string());
+    }
+
+    let is_unreachable =
+        |idx: usize| matrix.error_codes.as_ref().and_then(|codes| codes.get(idx)).map_or(false, |code| *code > 0);
+    let has_unreachable = (0..matrix.distances.len()).any(is_unreachable);
+    let has_durations = matrix.travel_times != matrix.distances;
+
+    let flags =
+        if has_durations { HAS_DURATIONS_FLAG } else { 0 } | if has_unreachable { HAS_UNREACHABLE_FLAG } else { 0 };
+    let value_type_code = match value_type {
+        BinaryValueType::U32 => 0,
+        BinaryValueType::F32 => 1,
+    };
+    let profile = matrix.profile.as_ref().map_or(&[] as &[u8], |profile| profile.as_bytes());
+
+    let write = |writer: &mut W, bytes: &[u8]| writer.write_all(bytes).map_err(|err| err.to_string());
+
+    write(writer, BINARY_MATRIX_MAGIC)?;
+    write(writer, &[BINARY_MATRIX_VERSION, value_type_code, flags, 0])?;
+    write(writer, &(size as u32).to_le_bytes())?;
+    write(writer, &(profile.len() as u32).to_le_bytes())?;
+    write(writer, profile)?;
+
+    let values = if has_durations { vec![&matrix.travel_times, &matrix.distances] } else { vec![&matrix.distances] };
+
+    values.into_iter().try_for_each(|values| {
+        values.iter().enumerate().try_for_each(|(idx, &value)| match value_type {
+            BinaryValueType::U32 if is_unreachable(idx) => write(writer, &u32::MAX.to_le_bytes()),
+            BinaryValueType::U32 if value < 0 || value >= u32::MAX as i64 => {
+                Err(format!("value '{}' cannot be stored as u32", value))
+            }
+            BinaryValueType::U32 => write(writer, &(value as u32).to_le_bytes()),
+            BinaryValueType::F32 if is_unreachable(idx) => write(writer, &(-1_f32).to_le_bytes()),
+            BinaryValueType::F32 => write(writer, &(value as f32).to_le_bytes()),
+        })
+    })
+}
+
+/// Provides access to matrix values stored in a binary buffer.
+struct BinaryMatrixValues<B: AsRef<[u8]> + Send + Sync> {
+    bytes: Arc<B>,
+    offset: usize,
+    len: usize,
+    value_type: BinaryValueType,
+}
+
+impl<B: AsRef<[u8]> + Send + Sync> MatrixValues for BinaryMatrixValues<B> {
+    fn value(&self, index: usize) -> f64 {
+        let start = self.offset + index * 4;
+        let bytes = &self.bytes.as_ref().as_ref()[start..start + 4];
+        let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+
+        match self.value_type {
+            BinaryValueType::U32 => match u32::from_le_bytes(bytes) {
+                u32::MAX => -1.,
+                value => value as f64,
+            },
+            BinaryValueType::F32 => f32::from_le_bytes(bytes) as f64,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
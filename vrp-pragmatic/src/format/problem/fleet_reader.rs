@@ -2,44 +2,44 @@
 #[path = "../../../tests/unit/format/problem/fleet_reader_test.rs"]
 mod fleet_reader_test;
 
+use crate::constraints::{Battery, TourSize};
 use crate::extensions::create_typed_actor_groups;
 use crate::format::coord_index::CoordIndex;
-use crate::format::problem::reader::{add_skills, ApiProblem, ProblemProperties};
-use crate::format::problem::Matrix;
+use crate::format::problem::reader::{add_skills, to_fixed_load, ApiProblem, ProblemProperties};
+use crate::format::problem::{BinaryMatrix, Matrix, VehicleBattery, VehicleCompartment, VehicleLimits};
+use crate::format::Location;
 use crate::parse_time;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use vrp_core::construction::constraints::{Area, TravelLimitFunc};
+use vrp_core::construction::constraints::{ActorAreas, Area, Compartment, CompartmentDimension, TravelLimitFunc};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 
 pub(crate) fn create_transport_costs(
     api_problem: &ApiProblem,
     matrices: &[Matrix],
+    use_compact_matrices: bool,
 ) -> Result<Arc<dyn TransportCost + Sync + Send>, String> {
-    if !matrices.iter().all(|m| m.profile.is_some()) && !matrices.iter().all(|m| m.profile.is_none()) {
-        return Err("all matrices should have profile set or none of them".to_string());
-    }
-
     if matrices.iter().any(|m| m.profile.is_none()) && matrices.iter().any(|m| m.timestamp.is_some()) {
         return Err("when timestamp is set, all matrices should have profile set".to_string());
     }
 
-    let fleet_profiles = get_profile_map(api_problem);
-    if fleet_profiles.len() > matrices.len() {
-        return Err(format!(
-            "not enough routing matrices specified for fleet profiles defined: \
-             {} must be less or equal to {}",
-            fleet_profiles.len(),
-            matrices.len()
-        ));
+    let fleet_profiles = get_matrix_profiles(api_problem, matrices.iter().map(|m| m.profile.as_ref()).collect())?;
+
+    // NOTE compact storage is used only on demand as it keeps values as u32 and ignores time dependent matrices
+    let compact_data = if use_compact_matrices { create_compact_matrix_data(matrices, &fleet_profiles) } else { None };
+
+    if let Some(compact_data) = compact_data {
+        check_matrix_profiles(&fleet_profiles, compact_data.iter().map(|data| data.profile))?;
+
+        return create_compact_matrix_transport_cost(compact_data);
     }
 
     let matrix_data = matrices
         .iter()
         .enumerate()
         .map(|(idx, matrix)| {
-            let profile = matrix.profile.as_ref().and_then(|p| fleet_profiles.get(p)).cloned().unwrap_or(idx as i32);
+            let profile = get_matrix_profile(&fleet_profiles, matrix.profile.as_ref(), idx);
             (profile, matrix.timestamp.clone(), matrix)
         })
         .map(|(profile, timestamp, matrix)| {
@@ -67,14 +67,126 @@ pub(crate) fn create_transport_costs(
         })
         .collect::<Vec<_>>();
 
-    let matrix_profiles = matrix_data.iter().map(|data| data.profile).collect::<HashSet<_>>().len();
-    if fleet_profiles.len() != matrix_profiles {
-        return Err("amount of fleet profiles does not match matrix profiles".to_string());
-    }
+    check_matrix_profiles(&fleet_profiles, matrix_data.iter().map(|data| data.profile))?;
 
     create_matrix_transport_cost(matrix_data)
 }
 
+/// Creates transport costs from routing matrices in binary format.
+pub(crate) fn create_binary_transport_costs(
+    api_problem: &ApiProblem,
+    matrices: &[BinaryMatrix],
+) -> Result<Arc<dyn TransportCost + Sync + Send>, String> {
+    let fleet_profiles = get_matrix_profiles(api_problem, matrices.iter().map(|m| m.profile.as_ref()).collect())?;
+
+    let matrix_data = matrices
+        .iter()
+        .enumerate()
+        .map(|(idx, matrix)| CompactMatrixData {
+            profile: get_matrix_profile(&fleet_profiles, matrix.profile.as_ref(), idx),
+            durations: matrix.durations.clone(),
+            distances: matrix.distances.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    check_matrix_profiles(&fleet_profiles, matrix_data.iter().map(|data| data.profile))?;
+
+    create_compact_matrix_transport_cost(matrix_data)
+}
+
+/// Converts time agnostic matrices into compact form when all values can be stored as u32.
+fn create_compact_matrix_data(
+    matrices: &[Matrix],
+    fleet_profiles: &HashMap<String, i32>,
+) -> Option<Vec<CompactMatrixData>> {
+    let fits_compact = |values: &[i64]| values.iter().all(|&value| value >= 0 && value < u32::MAX as i64);
+    let is_compact = matrices.iter().all(|matrix| {
+        matrix.timestamp.is_none()
+            && matrix.travel_times.len() == matrix.distances.len()
+            && fits_compact(&matrix.travel_times)
+            && fits_compact(&matrix.distances)
+    });
+
+    if !is_compact {
+        return None;
+    }
+
+    let matrix_data = matrices
+        .iter()
+        .enumerate()
+        .map(|(idx, matrix)| {
+            let to_values = |values: &[i64]| -> Arc<dyn MatrixValues> {
+                Arc::new(
+                    values
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &value)| {
+                            let is_unreachable = matrix
+                                .error_codes
+                                .as_ref()
+                                .and_then(|codes| codes.get(i))
+                                .map_or(false, |code| *code > 0);
+
+                            if is_unreachable {
+                                u32::MAX
+                            } else {
+                                value as u32
+                            }
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            };
+
+            let profile = get_matrix_profile(fleet_profiles, matrix.profile.as_ref(), idx);
+            let distances = to_values(&matrix.distances);
+
+            if matrix.travel_times == matrix.distances {
+                CompactMatrixData::new_distance_only(profile, distances)
+            } else {
+                CompactMatrixData::new(profile, to_values(&matrix.travel_times), distances)
+            }
+        })
+        .collect();
+
+    Some(matrix_data)
+}
+
+fn get_matrix_profiles(
+    api_problem: &ApiProblem,
+    matrix_profiles: Vec<Option<&String>>,
+) -> Result<HashMap<String, i32>, String> {
+    if !matrix_profiles.iter().all(|p| p.is_some()) && !matrix_profiles.iter().all(|p| p.is_none()) {
+        return Err("all matrices should have profile set or none of them".to_string());
+    }
+
+    let fleet_profiles = get_profile_map(api_problem);
+    if fleet_profiles.len() > matrix_profiles.len() {
+        return Err(format!(
+            "not enough routing matrices specified for fleet profiles defined: \
+             {} must be less or equal to {}",
+            fleet_profiles.len(),
+            matrix_profiles.len()
+        ));
+    }
+
+    Ok(fleet_profiles)
+}
+
+fn get_matrix_profile(fleet_profiles: &HashMap<String, i32>, profile: Option<&String>, idx: usize) -> i32 {
+    profile.and_then(|p| fleet_profiles.get(p)).cloned().unwrap_or(idx as i32)
+}
+
+fn check_matrix_profiles(
+    fleet_profiles: &HashMap<String, i32>,
+    matrix_profiles: impl Iterator<Item = i32>,
+) -> Result<(), String> {
+    if fleet_profiles.len() != matrix_profiles.collect::<HashSet<_>>().len() {
+        Err("amount of fleet profiles does not match matrix profiles".to_string())
+    } else {
+        Ok(())
+    }
+}
+
 pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> Fleet {
     let profiles = get_profile_map(api_problem);
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
@@ -89,15 +201,8 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         };
 
         let profile = *profiles.get(&vehicle.profile).unwrap() as Profile;
-        let mut areas = vehicle.limits.as_ref().and_then(|l| l.allowed_areas.as_ref()).map(|areas| {
-            areas
-                .iter()
-                .map(|area| Area {
-                    priority: area.priority,
-                    outer_shape: area.outer_shape.iter().map(|l| l.to_lat_lng()).collect::<Vec<_>>(),
-                })
-                .collect::<Vec<_>>()
-        });
+        let areas = vehicle.limits.as_ref().and_then(read_areas);
+        let tour_size = vehicle.limits.as_ref().and_then(read_tour_size);
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             let start = {
@@ -130,16 +235,23 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                 dimens.set_value("shift_index", shift_index);
                 dimens.set_id(vehicle_id);
 
-                if let Some(areas) = areas.take() {
-                    dimens.set_value("areas", areas);
+                if let Some(areas) = areas.as_ref() {
+                    dimens.set_value("areas", areas.clone());
+                }
+
+                if let Some(tour_size) = tour_size.as_ref() {
+                    dimens.set_value("tour_size", tour_size.clone());
                 }
 
+                let capacity = to_fixed_load(vehicle.capacity.as_slice(), props.load_scale);
                 if props.has_multi_dimen_capacity {
-                    dimens.set_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
+                    dimens.set_capacity(MultiDimLoad::new(capacity));
                 } else {
-                    dimens.set_capacity(SingleDimLoad::new(*vehicle.capacity.first().unwrap()));
+                    dimens.set_capacity(SingleDimLoad::new(*capacity.first().unwrap()));
                 }
                 add_skills(&mut dimens, &vehicle.skills);
+                add_compartments(&mut dimens, &vehicle.compartments, props);
+                add_battery(&mut dimens, &vehicle.battery);
 
                 vehicles.push(Arc::new(Vehicle { profile, costs: costs.clone(), dimens, details: details.clone() }));
             });
@@ -184,7 +296,47 @@ pub fn read_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
     }
 }
 
-fn get_profile_map(api_problem: &ApiProblem) -> HashMap<String, i32> {
+fn read_tour_size(limits: &VehicleLimits) -> Option<TourSize> {
+    if limits.max_jobs.is_some() || limits.max_stops.is_some() {
+        Some(TourSize { max_jobs: limits.max_jobs, max_stops: limits.max_stops })
+    } else {
+        None
+    }
+}
+
+pub(crate) fn read_areas(limits: &VehicleLimits) -> Option<ActorAreas> {
+    let create_area = |priority: Option<usize>, outer_shape: &[Location], inner_shapes: &Option<Vec<Vec<Location>>>| {
+        let to_shape = |shape: &[Location]| shape.iter().map(|l| l.to_lat_lng()).collect::<Vec<_>>();
+
+        Area {
+            priority,
+            outer_shape: to_shape(outer_shape),
+            inner_shapes: inner_shapes
+                .as_ref()
+                .map_or_else(Vec::new, |shapes| shapes.iter().map(|shape| to_shape(shape)).collect()),
+        }
+    };
+
+    let areas = ActorAreas {
+        allowed: limits.allowed_areas.as_ref().map_or_else(Vec::new, |areas| {
+            areas.iter().map(|area| create_area(area.priority, &area.outer_shape, &area.inner_shapes)).collect()
+        }),
+        forbidden: limits.forbidden_areas.as_ref().map_or_else(Vec::new, |areas| {
+            areas.iter().map(|area| create_area(None, &area.outer_shape, &area.inner_shapes)).collect()
+        }),
+        costs: limits.area_costs.as_ref().map_or_else(Vec::new, |areas| {
+            areas.iter().map(|area| (create_area(None, &area.outer_shape, &area.inner_shapes), area.cost)).collect()
+        }),
+    };
+
+    if areas.allowed.is_empty() && areas.forbidden.is_empty() && areas.costs.is_empty() {
+        None
+    } else {
+        Some(areas)
+    }
+}
+
+pub(crate) fn get_profile_map(api_problem: &ApiProblem) -> HashMap<String, i32> {
     api_problem.fleet.profiles.iter().fold(Default::default(), |mut acc, profile| {
         if acc.get(&profile.name) == None {
             acc.insert(profile.name.clone(), acc.len() as i32);
@@ -192,3 +344,51 @@ fn get_profile_map(api_problem: &ApiProblem) -> HashMap<String, i32> {
         acc
     })
 }
+
+fn add_compartments(
+    dimens: &mut Dimensions,
+    compartments: &Option<Vec<VehicleCompartment>>,
+    props: &ProblemProperties,
+) {
+    let compartments = match compartments {
+        Some(compartments) if !compartments.is_empty() => compartments,
+        _ => return,
+    };
+
+    let get_goods = |compartment: &VehicleCompartment| compartment.goods.iter().cloned().collect();
+    let get_capacity =
+        |compartment: &VehicleCompartment| to_fixed_load(compartment.capacity.as_slice(), props.load_scale);
+
+    if props.has_multi_dimen_capacity {
+        dimens.set_compartments(
+            compartments
+                .iter()
+                .map(|c| Compartment { capacity: MultiDimLoad::new(get_capacity(c)), goods: get_goods(c) })
+                .collect(),
+        );
+    } else {
+        dimens.set_compartments(
+            compartments
+                .iter()
+                .map(|c| Compartment {
+                    capacity: SingleDimLoad::new(get_capacity(c).first().cloned().unwrap_or(0)),
+                    goods: get_goods(c),
+                })
+                .collect(),
+        );
+    }
+}
+
+fn add_battery(dimens: &mut Dimensions, battery: &Option<VehicleBattery>) {
+    if let Some(battery) = battery {
+        dimens.set_value(
+            "battery",
+            Battery {
+                capacity: battery.capacity,
+                initial: battery.initial.unwrap_or(battery.capacity),
+                consumption: battery.consumption,
+                load_consumption: battery.load_consumption.unwrap_or(0.),
+            },
+        );
+    }
+}
@@ -1,15 +1,26 @@
+use crate::constraints::{TourOrder, VehicleTimes};
 use crate::format::coord_index::CoordIndex;
-use crate::format::problem::reader::{add_skills, parse_time_window, ApiProblem, ProblemProperties};
-use crate::format::problem::{JobTask, RelationType, VehicleBreak, VehicleBreakTime, VehicleCargoPlace, VehicleType};
-use crate::format::{JobIndex, Location};
+use crate::format::problem::reader::{
+    add_skills, from_fixed_load, get_profile_map, parse_time_window, to_fixed_load, ApiProblem, ProblemProperties,
+    ReaderOptions,
+};
+use crate::format::problem::{
+    JobOrder, JobOrderPosition, JobPlace, JobTask, RelationType, VehicleBreak, VehicleBreakTime, VehicleCargoPlace,
+    VehicleChargingStation, VehicleType,
+};
+use crate::format::{get_split_job_id, JobIndex, Location};
 use crate::utils::VariableJobPermutation;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
 use std::sync::Arc;
+use vrp_core::construction::constraints::GoodsDimension;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, Single, TransportCost};
 use vrp_core::models::{Lock, LockDetail, LockOrder, LockPosition};
 use vrp_core::utils::{CollectGroupBy, Random};
 
+type ApiJob = crate::format::problem::Job;
+
 // TODO configure sample size
 const MULTI_JOB_SAMPLE_SIZE: usize = 3;
 
@@ -21,6 +32,7 @@ pub(crate) fn read_jobs_with_extra_locks(
     transport: &Arc<dyn TransportCost + Sync + Send>,
     job_index: &mut JobIndex,
     random: &Arc<dyn Random + Send + Sync>,
+    options: &ReaderOptions,
 ) -> (Jobs, Vec<Arc<Lock>>) {
     let (mut jobs, mut locks) = read_required_jobs(api_problem, props, coord_index, job_index, random);
     let (conditional_jobs, conditional_locks) = read_conditional_jobs(api_problem, coord_index, job_index);
@@ -28,7 +40,13 @@ pub(crate) fn read_jobs_with_extra_locks(
     jobs.extend(conditional_jobs);
     locks.extend(conditional_locks);
 
-    (Jobs::new(fleet, jobs, transport), locks)
+    // NOTE full job index has quadratic memory footprint, so keep only nearest neighbours for big problems
+    let max_neighbours = options
+        .full_job_index_threshold
+        .filter(|&threshold| jobs.len() > threshold)
+        .map(|_| options.max_job_neighbours);
+
+    (Jobs::new_with_neighbours(fleet, jobs, transport, max_neighbours), locks)
 }
 
 pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Lock>> {
@@ -65,7 +83,7 @@ pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Loc
                 .filter(|job| job.as_str() != "departure" && job.as_str() != "arrival")
                 .fold((HashMap::<String, _>::default(), vec![]), |(mut indexer, mut jobs), job| {
                     let job_id = match job.as_str() {
-                        "break" | "depot" | "reload" => {
+                        "break" | "depot" | "reload" | "charging" => {
                             let entry = indexer.entry(job.clone()).or_insert(1_usize);
                             let job_index = *entry;
                             *entry += 1;
@@ -103,10 +121,14 @@ fn read_required_jobs(
 ) -> (Vec<Job>, Vec<Arc<Lock>>) {
     let mut jobs = vec![];
     let has_multi_dimens = props.has_multi_dimen_capacity;
+    let profiles = get_profile_map(api_problem);
 
     let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool| {
         let absent = (empty(), empty());
-        let capacity = task.demand.clone().map_or_else(empty, MultiDimLoad::new);
+        let capacity = task
+            .demand
+            .as_ref()
+            .map_or_else(empty, |demand| MultiDimLoad::new(to_fixed_load(demand.as_slice(), props.load_scale)));
         let demand = if is_static_demand { (capacity, empty()) } else { (empty(), capacity) };
 
         let demand = match activity_type {
@@ -120,7 +142,11 @@ fn read_required_jobs(
         let places =
             task.places.iter().map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times))).collect();
 
-        get_single_with_extras(places, demand, &task.tag, activity_type, has_multi_dimens, &coord_index)
+        let mut single =
+            get_single_with_extras(places, demand, &task.tag, activity_type, has_multi_dimens, &coord_index);
+        add_vehicle_times(&mut single.dimens, &task.places, &profiles, coord_index);
+
+        single
     };
 
     api_problem.plan.jobs.iter().for_each(|job| {
@@ -128,7 +154,27 @@ fn read_required_jobs(
         let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
         let is_static_demand = pickups == 0 || deliveries == 0;
 
-        let singles =
+        if let Some((task, activity_type, parts)) = get_split_task(api_problem, job) {
+            let demand = to_fixed_load(task.demand.as_deref().unwrap_or_default(), props.load_scale);
+            (0..parts).for_each(|part| {
+                let demand =
+                    demand.iter().map(|&d| d / parts + if part < d % parts { 1 } else { 0 }).collect::<Vec<_>>();
+                let task =
+                    JobTask { demand: Some(from_fixed_load(demand.as_slice(), props.load_scale)), ..task.clone() };
+
+                let mut single = get_single_from_task(&task, activity_type, true);
+                single.dimens.set_value("split", true);
+                add_goods(&mut single.dimens, &job.goods);
+
+                let problem_job = get_single_job(job, single);
+                job_index.insert(get_split_job_id(job.id.as_str(), part as usize + 1), problem_job.clone());
+                jobs.push(problem_job);
+            });
+
+            return;
+        }
+
+        let mut singles =
             job.pickups
                 .iter()
                 .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "pickup", is_static_demand)))
@@ -148,18 +194,12 @@ fn read_required_jobs(
                 .collect::<Vec<_>>();
 
         assert!(!singles.is_empty());
+        singles.iter_mut().for_each(|single| add_goods(&mut single.dimens, &job.goods));
 
         let problem_job = if singles.len() > 1 {
-            get_multi_job(
-                &job.id,
-                job.priority,
-                &job.skills,
-                singles,
-                job.pickups.as_ref().map_or(0, |p| p.len()),
-                random,
-            )
+            get_multi_job(job, singles, job.pickups.as_ref().map_or(0, |p| p.len()), random)
         } else {
-            get_single_job(&job.id, singles.into_iter().next().unwrap(), job.priority, &job.skills)
+            get_single_job(job, singles.into_iter().next().unwrap())
         };
 
         job_index.insert(job.id.clone(), problem_job.clone());
@@ -169,6 +209,50 @@ fn read_required_jobs(
     (jobs, vec![])
 }
 
+/// Returns job's task with its activity type and amount of parts if the job has to be split.
+pub(crate) fn get_split_task<'a>(
+    api_problem: &ApiProblem,
+    job: &'a ApiJob,
+) -> Option<(&'a JobTask, &'static str, i32)> {
+    if !job.split.unwrap_or(false) || job.replacements.is_some() || job.services.is_some() {
+        return None;
+    }
+
+    let (task, activity_type) = match (job.pickups.as_deref(), job.deliveries.as_deref()) {
+        (Some([task]), None) => (task, "pickup"),
+        (None, Some([task])) => (task, "delivery"),
+        _ => return None,
+    };
+
+    let demand = task.demand.as_ref()?;
+
+    // NOTE parts are sized by the smallest capacity of compatible vehicle types, so that each part
+    // can be served by any of them
+    let parts = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| {
+            demand
+                .iter()
+                .enumerate()
+                .filter(|(_, &d)| d > 0.)
+                .map(|(idx, &d)| match vehicle.capacity.get(idx).cloned().unwrap_or(0.) {
+                    // NOTE tolerance is needed to avoid an extra part due to decimal representation errors
+                    capacity if capacity > 0. => Some((d / capacity - 1E-6).ceil().max(1.) as i32),
+                    _ => None,
+                })
+                .try_fold(1, |acc, parts| parts.map(|parts| acc.max(parts)))
+        })
+        .max()?;
+
+    if parts > 1 {
+        Some((task, activity_type, parts))
+    } else {
+        None
+    }
+}
+
 fn read_conditional_jobs(
     api_problem: &ApiProblem,
     coord_index: &CoordIndex,
@@ -189,6 +273,10 @@ fn read_conditional_jobs(
             if let Some(reloads) = &shift.reloads {
                 read_reloads(coord_index, job_index, &mut jobs, vehicle, shift_index, reloads);
             }
+
+            if let Some(stations) = vehicle.battery.as_ref().and_then(|battery| battery.stations.as_ref()) {
+                read_charging_stations(coord_index, job_index, &mut jobs, vehicle, shift_index, stations);
+            }
         }
     });
 
@@ -311,6 +399,35 @@ fn read_reloads(
     });
 }
 
+fn read_charging_stations(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    stations: &[VehicleChargingStation],
+) {
+    (1..).zip(stations.iter()).for_each(|(place_idx, station)| {
+        vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
+            let job_id = format!("{}_charging_{}_{}", vehicle_id, shift_index, place_idx);
+            let times = parse_times(&station.times);
+
+            let mut single = get_conditional_job(
+                coord_index,
+                vehicle_id.clone(),
+                &job_id,
+                "charging",
+                shift_index,
+                vec![(Some(station.location.clone()), station.duration, times)],
+                &station.tag,
+            );
+            single.dimens.set_value("charging_rate", station.rate);
+
+            add_conditional_job(job_index, jobs, job_id, single);
+        });
+    });
+}
+
 fn get_cargo_jobs(
     job_type: &str,
     coord_index: &CoordIndex,
@@ -409,28 +526,21 @@ fn get_single_with_extras(
     single
 }
 
-fn get_single_job(id: &str, single: Single, priority: Option<i32>, skills: &Option<Vec<String>>) -> Job {
+fn get_single_job(job: &ApiJob, single: Single) -> Job {
     let mut single = single;
-    single.dimens.set_id(id);
-
-    add_priority(&mut single.dimens, priority);
-    add_skills(&mut single.dimens, skills);
+    add_job_dimens(&mut single.dimens, job);
 
     Job::Single(Arc::new(single))
 }
 
 fn get_multi_job(
-    id: &str,
-    priority: Option<i32>,
-    skills: &Option<Vec<String>>,
+    job: &ApiJob,
     singles: Vec<Single>,
     deliveries_start_index: usize,
     random: &Arc<dyn Random + Send + Sync>,
 ) -> Job {
     let mut dimens: Dimensions = Default::default();
-    dimens.set_id(id);
-    add_priority(&mut dimens, priority);
-    add_skills(&mut dimens, skills);
+    add_job_dimens(&mut dimens, job);
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
@@ -472,6 +582,73 @@ fn add_priority(dimens: &mut Dimensions, priority: Option<i32>) {
     }
 }
 
+fn add_vehicle_times(
+    dimens: &mut Dimensions,
+    places: &[JobPlace],
+    profiles: &HashMap<String, i32>,
+    coord_index: &CoordIndex,
+) {
+    let vehicle_times = places
+        .iter()
+        .filter_map(|place| place.vehicle_times.as_ref().map(|times| (place, times)))
+        .map(|(place, times)| {
+            let times = times
+                .iter()
+                .map(|times| VehicleTimes {
+                    type_ids: HashSet::from_iter(times.type_ids.iter().flat_map(|ids| ids.iter().cloned())),
+                    profiles: HashSet::from_iter(
+                        times
+                            .profiles
+                            .iter()
+                            .flat_map(|names| names.iter().filter_map(|name| profiles.get(name)))
+                            .cloned(),
+                    ),
+                    times: times.times.iter().map(|tw| parse_time_window(tw)).collect(),
+                })
+                .collect::<Vec<_>>();
+
+            (coord_index.get_by_loc(&place.location).unwrap(), times)
+        })
+        .collect::<Vec<_>>();
+
+    if !vehicle_times.is_empty() {
+        dimens.set_value("vehicle_times", vehicle_times);
+    }
+}
+
+fn add_value(dimens: &mut Dimensions, value: Option<f64>) {
+    if let Some(value) = value {
+        dimens.set_value("value", value);
+    }
+}
+
+fn add_order(dimens: &mut Dimensions, order: &Option<JobOrder>) {
+    if let Some(order) = order {
+        dimens.set_value(
+            "order",
+            match order {
+                JobOrder::Position(JobOrderPosition::First) => TourOrder::First,
+                JobOrder::Position(JobOrderPosition::Last) => TourOrder::Last,
+                JobOrder::Rank(rank) => TourOrder::Rank(*rank),
+            },
+        );
+    }
+}
+
+fn add_job_dimens(dimens: &mut Dimensions, job: &ApiJob) {
+    dimens.set_id(job.id.as_str());
+    add_priority(dimens, job.priority);
+    add_value(dimens, job.value);
+    add_skills(dimens, &job.skills);
+    add_order(dimens, &job.order);
+}
+
+fn add_goods(dimens: &mut Dimensions, goods: &Option<String>) {
+    if let Some(goods) = goods {
+        dimens.set_goods(goods);
+    }
+}
+
 fn empty() -> MultiDimLoad {
     MultiDimLoad::default()
 }
@@ -1,8 +1,18 @@
-//! Specifies logic to read problem and routing matrix from json input.
+//! Specifies logic to read problem and routing matrix from json or binary input.
 //!
 
 mod model;
 pub use self::model::*;
 
+mod binary_matrix;
+pub use self::binary_matrix::*;
+
+mod progress;
+pub use self::progress::*;
+
 mod reader;
-pub use self::reader::PragmaticProblem;
+pub(crate) use self::reader::{
+    apply_named_dimensions, from_fixed_load, get_load_scale, get_positional_load, get_split_task, is_valid_fixed_load,
+    read_areas, to_fixed_load,
+};
+pub use self::reader::{PragmaticProblem, ReaderOptions};
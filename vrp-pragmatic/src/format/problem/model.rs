@@ -6,6 +6,7 @@ extern crate serde_json;
 
 use crate::format::{FormatError, Location};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::io::{BufReader, BufWriter, Error, Read, Write};
 
 // region Plan
@@ -40,6 +41,7 @@ pub struct Relation {
 
 /// Specifies a place for sub job.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct JobPlace {
     /// A job place location.
     pub location: Location,
@@ -48,6 +50,24 @@ pub struct JobPlace {
     /// A list of job place time windows with time specified in RFC3339 format.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub times: Option<Vec<Vec<String>>>,
+    /// A list of time windows which are applied only to specific vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_times: Option<Vec<JobVehicleTimes>>,
+}
+
+/// Specifies job place time windows which restrict access for specific vehicle types or profiles.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobVehicleTimes {
+    /// A list of vehicle type ids.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_ids: Option<Vec<String>>,
+    /// A list of vehicle profile names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profiles: Option<Vec<String>>,
+    /// A list of time windows with time specified in RFC3339 format. Matching vehicle has to
+    /// start serving the job place within one of them.
+    pub times: Vec<Vec<String>>,
 }
 
 /// Specifies a job task.
@@ -57,10 +77,13 @@ pub struct JobTask {
     pub places: Vec<JobPlace>,
     /// Job place demand.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub demand: Option<Vec<i32>>,
+    pub demand: Option<Vec<f64>>,
     /// An tag which will be propagated back within corresponding activity in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    /// Job place demand specified by dimension names declared in `fleet.dimensions`.
+    #[serde(rename = "namedDemand", skip_serializing_if = "Option::is_none")]
+    pub named_demand: Option<HashMap<String, f64>>,
 }
 
 /// A customer job model. Actual tasks of the job specified by list of pickups and deliveries
@@ -92,9 +115,46 @@ pub struct Job {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<i32>,
 
+    /// Job value, bigger value - more profitable to serve.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+
     /// A set of skills required to serve a job.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skills: Option<Vec<String>>,
+
+    /// Job order within a tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<JobOrder>,
+
+    /// Allows to serve job by several visits when its demand exceeds vehicle capacity.
+    /// Only jobs with single pickup or delivery task can be split.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split: Option<bool>,
+
+    /// A type of goods. Such job can be loaded only into compatible vehicle compartments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub goods: Option<String>,
+}
+
+/// Specifies job order within a tour.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(untagged)]
+pub enum JobOrder {
+    /// A fixed position of the job in a tour.
+    Position(JobOrderPosition),
+    /// A job rank: jobs with lower rank are preferred to be visited earlier. Violation of rank is penalized.
+    Rank(i32),
+}
+
+/// Specifies a fixed position of the job in a tour.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobOrderPosition {
+    /// A job has to be served before any other job in a tour.
+    First,
+    /// A job has to be served after any other job in a tour.
+    Last,
 }
 
 /// A plan specifies work which has to be done.
@@ -216,6 +276,25 @@ pub struct VehicleLimits {
     /// No area restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_areas: Option<Vec<AreaLimit>>,
+
+    /// Specifies a list of areas where vehicle cannot serve jobs.
+    /// Area priority is ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forbidden_areas: Option<Vec<AreaLimit>>,
+
+    /// Specifies a list of areas where vehicle has an extra cost for each served job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area_costs: Option<Vec<AreaCost>>,
+
+    /// Max amount of jobs served per shift/tour.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_jobs: Option<usize>,
+
+    /// Max amount of stops with jobs per shift/tour.
+    /// No restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_stops: Option<usize>,
 }
 
 /// Specifies area limit.
@@ -227,6 +306,22 @@ pub struct AreaLimit {
     pub priority: Option<usize>,
     /// An area outer shape.
     pub outer_shape: Vec<Location>,
+    /// An area inner shapes (holes).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inner_shapes: Option<Vec<Vec<Location>>>,
+}
+
+/// Specifies an area with extra cost.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AreaCost {
+    /// An extra cost applied for each job served inside the area.
+    pub cost: f64,
+    /// An area outer shape.
+    pub outer_shape: Vec<Location>,
+    /// An area inner shapes (holes).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inner_shapes: Option<Vec<Vec<Location>>>,
 }
 
 /// Vehicle break time variant.
@@ -273,7 +368,8 @@ pub struct VehicleType {
     pub shifts: Vec<VehicleShift>,
 
     /// Vehicle capacity.
-    pub capacity: Vec<i32>,
+    #[serde(default)]
+    pub capacity: Vec<f64>,
 
     /// Vehicle skills.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -282,6 +378,72 @@ pub struct VehicleType {
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Vehicle compartments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compartments: Option<Vec<VehicleCompartment>>,
+
+    /// Vehicle capacity specified by dimension names declared in `fleet.dimensions`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub named_capacity: Option<HashMap<String, f64>>,
+
+    /// Vehicle battery which limits vehicle range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub battery: Option<VehicleBattery>,
+}
+
+/// Specifies electric vehicle battery.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleBattery {
+    /// Battery capacity.
+    pub capacity: f64,
+
+    /// Initial state of charge. Full battery is assumed when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial: Option<f64>,
+
+    /// Energy consumed per distance unit by empty vehicle.
+    pub consumption: f64,
+
+    /// Extra energy consumed per distance unit for each unit of load in the first capacity dimension.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_consumption: Option<f64>,
+
+    /// Charging stations which can be visited by vehicle in each shift.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stations: Option<Vec<VehicleChargingStation>>,
+}
+
+/// Specifies a place where vehicle can charge its battery.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleChargingStation {
+    /// A station location.
+    pub location: Location,
+
+    /// A charging duration.
+    pub duration: f64,
+
+    /// An amount of energy charged per time unit.
+    pub rate: f64,
+
+    /// A list of time windows with time specified in RFC3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<Vec<Vec<String>>>,
+
+    /// An tag which will be propagated back within corresponding activity in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+/// Specifies vehicle compartment which can hold goods of one type at a time.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleCompartment {
+    /// Compartment capacity.
+    pub capacity: Vec<f64>,
+
+    /// Types of goods which can be loaded into the compartment.
+    pub goods: Vec<String>,
 }
 
 /// Specifies routing profile.
@@ -307,6 +469,9 @@ pub struct Fleet {
     pub vehicles: Vec<VehicleType>,
     /// Routing profiles.
     pub profiles: Vec<Profile>,
+    /// Names of capacity dimensions. When specified, demand and capacity can be defined by name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<Vec<String>>,
 }
 
 // endregion
@@ -353,6 +518,14 @@ pub enum Objective {
     #[serde(rename(deserialize = "minimize-unassigned", serialize = "minimize-unassigned"))]
     MinimizeUnassignedJobs,
 
+    /// An objective to maximize total value of served jobs.
+    #[serde(rename(deserialize = "maximize-value", serialize = "maximize-value"))]
+    MaximizeValue,
+
+    /// An objective to minimize job rank violations.
+    #[serde(rename(deserialize = "minimize-rank-violations", serialize = "minimize-rank-violations"))]
+    MinimizeRankViolations,
+
     /// An objective to balance max load across all tours.
     #[serde(rename(deserialize = "balance-max-load", serialize = "balance-max-load"))]
     BalanceMaxLoad {
@@ -387,6 +560,27 @@ pub enum Objective {
         #[serde(skip_serializing_if = "Option::is_none")]
         options: Option<BalanceOptions>,
     },
+
+    /// An objective to minimize deviation from a reference solution.
+    #[serde(rename(deserialize = "minimize-deviation", serialize = "minimize-deviation"))]
+    MinimizeDeviation {
+        /// An options which can be used to specify weights of different deviation types.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        options: Option<StabilityOptions>,
+    },
+}
+
+/// Specifies plan stability objective options.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StabilityOptions {
+    /// A weight of job assignment to a different vehicle shift. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reassignment_weight: Option<f64>,
+
+    /// A weight of job order change within the same vehicle shift. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reorder_weight: Option<f64>,
 }
 
 /// Specifies balance objective options. At the moment, it uses coefficient of variation as
@@ -1,19 +1,25 @@
+use crate::constraints::TourOrder;
 use crate::format::problem::reader::{ApiProblem, ProblemProperties};
 use crate::format::problem::BalanceOptions;
 use crate::format::problem::Objective::*;
+use crate::format::VALUE_CONSTRAINT_CODE;
 use std::sync::Arc;
 use vrp_core::construction::constraints::{ConstraintPipeline, FleetUsageConstraintModule};
-use vrp_core::models::common::{MultiDimLoad, SingleDimLoad};
-use vrp_core::models::problem::{ObjectiveCost, TargetConstraint, TargetObjective};
+use vrp_core::models::common::{MultiDimLoad, SingleDimLoad, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, ObjectiveCost, TargetConstraint, TargetObjective, TransportCost};
 use vrp_core::solver::objectives::*;
 
 pub fn create_objective(
     api_problem: &ApiProblem,
     constraint: &mut ConstraintPipeline,
     props: &ProblemProperties,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    stability_reference: Arc<StabilityReference>,
 ) -> Arc<ObjectiveCost> {
     Arc::new(if let Some(objectives) = &api_problem.objectives {
-        let mut map_objectives = |objectives: &Vec<_>| {
+        let is_cost_primary = objectives.primary.iter().any(|objective| matches!(objective, MinimizeCost));
+        let mut map_objectives = |objectives: &Vec<_>, is_primary: bool| {
             let mut core_objectives: Vec<TargetObjective> = vec![];
             objectives.iter().for_each(|objective| match objective {
                 MinimizeCost => core_objectives.push(Box::new(TotalTransportCost::default())),
@@ -26,6 +32,28 @@ pub fn create_objective(
                     core_objectives.push(Box::new(TotalRoutes::new_maximized()))
                 }
                 MinimizeUnassignedJobs => core_objectives.push(Box::new(TotalUnassignedJobs::default())),
+                MaximizeValue => {
+                    let (module, objective) = TotalValue::new_maximized(
+                        Arc::new(|job| {
+                            match job {
+                                Job::Single(job) => job.dimens.get_value::<f64>("value"),
+                                Job::Multi(job) => job.dimens.get_value::<f64>("value"),
+                            }
+                            .cloned()
+                            .unwrap_or(0.)
+                        }),
+                        activity.clone(),
+                        transport.clone(),
+                        VALUE_CONSTRAINT_CODE,
+                    );
+                    constraint.add_module(module);
+                    core_objectives.push(objective);
+                }
+                MinimizeRankViolations => {
+                    let (module, objective) = get_tour_rank();
+                    constraint.add_module(module);
+                    core_objectives.push(objective);
+                }
                 BalanceMaxLoad { options } => {
                     let (module, objective) = get_load_balance(props, options);
                     constraint.add_module(module);
@@ -49,20 +77,57 @@ pub fn create_objective(
                     constraint.add_module(module);
                     core_objectives.push(objective);
                 }
+                MinimizeDeviation { options } => {
+                    let (reassignment_weight, reorder_weight) =
+                        options.as_ref().map_or((None, None), |o| (o.reassignment_weight, o.reorder_weight));
+                    let (module, objective) = PlanStability::new_minimized(
+                        stability_reference.clone(),
+                        reassignment_weight.unwrap_or(1.),
+                        reorder_weight.unwrap_or(1.),
+                        is_primary && !is_cost_primary,
+                    );
+                    constraint.add_module(module);
+                    core_objectives.push(objective);
+                }
             });
             core_objectives
         };
 
-        let primary_objectives = map_objectives(&objectives.primary);
-        let secondary_objectives = map_objectives(&objectives.secondary.clone().unwrap_or_else(Vec::new));
+        let primary_objectives = map_objectives(&objectives.primary, true);
+        let secondary_objectives = map_objectives(&objectives.secondary.clone().unwrap_or_else(Vec::new), false);
 
         ObjectiveCost::new(primary_objectives, secondary_objectives)
     } else {
         constraint.add_module(Box::new(FleetUsageConstraintModule::new_minimized()));
-        ObjectiveCost::default()
+
+        if props.has_tour_ranks {
+            let (module, objective) = get_tour_rank();
+            constraint.add_module(module);
+
+            let primary_objectives: Vec<TargetObjective> =
+                vec![Box::new(TotalUnassignedJobs::default()), Box::new(TotalRoutes::default()), objective];
+
+            ObjectiveCost::new(primary_objectives, vec![Box::new(TotalTransportCost::default())])
+        } else {
+            ObjectiveCost::default()
+        }
     })
 }
 
+/// Returns objective which penalizes job rank violations as they are not enforced by hard constraints.
+fn get_tour_rank() -> (TargetConstraint, TargetObjective) {
+    TourRank::new_minimized(Arc::new(|job| {
+        match job {
+            Job::Single(job) => job.dimens.get_value::<TourOrder>("order"),
+            Job::Multi(job) => job.dimens.get_value::<TourOrder>("order"),
+        }
+        .and_then(|order| match order {
+            TourOrder::Rank(rank) => Some(*rank),
+            _ => None,
+        })
+    }))
+}
+
 fn unwrap_options(options: &Option<BalanceOptions>) -> (Option<f64>, Option<f64>) {
     (options.as_ref().and_then(|o| o.threshold), options.as_ref().and_then(|o| o.tolerance))
 }
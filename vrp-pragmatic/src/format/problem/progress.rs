@@ -0,0 +1,352 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/progress_test.rs"]
+mod progress_test;
+
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::{format_time, parse_time_safe};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::io::{BufReader, Read};
+
+/// Specifies state of plan execution which is used to re-plan its remaining part.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanProgress {
+    /// A list of vehicles which are already en route.
+    pub vehicles: Vec<VehicleProgress>,
+}
+
+/// Specifies state of the vehicle which is already en route.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleProgress {
+    /// Vehicle id.
+    pub vehicle_id: String,
+
+    /// Index of the shift which is being executed.
+    #[serde(default)]
+    pub shift_index: usize,
+
+    /// Current vehicle location.
+    pub location: Location,
+
+    /// Current time in RFC3339 format.
+    pub time: String,
+
+    /// Ids of jobs with completed activities. Job id should be repeated for each completed activity
+    /// of multi job, e.g. pickup and delivery. Reserved `break` and `depot` ids mark visited
+    /// vehicle break and depot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed: Option<Vec<String>>,
+
+    /// Ids of jobs which vehicle is committed to serve next in given order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committed: Option<Vec<String>>,
+
+    /// Ids of jobs which should be served by the vehicle in any order, e.g. goods are already on board.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned: Option<Vec<String>>,
+}
+
+/// Deserializes plan progress from json format.
+pub fn deserialize_progress<R: Read>(reader: BufReader<R>) -> Result<PlanProgress, String> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize plan progress: '{}'", err))
+}
+
+/// Creates a problem which represents remaining part of the plan:
+/// * completed activities are removed, partially completed jobs keep only not served deliveries
+/// * completed pickup only jobs are moved to current vehicle location as their goods are still on board
+/// * vehicle shift starts at current vehicle location and time
+/// * committed jobs are locked at the beginning of the tour using strict relation
+/// * assigned and partially completed jobs are locked to the vehicle
+///
+/// Newly arrived jobs are expected to be added to the plan of the problem.
+pub fn apply_progress(problem: Problem, progress: &PlanProgress) -> Result<Problem, String> {
+    let mut problem = problem;
+
+    check_vehicles(&problem, progress)?;
+
+    let job_ids = problem.plan.jobs.iter().map(|job| job.id.clone()).collect::<HashSet<_>>();
+    let check_job_ids = |ids: &Option<Vec<String>>| {
+        ids.iter().flat_map(|ids| ids.iter()).filter(|id| !is_reserved_id(id)).try_for_each(|id| {
+            if job_ids.contains(id) {
+                Ok(())
+            } else {
+                Err(format!("unknown job id in plan progress: '{}'", id))
+            }
+        })
+    };
+
+    progress.vehicles.iter().try_for_each(|vehicle| {
+        check_job_ids(&vehicle.completed)
+            .and_then(|_| check_job_ids(&vehicle.committed))
+            .and_then(|_| check_job_ids(&vehicle.assigned))
+    })?;
+
+    let mut relations = problem.plan.relations.take().unwrap_or_default();
+    let mut jobs = problem.plan.jobs;
+
+    for vehicle in progress.vehicles.iter() {
+        let completed = get_completed_counts(vehicle);
+        let mut loaded = Vec::new();
+        let mut on_board = Vec::new();
+
+        jobs = jobs.into_iter().try_fold(Vec::new(), |mut acc, job| {
+            match completed.get(job.id.as_str()).cloned() {
+                Some(count) if is_completed_pickup_job(&job, count) => {
+                    let job = keep_goods_on_board(job, &vehicle.location);
+                    on_board.extend(job.pickups.iter().flat_map(|tasks| tasks.iter()).map(|_| job.id.clone()));
+                    acc.push(job);
+                }
+                Some(count) => {
+                    if let Some(job) = remove_completed_tasks(job, count)? {
+                        loaded.push(job.id.clone());
+                        acc.push(job);
+                    }
+                }
+                None => acc.push(job),
+            }
+
+            Ok::<_, String>(acc)
+        })?;
+
+        relations = remove_completed_relations(relations, &completed);
+        relations.extend(create_relations(vehicle, loaded, on_board));
+
+        update_fleet(&mut problem.fleet, vehicle)?;
+    }
+
+    problem.plan.jobs = jobs;
+    problem.plan.relations = if relations.is_empty() { None } else { Some(relations) };
+
+    Ok(problem)
+}
+
+fn check_vehicles(problem: &Problem, progress: &PlanProgress) -> Result<(), String> {
+    let mut vehicle_ids = HashSet::new();
+
+    progress.vehicles.iter().try_for_each(|vehicle| {
+        if !vehicle_ids.insert(vehicle.vehicle_id.as_str()) {
+            return Err(format!("duplicate vehicle id in plan progress: '{}'", vehicle.vehicle_id));
+        }
+
+        parse_time_safe(vehicle.time.as_str())
+            .map_err(|err| format!("cannot parse time of vehicle '{}': '{}'", vehicle.vehicle_id, err))?;
+
+        problem
+            .fleet
+            .vehicles
+            .iter()
+            .find(|vehicle_type| vehicle_type.vehicle_ids.contains(&vehicle.vehicle_id))
+            .filter(|vehicle_type| vehicle.shift_index < vehicle_type.shifts.len())
+            .map(|_| ())
+            .ok_or_else(|| {
+                format!("cannot find vehicle '{}' with shift index {}", vehicle.vehicle_id, vehicle.shift_index)
+            })
+    })
+}
+
+fn get_completed_counts(vehicle: &VehicleProgress) -> HashMap<&str, usize> {
+    vehicle.completed.iter().flat_map(|ids| ids.iter()).filter(|id| !is_reserved_id(id)).fold(
+        HashMap::new(),
+        |mut acc, id| {
+            *acc.entry(id.as_str()).or_insert(0) += 1;
+            acc
+        },
+    )
+}
+
+fn get_size(tasks: &Option<Vec<JobTask>>) -> usize {
+    tasks.as_ref().map_or(0, |tasks| tasks.len())
+}
+
+/// Checks whether all tasks of the job are completed pickups.
+fn is_completed_pickup_job(job: &Job, count: usize) -> bool {
+    let pickups = get_size(&job.pickups);
+    let others = get_size(&job.deliveries) + get_size(&job.replacements) + get_size(&job.services);
+
+    pickups > 0 && others == 0 && count >= pickups
+}
+
+/// Moves pickups of the completed job to given location, so its goods keep occupying vehicle
+/// capacity till the end of the tour.
+fn keep_goods_on_board(job: Job, location: &Location) -> Job {
+    let pickups = job.pickups.map(|tasks| {
+        tasks
+            .into_iter()
+            .map(|task| JobTask {
+                places: vec![JobPlace { location: location.clone(), duration: 0., times: None, vehicle_times: None }],
+                ..task
+            })
+            .collect()
+    });
+
+    Job { pickups, ..job }
+}
+
+/// Removes completed tasks from the job. Returns None if all tasks are completed.
+fn remove_completed_tasks(job: Job, count: usize) -> Result<Option<Job>, String> {
+    let pickups = get_size(&job.pickups);
+    let total = pickups + get_size(&job.deliveries) + get_size(&job.replacements) + get_size(&job.services);
+
+    match count {
+        count if count >= total => Ok(None),
+        // NOTE only pickups can be served before the rest of the job, so remaining deliveries are on board
+        count if count == pickups && get_size(&job.replacements) == 0 && get_size(&job.services) == 0 => {
+            Ok(Some(Job { pickups: None, ..job }))
+        }
+        _ => Err(format!("cannot re-plan partially completed job '{}': only completed pickups are supported", job.id)),
+    }
+}
+
+fn remove_completed_relations(relations: Vec<Relation>, completed: &HashMap<&str, usize>) -> Vec<Relation> {
+    relations
+        .into_iter()
+        .map(|relation| {
+            let mut counts = completed.clone();
+            let jobs = relation
+                .jobs
+                .into_iter()
+                .filter(|id| match counts.get_mut(id.as_str()) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        false
+                    }
+                    _ => true,
+                })
+                .collect();
+
+            Relation { jobs, ..relation }
+        })
+        .filter(|relation| relation.jobs.iter().any(|id| !is_reserved_id(id)))
+        .collect()
+}
+
+fn create_relations(vehicle: &VehicleProgress, loaded: Vec<String>, on_board: Vec<String>) -> Vec<Relation> {
+    let committed = vehicle.committed.clone().unwrap_or_default();
+    let assigned = vehicle
+        .assigned
+        .iter()
+        .flat_map(|ids| ids.iter().cloned())
+        .chain(loaded.into_iter())
+        .filter(|id| !committed.contains(id) && !on_board.contains(id))
+        .fold(Vec::new(), |mut acc, id| {
+            if !acc.contains(&id) {
+                acc.push(id);
+            }
+            acc
+        });
+
+    let create_relation = |type_field: RelationType, jobs: Vec<String>| Relation {
+        type_field,
+        jobs,
+        vehicle_id: vehicle.vehicle_id.clone(),
+        shift_index: Some(vehicle.shift_index),
+    };
+
+    let mut relations = Vec::new();
+
+    // NOTE goods of completed pickup jobs are taken right after departure
+    if !committed.is_empty() || !on_board.is_empty() {
+        relations.push(create_relation(
+            RelationType::Strict,
+            std::iter::once("departure".to_string()).chain(on_board.into_iter()).chain(committed.into_iter()).collect(),
+        ));
+    }
+
+    if !assigned.is_empty() {
+        relations.push(create_relation(RelationType::Any, assigned));
+    }
+
+    relations
+}
+
+fn update_fleet(fleet: &mut Fleet, vehicle: &VehicleProgress) -> Result<(), String> {
+    let type_idx = fleet
+        .vehicles
+        .iter()
+        .position(|vehicle_type| vehicle_type.vehicle_ids.contains(&vehicle.vehicle_id))
+        .ok_or_else(|| format!("cannot find vehicle '{}'", vehicle.vehicle_id))?;
+
+    // NOTE vehicle with changed shift start needs its own vehicle type
+    let type_idx = if fleet.vehicles[type_idx].vehicle_ids.len() > 1 {
+        let vehicle_type = &mut fleet.vehicles[type_idx];
+        vehicle_type.vehicle_ids.retain(|id| *id != vehicle.vehicle_id);
+
+        let new_type = VehicleType {
+            type_id: format!("{}_{}", vehicle_type.type_id, vehicle.vehicle_id),
+            vehicle_ids: vec![vehicle.vehicle_id.clone()],
+            ..vehicle_type.clone()
+        };
+        fleet.vehicles.push(new_type);
+
+        fleet.vehicles.len() - 1
+    } else {
+        type_idx
+    };
+
+    let shift = fleet.vehicles[type_idx]
+        .shifts
+        .get_mut(vehicle.shift_index)
+        .ok_or_else(|| format!("cannot find shift {} of vehicle '{}'", vehicle.shift_index, vehicle.vehicle_id))?;
+
+    let start_time = parse_time_safe(shift.start.earliest.as_str()).map_err(|err| err.to_string())?;
+    let current_time = parse_time_safe(vehicle.time.as_str()).map_err(|err| err.to_string())?;
+    let completed = vehicle.completed.iter().flat_map(|ids| ids.iter());
+
+    let taken_breaks = completed.clone().filter(|id| *id == "break").count();
+    shift.breaks = shift
+        .breaks
+        .take()
+        .map(|breaks| {
+            breaks
+                .into_iter()
+                .skip(taken_breaks)
+                .filter_map(|vehicle_break| update_break(vehicle_break, start_time, current_time))
+                .collect::<Vec<_>>()
+        })
+        .filter(|breaks| !breaks.is_empty());
+
+    if completed.clone().any(|id| id == "depot") {
+        shift.depots = None;
+    }
+
+    shift.start = ShiftStart {
+        earliest: vehicle.time.clone(),
+        latest: Some(vehicle.time.clone()),
+        location: vehicle.location.clone(),
+    };
+
+    Ok(())
+}
+
+/// Adjusts break time to the new shift start. Returns None if break cannot be taken anymore.
+fn update_break(vehicle_break: VehicleBreak, start_time: f64, current_time: f64) -> Option<VehicleBreak> {
+    let time = match &vehicle_break.time {
+        VehicleBreakTime::TimeWindow(tw) => {
+            let end = tw.last().and_then(|time| parse_time_safe(time).ok())?;
+            if end < current_time {
+                return None;
+            }
+
+            let start = tw.first().and_then(|time| parse_time_safe(time).ok())?;
+            VehicleBreakTime::TimeWindow(vec![format_time(start.max(current_time)), format_time(end)])
+        }
+        VehicleBreakTime::TimeOffset(offset) => {
+            let elapsed = current_time - start_time;
+            let (start, end) = (offset.first().cloned()? - elapsed, offset.last().cloned()? - elapsed);
+            if end < 0. {
+                return None;
+            }
+
+            VehicleBreakTime::TimeOffset(vec![start.max(0.), end])
+        }
+    };
+
+    Some(VehicleBreak { time, ..vehicle_break })
+}
+
+fn is_reserved_id(id: &str) -> bool {
+    matches!(id, "departure" | "arrival" | "break" | "reload" | "depot" | "charging")
+}
@@ -11,19 +11,21 @@ mod fleet_reader;
 #[path = "./objective_reader.rs"]
 mod objective_reader;
 
-use self::fleet_reader::{create_transport_costs, read_fleet, read_limits};
+use self::fleet_reader::{create_binary_transport_costs, create_transport_costs, read_fleet, read_limits};
+pub(crate) use self::fleet_reader::{get_profile_map, read_areas};
+pub(crate) use self::job_reader::get_split_task;
 use self::job_reader::{read_jobs_with_extra_locks, read_locks};
 use self::objective_reader::create_objective;
 use crate::constraints::*;
 use crate::extensions::{get_route_modifier, OnlyVehicleActivityCost};
 use crate::format::coord_index::CoordIndex;
-use crate::format::problem::{deserialize_matrix, deserialize_problem, Matrix};
+use crate::format::problem::{deserialize_matrix, deserialize_problem, BinaryMatrix, JobOrder, Matrix, Objective};
 use crate::format::*;
 use crate::utils::get_approx_transportation;
 use crate::validation::ValidationContext;
 use crate::{get_unique_locations, parse_time};
 use std::cmp::Ordering::Equal;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::io::{BufReader, Read};
 use std::iter::FromIterator;
 use std::sync::Arc;
@@ -31,18 +33,51 @@ use vrp_core::construction::constraints::*;
 use vrp_core::models::common::{Dimensions, MultiDimLoad, SingleDimLoad, TimeWindow, ValueDimension};
 use vrp_core::models::problem::{ActivityCost, Fleet, TransportCost};
 use vrp_core::models::{Extras, Lock, Problem};
+use vrp_core::solver::objectives::StabilityReference;
+use vrp_core::solver::JobVehicleAffinity;
 use vrp_core::utils::{compare_floats, DefaultRandom, Random};
 
 pub type ApiProblem = crate::format::problem::Problem;
 
+/// Specifies options which control how problem definition is read.
+#[derive(Clone, Debug)]
+pub struct ReaderOptions {
+    /// Max amount of jobs for which full job neighbourhood index is built. When not set, full index
+    /// is always built.
+    pub full_job_index_threshold: Option<usize>,
+    /// Amount of nearest neighbours kept in job index for problems above the threshold.
+    pub max_job_neighbours: usize,
+    /// Specifies whether time agnostic json routing matrices should be stored in compact form.
+    pub compact_matrices: bool,
+}
+
+impl Default for ReaderOptions {
+    fn default() -> Self {
+        Self { full_job_index_threshold: None, max_job_neighbours: 512, compact_matrices: false }
+    }
+}
+
 /// Reads specific problem definition from various sources.
 pub trait PragmaticProblem {
     /// Reads problem defined in pragmatic format.
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>>;
+
+    /// Reads problem defined in pragmatic format using given reader options. Default implementation
+    /// ignores options.
+    fn read_pragmatic_with_options(self, _options: &ReaderOptions) -> Result<Problem, Vec<FormatError>>
+    where
+        Self: Sized,
+    {
+        self.read_pragmatic()
+    }
 }
 
 impl<R: Read> PragmaticProblem for (BufReader<R>, Vec<BufReader<R>>) {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+        self.read_pragmatic_with_options(&ReaderOptions::default())
+    }
+
+    fn read_pragmatic_with_options(self, options: &ReaderOptions) -> Result<Problem, Vec<FormatError>> {
         let problem = deserialize_problem(self.0)?;
 
         let mut matrices = vec![];
@@ -50,20 +85,28 @@ impl<R: Read> PragmaticProblem for (BufReader<R>, Vec<BufReader<R>>) {
             matrices.push(deserialize_matrix(matrix)?);
         }
 
-        map_to_problem_with_matrices(problem, matrices)
+        map_to_problem_with_matrices(problem, matrices, options)
     }
 }
 
 impl<R: Read> PragmaticProblem for BufReader<R> {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+        self.read_pragmatic_with_options(&ReaderOptions::default())
+    }
+
+    fn read_pragmatic_with_options(self, options: &ReaderOptions) -> Result<Problem, Vec<FormatError>> {
         let problem = deserialize_problem(self)?;
 
-        map_to_problem_with_approx(problem)
+        map_to_problem_with_approx(problem, options)
     }
 }
 
 impl PragmaticProblem for (String, Vec<String>) {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+        self.read_pragmatic_with_options(&ReaderOptions::default())
+    }
+
+    fn read_pragmatic_with_options(self, options: &ReaderOptions) -> Result<Problem, Vec<FormatError>> {
         let problem = deserialize_problem(BufReader::new(self.0.as_bytes()))?;
 
         let mut matrices = vec![];
@@ -71,40 +114,79 @@ impl PragmaticProblem for (String, Vec<String>) {
             matrices.push(deserialize_matrix(BufReader::new(matrix.as_bytes()))?);
         }
 
-        map_to_problem_with_matrices(problem, matrices)
+        map_to_problem_with_matrices(problem, matrices, options)
     }
 }
 
 impl PragmaticProblem for String {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+        self.read_pragmatic_with_options(&ReaderOptions::default())
+    }
+
+    fn read_pragmatic_with_options(self, options: &ReaderOptions) -> Result<Problem, Vec<FormatError>> {
         let problem = deserialize_problem(BufReader::new(self.as_bytes()))?;
 
-        map_to_problem_with_approx(problem)
+        map_to_problem_with_approx(problem, options)
     }
 }
 
 impl PragmaticProblem for (ApiProblem, Vec<Matrix>) {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
-        map_to_problem_with_matrices(self.0, self.1)
+        self.read_pragmatic_with_options(&ReaderOptions::default())
+    }
+
+    fn read_pragmatic_with_options(self, options: &ReaderOptions) -> Result<Problem, Vec<FormatError>> {
+        map_to_problem_with_matrices(self.0, self.1, options)
+    }
+}
+
+impl PragmaticProblem for (ApiProblem, Vec<BinaryMatrix>) {
+    fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+        self.read_pragmatic_with_options(&ReaderOptions::default())
+    }
+
+    fn read_pragmatic_with_options(self, options: &ReaderOptions) -> Result<Problem, Vec<FormatError>> {
+        let (api_problem, matrices) = self;
+        let api_problem = apply_named_dimensions(api_problem);
+        let coord_index = CoordIndex::new(&api_problem);
+
+        ValidationContext::new_with_matrix_sizes(&api_problem, matrices.iter().map(|m| m.size).collect()).validate()?;
+
+        let has_unreachable_locations = matrices.iter().any(|m| m.has_unreachable);
+        let problem_props = get_problem_properties(&api_problem, has_unreachable_locations);
+        let transport = create_binary_transport_costs(&api_problem, &matrices).map_err(to_transport_costs_error)?;
+
+        map_to_problem(api_problem, problem_props, transport, coord_index, options)
     }
 }
 
 impl PragmaticProblem for ApiProblem {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
-        map_to_problem_with_approx(self)
+        self.read_pragmatic_with_options(&ReaderOptions::default())
+    }
+
+    fn read_pragmatic_with_options(self, options: &ReaderOptions) -> Result<Problem, Vec<FormatError>> {
+        map_to_problem_with_approx(self, options)
     }
 }
 
 impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>) {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
+        self.read_pragmatic_with_options(&ReaderOptions::default())
+    }
+
+    fn read_pragmatic_with_options(self, options: &ReaderOptions) -> Result<Problem, Vec<FormatError>> {
         if let Some(matrices) = self.1 {
-            (self.0, matrices).read_pragmatic()
+            (self.0, matrices).read_pragmatic_with_options(options)
         } else {
-            self.0.read_pragmatic()
+            self.0.read_pragmatic_with_options(options)
         }
     }
 }
 
+/// Max amount of decimal digits kept in demand and capacity values.
+const MAX_LOAD_PRECISION: i32 = 3;
+
 pub struct ProblemProperties {
     has_multi_dimen_capacity: bool,
     has_breaks: bool,
@@ -113,7 +195,15 @@ pub struct ProblemProperties {
     has_depots: bool,
     has_reloads: bool,
     has_priorities: bool,
+    has_vehicle_times: bool,
+    has_tour_order: bool,
+    has_tour_ranks: bool,
     has_area_limits: bool,
+    has_compartments: bool,
+    has_batteries: bool,
+    has_tour_size_limits: bool,
+    has_stability_objective: bool,
+    load_scale: f64,
 }
 
 fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
@@ -151,34 +241,141 @@ fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
         .collect()
 }
 
-fn map_to_problem_with_approx(problem: ApiProblem) -> Result<Problem, Vec<FormatError>> {
+fn map_to_problem_with_approx(problem: ApiProblem, options: &ReaderOptions) -> Result<Problem, Vec<FormatError>> {
     let coord_index = CoordIndex::new(&problem);
     let matrices = if coord_index.get_used_types().1 { vec![] } else { create_approx_matrices(&problem) };
-    map_to_problem(problem, matrices, coord_index)
+    map_to_problem_with_json_matrices(problem, matrices, coord_index, options)
 }
 
-fn map_to_problem_with_matrices(problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Problem, Vec<FormatError>> {
+fn map_to_problem_with_matrices(
+    problem: ApiProblem,
+    matrices: Vec<Matrix>,
+    options: &ReaderOptions,
+) -> Result<Problem, Vec<FormatError>> {
     let coord_index = CoordIndex::new(&problem);
-    map_to_problem(problem, matrices, coord_index)
+    map_to_problem_with_json_matrices(problem, matrices, coord_index, options)
 }
 
-fn map_to_problem(
+fn map_to_problem_with_json_matrices(
     api_problem: ApiProblem,
     matrices: Vec<Matrix>,
     coord_index: CoordIndex,
+    options: &ReaderOptions,
 ) -> Result<Problem, Vec<FormatError>> {
+    let api_problem = apply_named_dimensions(api_problem);
     ValidationContext::new(&api_problem, Some(&matrices)).validate()?;
 
-    let problem_props = get_problem_properties(&api_problem, &matrices);
+    let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some());
+    let problem_props = get_problem_properties(&api_problem, has_unreachable_locations);
+    let transport =
+        create_transport_costs(&api_problem, &matrices, options.compact_matrices).map_err(to_transport_costs_error)?;
+
+    map_to_problem(api_problem, problem_props, transport, coord_index, options)
+}
+
+/// Converts demand and capacity specified by dimension names into positional ones using dimensions
+/// declared on fleet level. Positional values, if specified explicitly, are kept as is.
+pub(crate) fn apply_named_dimensions(mut api_problem: ApiProblem) -> ApiProblem {
+    let dimensions = if let Some(dimensions) = api_problem.fleet.dimensions.clone() {
+        dimensions
+    } else {
+        return api_problem;
+    };
+
+    api_problem.fleet.vehicles.iter_mut().filter(|vehicle| vehicle.capacity.is_empty()).for_each(|vehicle| {
+        if let Some(named_capacity) = vehicle.named_capacity.as_ref() {
+            vehicle.capacity = get_positional_load(&dimensions, named_capacity);
+        }
+    });
+
+    api_problem
+        .plan
+        .jobs
+        .iter_mut()
+        .flat_map(|job| {
+            job.pickups
+                .iter_mut()
+                .chain(job.deliveries.iter_mut())
+                .chain(job.replacements.iter_mut())
+                .chain(job.services.iter_mut())
+                .flat_map(|tasks| tasks.iter_mut())
+        })
+        .filter(|task| task.demand.is_none())
+        .for_each(|task| task.demand = task.named_demand.as_ref().map(|named| get_positional_load(&dimensions, named)));
+
+    api_problem
+}
+
+/// Returns load values ordered by dimension names. Missing dimensions are set to zero.
+pub(crate) fn get_positional_load(dimensions: &[String], named_load: &HashMap<String, f64>) -> Vec<f64> {
+    dimensions.iter().map(|name| named_load.get(name).cloned().unwrap_or(0.)).collect()
+}
+
+/// Returns a scale used to convert decimal demand and capacity into fixed-point integer units: the
+/// smallest power of ten which makes all values integer, but not bigger than `10^MAX_LOAD_PRECISION`.
+pub(crate) fn get_load_scale(api_problem: &ApiProblem) -> f64 {
+    let demands = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| {
+            job.pickups
+                .iter()
+                .chain(job.deliveries.iter())
+                .chain(job.replacements.iter())
+                .chain(job.services.iter())
+                .flat_map(|tasks| tasks.iter())
+        })
+        .flat_map(|task| task.demand.iter().flat_map(|demand| demand.iter()));
+    let capacities = api_problem.fleet.vehicles.iter().flat_map(|vehicle| {
+        vehicle
+            .capacity
+            .iter()
+            .chain(vehicle.compartments.iter().flat_map(|c| c.iter()).flat_map(|c| c.capacity.iter()))
+    });
+    let values = demands.chain(capacities).cloned().collect::<Vec<_>>();
+
+    (0..MAX_LOAD_PRECISION)
+        .map(|precision| 10_f64.powi(precision))
+        .find(|scale| values.iter().all(|value| ((value * scale).round() - value * scale).abs() < 1E-6))
+        .unwrap_or_else(|| 10_f64.powi(MAX_LOAD_PRECISION))
+}
+
+/// Checks whether decimal load value can be converted into fixed-point integer units using given scale
+/// without loss of precision and overflow.
+pub(crate) fn is_valid_fixed_load(value: f64, scale: f64) -> bool {
+    let max_scale = 10_f64.powi(MAX_LOAD_PRECISION);
+    let is_exact = ((value * max_scale).round() - value * max_scale).abs() < 1E-6;
+
+    is_exact && (value * scale).round().abs() <= i32::MAX as f64
+}
+
+/// Converts decimal load values into fixed-point integer units.
+pub(crate) fn to_fixed_load(values: &[f64], scale: f64) -> Vec<i32> {
+    values.iter().map(|value| (value * scale).round() as i32).collect()
+}
+
+/// Converts fixed-point integer units back into decimal load values.
+pub(crate) fn from_fixed_load(values: &[i32], scale: f64) -> Vec<f64> {
+    values.iter().map(|&value| value as f64 / scale).collect()
+}
 
+fn to_transport_costs_error(err: String) -> Vec<FormatError> {
+    vec![FormatError::new(
+        "E0002".to_string(),
+        "cannot create transport costs".to_string(),
+        format!("Check matrix routing data: '{}'", err),
+    )]
+}
+
+fn map_to_problem(
+    api_problem: ApiProblem,
+    problem_props: ProblemProperties,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    coord_index: CoordIndex,
+    options: &ReaderOptions,
+) -> Result<Problem, Vec<FormatError>> {
     let coord_index = Arc::new(coord_index);
-    let transport = create_transport_costs(&api_problem, &matrices).map_err(|err| {
-        vec![FormatError::new(
-            "E0002".to_string(),
-            "cannot create transport costs".to_string(),
-            format!("Check matrix routing data: '{}'", err),
-        )]
-    })?;
     let activity = Arc::new(OnlyVehicleActivityCost::default());
     let fleet = read_fleet(&api_problem, &problem_props, &coord_index);
 
@@ -194,6 +391,7 @@ fn map_to_problem(
         &transport,
         &mut job_index,
         &random,
+        options,
     );
     let locks = locks.into_iter().chain(read_locks(&api_problem, &job_index).into_iter()).collect::<Vec<_>>();
     let limits = read_limits(&api_problem).unwrap_or_else(|| Arc::new(|_| (None, None)));
@@ -207,9 +405,24 @@ fn map_to_problem(
         limits,
     );
 
-    let objective = create_objective(&api_problem, &mut constraint, &problem_props);
+    let stability_reference = Arc::new(StabilityReference::default());
+    let objective = create_objective(
+        &api_problem,
+        &mut constraint,
+        &problem_props,
+        activity.clone(),
+        transport.clone(),
+        stability_reference.clone(),
+    );
     let constraint = Arc::new(constraint);
-    let extras = Arc::new(create_extras(constraint.clone(), &problem_props, job_index, coord_index));
+    let extras = Arc::new(create_extras(
+        &api_problem,
+        constraint.clone(),
+        &problem_props,
+        job_index,
+        coord_index,
+        stability_reference,
+    ));
 
     Ok(Problem {
         fleet: Arc::new(fleet),
@@ -233,6 +446,21 @@ fn create_constraint_pipeline(
     limits: TravelLimitFunc,
 ) -> ConstraintPipeline {
     let mut constraint = ConstraintPipeline::default();
+
+    // NOTE charging module removes redundant charging activities, so transport module has to update schedules after it
+    if props.has_batteries {
+        add_charging_module(&mut constraint, transport.clone(), props);
+    }
+
+    // NOTE vehicle specific time windows have to be applied before transport module updates schedules
+    if props.has_vehicle_times {
+        constraint.add_module(Box::new(VehicleTimesModule::new(
+            activity.clone(),
+            transport.clone(),
+            VEHICLE_TIMES_CONSTRAINT_CODE,
+        )));
+    }
+
     constraint.add_module(Box::new(TransportConstraintModule::new(
         activity.clone(),
         transport.clone(),
@@ -244,6 +472,10 @@ fn create_constraint_pipeline(
 
     add_capacity_module(&mut constraint, &props);
 
+    if props.has_compartments {
+        add_compartment_module(&mut constraint, &props);
+    }
+
     if props.has_breaks {
         constraint.add_module(Box::new(BreakModule::new(BREAK_CONSTRAINT_CODE)));
     }
@@ -256,6 +488,10 @@ fn create_constraint_pipeline(
         constraint.add_module(Box::new(PriorityModule::new(PRIORITY_CONSTRAINT_CODE)));
     }
 
+    if props.has_tour_order {
+        constraint.add_module(Box::new(TourOrderModule::new(TOUR_ORDER_CONSTRAINT_CODE)));
+    }
+
     if !locks.is_empty() {
         constraint.add_module(Box::new(StrictLockingModule::new(fleet, locks, LOCKING_CONSTRAINT_CODE)));
     }
@@ -268,9 +504,35 @@ fn create_constraint_pipeline(
         add_area_module(&mut constraint, coord_index);
     }
 
+    if props.has_tour_size_limits {
+        constraint.add_module(Box::new(TourSizeModule::new(TOUR_SIZE_CONSTRAINT_CODE)));
+    }
+
     constraint
 }
 
+fn add_charging_module(
+    constraint: &mut ConstraintPipeline,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    props: &ProblemProperties,
+) {
+    let load_scale = props.load_scale;
+
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Box::new(ChargingModule::<MultiDimLoad>::new(
+            transport,
+            Arc::new(move |load| load.load[0] as f64 / load_scale),
+            CHARGING_CONSTRAINT_CODE,
+        ))
+    } else {
+        Box::new(ChargingModule::<SingleDimLoad>::new(
+            transport,
+            Arc::new(move |load| load.value as f64 / load_scale),
+            CHARGING_CONSTRAINT_CODE,
+        ))
+    });
+}
+
 fn add_capacity_module(constraint: &mut ConstraintPipeline, props: &ProblemProperties) {
     constraint.add_module(if props.has_reloads {
         let threshold = 0.9;
@@ -292,9 +554,17 @@ fn add_capacity_module(constraint: &mut ConstraintPipeline, props: &ProblemPrope
     });
 }
 
+fn add_compartment_module(constraint: &mut ConstraintPipeline, props: &ProblemProperties) {
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Box::new(CompartmentConstraintModule::<MultiDimLoad>::new(COMPARTMENT_CONSTRAINT_CODE))
+    } else {
+        Box::new(CompartmentConstraintModule::<SingleDimLoad>::new(COMPARTMENT_CONSTRAINT_CODE))
+    });
+}
+
 fn add_area_module(constraint: &mut ConstraintPipeline, coord_index: Arc<CoordIndex>) {
     constraint.add_module(Box::new(AreaModule::new(
-        Arc::new(|actor| actor.vehicle.dimens.get_value::<Vec<Area>>("areas")),
+        Arc::new(|actor| actor.vehicle.dimens.get_value::<ActorAreas>("areas")),
         Arc::new(move |location| {
             coord_index
                 .get_by_idx(location)
@@ -305,10 +575,12 @@ fn add_area_module(constraint: &mut ConstraintPipeline, coord_index: Arc<CoordIn
 }
 
 fn create_extras(
+    api_problem: &ApiProblem,
     constraint: Arc<ConstraintPipeline>,
     props: &ProblemProperties,
     job_index: JobIndex,
     coord_index: Arc<CoordIndex>,
+    stability_reference: Arc<StabilityReference>,
 ) -> Extras {
     let mut extras = Extras::default();
     extras.insert(
@@ -316,8 +588,22 @@ fn create_extras(
         Arc::new((if props.has_multi_dimen_capacity { "multi" } else { "single" }).to_string()),
     );
     extras.insert("coord_index".to_owned(), coord_index);
+    extras.insert("load_scale".to_owned(), Arc::new(props.load_scale));
+
+    if let Some(dimensions) = api_problem.fleet.dimensions.as_ref() {
+        extras.insert("capacity_dimensions".to_owned(), Arc::new(dimensions.clone()));
+    }
     extras.insert("job_index".to_owned(), Arc::new(job_index.clone()));
 
+    extras.insert(
+        "job_vehicle_affinity".to_owned(),
+        Arc::new(JobVehicleAffinity::new(|job| job.as_single().and_then(get_vehicle_id_from_job).cloned())),
+    );
+
+    if props.has_stability_objective {
+        extras.insert("stability_reference".to_owned(), stability_reference);
+    }
+
     if props.has_depots {
         extras.insert("route_modifier".to_owned(), Arc::new(get_route_modifier(constraint, job_index)));
     }
@@ -330,10 +616,11 @@ fn parse_time_window(tw: &[String]) -> TimeWindow {
     TimeWindow::new(parse_time(tw.first().unwrap()), parse_time(tw.last().unwrap()))
 }
 
-fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> ProblemProperties {
-    let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some());
-    let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
-        || api_problem.plan.jobs.iter().any(|job| {
+fn get_problem_properties(api_problem: &ApiProblem, has_unreachable_locations: bool) -> ProblemProperties {
+    let has_multi_dimen_capacity =
+        api_problem.fleet.vehicles.iter().any(|t| {
+            t.capacity.len() > 1 || t.compartments.iter().flat_map(|c| c.iter()).any(|c| c.capacity.len() > 1)
+        }) || api_problem.plan.jobs.iter().any(|job| {
             job.pickups
                 .iter()
                 .chain(job.deliveries.iter())
@@ -360,11 +647,44 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .any(|t| t.shifts.iter().any(|s| s.reloads.as_ref().map_or(false, |reloads| !reloads.is_empty())));
 
     let has_priorities = api_problem.plan.jobs.iter().filter_map(|job| job.priority).any(|priority| priority > 1);
-    let has_area_limits = api_problem
+    let has_vehicle_times = api_problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| {
+            job.pickups
+                .iter()
+                .chain(job.deliveries.iter())
+                .chain(job.replacements.iter())
+                .chain(job.services.iter())
+                .flat_map(|tasks| tasks.iter())
+        })
+        .flat_map(|task| task.places.iter())
+        .any(|place| place.vehicle_times.as_ref().map_or(false, |times| !times.is_empty()));
+    let has_tour_order = api_problem.plan.jobs.iter().any(|job| matches!(job.order, Some(JobOrder::Position(_))));
+    let has_tour_ranks = api_problem.plan.jobs.iter().any(|job| matches!(job.order, Some(JobOrder::Rank(_))));
+    let has_area_limits = api_problem.fleet.vehicles.iter().filter_map(|v| v.limits.as_ref()).any(|limits| {
+        limits.allowed_areas.as_ref().map_or(false, |a| !a.is_empty())
+            || limits.forbidden_areas.as_ref().map_or(false, |a| !a.is_empty())
+            || limits.area_costs.as_ref().map_or(false, |a| !a.is_empty())
+    });
+
+    let has_compartments =
+        api_problem.fleet.vehicles.iter().any(|t| t.compartments.as_ref().map_or(false, |c| !c.is_empty()));
+    let has_batteries = api_problem.fleet.vehicles.iter().any(|t| t.battery.is_some());
+    let has_tour_size_limits = api_problem
         .fleet
         .vehicles
         .iter()
-        .any(|v| v.limits.as_ref().and_then(|l| l.allowed_areas.as_ref()).map_or(false, |a| !a.is_empty()));
+        .filter_map(|v| v.limits.as_ref())
+        .any(|limits| limits.max_jobs.is_some() || limits.max_stops.is_some());
+    let has_stability_objective = api_problem.objectives.as_ref().map_or(false, |objectives| {
+        objectives
+            .primary
+            .iter()
+            .chain(objectives.secondary.iter().flat_map(|objectives| objectives.iter()))
+            .any(|objective| matches!(objective, Objective::MinimizeDeviation { .. }))
+    });
 
     ProblemProperties {
         has_multi_dimen_capacity,
@@ -374,7 +694,15 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_depots,
         has_reloads,
         has_priorities,
+        has_vehicle_times,
+        has_tour_order,
+        has_tour_ranks,
         has_area_limits,
+        has_compartments,
+        has_batteries,
+        has_tour_size_limits,
+        has_stability_objective,
+        load_scale: get_load_scale(api_problem),
     }
 }
 
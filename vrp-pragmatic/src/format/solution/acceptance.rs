@@ -0,0 +1,171 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/acceptance_test.rs"]
+mod acceptance_test;
+
+use crate::format::solution::writer::map_code_reason;
+use crate::format::solution::UnassignedJobReason;
+use crate::format::{get_coord_index, get_job_index, Location};
+use crate::format_time;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::*;
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::models::{Problem, Solution};
+use vrp_core::utils::DefaultRandom;
+
+/// Specifies result of job acceptance evaluation.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JobAcceptance {
+    /// Job id.
+    pub job_id: String,
+    /// True if job can be inserted into existing plan.
+    pub feasible: bool,
+    /// The cheapest insertion of the job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insertion: Option<JobInsertion>,
+    /// Reasons why job cannot be inserted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasons: Option<Vec<UnassignedJobReason>>,
+}
+
+/// Specifies job insertion into the tour.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JobInsertion {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle type id.
+    pub type_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Cost change of the solution: a difference between route costs after and before insertion.
+    pub cost: f64,
+    /// Inserted job activities.
+    pub activities: Vec<InsertedActivity>,
+}
+
+/// Specifies inserted job activity.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct InsertedActivity {
+    /// Activity type.
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub activity_type: String,
+    /// Job tag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// Activity index in the tour, departure has zero index.
+    pub index: usize,
+    /// Activity location.
+    pub location: Location,
+    /// Estimated arrival time in RFC3339 format.
+    pub arrival: String,
+}
+
+/// Evaluates the cheapest insertion of each job into existing solution without changing it.
+/// Jobs are evaluated independently from each other and should be a part of the problem,
+/// but not a part of the solution.
+pub fn evaluate_job_acceptance(
+    problem: Arc<Problem>,
+    solution: Solution,
+    job_ids: &[String],
+) -> Result<Vec<JobAcceptance>, String> {
+    let job_index = get_job_index(problem.as_ref());
+    let jobs = job_ids
+        .iter()
+        .map(|job_id| {
+            let job = job_index.get(job_id).ok_or_else(|| format!("cannot find job with id '{}'", job_id))?;
+
+            if solution.routes.iter().any(|route| route.tour.contains(job)) {
+                Err(format!("job '{}' is already assigned", job_id))
+            } else {
+                Ok((job_id.clone(), job.clone()))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let ctx =
+        InsertionContext::new_from_solution(problem.clone(), (solution, None), Arc::new(DefaultRandom::default()));
+    let route_selector = AllRouteSelector::default();
+
+    Ok(jobs
+        .into_iter()
+        .map(|(job_id, job)| match evaluate_job_insertion(&job, &ctx, &route_selector, InsertionPosition::Any) {
+            InsertionResult::Success(success) => JobAcceptance {
+                job_id,
+                feasible: true,
+                insertion: Some(create_insertion(problem.as_ref(), success)),
+                reasons: None,
+            },
+            InsertionResult::Failure(_) => {
+                JobAcceptance { job_id, feasible: false, insertion: None, reasons: Some(get_reasons(&ctx, &job)) }
+            }
+        })
+        .collect())
+}
+
+fn create_insertion(problem: &Problem, success: InsertionSuccess) -> JobInsertion {
+    let coord_index = get_coord_index(problem);
+
+    let InsertionSuccess { job, activities, context, .. } = success;
+
+    // NOTE insertion cost estimate includes soft constraint penalties, so actual route cost change is reported
+    let cost_before = if context.route.tour.job_count() > 0 { context.get_route_cost() } else { 0. };
+
+    let mut route_ctx = context.deep_copy();
+    activities.into_iter().for_each(|(activity, index)| {
+        route_ctx.route_mut().tour.insert_at(activity, index + 1);
+    });
+    problem.constraint.accept_route_state(&mut route_ctx);
+    let cost = route_ctx.get_route_cost() - cost_before;
+
+    let dimens = &route_ctx.route.actor.vehicle.dimens;
+    let activities = route_ctx
+        .route
+        .tour
+        .all_activities()
+        .enumerate()
+        .filter(|(_, activity)| activity.has_same_job(&job))
+        .map(|(index, activity)| {
+            let single_dimens = &activity.job.as_ref().expect("job activity expected").dimens;
+
+            InsertedActivity {
+                activity_type: single_dimens.get_value::<String>("type").cloned().unwrap_or_default(),
+                tag: single_dimens.get_value::<String>("tag").cloned(),
+                index,
+                location: coord_index.get_by_idx(activity.place.location).expect("cannot get location"),
+                arrival: format_time(activity.schedule.arrival),
+            }
+        })
+        .collect();
+
+    JobInsertion {
+        vehicle_id: dimens.get_id().cloned().expect("cannot get vehicle id"),
+        type_id: dimens.get_value::<String>("type_id").cloned().expect("cannot get type id"),
+        shift_index: dimens.get_value::<usize>("shift_index").cloned().expect("cannot get shift index"),
+        cost,
+        activities,
+    }
+}
+
+/// Collects distinct reasons of insertion failure in each available route.
+fn get_reasons(ctx: &InsertionContext, job: &Job) -> Vec<UnassignedJobReason> {
+    let codes = AllRouteSelector::default().select(ctx, job).fold(Vec::new(), |mut acc, route_ctx| {
+        if let InsertionResult::Failure(failure) =
+            evaluate_job_insertion_in_route(job, ctx, &route_ctx, InsertionPosition::Any, None)
+        {
+            let code = map_code_reason(failure.constraint);
+            if !acc.contains(&code) {
+                acc.push(code);
+            }
+        }
+
+        acc
+    });
+
+    let codes = if codes.is_empty() { vec![map_code_reason(0)] } else { codes };
+
+    codes.into_iter().map(|(code, reason)| UnassignedJobReason { code, description: reason.to_string() }).collect()
+}
@@ -1,4 +1,4 @@
-use crate::format::{CoordIndex, JobIndex};
+use crate::format::{get_split_jobs, CoordIndex, JobIndex};
 use crate::parse_time;
 use std::collections::HashSet;
 use std::iter::once;
@@ -45,8 +45,11 @@ pub(crate) fn try_match_job(
     match activity.activity_type.as_str() {
         "departure" | "arrival" => Ok(None),
         "pickup" | "delivery" | "replacement" | "service" => {
-            let job =
-                job_index.get(&activity.job_id).ok_or_else(|| format!("unknown job id: '{}'", activity.job_id))?;
+            // NOTE all parts of split job have the same properties except demand, so the first one is used
+            let job = job_index
+                .get(&activity.job_id)
+                .or_else(|| get_split_jobs(job_index, activity.job_id.as_str()).next())
+                .ok_or_else(|| format!("unknown job id: '{}'", activity.job_id))?;
             let singles: Box<dyn Iterator<Item = &Arc<_>>> = match job {
                 Job::Single(single) => Box::new(once(single)),
                 Job::Multi(multi) => {
@@ -68,7 +71,7 @@ pub(crate) fn try_match_job(
 
             Ok(Some(JobInfo(job.clone(), single.clone(), place, ctx.time)))
         }
-        "break" | "depot" | "reload" => Ok(Some(
+        "break" | "depot" | "reload" | "charging" => Ok(Some(
             (1..)
                 .map(|idx| format!("{}_{}_{}_{}", tour.vehicle_id, activity.activity_type, tour.shift_index, idx))
                 .map(|job_id| job_index.get(&job_id))
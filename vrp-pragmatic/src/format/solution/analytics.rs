@@ -0,0 +1,304 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/analytics_test.rs"]
+mod analytics_test;
+
+use crate::format::problem::{Job, JobTask, Problem, VehicleType};
+use crate::format::solution::{is_job_activity, Activity, Solution, Stop, Tour};
+use crate::format::Location;
+use crate::parse_time_safe;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::io::{BufWriter, Error, Write};
+use vrp_core::algorithms::statistics::{get_cv, get_mean};
+
+/// Specifies key performance indicators of a single stop.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StopAnalytics {
+    /// Ids of jobs served at the stop.
+    pub job_ids: Vec<String>,
+    /// Minimum time (in seconds) between job service start and its time window end.
+    /// Absent if no job at the stop has a time window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_window_slack: Option<i64>,
+}
+
+/// Specifies key performance indicators of a single tour.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourAnalytics {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Amount of served jobs.
+    pub jobs: usize,
+    /// Total distance.
+    pub distance: i64,
+    /// Total duration.
+    pub duration: i64,
+    /// Max load to capacity ratio per capacity dimension.
+    pub utilization: Vec<f64>,
+    /// Waiting time to tour duration ratio.
+    pub waiting_ratio: f64,
+    /// Time spent neither driving nor serving jobs to tour duration ratio.
+    pub idle_ratio: f64,
+    /// A share of distance driven without any load.
+    pub empty_distance_ratio: f64,
+    /// Amount of served jobs per hour of tour duration.
+    pub jobs_per_hour: f64,
+    /// Stop analytics.
+    pub stops: Vec<StopAnalytics>,
+}
+
+/// Specifies mean and coefficient of variation of some tour characteristic.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct BalanceValue {
+    /// Mean value.
+    pub mean: f64,
+    /// Coefficient of variation.
+    pub cv: f64,
+}
+
+/// Specifies how balanced are tours of the solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct BalanceAnalytics {
+    /// Balance of served jobs.
+    pub jobs: BalanceValue,
+    /// Balance of tour distances.
+    pub distance: BalanceValue,
+    /// Balance of tour durations.
+    pub duration: BalanceValue,
+    /// Balance of max utilization across all capacity dimensions.
+    pub utilization: BalanceValue,
+}
+
+/// Specifies key performance indicators of the solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionAnalytics {
+    /// Names of capacity dimensions in the order used by tour utilization.
+    pub dimensions: Vec<String>,
+    /// Tour analytics.
+    pub tours: Vec<TourAnalytics>,
+    /// Balance analytics.
+    pub balance: BalanceAnalytics,
+}
+
+/// Calculates key performance indicators of pragmatic solution.
+pub fn analyze_solution(problem: &Problem, solution: &Solution) -> Result<SolutionAnalytics, String> {
+    let vehicle_types =
+        problem.fleet.vehicles.iter().map(|vehicle| (vehicle.type_id.as_str(), vehicle)).collect::<HashMap<_, _>>();
+    let jobs = problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
+
+    let tours = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let vehicle_type = vehicle_types
+                .get(tour.type_id.as_str())
+                .ok_or_else(|| format!("cannot find vehicle type '{}'", tour.type_id))?;
+
+            analyze_tour(problem, vehicle_type, &jobs, tour)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let dimensions_size = tours.iter().map(|tour| tour.utilization.len()).max().unwrap_or(0);
+    let dimensions = problem
+        .fleet
+        .dimensions
+        .clone()
+        .unwrap_or_else(|| (0..dimensions_size).map(|idx| format!("dimension_{}", idx)).collect());
+
+    let get_balance = |value_fn: &dyn Fn(&TourAnalytics) -> f64| {
+        get_balance_value(tours.iter().map(value_fn).collect::<Vec<_>>().as_slice())
+    };
+
+    let balance = BalanceAnalytics {
+        jobs: get_balance(&|tour| tour.jobs as f64),
+        distance: get_balance(&|tour| tour.distance as f64),
+        duration: get_balance(&|tour| tour.duration as f64),
+        utilization: get_balance(&|tour| tour.utilization.iter().cloned().fold(0., f64::max)),
+    };
+
+    Ok(SolutionAnalytics { dimensions, tours, balance })
+}
+
+/// Writes tour analytics as csv: one row per tour.
+pub fn serialize_analytics_as_csv<W: Write>(
+    mut writer: BufWriter<W>,
+    analytics: &SolutionAnalytics,
+) -> Result<(), Error> {
+    let utilization_columns =
+        analytics.dimensions.iter().map(|dimension| format!(",utilization_{}", dimension)).collect::<String>();
+
+    writeln!(
+        writer,
+        "vehicle_id,shift_index,jobs,distance,duration{},waiting_ratio,idle_ratio,empty_distance_ratio,\
+         jobs_per_hour,min_time_window_slack",
+        utilization_columns
+    )?;
+
+    analytics.tours.iter().try_for_each(|tour| {
+        let utilization = (0..analytics.dimensions.len())
+            .map(|idx| format!(",{:.4}", tour.utilization.get(idx).cloned().unwrap_or(0.)))
+            .collect::<String>();
+        let min_slack = tour.stops.iter().filter_map(|stop| stop.time_window_slack).min();
+
+        writeln!(
+            writer,
+            "{},{},{},{},{}{},{:.4},{:.4},{:.4},{:.4},{}",
+            tour.vehicle_id,
+            tour.shift_index,
+            tour.jobs,
+            tour.distance,
+            tour.duration,
+            utilization,
+            tour.waiting_ratio,
+            tour.idle_ratio,
+            tour.empty_distance_ratio,
+            tour.jobs_per_hour,
+            min_slack.map_or_else(String::new, |slack| slack.to_string())
+        )
+    })?;
+
+    writer.flush()
+}
+
+fn analyze_tour(
+    problem: &Problem,
+    vehicle_type: &VehicleType,
+    jobs: &HashMap<&str, &Job>,
+    tour: &Tour,
+) -> Result<TourAnalytics, String> {
+    let statistic = &tour.statistic;
+    let capacity = get_capacity(problem, vehicle_type);
+
+    let utilization = capacity
+        .iter()
+        .enumerate()
+        .map(|(idx, capacity)| {
+            let max_load = tour.stops.iter().filter_map(|stop| stop.load.get(idx)).cloned().fold(0., f64::max);
+            get_ratio(max_load, *capacity)
+        })
+        .collect();
+
+    let empty_distance = tour
+        .stops
+        .windows(2)
+        .filter(|stops| stops[0].load.iter().all(|load| *load == 0.))
+        .map(|stops| stops[1].distance - stops[0].distance)
+        .sum::<i64>();
+
+    let job_ids = tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| is_job_activity(activity))
+        .map(|activity| activity.job_id.as_str())
+        .collect::<HashSet<_>>();
+
+    let duration = statistic.duration as f64;
+    let idle = duration - (statistic.times.driving + statistic.times.serving) as f64;
+
+    let stops = tour.stops.iter().map(|stop| analyze_stop(jobs, stop)).collect::<Result<Vec<_>, String>>()?;
+
+    Ok(TourAnalytics {
+        vehicle_id: tour.vehicle_id.clone(),
+        shift_index: tour.shift_index,
+        jobs: job_ids.len(),
+        distance: statistic.distance,
+        duration: statistic.duration,
+        utilization,
+        waiting_ratio: get_ratio(statistic.times.waiting as f64, duration),
+        idle_ratio: get_ratio(idle, duration),
+        empty_distance_ratio: get_ratio(empty_distance as f64, statistic.distance as f64),
+        jobs_per_hour: get_ratio(job_ids.len() as f64 * 3600., duration),
+        stops,
+    })
+}
+
+fn analyze_stop(jobs: &HashMap<&str, &Job>, stop: &Stop) -> Result<StopAnalytics, String> {
+    let activities = stop.activities.iter().filter(|activity| is_job_activity(activity)).collect::<Vec<_>>();
+    let slacks =
+        activities.iter().map(|activity| get_slack(jobs, stop, activity)).collect::<Result<Vec<_>, String>>()?;
+
+    Ok(StopAnalytics {
+        job_ids: activities.iter().map(|activity| activity.job_id.clone()).collect(),
+        time_window_slack: slacks.into_iter().flatten().min(),
+    })
+}
+
+/// Returns time between activity service start and end of the time window it is served in.
+fn get_slack(jobs: &HashMap<&str, &Job>, stop: &Stop, activity: &Activity) -> Result<Option<i64>, String> {
+    let job = if let Some(job) = jobs.get(activity.job_id.as_str()) {
+        job
+    } else {
+        return Ok(None);
+    };
+    let location = activity.location.as_ref().unwrap_or(&stop.location);
+    let arrival = parse_time(activity.time.as_ref().map_or(&stop.time.arrival, |time| &time.start))?;
+
+    let mut time_windows = get_tasks(job, activity.activity_type.as_str())
+        .filter(|task| activity.job_tag.is_none() || task.tag == activity.job_tag)
+        .flat_map(|task| task.places.iter())
+        .filter(|place| is_same_location(&place.location, location))
+        .flat_map(|place| place.times.iter().flat_map(|times| times.iter()))
+        .filter(|tw| tw.len() == 2)
+        .map(|tw| Ok((parse_time(&tw[0])?, parse_time(&tw[1])?)))
+        .collect::<Result<Vec<_>, String>>()?;
+    time_windows.retain(|(_, end)| *end >= arrival);
+    time_windows.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap());
+
+    Ok(time_windows.first().map(|(start, end)| (end - arrival.max(*start)) as i64))
+}
+
+fn parse_time(time: &str) -> Result<f64, String> {
+    parse_time_safe(time).map_err(|err| format!("cannot parse time '{}': {}", time, err))
+}
+
+pub(super) fn get_tasks<'a>(job: &'a Job, activity_type: &str) -> impl Iterator<Item = &'a JobTask> + 'a {
+    match activity_type {
+        "pickup" => job.pickups.as_ref(),
+        "delivery" => job.deliveries.as_ref(),
+        "replacement" => job.replacements.as_ref(),
+        "service" => job.services.as_ref(),
+        _ => None,
+    }
+    .into_iter()
+    .flat_map(|tasks| tasks.iter())
+}
+
+fn get_capacity(problem: &Problem, vehicle_type: &VehicleType) -> Vec<f64> {
+    match (vehicle_type.named_capacity.as_ref(), problem.fleet.dimensions.as_ref()) {
+        (Some(capacity), Some(dimensions)) => {
+            dimensions.iter().map(|dimension| capacity.get(dimension).cloned().unwrap_or(0.)).collect()
+        }
+        _ => vehicle_type.capacity.clone(),
+    }
+}
+
+fn get_balance_value(values: &[f64]) -> BalanceValue {
+    let mean = if values.is_empty() { 0. } else { get_mean(values) };
+    let cv = if values.len() < 2 || mean == 0. { 0. } else { get_cv(values) };
+
+    BalanceValue { mean, cv }
+}
+
+fn get_ratio(value: f64, total: f64) -> f64 {
+    if total > 0. {
+        value / total
+    } else {
+        0.
+    }
+}
+
+pub(super) fn is_same_location(a: &Location, b: &Location) -> bool {
+    match (a, b) {
+        (Location::Coordinate { lat: a_lat, lng: a_lng }, Location::Coordinate { lat: b_lat, lng: b_lng }) => {
+            a_lat == b_lat && a_lng == b_lng
+        }
+        (Location::Reference { index: a }, Location::Reference { index: b }) => a == b,
+        _ => false,
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/csv_serializer_test.rs"]
+mod csv_serializer_test;
+
+use super::Solution;
+use crate::format::solution::{Activity, Stop, Tour};
+use crate::format::Location;
+use std::io::{BufWriter, Error, Write};
+
+const CSV_HEADER: &str = "vehicle_id,type_id,shift_index,stop_index,job_id,activity_type,job_tag,lat,lng,\
+                          location_index,arrival,departure,activity_start,activity_end,load,distance";
+
+/// Serializes solution as csv with one row per activity.
+pub fn serialize_solution_as_csv<W: Write>(mut writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
+    writeln!(writer, "{}", CSV_HEADER)?;
+
+    solution.tours.iter().try_for_each(|tour| {
+        tour.stops.iter().enumerate().try_for_each(|(stop_idx, stop)| {
+            stop.activities.iter().try_for_each(|activity| {
+                writeln!(writer, "{}", get_activity_row(tour, stop_idx, stop, activity).join(","))
+            })
+        })
+    })?;
+
+    writer.flush()
+}
+
+fn get_activity_row(tour: &Tour, stop_idx: usize, stop: &Stop, activity: &Activity) -> Vec<String> {
+    let (lat, lng, index) = match activity.location.as_ref().unwrap_or(&stop.location) {
+        Location::Coordinate { lat, lng } => (lat.to_string(), lng.to_string(), String::new()),
+        Location::Reference { index } => (String::new(), String::new(), index.to_string()),
+    };
+    let (start, end) =
+        activity.time.as_ref().map_or((String::new(), String::new()), |time| (time.start.clone(), time.end.clone()));
+
+    vec![
+        escape(&tour.vehicle_id),
+        escape(&tour.type_id),
+        tour.shift_index.to_string(),
+        stop_idx.to_string(),
+        escape(&activity.job_id),
+        escape(&activity.activity_type),
+        activity.job_tag.as_ref().map_or_else(String::new, |tag| escape(tag)),
+        lat,
+        lng,
+        index,
+        stop.time.arrival.clone(),
+        stop.time.departure.clone(),
+        start,
+        end,
+        stop.load.iter().map(|load| load.to_string()).collect::<Vec<_>>().join(" "),
+        stop.distance.to_string(),
+    ]
+}
+
+/// Quotes value if it contains characters which have special meaning in csv.
+fn escape(value: &str) -> String {
+    if value.contains(|c| c == ',' || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
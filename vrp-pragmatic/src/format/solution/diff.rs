@@ -0,0 +1,336 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/diff_test.rs"]
+mod diff_test;
+
+use crate::format::solution::{is_job_activity, Solution, Statistic, Tour};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::Sub;
+
+/// Specifies a change of some value between two solutions.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct ValueChange<T> {
+    /// A value in original solution.
+    pub before: T,
+    /// A value in new solution.
+    pub after: T,
+    /// A difference between new and original value.
+    pub delta: T,
+}
+
+/// Specifies change of statistic between two solutions.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct StatisticChange {
+    /// Total cost change.
+    pub cost: ValueChange<f64>,
+    /// Total distance change.
+    pub distance: ValueChange<i64>,
+    /// Total duration change.
+    pub duration: ValueChange<i64>,
+}
+
+/// Identifies a tour in the solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourRef {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+}
+
+/// Specifies how the tour is changed.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum TourStatus {
+    /// Tour is present only in new solution.
+    Added,
+    /// Tour is present only in original solution.
+    Removed,
+    /// Tour is present in both solutions, but its content is changed.
+    Changed,
+}
+
+/// Specifies difference between the same tour in two solutions.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourDiff {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Tour status.
+    pub status: TourStatus,
+    /// Jobs which are served by tour only in new solution.
+    pub added_jobs: Vec<String>,
+    /// Jobs which are served by tour only in original solution.
+    pub removed_jobs: Vec<String>,
+    /// True if jobs served in both solutions are visited in different order.
+    pub reordered: bool,
+    /// Tour statistic change.
+    pub statistic: StatisticChange,
+}
+
+/// Specifies a job which is served by different tours in two solutions.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JobMove {
+    /// Job id.
+    pub job_id: String,
+    /// Tours which serve the job in original solution.
+    pub from: Vec<TourRef>,
+    /// Tours which serve the job in new solution.
+    pub to: Vec<TourRef>,
+}
+
+/// Specifies difference between two solutions of the same problem.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionDiff {
+    /// Total statistic change.
+    pub statistic: StatisticChange,
+    /// Changed tours.
+    pub tours: Vec<TourDiff>,
+    /// Jobs moved between tours.
+    pub moved_jobs: Vec<JobMove>,
+    /// Jobs which are assigned in original solution, but unassigned in new one.
+    pub newly_unassigned: Vec<String>,
+    /// Jobs which are unassigned in original solution, but assigned in new one.
+    pub newly_assigned: Vec<String>,
+}
+
+impl SolutionDiff {
+    /// Returns true if there is no difference between solutions.
+    pub fn is_empty(&self) -> bool {
+        self.tours.is_empty()
+            && self.moved_jobs.is_empty()
+            && self.newly_unassigned.is_empty()
+            && self.newly_assigned.is_empty()
+            && self.statistic.is_empty()
+    }
+}
+
+impl StatisticChange {
+    fn new(before: Option<&Statistic>, after: Option<&Statistic>) -> Self {
+        let get = |statistic: Option<&Statistic>| {
+            statistic.map_or((0., 0, 0), |statistic| (statistic.cost, statistic.distance, statistic.duration))
+        };
+        let (before, after) = (get(before), get(after));
+
+        Self {
+            cost: ValueChange::new(before.0, after.0),
+            distance: ValueChange::new(before.1, after.1),
+            duration: ValueChange::new(before.2, after.2),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.cost.delta.abs() < std::f64::EPSILON && self.distance.delta == 0 && self.duration.delta == 0
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> ValueChange<T> {
+    fn new(before: T, after: T) -> Self {
+        Self { before, after, delta: after - before }
+    }
+}
+
+/// Compares two pragmatic solutions of the same problem.
+pub fn compare_solutions(original: &Solution, new: &Solution) -> SolutionDiff {
+    let original_tours = get_tours(original);
+    let new_tours = get_tours(new);
+
+    let mut tour_refs = original_tours.keys().chain(new_tours.keys()).cloned().collect::<Vec<_>>();
+    tour_refs.sort();
+    tour_refs.dedup();
+
+    let tours = tour_refs
+        .iter()
+        .filter_map(|tour_ref| compare_tours(tour_ref, original_tours.get(tour_ref), new_tours.get(tour_ref)))
+        .collect();
+
+    let original_jobs = get_job_tours(original);
+    let new_jobs = get_job_tours(new);
+
+    let mut moved_jobs = original_jobs
+        .iter()
+        .filter_map(|(job_id, from)| {
+            new_jobs.get(job_id).filter(|to| *to != from).map(|to| JobMove {
+                job_id: job_id.clone(),
+                from: from.clone(),
+                to: to.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+    moved_jobs.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    let original_unassigned = get_unassigned(original);
+    let new_unassigned = get_unassigned(new);
+
+    SolutionDiff {
+        statistic: StatisticChange::new(Some(&original.statistic), Some(&new.statistic)),
+        tours,
+        moved_jobs,
+        newly_unassigned: get_sorted_difference(&new_unassigned, &original_unassigned),
+        newly_assigned: get_sorted_difference(&original_unassigned, &new_unassigned),
+    }
+}
+
+fn compare_tours(tour_ref: &TourRef, original: Option<&&Tour>, new: Option<&&Tour>) -> Option<TourDiff> {
+    let original_sequence = original.map_or_else(Vec::new, |tour| get_job_sequence(tour));
+    let new_sequence = new.map_or_else(Vec::new, |tour| get_job_sequence(tour));
+
+    let original_jobs = original_sequence.iter().map(|(job_id, _, _)| job_id.clone()).collect::<HashSet<_>>();
+    let new_jobs = new_sequence.iter().map(|(job_id, _, _)| job_id.clone()).collect::<HashSet<_>>();
+
+    let added_jobs = get_sorted_difference(&new_jobs, &original_jobs);
+    let removed_jobs = get_sorted_difference(&original_jobs, &new_jobs);
+
+    let get_common = |sequence: Vec<(String, String, Option<String>)>, other: &HashSet<String>| {
+        sequence.into_iter().filter(|(job_id, _, _)| other.contains(job_id)).collect::<Vec<_>>()
+    };
+    let reordered = get_common(original_sequence, &new_jobs) != get_common(new_sequence, &original_jobs);
+
+    let statistic = StatisticChange::new(original.map(|tour| &tour.statistic), new.map(|tour| &tour.statistic));
+
+    let status = match (original, new) {
+        (None, Some(_)) => TourStatus::Added,
+        (Some(_), None) => TourStatus::Removed,
+        _ if added_jobs.is_empty() && removed_jobs.is_empty() && !reordered && statistic.is_empty() => return None,
+        _ => TourStatus::Changed,
+    };
+
+    Some(TourDiff {
+        vehicle_id: tour_ref.vehicle_id.clone(),
+        shift_index: tour_ref.shift_index,
+        status,
+        added_jobs,
+        removed_jobs,
+        reordered,
+        statistic,
+    })
+}
+
+fn get_tours(solution: &Solution) -> HashMap<TourRef, &Tour> {
+    solution
+        .tours
+        .iter()
+        .map(|tour| (TourRef { vehicle_id: tour.vehicle_id.clone(), shift_index: tour.shift_index }, tour))
+        .collect()
+}
+
+/// Returns job activities of the tour as a sequence of job id, activity type and tag.
+fn get_job_sequence(tour: &Tour) -> Vec<(String, String, Option<String>)> {
+    tour.stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| is_job_activity(activity))
+        .map(|activity| (activity.job_id.clone(), activity.activity_type.clone(), activity.job_tag.clone()))
+        .collect()
+}
+
+fn get_job_tours(solution: &Solution) -> HashMap<String, Vec<TourRef>> {
+    let mut job_tours = solution.tours.iter().fold(HashMap::<String, Vec<TourRef>>::new(), |mut acc, tour| {
+        get_job_sequence(tour).into_iter().for_each(|(job_id, _, _)| {
+            acc.entry(job_id)
+                .or_insert_with(Vec::new)
+                .push(TourRef { vehicle_id: tour.vehicle_id.clone(), shift_index: tour.shift_index })
+        });
+        acc
+    });
+
+    job_tours.values_mut().for_each(|tours| {
+        tours.sort();
+        tours.dedup();
+    });
+
+    job_tours
+}
+
+fn get_sorted_difference(jobs: &HashSet<String>, other: &HashSet<String>) -> Vec<String> {
+    let mut jobs = jobs.difference(other).cloned().collect::<Vec<_>>();
+    jobs.sort();
+
+    jobs
+}
+
+fn get_unassigned(solution: &Solution) -> HashSet<String> {
+    solution.unassigned.iter().flat_map(|jobs| jobs.iter().map(|job| job.job_id.clone())).collect()
+}
+
+impl Display for TourRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} (shift {})", self.vehicle_id, self.shift_index)
+    }
+}
+
+impl Display for StatisticChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "cost: {:.2} -> {:.2} ({:+.2}), distance: {} -> {} ({:+}), duration: {} -> {} ({:+})",
+            self.cost.before,
+            self.cost.after,
+            self.cost.delta,
+            self.distance.before,
+            self.distance.after,
+            self.distance.delta,
+            self.duration.before,
+            self.duration.after,
+            self.duration.delta
+        )
+    }
+}
+
+impl Display for SolutionDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let join = |items: &[String]| items.join(", ");
+        let join_tours = |tours: &[TourRef]| tours.iter().map(|tour| tour.to_string()).collect::<Vec<_>>().join(", ");
+
+        writeln!(f, "total {}", self.statistic)?;
+
+        if self.is_empty() {
+            return writeln!(f, "solutions are the same");
+        }
+
+        if !self.tours.is_empty() {
+            writeln!(f, "tours:")?;
+            for tour in self.tours.iter() {
+                let status = match tour.status {
+                    TourStatus::Added => "added",
+                    TourStatus::Removed => "removed",
+                    TourStatus::Changed => "changed",
+                };
+                writeln!(f, "  {} (shift {}): {}", tour.vehicle_id, tour.shift_index, status)?;
+                if !tour.added_jobs.is_empty() {
+                    writeln!(f, "    added jobs: {}", join(&tour.added_jobs))?;
+                }
+                if !tour.removed_jobs.is_empty() {
+                    writeln!(f, "    removed jobs: {}", join(&tour.removed_jobs))?;
+                }
+                if tour.reordered {
+                    writeln!(f, "    jobs are reordered")?;
+                }
+                writeln!(f, "    {}", tour.statistic)?;
+            }
+        }
+
+        if !self.moved_jobs.is_empty() {
+            writeln!(f, "moved jobs:")?;
+            for job in self.moved_jobs.iter() {
+                writeln!(f, "  {}: {} -> {}", job.job_id, join_tours(&job.from), join_tours(&job.to))?;
+            }
+        }
+
+        if !self.newly_unassigned.is_empty() {
+            writeln!(f, "newly unassigned jobs: {}", join(&self.newly_unassigned))?;
+        }
+
+        if !self.newly_assigned.is_empty() {
+            writeln!(f, "newly assigned jobs: {}", join(&self.newly_assigned))?;
+        }
+
+        Ok(())
+    }
+}
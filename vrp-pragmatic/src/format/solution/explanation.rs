@@ -0,0 +1,180 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/explanation_test.rs"]
+mod explanation_test;
+
+use crate::format::solution::writer::map_code_reason;
+use crate::format::solution::{Solution as ApiSolution, UnassignedJobDetail, UnassignedJobReason};
+use crate::format::{get_job_index, get_split_jobs};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::*;
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::models::problem::Job;
+use vrp_core::models::solution::{Activity, Place};
+use vrp_core::models::{Problem, Solution};
+use vrp_core::utils::DefaultRandom;
+
+/// Explains why jobs are unassigned: each unassigned job of the solution is evaluated against every
+/// vehicle shift and all violated constraints are reported per vehicle shift. Unassigned parts of
+/// split job are explained together.
+pub fn explain_unassigned_jobs(problem: Arc<Problem>, core_solution: Solution, solution: ApiSolution) -> ApiSolution {
+    let job_index = get_job_index(problem.as_ref());
+    let ctx =
+        InsertionContext::new_from_solution(problem.clone(), (core_solution, None), Arc::new(DefaultRandom::default()));
+
+    let unassigned = solution.unassigned.map(|unassigned| {
+        unassigned
+            .into_iter()
+            .map(|mut unassigned_job| {
+                let jobs = job_index.get(&unassigned_job.job_id).map_or_else(
+                    || {
+                        get_split_jobs(job_index, unassigned_job.job_id.as_str())
+                            .filter(|job| {
+                                ctx.solution.routes.iter().all(|route_ctx| !route_ctx.route.tour.contains(job))
+                            })
+                            .collect::<Vec<_>>()
+                    },
+                    |job| vec![job],
+                );
+
+                unassigned_job.details = if jobs.is_empty() {
+                    None
+                } else {
+                    Some(jobs.into_iter().fold(Vec::new(), |acc, job| merge_details(acc, get_details(&ctx, job))))
+                };
+
+                unassigned_job
+            })
+            .collect()
+    });
+
+    ApiSolution { unassigned, ..solution }
+}
+
+fn get_details(ctx: &InsertionContext, job: &Job) -> Vec<UnassignedJobDetail> {
+    let constraint = &ctx.problem.constraint;
+
+    let mut details = ctx
+        .solution
+        .routes
+        .iter()
+        .cloned()
+        .chain(ctx.solution.registry.available())
+        .map(|route_ctx| {
+            let violations = constraint.evaluate_hard_route_violations(&ctx.solution, &route_ctx, job);
+
+            let codes = if violations.is_empty() {
+                match evaluate_job_insertion_in_route(job, ctx, &route_ctx, InsertionPosition::Any, None) {
+                    InsertionResult::Success(_) => vec![],
+                    InsertionResult::Failure(failure) => {
+                        let codes = get_activity_violation_codes(ctx, &route_ctx, job);
+                        // NOTE insertion can fail even when each activity fits somewhere, e.g. due to their order
+                        if codes.is_empty() {
+                            vec![failure.constraint]
+                        } else {
+                            codes
+                        }
+                    }
+                }
+            } else {
+                violations.into_iter().map(|violation| violation.code).collect()
+            };
+
+            let dimens = &route_ctx.route.actor.vehicle.dimens;
+
+            UnassignedJobDetail {
+                vehicle_id: dimens.get_id().cloned().expect("cannot get vehicle id"),
+                shift_index: dimens.get_value::<usize>("shift_index").cloned().expect("cannot get shift index"),
+                reasons: add_reasons(
+                    Vec::new(),
+                    codes
+                        .into_iter()
+                        .map(map_code_reason)
+                        .map(|(code, description)| UnassignedJobReason { code, description: description.to_string() }),
+                ),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    details.sort_by(|a, b| a.vehicle_id.cmp(&b.vehicle_id).then(a.shift_index.cmp(&b.shift_index)));
+
+    details
+}
+
+/// Returns codes of all hard activity constraints violated by job activities at every position of the route.
+fn get_activity_violation_codes(ctx: &InsertionContext, route_ctx: &RouteContext, job: &Job) -> Vec<i32> {
+    let constraint = &ctx.problem.constraint;
+    let tour = &route_ctx.route.tour;
+    let start_time = tour.start().map_or(0., |start| start.schedule.departure);
+    let singles = match job {
+        Job::Single(single) => vec![single.clone()],
+        Job::Multi(multi) => multi.jobs.clone(),
+    };
+
+    let mut codes = Vec::new();
+    singles.into_iter().for_each(|single| {
+        let mut target = Activity::new_with_job(single.clone());
+        tour.legs().for_each(|(items, index)| {
+            let (prev, next) = match items {
+                [prev] => (prev, None),
+                [prev, next] => (prev, Some(next)),
+                _ => return,
+            };
+
+            single.places.iter().for_each(|place| {
+                place.times.iter().for_each(|time| {
+                    target.place = Place {
+                        location: place.location.unwrap_or(prev.place.location),
+                        duration: place.duration,
+                        time: time.to_time_window(start_time),
+                    };
+
+                    let activity_ctx = ActivityContext { index, prev, target: &target, next };
+                    constraint.evaluate_hard_activity_violations(route_ctx, &activity_ctx).into_iter().for_each(
+                        |violation| {
+                            if !codes.contains(&violation.code) {
+                                codes.push(violation.code);
+                            }
+                        },
+                    );
+                });
+            });
+        });
+    });
+
+    codes
+}
+
+/// Merges details of split job parts by vehicle shift.
+fn merge_details(acc: Vec<UnassignedJobDetail>, details: Vec<UnassignedJobDetail>) -> Vec<UnassignedJobDetail> {
+    if acc.is_empty() {
+        return details;
+    }
+
+    acc.into_iter()
+        .map(|detail| {
+            let other = details
+                .iter()
+                .find(|other| other.vehicle_id == detail.vehicle_id && other.shift_index == detail.shift_index);
+
+            match other {
+                Some(other) => UnassignedJobDetail {
+                    reasons: add_reasons(detail.reasons, other.reasons.iter().cloned()),
+                    ..detail
+                },
+                None => detail,
+            }
+        })
+        .collect()
+}
+
+fn add_reasons<I>(reasons: Vec<UnassignedJobReason>, others: I) -> Vec<UnassignedJobReason>
+where
+    I: Iterator<Item = UnassignedJobReason>,
+{
+    others.fold(reasons, |mut acc, reason| {
+        if acc.iter().all(|existing| existing.code != reason.code) {
+            acc.push(reason);
+        }
+        acc
+    })
+}
@@ -29,15 +29,17 @@ fn slice_to_map(vec: &[(&str, &str)]) -> HashMap<String, String> {
     vec.iter().map(|&(key, value)| (key.to_string(), value.to_string())).collect()
 }
 
-fn get_marker_symbol(stop: &Stop) -> String {
+pub(super) fn get_marker_symbol(stop: &Stop) -> String {
     let contains_activity_type =
         |activity_type: &&str| stop.activities.iter().any(|activity| activity.activity_type == *activity_type);
     match (
         ["departure", "depot", "reload", "arrival"].iter().any(contains_activity_type),
         contains_activity_type(&"break"),
+        contains_activity_type(&"charging"),
     ) {
-        (true, _) => "warehouse",
-        (_, true) => "beer",
+        (true, _, _) => "warehouse",
+        (_, true, _) => "beer",
+        (_, _, true) => "fuel",
         _ => "marker",
     }
     .to_string()
@@ -105,7 +107,7 @@ pub fn serialize_solution_as_geojson<W: Write>(writer: BufWriter<W>, solution: &
     .map_err(Error::from)
 }
 
-fn get_color(idx: usize) -> String {
+pub(super) fn get_color(idx: usize) -> String {
     static COLOR_LIST: ColorList = get_color_list();
 
     let idx = idx % COLOR_LIST.len();
@@ -113,7 +115,7 @@ fn get_color(idx: usize) -> String {
     (**COLOR_LIST.get(idx).as_ref().unwrap()).to_string()
 }
 
-fn get_color_inverse(idx: usize) -> String {
+pub(super) fn get_color_inverse(idx: usize) -> String {
     static COLOR_LIST: ColorList = get_color_list();
 
     let idx = (COLOR_LIST.len() - idx) % COLOR_LIST.len();
@@ -4,7 +4,7 @@ mod initial_reader_test;
 
 use crate::format::solution::activity_matcher::{try_match_job, JobInfo};
 use crate::format::solution::deserialize_solution;
-use crate::format::{get_coord_index, get_job_index, CoordIndex, JobIndex};
+use crate::format::{get_coord_index, get_job_index, get_split_jobs, is_split_job, CoordIndex, JobIndex};
 use crate::parse_time;
 use std::collections::{HashMap, HashSet};
 use std::io::{BufReader, Read};
@@ -56,18 +56,28 @@ pub fn read_init_solution<R: Read>(solution: BufReader<R>, problem: Arc<Problem>
     let mut unassigned = solution.unassigned.unwrap_or_default().iter().try_fold::<Vec<_>, _, Result<_, String>>(
         Default::default(),
         |mut acc, unassigned_job| {
-            let job = job_index
-                .get(&unassigned_job.job_id)
-                .cloned()
-                .ok_or_else(|| format!("cannot get job id for: {:?}", unassigned_job))?;
+            let jobs = match job_index.get(&unassigned_job.job_id) {
+                Some(job) => vec![job.clone()],
+                None => get_split_jobs(job_index, unassigned_job.job_id.as_str())
+                    .filter(|job| !added_jobs.contains(*job))
+                    .cloned()
+                    .collect(),
+            };
+
+            if jobs.is_empty() && get_split_jobs(job_index, unassigned_job.job_id.as_str()).next().is_none() {
+                return Err(format!("cannot get job id for: {:?}", unassigned_job));
+            }
+
             let code = unassigned_job
                 .reasons
                 .first()
                 .map(|reason| reason.code)
                 .ok_or_else(|| format!("cannot get reason for: {:?}", unassigned_job))?;
 
-            added_jobs.insert(job.clone());
-            acc.push((job, code));
+            jobs.into_iter().for_each(|job| {
+                added_jobs.insert(job.clone());
+                acc.push((job, code));
+            });
 
             Ok(acc)
         },
@@ -88,6 +98,18 @@ fn try_insert_activity(
     added_jobs: &mut HashSet<Job>,
 ) -> Result<(), String> {
     if let Some(JobInfo(job, single, place, time)) = try_match_job(tour, stop, activity, job_index, coord_index)? {
+        let (job, single) = if is_split_job(&single.dimens) {
+            let job = get_split_jobs(job_index, activity.job_id.as_str())
+                .find(|job| !added_jobs.contains(*job))
+                .cloned()
+                .ok_or_else(|| format!("too many visits of split job: '{}'", activity.job_id))?;
+            let single = job.to_single().clone();
+
+            (job, single)
+        } else {
+            (job, single)
+        };
+
         added_jobs.insert(job);
         try_insert_new_activity(route, single, place, time)?;
     }
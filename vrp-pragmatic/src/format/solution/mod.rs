@@ -5,12 +5,30 @@ pub use self::model::*;
 
 pub(crate) mod activity_matcher;
 
+mod csv_serializer;
+pub use self::csv_serializer::serialize_solution_as_csv;
+
 mod geo_serializer;
 pub use self::geo_serializer::serialize_solution_as_geojson;
 
+mod report_serializer;
+pub use self::report_serializer::{serialize_solution_as_html, serialize_solution_as_svg};
+
 mod initial_reader;
 pub use self::initial_reader::read_init_solution;
 
+mod analytics;
+pub use self::analytics::*;
+
+mod acceptance;
+pub use self::acceptance::*;
+
+mod diff;
+pub use self::diff::*;
+
+mod explanation;
+pub use self::explanation::explain_unassigned_jobs;
+
 mod extensions;
 
 mod writer;
@@ -1,5 +1,6 @@
 use crate::format::Location;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::HashMap;
 use std::io::{BufReader, BufWriter, Error, Read, Write};
 
 /// Timing statistic.
@@ -27,6 +28,9 @@ pub struct Statistic {
     pub duration: i64,
     /// Timing statistic.
     pub times: Timing,
+    /// Total value of served jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
 }
 
 /// Represents a schedule.
@@ -65,6 +69,14 @@ pub struct Activity {
     /// Job tag.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub job_tag: Option<String>,
+    /// Demand served by this visit, specified only for split jobs.
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_optional_load")]
+    pub demand: Option<Vec<f64>>,
+}
+
+/// Checks whether activity serves a job, i.e. it is not a departure, arrival, break, etc.
+pub(crate) fn is_job_activity(activity: &Activity) -> bool {
+    matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement")
 }
 
 /// A stop is a place where vehicle is supposed to be parked.
@@ -77,9 +89,45 @@ pub struct Stop {
     /// Distance traveled since departure from start.
     pub distance: i64,
     /// Vehicle load after departure from this stop.
-    pub load: Vec<i32>,
+    #[serde(serialize_with = "serialize_load")]
+    pub load: Vec<f64>,
     /// Activities performed at the stop.
     pub activities: Vec<Activity>,
+    /// Vehicle load after departure from this stop keyed by dimension name. Present only when
+    /// capacity dimensions are declared in the problem.
+    #[serde(rename = "namedLoad", skip_serializing_if = "Option::is_none", serialize_with = "serialize_named_load")]
+    pub named_load: Option<HashMap<String, f64>>,
+}
+
+/// A load value which is serialized as integer when it has no fractional part.
+struct LoadValue(f64);
+
+impl Serialize for LoadValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.fract() == 0. && self.0.abs() < i64::MAX as f64 {
+            serializer.serialize_i64(self.0 as i64)
+        } else {
+            serializer.serialize_f64(self.0)
+        }
+    }
+}
+
+fn serialize_load<S: Serializer>(load: &[f64], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(load.iter().map(|value| LoadValue(*value)))
+}
+
+fn serialize_optional_load<S: Serializer>(load: &Option<Vec<f64>>, serializer: S) -> Result<S::Ok, S::Error> {
+    match load {
+        Some(load) => serialize_load(load.as_slice(), serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn serialize_named_load<S: Serializer>(load: &Option<HashMap<String, f64>>, serializer: S) -> Result<S::Ok, S::Error> {
+    match load {
+        Some(load) => serializer.collect_map(load.iter().map(|(name, value)| (name, LoadValue(*value)))),
+        None => serializer.serialize_none(),
+    }
 }
 
 /// A tour is list of stops with their activities performed by specific vehicle.
@@ -115,6 +163,21 @@ pub struct UnassignedJob {
     pub job_id: String,
     /// Possible reasons.
     pub reasons: Vec<UnassignedJobReason>,
+    /// Reasons per vehicle shift, available only when explanation is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<Vec<UnassignedJobDetail>>,
+}
+
+/// Specifies why job cannot be assigned to specific vehicle shift.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UnassignedJobDetail {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// Violated constraints. Empty list means that job can be inserted into vehicle tour.
+    pub reasons: Vec<UnassignedJobReason>,
 }
 
 /// Specifies a type of violation.
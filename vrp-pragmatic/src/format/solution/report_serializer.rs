@@ -0,0 +1,458 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/report_serializer_test.rs"]
+mod report_serializer_test;
+
+use super::analytics::{get_tasks, is_same_location};
+use super::geo_serializer::{get_color, get_color_inverse, get_marker_symbol};
+use super::Solution;
+use crate::format::problem::{Job, Problem, VehicleType};
+use crate::format::solution::{Activity, Stop, Tour};
+use crate::format::Location;
+use crate::{format_time, parse_time_safe};
+use std::collections::HashMap;
+use std::fmt::Write as FmtWrite;
+use std::io::{BufWriter, Error, ErrorKind, Write};
+
+const WIDTH: f64 = 960.;
+const PADDING: f64 = 20.;
+const MAP_MAX_HEIGHT: f64 = 640.;
+const LABEL_WIDTH: f64 = 160.;
+const AXIS_HEIGHT: f64 = 30.;
+const ROW_HEIGHT: f64 = 24.;
+const WAITING_COLOR: &str = "#c0c0c0";
+const BREAK_COLOR: &str = "#404040";
+const UNASSIGNED_COLOR: &str = "#808080";
+
+/// Keeps svg elements of a report part together with its size.
+struct SvgPart {
+    height: f64,
+    content: String,
+}
+
+/// Serializes solution as a self-contained svg image with tour map and tour timeline.
+pub fn serialize_solution_as_svg<W: Write>(
+    mut writer: BufWriter<W>,
+    problem: &Problem,
+    solution: &Solution,
+) -> Result<(), Error> {
+    let parts = [create_map(problem, solution), create_timeline(problem, solution)?];
+    let height = parts.iter().map(|part| part.height).sum::<f64>();
+
+    writeln!(writer, "{}", get_svg_header(height))?;
+    parts.iter().try_fold(0., |offset, part| {
+        writeln!(writer, "<g transform=\"translate(0,{:.1})\">\n{}</g>", offset, part.content)?;
+        Ok::<_, Error>(offset + part.height)
+    })?;
+    writeln!(writer, "</svg>")?;
+
+    writer.flush()
+}
+
+/// Serializes solution as a self-contained html page with solution statistic, tour map and tour timeline.
+pub fn serialize_solution_as_html<W: Write>(
+    mut writer: BufWriter<W>,
+    problem: &Problem,
+    solution: &Solution,
+) -> Result<(), Error> {
+    let statistic = &solution.statistic;
+    let unassigned = solution.unassigned.as_ref().map_or(0, |unassigned| unassigned.len());
+
+    writeln!(
+        writer,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Solution report</title>\n<style>\
+         body {{ font-family: sans-serif; margin: 20px; }} td, th {{ padding: 2px 12px; text-align: left; }}\
+         </style>\n</head>\n<body>\n<h1>Solution report</h1>"
+    )?;
+    writeln!(
+        writer,
+        "<table>\n<tr><th>cost</th><th>distance</th><th>duration</th><th>tours</th><th>unassigned</th></tr>\n\
+         <tr><td>{:.2}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n</table>",
+        statistic.cost,
+        statistic.distance,
+        statistic.duration,
+        solution.tours.len(),
+        unassigned
+    )?;
+
+    [("Map", create_map(problem, solution)), ("Timeline", create_timeline(problem, solution)?)].iter().try_for_each(
+        |(title, part)| writeln!(writer, "<h2>{}</h2>\n{}\n{}</svg>", title, get_svg_header(part.height), part.content),
+    )?;
+
+    if let Some(unassigned) = solution.unassigned.as_ref().filter(|unassigned| !unassigned.is_empty()) {
+        writeln!(writer, "<h2>Unassigned jobs</h2>\n<table>\n<tr><th>job id</th><th>reasons</th></tr>")?;
+        unassigned.iter().try_for_each(|job| {
+            let reasons = job.reasons.iter().map(|reason| reason.description.as_str()).collect::<Vec<_>>().join("; ");
+            writeln!(writer, "<tr><td>{}</td><td>{}</td></tr>", escape(&job.job_id), escape(&reasons))
+        })?;
+        writeln!(writer, "</table>")?;
+    }
+
+    writeln!(writer, "</body>\n</html>")?;
+
+    writer.flush()
+}
+
+fn get_svg_header(height: f64) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h:.1}\" viewBox=\"0 0 {w} {h:.1}\" \
+         font-family=\"sans-serif\" font-size=\"12\">",
+        w = WIDTH,
+        h = height
+    )
+}
+
+/// Creates map with tour polylines and stop markers. Locations are projected using equirectangular
+/// projection, so no external map tiles are needed.
+fn create_map(problem: &Problem, solution: &Solution) -> SvgPart {
+    let stop_coordinates =
+        solution.tours.iter().flat_map(|tour| tour.stops.iter()).map(|stop| get_lat_lng(&stop.location));
+    let unassigned_jobs = get_unassigned_jobs(problem, solution);
+    let unassigned_coordinates = unassigned_jobs.iter().flat_map(|(_, locations)| locations.iter().cloned());
+
+    let coordinates = match stop_coordinates.chain(unassigned_coordinates.map(Some)).collect::<Option<Vec<_>>>() {
+        Some(coordinates) if !coordinates.is_empty() => coordinates,
+        Some(_) => return create_message("no locations to show"),
+        None => return create_message("map cannot be used with location indices"),
+    };
+
+    let projection = Projection::new(coordinates.as_slice());
+    let mut content = String::new();
+
+    solution.tours.iter().enumerate().for_each(|(tour_idx, tour)| {
+        let points = tour
+            .stops
+            .iter()
+            .filter_map(|stop| get_lat_lng(&stop.location))
+            .map(|coordinate| projection.project(coordinate))
+            .map(|(x, y)| format!("{:.1},{:.1}", x, y))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let _ = writeln!(
+            content,
+            "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"3\" stroke-opacity=\"0.8\">\
+             <title>{}, shift {}, distance {}</title></polyline>",
+            points,
+            get_color(tour_idx),
+            escape(&tour.vehicle_id),
+            tour.shift_index,
+            tour.stops.last().map_or(0, |stop| stop.distance)
+        );
+    });
+
+    solution.tours.iter().enumerate().for_each(|(tour_idx, tour)| {
+        tour.stops.iter().enumerate().for_each(|(stop_idx, stop)| {
+            if let Some(coordinate) = get_lat_lng(&stop.location) {
+                let title = format!(
+                    "{}, stop {}&#10;{} - {}&#10;{}",
+                    escape(&tour.vehicle_id),
+                    stop_idx,
+                    stop.time.arrival,
+                    stop.time.departure,
+                    escape(&stop.activities.iter().map(|a| a.job_id.clone()).collect::<Vec<_>>().join(","))
+                );
+                let marker = get_marker(
+                    get_marker_symbol(stop).as_str(),
+                    projection.project(coordinate),
+                    get_color_inverse(tour_idx).as_str(),
+                );
+
+                let _ = writeln!(content, "<g>{}<title>{}</title></g>", marker, title);
+            }
+        });
+    });
+
+    unassigned_jobs.iter().for_each(|(job_id, locations)| {
+        locations.iter().for_each(|coordinate| {
+            let (x, y) = projection.project(*coordinate);
+            let _ = writeln!(
+                content,
+                "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"5\" fill=\"white\" stroke=\"{}\" stroke-width=\"2\">\
+                 <title>unassigned: {}</title></circle>",
+                x,
+                y,
+                UNASSIGNED_COLOR,
+                escape(job_id)
+            );
+        });
+    });
+
+    SvgPart { height: projection.height, content }
+}
+
+/// Returns svg shape for the marker symbol used in geojson output.
+fn get_marker(symbol: &str, (x, y): (f64, f64), color: &str) -> String {
+    match symbol {
+        "warehouse" => {
+            format!(
+                "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"12\" height=\"12\" fill=\"{}\" stroke=\"black\"/>",
+                x - 6.,
+                y - 6.,
+                color
+            )
+        }
+        "beer" => format!(
+            "<polygon points=\"{:.1},{:.1} {:.1},{:.1} {:.1},{:.1}\" fill=\"{}\" stroke=\"black\"/>",
+            x,
+            y - 7.,
+            x - 6.,
+            y + 5.,
+            x + 6.,
+            y + 5.,
+            color
+        ),
+        "fuel" => format!(
+            "<polygon points=\"{:.1},{:.1} {:.1},{:.1} {:.1},{:.1} {:.1},{:.1}\" fill=\"{}\" stroke=\"black\"/>",
+            x,
+            y - 7.,
+            x + 7.,
+            y,
+            x,
+            y + 7.,
+            x - 7.,
+            y,
+            color
+        ),
+        _ => format!("<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"5\" fill=\"{}\" stroke=\"black\"/>", x, y, color),
+    }
+}
+
+/// Creates Gantt-style timeline: one row per tour with driving, waiting, service and break intervals.
+fn create_timeline(problem: &Problem, solution: &Solution) -> Result<SvgPart, Error> {
+    let times = solution
+        .tours
+        .iter()
+        .filter_map(|tour| tour.stops.first().zip(tour.stops.last()))
+        .map(|(first, last)| Ok((parse_time(&first.time.arrival)?, parse_time(&last.time.departure)?)))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    if times.is_empty() {
+        return Ok(create_message("no tours to show"));
+    }
+
+    let start = times.iter().map(|(start, _)| *start).fold(f64::MAX, f64::min);
+    let end = times.iter().map(|(_, end)| *end).fold(f64::MIN, f64::max);
+    let scale = (WIDTH - LABEL_WIDTH - 2. * PADDING) / (end - start).max(1.);
+    let get_x = |time: f64| LABEL_WIDTH + PADDING + (time - start) * scale;
+
+    let vehicle_types =
+        problem.fleet.vehicles.iter().map(|vehicle| (vehicle.type_id.as_str(), vehicle)).collect::<HashMap<_, _>>();
+    let jobs = problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
+
+    let mut content = String::new();
+    let height = AXIS_HEIGHT + solution.tours.len() as f64 * ROW_HEIGHT + PADDING;
+
+    let step = get_time_step(end - start);
+    let mut tick = (start / step).ceil() * step;
+    while tick <= end {
+        let x = get_x(tick);
+        let _ = writeln!(
+            content,
+            "<line x1=\"{x:.1}\" y1=\"{y1}\" x2=\"{x:.1}\" y2=\"{y2:.1}\" stroke=\"#e0e0e0\"/>\
+             <text x=\"{x:.1}\" y=\"{y1}\" text-anchor=\"middle\">{label}</text>",
+            x = x,
+            y1 = AXIS_HEIGHT - 10.,
+            y2 = height - PADDING,
+            label = format_time(tick).get(11..16).unwrap_or_default()
+        );
+        tick += step;
+    }
+
+    let get_rect = |from: f64, to: f64, y: f64, bar_height: f64, style: &str, title: &str| {
+        format!(
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" {}><title>{}</title></rect>",
+            get_x(from),
+            y + (ROW_HEIGHT - bar_height) / 2.,
+            ((to - from) * scale).max(1.),
+            bar_height,
+            style,
+            title
+        )
+    };
+
+    solution.tours.iter().enumerate().try_for_each(|(tour_idx, tour)| {
+        let y = AXIS_HEIGHT + tour_idx as f64 * ROW_HEIGHT;
+        let color = get_color(tour_idx);
+        let vehicle_type = vehicle_types.get(tour.type_id.as_str()).cloned();
+
+        let _ = writeln!(
+            content,
+            "<text x=\"{:.1}\" y=\"{:.1}\" fill=\"{}\">{}</text>",
+            PADDING,
+            y + ROW_HEIGHT / 2. + 4.,
+            color,
+            escape(&tour.vehicle_id)
+        );
+
+        tour.stops.windows(2).try_for_each(|stops| {
+            let (from, to) = (parse_time(&stops[0].time.departure)?, parse_time(&stops[1].time.arrival)?);
+            if to > from {
+                let style = format!("fill=\"{}\" fill-opacity=\"0.35\"", color);
+                let _ = writeln!(content, "{}", get_rect(from, to, y, 6., style.as_str(), "driving"));
+            }
+
+            Ok::<_, Error>(())
+        })?;
+
+        tour.stops.iter().try_for_each(|stop| {
+            stop.activities.iter().try_for_each(|activity| {
+                let (from, to) = match activity.time.as_ref() {
+                    Some(time) => (parse_time(&time.start)?, parse_time(&time.end)?),
+                    None => (parse_time(&stop.time.arrival)?, parse_time(&stop.time.departure)?),
+                };
+                let service = get_service_duration(&jobs, vehicle_type, tour, stop, activity, to - from);
+                let service_start = to - service;
+
+                if service_start > from {
+                    let style = format!("fill=\"{}\"", WAITING_COLOR);
+                    let title = format!("waiting: {} min", ((service_start - from) / 60.).round());
+                    let _ = writeln!(content, "{}", get_rect(from, service_start, y, 14., style.as_str(), &title));
+                }
+
+                if to > service_start {
+                    let fill = if activity.activity_type == "break" { BREAK_COLOR } else { color.as_str() };
+                    let style = format!("fill=\"{}\" stroke=\"white\" stroke-width=\"0.5\"", fill);
+                    let title = format!(
+                        "{} ({})&#10;{} - {}",
+                        escape(&activity.job_id),
+                        escape(&activity.activity_type),
+                        format_time(service_start),
+                        format_time(to)
+                    );
+                    let _ = writeln!(content, "{}", get_rect(service_start, to, y, 14., style.as_str(), &title));
+                }
+
+                Ok::<_, Error>(())
+            })
+        })
+    })?;
+
+    Ok(SvgPart { height, content })
+}
+
+fn parse_time(time: &str) -> Result<f64, Error> {
+    parse_time_safe(time)
+        .map_err(|err| Error::new(ErrorKind::InvalidData, format!("cannot parse time '{}': {}", time, err)))
+}
+
+/// Returns activity service duration defined in the problem, the rest of activity time is waiting.
+fn get_service_duration(
+    jobs: &HashMap<&str, &Job>,
+    vehicle_type: Option<&VehicleType>,
+    tour: &Tour,
+    stop: &Stop,
+    activity: &Activity,
+    total: f64,
+) -> f64 {
+    let location = activity.location.as_ref().unwrap_or(&stop.location);
+
+    match activity.activity_type.as_str() {
+        "departure" | "arrival" => Some(0.),
+        "break" => vehicle_type
+            .and_then(|vehicle_type| vehicle_type.shifts.get(tour.shift_index))
+            .and_then(|shift| shift.breaks.as_ref())
+            .and_then(|breaks| {
+                breaks
+                    .iter()
+                    .map(|vehicle_break| vehicle_break.duration)
+                    .filter(|&duration| duration <= total)
+                    .fold(None, |acc: Option<f64>, duration| Some(acc.map_or(duration, |acc| acc.max(duration))))
+            }),
+        activity_type => jobs.get(activity.job_id.as_str()).and_then(|job| {
+            get_tasks(job, activity_type)
+                .filter(|task| activity.job_tag.is_none() || task.tag == activity.job_tag)
+                .flat_map(|task| task.places.iter())
+                .find(|place| is_same_location(&place.location, location))
+                .map(|place| place.duration)
+        }),
+    }
+    .map_or(total, |duration| duration.min(total))
+}
+
+/// Returns time step between timeline ticks which keeps amount of ticks reasonable.
+fn get_time_step(range: f64) -> f64 {
+    [60., 300., 900., 1800., 3600., 7200., 14400., 21600., 43200.]
+        .iter()
+        .cloned()
+        .find(|step| range / step <= 12.)
+        .unwrap_or(86400.)
+}
+
+/// Returns locations of unassigned jobs defined by coordinates.
+fn get_unassigned_jobs(problem: &Problem, solution: &Solution) -> Vec<(String, Vec<(f64, f64)>)> {
+    let jobs = problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
+
+    solution
+        .unassigned
+        .iter()
+        .flat_map(|unassigned| unassigned.iter())
+        .filter_map(|unassigned| jobs.get(unassigned.job_id.as_str()))
+        .map(|job| {
+            let locations = ["pickup", "delivery", "replacement", "service"]
+                .iter()
+                .flat_map(|activity_type| get_tasks(job, activity_type))
+                .flat_map(|task| task.places.iter())
+                .filter_map(|place| get_lat_lng(&place.location))
+                .collect();
+
+            (job.id.clone(), locations)
+        })
+        .collect()
+}
+
+fn create_message(message: &str) -> SvgPart {
+    SvgPart { height: 2. * PADDING, content: format!("<text x=\"{}\" y=\"{}\">{}</text>\n", PADDING, PADDING, message) }
+}
+
+fn get_lat_lng(location: &Location) -> Option<(f64, f64)> {
+    match location {
+        Location::Coordinate { lat, lng } => Some((*lat, *lng)),
+        Location::Reference { .. } => None,
+    }
+}
+
+/// Escapes characters which have special meaning in xml.
+fn escape(value: &str) -> String {
+    value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Projects coordinates into svg space keeping aspect ratio.
+struct Projection {
+    min: (f64, f64),
+    lng_ratio: f64,
+    scale: f64,
+    offset: f64,
+    height: f64,
+}
+
+impl Projection {
+    fn new(coordinates: &[(f64, f64)]) -> Self {
+        let (min_lat, max_lat) = get_range(coordinates.iter().map(|(lat, _)| *lat));
+        let (min_lng, max_lng) = get_range(coordinates.iter().map(|(_, lng)| *lng));
+        let lng_ratio = ((min_lat + max_lat) / 2.).to_radians().cos();
+
+        let (width, height) = ((max_lng - min_lng) * lng_ratio, max_lat - min_lat);
+        let scale = ((WIDTH - 2. * PADDING) / width.max(f64::EPSILON))
+            .min((MAP_MAX_HEIGHT - 2. * PADDING) / height.max(f64::EPSILON));
+        let (width, height) = (width * scale, height * scale);
+
+        Self {
+            min: (min_lat, min_lng),
+            lng_ratio,
+            scale,
+            offset: (WIDTH - 2. * PADDING - width) / 2.,
+            height: height + 2. * PADDING,
+        }
+    }
+
+    fn project(&self, (lat, lng): (f64, f64)) -> (f64, f64) {
+        let x = PADDING + self.offset + (lng - self.min.1) * self.lng_ratio * self.scale;
+        let y = self.height - PADDING - (lat - self.min.0) * self.scale;
+
+        (x, y)
+    }
+}
+
+fn get_range(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values.fold((f64::MAX, f64::MIN), |(min, max), value| (min.min(value), max.max(value)))
+}
@@ -3,12 +3,14 @@
 mod writer_test;
 
 use crate::format::coord_index::CoordIndex;
+use crate::format::problem::from_fixed_load;
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
 use crate::format::*;
 use crate::format_time;
+use std::collections::HashSet;
 use std::io::{BufWriter, Write};
-use vrp_core::construction::constraints::route_intervals;
+use vrp_core::construction::constraints::{route_intervals, ActorAreas};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::Multi;
 use vrp_core::models::solution::{Activity, Route};
@@ -32,6 +34,11 @@ pub trait PragmaticSolution<W: Write> {
 
     /// Serializes solution in pragmatic geo json format.
     fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
+
+    /// Serializes solution as csv with one row per activity.
+    fn write_csv(&self, _problem: &Problem, _writer: BufWriter<W>) -> Result<(), String> {
+        Err("csv output is not supported".to_string())
+    }
 }
 
 impl<W: Write> PragmaticSolution<W> for Solution {
@@ -46,6 +53,12 @@ impl<W: Write> PragmaticSolution<W> for Solution {
         serialize_solution_as_geojson(writer, &solution).map_err(|err| err.to_string())?;
         Ok(())
     }
+
+    fn write_csv(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        let solution = create_solution(problem, &self, None);
+        serialize_solution_as_csv(writer, &solution).map_err(|err| err.to_string())?;
+        Ok(())
+    }
 }
 
 impl<W: Write> PragmaticSolution<W> for (Solution, Metrics) {
@@ -58,6 +71,10 @@ impl<W: Write> PragmaticSolution<W> for (Solution, Metrics) {
     fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
         self.0.write_geo_json(problem, writer)
     }
+
+    fn write_csv(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        self.0.write_csv(problem, writer)
+    }
 }
 
 struct Leg {
@@ -94,6 +111,8 @@ pub fn create_solution(problem: &Problem, solution: &Solution, metrics: Option<&
 
 fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> Tour {
     let is_multi_dimen = has_multi_dimensional_capacity(problem.extras.as_ref());
+    let load_scale = get_load_scale(problem.extras.as_ref());
+    let to_load = |load: MultiDimLoad| from_fixed_load(load.as_vec().as_slice(), load_scale);
 
     let actor = route.actor.as_ref();
     let vehicle = actor.vehicle.as_ref();
@@ -106,6 +125,14 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
         statistic: Statistic::default(),
     };
 
+    let get_area_cost = |act: &Activity| {
+        vehicle
+            .dimens
+            .get_value::<ActorAreas>("areas")
+            .filter(|_| act.job.is_some())
+            .map_or(0., |areas| areas.get_cost(&coord_index.get_by_idx(act.place.location).unwrap().to_lat_lng()))
+    };
+
     let intervals = route_intervals(route, Box::new(|a| get_activity_type(a).map_or(false, |t| t == "reload")));
 
     let mut leg = intervals.into_iter().fold(Leg::empty(), |leg, (start_idx, end_idx)| {
@@ -132,7 +159,7 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
             tour.stops.push(Stop {
                 location: coord_index.get_by_idx(start.place.location).unwrap(),
                 time: format_schedule(&start.schedule),
-                load: if has_depot { MultiDimLoad::default().as_vec() } else { start_delivery.as_vec() },
+                load: to_load(if has_depot { MultiDimLoad::default() } else { start_delivery }),
                 distance: 0,
                 activities: vec![ApiActivity {
                     job_id: "departure".to_string(),
@@ -140,7 +167,9 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                     location: None,
                     time: None,
                     job_tag: None,
+                    demand: None,
                 }],
+                named_load: None,
             });
             (start_idx + 1, start)
         } else {
@@ -184,7 +213,8 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                 // total cost and distance
                 let cost = leg.statistic.cost
                     + problem.activity.cost(actor, act, act.schedule.arrival)
-                    + problem.transport.cost(actor, prev_location, act.place.location, prev_departure);
+                    + problem.transport.cost(actor, prev_location, act.place.location, prev_departure)
+                    + get_area_cost(act);
                 let distance = leg.statistic.distance
                     + problem.transport.distance(vehicle.profile, prev_location, act.place.location, prev_departure)
                         as i64;
@@ -193,9 +223,10 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                     tour.stops.push(Stop {
                         location: coord_index.get_by_idx(act.place.location).unwrap(),
                         time: format_as_schedule(&(arrival, departure)),
-                        load: prev_load.as_vec(),
+                        load: to_load(prev_load),
                         distance,
                         activities: vec![],
+                        named_load: None,
                     });
                 }
 
@@ -205,13 +236,14 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                 let mut last = tour.stops.get_mut(last).unwrap();
 
                 last.time.departure = format_time(departure);
-                last.load = load.as_vec();
+                last.load = to_load(load);
                 last.activities.push(ApiActivity {
                     job_id,
                     activity_type,
                     location: Some(coord_index.get_by_idx(act.place.location).unwrap()),
                     time: Some(Interval { start: format_time(arrival), end: format_time(departure) }),
                     job_tag,
+                    demand: get_split_demand(act, is_multi_dimen, load_scale),
                 });
 
                 Leg {
@@ -226,6 +258,7 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
                             waiting: leg.statistic.times.waiting + waiting as i64,
                             break_time: leg.statistic.times.break_time + (if is_break { serving as i64 } else { 0 }),
                         },
+                        value: None,
                     },
                     load: Some(load),
                 }
@@ -247,7 +280,19 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
             activity.time = None;
         });
 
+    if let Some(dimensions) = get_capacity_dimensions(problem.extras.as_ref()) {
+        tour.stops.iter_mut().for_each(|stop| {
+            stop.named_load =
+                Some(dimensions.iter().cloned().zip(stop.load.iter().cloned().chain(std::iter::repeat(0.))).collect())
+        });
+    }
+
     leg.statistic.cost += vehicle.costs.fixed;
+    leg.statistic.value = route
+        .tour
+        .jobs()
+        .filter_map(|job| job.dimens().get_value::<f64>("value").cloned())
+        .fold(None, |acc, value| Some(acc.unwrap_or(0.) + value));
 
     tour.vehicle_id = vehicle.dimens.get_id().unwrap().clone();
     tour.type_id = vehicle.dimens.get_value::<String>("type_id").unwrap().clone();
@@ -270,7 +315,16 @@ fn calculate_load(current: MultiDimLoad, act: &Activity, is_multi_dimen: bool) -
     current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
 }
 
-fn map_code_reason(code: i32) -> (i32, &'static str) {
+fn get_split_demand(act: &Activity, is_multi_dimen: bool, load_scale: f64) -> Option<Vec<f64>> {
+    act.job
+        .as_ref()
+        .filter(|job| is_split_job(&job.dimens))
+        .and_then(|job| get_capacity(&job.dimens, is_multi_dimen))
+        .map(|demand| (demand.delivery.0 + demand.delivery.1 + demand.pickup.0 + demand.pickup.1).as_vec())
+        .map(|demand| from_fixed_load(demand.as_slice(), load_scale))
+}
+
+pub(crate) fn map_code_reason(code: i32) -> (i32, &'static str) {
     match code {
         SKILLS_CONSTRAINT_CODE => (1, "cannot serve required skill"),
         TIME_CONSTRAINT_CODE => (2, "cannot be visited within time window"),
@@ -282,20 +336,31 @@ fn map_code_reason(code: i32) -> (i32, &'static str) {
         LOCKING_CONSTRAINT_CODE => (104, "cannot be served due to relation lock"),
         PRIORITY_CONSTRAINT_CODE => (105, "cannot be served due to priority"),
         AREA_CONSTRAINT_CODE => (106, "cannot be assigned due to area constraint"),
+        VALUE_CONSTRAINT_CODE => (107, "cannot be served as serving cost exceeds job value"),
+        VEHICLE_TIMES_CONSTRAINT_CODE => (108, "cannot be visited within vehicle specific time window"),
+        TOUR_ORDER_CONSTRAINT_CODE => (109, "cannot be served due to tour order"),
+        COMPARTMENT_CONSTRAINT_CODE => (110, "cannot be loaded into compatible vehicle compartment"),
+        CHARGING_CONSTRAINT_CODE => (111, "cannot be served due to vehicle battery range"),
+        TOUR_SIZE_CONSTRAINT_CODE => (112, "cannot be served due to tour size limit"),
         _ => (0, "unknown"),
     }
 }
 
 fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
+    // NOTE parts of split job share the same id
+    let mut job_ids = HashSet::new();
     let unassigned = solution
         .unassigned
         .iter()
         .filter(|(job, _)| job.dimens().get_value::<String>("vehicle_id").is_none())
-        .map(|(job, code)| {
+        .map(|(job, code)| (job.dimens().get_id().expect("job id expected").clone(), code))
+        .filter(|(job_id, _)| job_ids.insert(job_id.clone()))
+        .map(|(job_id, code)| {
             let (code, reason) = map_code_reason(*code);
             UnassignedJob {
-                job_id: job.dimens().get_id().expect("job id expected").clone(),
+                job_id,
                 reasons: vec![UnassignedJobReason { code, description: reason.to_string() }],
+                details: None,
             }
         })
         .collect::<Vec<_>>();
@@ -361,6 +426,14 @@ fn has_multi_dimensional_capacity(extras: &DomainExtras) -> bool {
     }
 }
 
+fn get_load_scale(extras: &DomainExtras) -> f64 {
+    extras.get("load_scale").and_then(|s| s.downcast_ref::<f64>()).cloned().unwrap_or(1.)
+}
+
+fn get_capacity_dimensions(extras: &DomainExtras) -> Option<&Vec<String>> {
+    extras.get("capacity_dimensions").and_then(|s| s.downcast_ref::<Vec<String>>())
+}
+
 fn create_extras(_solution: &Solution, metrics: Option<&Metrics>) -> Option<Extras> {
     if let Some(metrics) = metrics {
         Some(Extras {
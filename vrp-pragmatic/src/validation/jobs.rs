@@ -3,6 +3,8 @@
 mod jobs_test;
 
 use super::*;
+use crate::format::SPLIT_PART_SEPARATOR;
+use std::collections::HashSet;
 use vrp_core::models::common::MultiDimLoad;
 
 /// Checks that plan has no jobs with duplicate ids.
@@ -46,9 +48,17 @@ fn check_e1101_correct_job_types_demand(ctx: &ValidationContext) -> Result<(), F
 /// Checks that sum of pickup/delivery demand should be equal.
 fn check_e1102_multiple_pickups_deliveries_demand(ctx: &ValidationContext) -> Result<(), FormatError> {
     let has_tasks = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(false, |tasks| !tasks.is_empty());
+    let load_scale = get_load_scale(ctx.problem);
     let get_demand = |tasks: &Option<Vec<JobTask>>| {
         if let Some(tasks) = tasks {
-            tasks.iter().map(|task| task.demand.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new)).sum()
+            tasks
+                .iter()
+                .map(|task| {
+                    task.demand.as_ref().map_or_else(MultiDimLoad::default, |demand| {
+                        MultiDimLoad::new(to_fixed_load(demand.as_slice(), load_scale))
+                    })
+                })
+                .sum()
         } else {
             MultiDimLoad::default()
         }
@@ -162,7 +172,7 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
         .filter(|job| {
             ctx.tasks(job)
                 .iter()
-                .any(|task| task.demand.as_ref().map_or(false, |demand| demand.iter().any(|&dim| dim < 0)))
+                .any(|task| task.demand.as_ref().map_or(false, |demand| demand.iter().any(|&dim| dim < 0.)))
         })
         .map(|job| job.id.clone())
         .collect::<Vec<_>>();
@@ -178,6 +188,193 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job has no negative value.
+fn check_e1108_negative_value(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.value.map_or(false, |value| value < 0.))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "job has negative value".to_string(),
+            format!("fix negative value in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job's vehicle specific time windows are correct.
+fn check_e1109_vehicle_times(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx.vehicles().map(|vehicle| vehicle.type_id.clone()).collect::<HashSet<_>>();
+    let profiles = ctx.problem.fleet.profiles.iter().map(|profile| profile.name.clone()).collect::<HashSet<_>>();
+
+    let is_invalid = |times: &JobVehicleTimes| {
+        let has_type_ids = times.type_ids.as_ref().map_or(false, |ids| !ids.is_empty());
+        let has_profiles = times.profiles.as_ref().map_or(false, |names| !names.is_empty());
+
+        (!has_type_ids && !has_profiles)
+            || times.type_ids.as_ref().map_or(false, |ids| ids.iter().any(|id| !type_ids.contains(id)))
+            || times.profiles.as_ref().map_or(false, |names| names.iter().any(|name| !profiles.contains(name)))
+            || times.times.is_empty()
+            || !check_raw_time_windows(&times.times, false)
+    };
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .iter()
+                .flat_map(|task| task.places.iter())
+                .filter_map(|place| place.vehicle_times.as_ref())
+                .any(|vehicle_times| vehicle_times.iter().any(is_invalid))
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "invalid vehicle specific time windows in jobs".to_string(),
+            format!(
+                "specify known vehicle type ids or profiles and correct time windows in vehicle times, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that split job has single pickup or delivery task with demand, it is not used in relations
+/// and ids of its parts do not collide with ids of other jobs.
+fn check_e1110_split_jobs(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let relation_ids = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter().flat_map(|relation| relation.jobs.iter()))
+        .collect::<HashSet<_>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.split.unwrap_or(false))
+        .filter(|job| {
+            let has_single_task = match (job.pickups.as_deref(), job.deliveries.as_deref()) {
+                (Some([task]), None) | (None, Some([task])) => task.demand.is_some(),
+                _ => false,
+            };
+
+            !has_single_task || job.replacements.is_some() || job.services.is_some() || relation_ids.contains(&job.id)
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    let split_ids =
+        ctx.jobs().filter(|job| job.split.unwrap_or(false)).map(|job| job.id.as_str()).collect::<HashSet<_>>();
+    let colliding_ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.id.rfind(SPLIT_PART_SEPARATOR).map_or(false, |idx| {
+                let (split_id, part) = (&job.id[..idx], &job.id[idx + SPLIT_PART_SEPARATOR.len()..]);
+                split_ids.contains(split_id) && part.parse::<usize>().is_ok()
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    let actions = Some(ids)
+        .filter(|ids| !ids.is_empty())
+        .map(|ids| {
+            format!(
+                "split job should have single pickup or delivery with demand and no relations, jobs: '{}'",
+                ids.join(", ")
+            )
+        })
+        .into_iter()
+        .chain(
+            Some(colliding_ids)
+                .filter(|ids| !ids.is_empty())
+                .map(|ids| format!("job id should not be used as id of split job part, jobs: '{}'", ids.join(", "))),
+        )
+        .collect::<Vec<_>>();
+
+    if actions.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new("E1110".to_string(), "invalid split job".to_string(), actions.join(", ")))
+    }
+}
+
+/// Checks that job named demand uses only capacity dimensions declared on fleet level.
+fn check_e1111_named_demand(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let dimensions = ctx.problem.fleet.dimensions.as_ref();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).into_iter().any(|task| match (dimensions, task.named_demand.as_ref()) {
+                (_, None) => false,
+                (None, Some(_)) => true,
+                (Some(dimensions), Some(named_demand)) => {
+                    named_demand.keys().any(|name| !dimensions.contains(name))
+                        || task.demand.as_ref() != Some(&get_positional_load(dimensions, named_demand))
+                }
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "invalid job named demand".to_string(),
+            format!(
+                "ensure that fleet dimensions are declared, named demand uses only their names and \
+                 it does not conflict with demand, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job demand can be represented in fixed-point units without loss of precision.
+fn check_e1112_demand_precision(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let load_scale = get_load_scale(ctx.problem);
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job)
+                .into_iter()
+                .filter_map(|task| task.demand.as_ref())
+                .flat_map(|demand| demand.iter())
+                .any(|value| !is_valid_fixed_load(*value, load_scale))
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "invalid job demand precision".to_string(),
+            format!(
+                "ensure that demand has at most three decimal digits and scaled values fit 32-bit integer range, \
+                 jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     combine_error_results(&[
@@ -189,5 +386,10 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_negative_value(ctx),
+        check_e1109_vehicle_times(ctx),
+        check_e1110_split_jobs(ctx),
+        check_e1111_named_demand(ctx),
+        check_e1112_demand_precision(ctx),
     ])
 }
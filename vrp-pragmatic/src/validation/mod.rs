@@ -11,6 +11,7 @@ pub struct ValidationContext<'a> {
     /// Routing matrices.
     pub matrices: Option<&'a Vec<Matrix>>,
 
+    matrix_sizes: Option<Vec<usize>>,
     coord_index: CoordIndex,
     job_index: HashMap<String, Job>,
 }
@@ -36,14 +37,24 @@ use self::routing::validate_routing;
 impl<'a> ValidationContext<'a> {
     /// Creates an instance of `ValidationContext`.
     pub fn new(problem: &'a Problem, matrices: Option<&'a Vec<Matrix>>) -> Self {
+        let matrix_sizes = matrices.map(|matrices| {
+            matrices.iter().map(|matrix| (matrix.distances.len() as f64).sqrt().round() as usize).collect()
+        });
+
         Self {
             problem,
             matrices,
+            matrix_sizes,
             coord_index: CoordIndex::new(problem),
             job_index: problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect(),
         }
     }
 
+    /// Creates an instance of `ValidationContext` for routing matrices given by their amount of locations.
+    pub fn new_with_matrix_sizes(problem: &'a Problem, matrix_sizes: Vec<usize>) -> Self {
+        Self { matrix_sizes: Some(matrix_sizes), ..Self::new(problem, None) }
+    }
+
     /// Validates problem on set of rules.
     pub fn validate(&self) -> Result<(), Vec<FormatError>> {
         let errors = validate_jobs(&self)
@@ -97,5 +108,10 @@ fn combine_error_results(results: &[Result<(), FormatError>]) -> Result<(), Vec<
 }
 
 fn is_reserved_job_id(job_id: &str) -> bool {
-    job_id == "departure" || job_id == "arrival" || job_id == "break" || job_id == "reload" || job_id == "depot"
+    job_id == "departure"
+        || job_id == "arrival"
+        || job_id == "break"
+        || job_id == "reload"
+        || job_id == "depot"
+        || job_id == "charging"
 }
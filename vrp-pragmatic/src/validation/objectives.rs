@@ -29,10 +29,13 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 MinimizeTours => acc.entry("minimize-tours"),
                 MaximizeTours => acc.entry("maximize-tours"),
                 MinimizeUnassignedJobs => acc.entry("minimize-unassigned"),
+                MaximizeValue => acc.entry("maximize-value"),
+                MinimizeRankViolations => acc.entry("minimize-rank-violations"),
                 BalanceMaxLoad { .. } => acc.entry("balance-max-load"),
                 BalanceActivities { .. } => acc.entry("balance-activities"),
                 BalanceDistance { .. } => acc.entry("balance-distance"),
                 BalanceDuration { .. } => acc.entry("balance-duration"),
+                MinimizeDeviation { .. } => acc.entry("minimize-deviation"),
             }
             .and_modify(|count| *count += 1)
             .or_insert(1_usize);
@@ -77,6 +80,22 @@ fn check_e1602_no_cost_value_objective(objectives: &[&Objective]) -> Result<(),
     }
 }
 
+/// Checks that rank violation objective is specified when jobs have rank order.
+fn check_e1603_no_rank_objective(ctx: &ValidationContext, objectives: &[&Objective]) -> Result<(), FormatError> {
+    let has_ranks = ctx.problem.plan.jobs.iter().any(|job| matches!(job.order, Some(JobOrder::Rank(_))));
+    let has_rank_objective = objectives.iter().any(|objective| matches!(objective, MinimizeRankViolations));
+
+    if has_ranks && !has_rank_objective {
+        Err(FormatError::new(
+            "E1603".to_string(),
+            "missing rank objective".to_string(),
+            "specify 'minimize-rank-violations' objective or remove job ranks".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| {
         Some(&objectives.primary)
@@ -93,6 +112,7 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), Vec<FormatErro
             check_e1600_empty_objective(&objectives),
             check_e1601_duplicate_objectives(&objectives),
             check_e1602_no_cost_value_objective(&objectives),
+            check_e1603_no_rank_objective(ctx, &objectives),
         ])
     } else {
         Ok(())
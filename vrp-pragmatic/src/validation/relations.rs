@@ -174,21 +174,21 @@ fn check_e1206_relation_has_no_missing_shift_properties(
     let vehicle_ids: Vec<String> = relations
         .iter()
         .filter_map(|relation| {
-            vehicle_map
-                .get(&relation.vehicle_id)
-                .and_then(|vehicle| vehicle.shifts.get(relation.shift_index.unwrap_or(0)))
-                .map(|vehicle_shift| (vehicle_shift, relation))
+            vehicle_map.get(&relation.vehicle_id).and_then(|vehicle| {
+                vehicle.shifts.get(relation.shift_index.unwrap_or(0)).map(|shift| (vehicle, shift, relation))
+            })
         })
-        .filter(|(vehicle_shift, relation)| {
+        .filter(|(vehicle, vehicle_shift, relation)| {
             relation.jobs.iter().filter(|job_id| is_reserved_job_id(job_id)).any(|job_id| match job_id.as_str() {
                 "break" => vehicle_shift.breaks.is_none(),
                 "depot" => vehicle_shift.depots.is_none(),
                 "reload" => vehicle_shift.reloads.is_none(),
+                "charging" => vehicle.battery.as_ref().map_or(true, |battery| battery.stations.is_none()),
                 "arrival" => vehicle_shift.end.is_none(),
                 _ => false,
             })
         })
-        .map(|(_, relation)| relation.vehicle_id.clone())
+        .map(|(_, _, relation)| relation.vehicle_id.clone())
         .collect::<Vec<_>>();
 
     if vehicle_ids.is_empty() {
@@ -199,7 +199,7 @@ fn check_e1206_relation_has_no_missing_shift_properties(
             "relation has special job id which is not defined on vehicle shift".to_string(),
             format!(
                 "remove special job id or add vehicle shift property \
-            (e.g. break, depot, reload), vehicle ids: '{}'",
+            (e.g. break, depot, reload, battery stations), vehicle ids: '{}'",
                 vehicle_ids.join(", ")
             ),
         ))
@@ -50,7 +50,7 @@ fn check_e1503_no_matrix_when_indices_used(
 ) -> Result<(), FormatError> {
     let (_, has_indices) = location_types;
 
-    if has_indices && ctx.matrices.map_or(true, |matrices| matrices.is_empty()) {
+    if has_indices && ctx.matrix_sizes.as_ref().map_or(true, |sizes| sizes.is_empty()) {
         Err(FormatError::new(
             "E1503".to_string(),
             "location indices requires routing matrix to be specified".to_string(),
@@ -69,15 +69,9 @@ fn check_e1504_limit_areas_cannot_be_used_with_indices(
     let (_, has_indices) = location_types;
 
     if has_indices {
-        let has_areas = ctx
-            .problem
-            .fleet
-            .vehicles
-            .iter()
-            .filter_map(|vehicle| vehicle.limits.as_ref())
-            .filter_map(|limits| limits.allowed_areas.as_ref())
-            .next()
-            .is_some();
+        let has_areas = ctx.problem.fleet.vehicles.iter().filter_map(|vehicle| vehicle.limits.as_ref()).any(|limits| {
+            limits.allowed_areas.is_some() || limits.forbidden_areas.is_some() || limits.area_costs.is_some()
+        });
         if has_areas {
             return Err(FormatError::new(
                 "E1504".to_string(),
@@ -96,11 +90,7 @@ fn check_e1505_index_size_mismatch(ctx: &ValidationContext) -> Result<(), Format
         .coord_index
         .max_index()
         .into_iter()
-        .zip(
-            ctx.matrices
-                .and_then(|matrices| matrices.first())
-                .map(|matrix| (matrix.distances.len() as f64).sqrt().round() as usize),
-        )
+        .zip(ctx.matrix_sizes.as_ref().and_then(|sizes| sizes.first()).cloned())
         .next()
         .map_or((0_usize, 0_usize, true), |(max_index, matrix_size)| {
             (max_index, matrix_size, max_index + 1 == matrix_size)
@@ -3,6 +3,7 @@
 mod vehicles_test;
 
 use super::*;
+use crate::format::Location;
 use crate::validation::common::get_time_windows;
 use std::collections::HashSet;
 use std::ops::Deref;
@@ -123,14 +124,26 @@ fn check_e1304_vehicle_reload_time_is_correct(ctx: &ValidationContext) -> Result
 
 /// Checks that vehicle area restrictions are valid.
 fn check_e1305_vehicle_limit_area_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_invalid_shape = |outer_shape: &Vec<Location>, inner_shapes: &Option<Vec<Vec<Location>>>| {
+        outer_shape.len() < 3 || inner_shapes.as_ref().map_or(false, |shapes| shapes.iter().any(|s| s.len() < 3))
+    };
+    let is_invalid_areas = |areas: &Vec<AreaLimit>| {
+        areas.is_empty() || areas.iter().any(|area| is_invalid_shape(&area.outer_shape, &area.inner_shapes))
+    };
+
     let type_ids = ctx
         .vehicles()
         .filter(|vehicle| {
-            vehicle
-                .limits
-                .as_ref()
-                .and_then(|l| l.allowed_areas.as_ref())
-                .map_or(false, |areas| areas.is_empty() || areas.iter().any(|area| area.outer_shape.len() < 3))
+            vehicle.limits.as_ref().map_or(false, |limits| {
+                limits.allowed_areas.as_ref().map_or(false, is_invalid_areas)
+                    || limits.forbidden_areas.as_ref().map_or(false, is_invalid_areas)
+                    || limits.area_costs.as_ref().map_or(false, |areas| {
+                        areas.is_empty()
+                            || areas
+                                .iter()
+                                .any(|area| area.cost < 0. || is_invalid_shape(&area.outer_shape, &area.inner_shapes))
+                    })
+            })
         })
         .map(|vehicle| vehicle.type_id.to_string())
         .collect::<Vec<_>>();
@@ -142,8 +155,8 @@ fn check_e1305_vehicle_limit_area_is_correct(ctx: &ValidationContext) -> Result<
             "E1305".to_string(),
             "invalid allowed area definition in vehicle limits".to_string(),
             format!(
-                "ensure that areas list is not empty and each area has at least three coordinates, \
-                 vehicle type ids: '{}'",
+                "ensure that areas list is not empty, each area shape has at least three coordinates \
+                 and area cost is not negative, vehicle type ids: '{}'",
                 type_ids.join(", ")
             ),
         ))
@@ -182,6 +195,167 @@ fn check_e1306_vehicle_depot_is_correct(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+/// Checks that vehicle compartments are correct.
+fn check_e1307_vehicle_compartments_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.compartments.as_ref().map_or(false, |compartments| {
+                compartments.iter().any(|compartment| {
+                    compartment.goods.is_empty()
+                        || compartment.capacity.is_empty()
+                        || compartment.capacity.len() > vehicle.capacity.len()
+                        || compartment.capacity.iter().any(|&capacity| capacity < 0.)
+                })
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1307".to_string(),
+            "invalid vehicle compartments".to_string(),
+            format!(
+                "ensure that each compartment has non-negative capacity of vehicle capacity size \
+                 and at least one goods type. Vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that capacity dimension names declared on fleet level are unique and not empty.
+fn check_e1308_capacity_dimensions_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let dimensions = if let Some(dimensions) = ctx.problem.fleet.dimensions.as_ref() {
+        dimensions
+    } else {
+        return Ok(());
+    };
+
+    let has_duplicates = dimensions.iter().collect::<HashSet<_>>().len() != dimensions.len();
+    let has_empty = dimensions.is_empty() || dimensions.iter().any(|name| name.is_empty());
+
+    if has_duplicates || has_empty {
+        Err(FormatError::new(
+            "E1308".to_string(),
+            "invalid capacity dimensions".to_string(),
+            "ensure that fleet dimensions has at least one dimension and all names are unique and not empty"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that vehicle capacity is specified and matches declared capacity dimensions.
+fn check_e1309_vehicle_named_capacity_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let dimensions = ctx.problem.fleet.dimensions.as_ref();
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| match (dimensions, vehicle.named_capacity.as_ref()) {
+            (None, None) => vehicle.capacity.is_empty(),
+            (None, Some(_)) => true,
+            (Some(dimensions), None) => vehicle.capacity.len() != dimensions.len(),
+            (Some(dimensions), Some(named_capacity)) => {
+                named_capacity.len() != dimensions.len()
+                    || named_capacity.keys().any(|name| !dimensions.contains(name))
+                    || vehicle.capacity != get_positional_load(dimensions, named_capacity)
+            }
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid vehicle capacity".to_string(),
+            format!(
+                "ensure that vehicle capacity is specified for all fleet dimensions and named capacity \
+                 uses only declared dimension names. Vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle battery and its charging stations are correct.
+fn check_e1310_vehicle_battery_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids =
+        ctx.vehicles()
+            .filter(|vehicle| {
+                vehicle.battery.as_ref().map_or(false, |battery| {
+                    let stations = battery.stations.as_ref().map_or(&[][..], |stations| stations.as_slice());
+                    let station_tws = stations
+                        .iter()
+                        .filter_map(|station| station.times.as_ref())
+                        .flat_map(|tws| get_time_windows(tws))
+                        .collect::<Vec<_>>();
+
+                    battery.capacity <= 0.
+                        || battery.consumption < 0.
+                        || battery.load_consumption.map_or(false, |consumption| consumption < 0.)
+                        || battery.initial.map_or(false, |initial| initial < 0. || initial > battery.capacity)
+                        || stations.iter().any(|station| station.rate <= 0. || station.duration < 0.)
+                        || vehicle.shifts.iter().any(|shift| {
+                            !check_shift_time_windows(get_shift_time_window(shift), station_tws.clone(), true)
+                        })
+                })
+            })
+            .map(|vehicle| vehicle.type_id.clone())
+            .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid vehicle battery".to_string(),
+            format!(
+                "ensure that battery capacity is positive, initial charge does not exceed it, consumption is not \
+                 negative and charging stations have positive rate and correct time windows. Vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle capacity can be represented in fixed-point units without loss of precision.
+fn check_e1311_vehicle_capacity_precision(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let load_scale = get_load_scale(ctx.problem);
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle
+                .capacity
+                .iter()
+                .chain(vehicle.compartments.iter().flat_map(|c| c.iter()).flat_map(|c| c.capacity.iter()))
+                .any(|value| !is_valid_fixed_load(*value, load_scale))
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid vehicle capacity precision".to_string(),
+            format!(
+                "ensure that capacity has at most three decimal digits and scaled values fit 32-bit integer range, \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 fn get_invalid_type_ids(
     ctx: &ValidationContext,
     check_shift: Box<dyn Fn(&VehicleShift, Option<TimeWindow>) -> bool>,
@@ -240,5 +414,10 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>
         check_e1304_vehicle_reload_time_is_correct(ctx),
         check_e1305_vehicle_limit_area_is_correct(ctx),
         check_e1306_vehicle_depot_is_correct(ctx),
+        check_e1307_vehicle_compartments_are_correct(ctx),
+        check_e1308_capacity_dimensions_are_correct(ctx),
+        check_e1309_vehicle_named_capacity_is_correct(ctx),
+        check_e1310_vehicle_battery_is_correct(ctx),
+        check_e1311_vehicle_capacity_precision(ctx),
     ])
 }
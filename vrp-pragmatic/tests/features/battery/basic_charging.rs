@@ -0,0 +1,86 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem(jobs: Vec<Job>, battery_capacity: f64, stations: Option<Vec<VehicleChargingStation>>) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                battery: Some(VehicleBattery {
+                    capacity: battery_capacity,
+                    initial: None,
+                    consumption: 1.,
+                    load_consumption: None,
+                    stations,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_station(location: Vec<f64>) -> VehicleChargingStation {
+    VehicleChargingStation { location: location.to_loc(), duration: 5., rate: 1., times: None, tag: None }
+}
+
+fn get_charging_stops(solution: &Solution) -> Vec<&Stop> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter(|stop| stop.activities.iter().any(|activity| activity.activity_type == "charging"))
+        .collect()
+}
+
+#[test]
+fn can_visit_charging_station_when_range_is_not_enough() {
+    let problem = create_problem(
+        vec![create_delivery_job("job1", vec![3., 0.]), create_delivery_job("job2", vec![6., 0.])],
+        8.,
+        Some(vec![create_station(vec![4., 0.])]),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let charging_stops = get_charging_stops(&solution);
+    assert_eq!(charging_stops.len(), 1);
+    assert_eq!(charging_stops[0].location, vec![4., 0.].to_loc());
+}
+
+#[test]
+fn can_skip_charging_station_when_range_is_enough() {
+    let problem = create_problem(
+        vec![create_delivery_job("job1", vec![3., 0.]), create_delivery_job("job2", vec![6., 0.])],
+        12.,
+        Some(vec![create_station(vec![4., 0.])]),
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(get_charging_stops(&solution).is_empty());
+}
+
+#[test]
+fn can_skip_job_out_of_vehicle_range() {
+    let problem = create_problem(
+        vec![create_delivery_job("job1", vec![2., 0.]), create_delivery_job("job2", vec![5., 0.])],
+        8.,
+        None,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.expect("should have unassigned job");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job2");
+    assert_eq!(unassigned[0].reasons[0].code, 111);
+}
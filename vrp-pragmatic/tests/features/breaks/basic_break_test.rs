@@ -24,6 +24,7 @@ fn can_assign_break_between_jobs() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -39,6 +40,7 @@ fn can_assign_break_between_jobs() {
                 distance: 20,
                 duration: 24,
                 times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -49,7 +51,7 @@ fn can_assign_break_between_jobs() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0,
                     ),
@@ -57,7 +59,7 @@ fn can_assign_break_between_jobs() {
                         "job1",
                         "delivery",
                         (5., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
                         5,
                     ),
@@ -65,7 +67,7 @@ fn can_assign_break_between_jobs() {
                         "break",
                         "break",
                         (6., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:07Z", "1970-01-01T00:00:09Z"),
                         6,
                     ),
@@ -73,7 +75,7 @@ fn can_assign_break_between_jobs() {
                         "job2",
                         "delivery",
                         (10., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:13Z", "1970-01-01T00:00:14Z"),
                         10
                     ),
@@ -81,7 +83,7 @@ fn can_assign_break_between_jobs() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:24Z", "1970-01-01T00:00:24Z"),
                         20
                     )
@@ -91,6 +93,7 @@ fn can_assign_break_between_jobs() {
                     distance: 20,
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
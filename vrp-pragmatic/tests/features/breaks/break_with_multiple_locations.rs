@@ -29,6 +29,7 @@ fn can_assign_break_using_second_location() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -44,6 +45,7 @@ fn can_assign_break_using_second_location() {
                 distance: 30,
                 duration: 34,
                 times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -54,7 +56,7 @@ fn can_assign_break_using_second_location() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0,
                     ),
@@ -62,7 +64,7 @@ fn can_assign_break_using_second_location() {
                         "job1",
                         "delivery",
                         (10., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                         10,
                     ),
@@ -70,7 +72,7 @@ fn can_assign_break_using_second_location() {
                         "break",
                         "break",
                         (11., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:12Z", "1970-01-01T00:00:14Z"),
                         11,
                     ),
@@ -78,7 +80,7 @@ fn can_assign_break_using_second_location() {
                         "job2",
                         "delivery",
                         (20., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:23Z", "1970-01-01T00:00:24Z"),
                         20,
                     ),
@@ -86,7 +88,7 @@ fn can_assign_break_using_second_location() {
                         "arrival",
                         "arrival",
                         (30., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:34Z", "1970-01-01T00:00:34Z"),
                         30,
                     )
@@ -96,6 +98,7 @@ fn can_assign_break_using_second_location() {
                     distance: 30,
                     duration: 34,
                     times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -23,6 +23,7 @@ fn can_assign_interval_break_between_jobs() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -38,6 +39,7 @@ fn can_assign_interval_break_between_jobs() {
                 distance: 30,
                 duration: 34,
                 times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -48,7 +50,7 @@ fn can_assign_interval_break_between_jobs() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -59,7 +61,7 @@ fn can_assign_interval_break_between_jobs() {
                             departure: "1970-01-01T00:00:08Z".to_string(),
                         },
                         distance: 5,
-                        load: vec![1],
+                        load: vec![1.],
                         activities: vec![
                             Activity {
                                 job_id: "job1".to_string(),
@@ -70,6 +72,7 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:06Z".to_string(),
                                 }),
                                 job_tag: None,
+                                demand: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -80,14 +83,16 @@ fn can_assign_interval_break_between_jobs() {
                                     end: "1970-01-01T00:00:08Z".to_string(),
                                 }),
                                 job_tag: None,
+                                demand: None,
                             }
                         ],
+                        named_load: None,
                     },
                     create_stop_with_activity(
                         "job2",
                         "delivery",
                         (15., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:18Z", "1970-01-01T00:00:19Z"),
                         15
                     ),
@@ -95,7 +100,7 @@ fn can_assign_interval_break_between_jobs() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:34Z", "1970-01-01T00:00:34Z"),
                         30
                     )
@@ -105,6 +110,7 @@ fn can_assign_interval_break_between_jobs() {
                     distance: 30,
                     duration: 34,
                     times: Timing { driving: 30, serving: 2, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -146,10 +152,11 @@ fn can_assign_interval_break_with_reload() {
                         tag: None,
                     }]),
                 }],
-                capacity: vec![2],
+                capacity: vec![2.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -165,6 +172,7 @@ fn can_assign_interval_break_with_reload() {
                 distance: 60,
                 duration: 69,
                 times: Timing { driving: 60, serving: 7, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -175,7 +183,7 @@ fn can_assign_interval_break_with_reload() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -186,7 +194,7 @@ fn can_assign_interval_break_with_reload() {
                             departure: "1970-01-01T00:00:13Z".to_string(),
                         },
                         distance: 10,
-                        load: vec![1],
+                        load: vec![1.],
                         activities: vec![
                             Activity {
                                 job_id: "job1".to_string(),
@@ -197,6 +205,7 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:11Z".to_string(),
                                 }),
                                 job_tag: None,
+                                demand: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -207,14 +216,16 @@ fn can_assign_interval_break_with_reload() {
                                     end: "1970-01-01T00:00:13Z".to_string(),
                                 }),
                                 job_tag: None,
+                                demand: None,
                             }
                         ],
+                        named_load: None,
                     },
                     create_stop_with_activity(
                         "job2",
                         "delivery",
                         (15., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:18Z", "1970-01-01T00:00:19Z"),
                         15
                     ),
@@ -222,7 +233,7 @@ fn can_assign_interval_break_with_reload() {
                         "reload",
                         "reload",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:34Z", "1970-01-01T00:00:37Z"),
                         30
                     ),
@@ -230,7 +241,7 @@ fn can_assign_interval_break_with_reload() {
                         "job3",
                         "delivery",
                         (20., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:57Z", "1970-01-01T00:00:58Z"),
                         50
                     ),
@@ -238,7 +249,7 @@ fn can_assign_interval_break_with_reload() {
                         "job4",
                         "delivery",
                         (25., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:01:03Z", "1970-01-01T00:01:04Z"),
                         55
                     ),
@@ -246,7 +257,7 @@ fn can_assign_interval_break_with_reload() {
                         "arrival",
                         "arrival",
                         (30., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:01:09Z", "1970-01-01T00:01:09Z"),
                         60
                     )
@@ -256,6 +267,7 @@ fn can_assign_interval_break_with_reload() {
                     distance: 60,
                     duration: 69,
                     times: Timing { driving: 60, serving: 7, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -286,6 +298,7 @@ fn can_consider_departure_rescheduling() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -35,6 +35,7 @@ fn can_use_two_breaks() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -50,6 +51,7 @@ fn can_use_two_breaks() {
                 distance: 198,
                 duration: 204,
                 times: Timing { driving: 198, serving: 2, waiting: 0, break_time: 4 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -60,7 +62,7 @@ fn can_use_two_breaks() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0,
                     ),
@@ -68,7 +70,7 @@ fn can_use_two_breaks() {
                         "job1",
                         "delivery",
                         (5., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
                         5,
                     ),
@@ -76,7 +78,7 @@ fn can_use_two_breaks() {
                         "break",
                         "break",
                         (6., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:07Z", "1970-01-01T00:00:09Z"),
                         6,
                     ),
@@ -87,7 +89,7 @@ fn can_use_two_breaks() {
                             departure: "1970-01-01T00:01:45Z".to_string(),
                         },
                         distance: 99,
-                        load: vec![0],
+                        load: vec![0.],
                         activities: vec![
                             Activity {
                                 job_id: "job2".to_string(),
@@ -98,6 +100,7 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:43Z".to_string(),
                                 }),
                                 job_tag: None,
+                                demand: None,
                             },
                             Activity {
                                 job_id: "break".to_string(),
@@ -108,14 +111,16 @@ fn can_use_two_breaks() {
                                     end: "1970-01-01T00:01:45Z".to_string(),
                                 }),
                                 job_tag: None,
+                                demand: None,
                             }
                         ],
+                        named_load: None,
                     },
                     create_stop_with_activity(
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:03:24Z", "1970-01-01T00:03:24Z"),
                         198,
                     )
@@ -125,6 +130,7 @@ fn can_use_two_breaks() {
                     distance: 198,
                     duration: 204,
                     times: Timing { driving: 198, serving: 2, waiting: 0, break_time: 4 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
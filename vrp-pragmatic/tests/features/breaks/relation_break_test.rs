@@ -42,6 +42,7 @@ fn get_solution(
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -75,6 +76,7 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -85,7 +87,7 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -93,7 +95,7 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                         "job1",
                         "delivery",
                         (1., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1
                     ),
@@ -101,7 +103,7 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                         "break",
                         "break",
                         (3., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:04Z", "1970-01-01T00:00:06Z"),
                         3
                     ),
@@ -109,7 +111,7 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                         "job2",
                         "delivery",
                         (2., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                         4
                     ),
@@ -117,7 +119,7 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:10Z"),
                         6
                     )
@@ -127,6 +129,7 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -155,6 +158,7 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -165,7 +169,7 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -173,7 +177,7 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                         "job1",
                         "delivery",
                         (1., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1
                     ),
@@ -181,7 +185,7 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                         "job2",
                         "delivery",
                         (2., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
                         2
                     ),
@@ -189,7 +193,7 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                         "break",
                         "break",
                         (3., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:05Z", "1970-01-01T00:00:07Z"),
                         3
                     ),
@@ -197,7 +201,7 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:10Z"),
                         6
                     )
@@ -207,6 +211,7 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 2, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -37,6 +37,7 @@ fn can_skip_break_when_vehicle_not_used() {
                 create_default_vehicle("vehicle_without_break"),
             ],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -52,6 +53,7 @@ fn can_skip_break_when_vehicle_not_used() {
                 distance: 20,
                 duration: 22,
                 times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_without_break_1".to_string(),
@@ -62,7 +64,7 @@ fn can_skip_break_when_vehicle_not_used() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0,
                     ),
@@ -70,7 +72,7 @@ fn can_skip_break_when_vehicle_not_used() {
                         "job2",
                         "delivery",
                         (10., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                         10,
                     ),
@@ -78,7 +80,7 @@ fn can_skip_break_when_vehicle_not_used() {
                         "job1",
                         "delivery",
                         (5., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:16Z", "1970-01-01T00:00:17Z"),
                         15,
                     ),
@@ -86,7 +88,7 @@ fn can_skip_break_when_vehicle_not_used() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:22Z", "1970-01-01T00:00:22Z"),
                         20,
                     )
@@ -96,6 +98,7 @@ fn can_skip_break_when_vehicle_not_used() {
                     distance: 20,
                     duration: 22,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -123,6 +126,7 @@ fn can_skip_break_when_jobs_completed() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -138,6 +142,7 @@ fn can_skip_break_when_jobs_completed() {
                 distance: 2,
                 duration: 12,
                 times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -148,7 +153,7 @@ fn can_skip_break_when_jobs_completed() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0,
                     ),
@@ -156,7 +161,7 @@ fn can_skip_break_when_jobs_completed() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:11Z"),
                         1,
                     ),
@@ -164,7 +169,7 @@ fn can_skip_break_when_jobs_completed() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:12Z", "1970-01-01T00:00:12Z"),
                         2,
                     )
@@ -174,6 +179,7 @@ fn can_skip_break_when_jobs_completed() {
                     distance: 2,
                     duration: 12,
                     times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             violations: Some(vec![Violation::Break {
@@ -213,6 +219,7 @@ fn can_skip_second_break_when_jobs_completed() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -228,6 +235,7 @@ fn can_skip_second_break_when_jobs_completed() {
                 distance: 20,
                 duration: 24,
                 times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -238,7 +246,7 @@ fn can_skip_second_break_when_jobs_completed() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0,
                     ),
@@ -246,7 +254,7 @@ fn can_skip_second_break_when_jobs_completed() {
                         "job1",
                         "delivery",
                         (5., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
                         5,
                     ),
@@ -254,7 +262,7 @@ fn can_skip_second_break_when_jobs_completed() {
                         "break",
                         "break",
                         (6., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:07Z", "1970-01-01T00:00:09Z"),
                         6,
                     ),
@@ -262,7 +270,7 @@ fn can_skip_second_break_when_jobs_completed() {
                         "job2",
                         "delivery",
                         (10., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:13Z", "1970-01-01T00:00:14Z"),
                         10,
                     ),
@@ -270,7 +278,7 @@ fn can_skip_second_break_when_jobs_completed() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:24Z", "1970-01-01T00:00:24Z"),
                         20,
                     )
@@ -280,6 +288,7 @@ fn can_skip_second_break_when_jobs_completed() {
                     distance: 20,
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 2 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -0,0 +1,57 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_job_with_goods(id: &str, location: Vec<f64>, demand: f64, goods: &str) -> Job {
+    Job { goods: Some(goods.to_string()), ..create_delivery_job_with_demand(id, location, vec![demand]) }
+}
+
+fn create_problem(jobs: Vec<Job>) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                compartments: Some(vec![
+                    VehicleCompartment { capacity: vec![5.], goods: vec!["diesel".to_string()] },
+                    VehicleCompartment { capacity: vec![5.], goods: vec!["diesel".to_string(), "petrol".to_string()] },
+                ]),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_use_separate_vehicles_when_compartments_are_occupied() {
+    let problem = create_problem(vec![
+        create_job_with_goods("job1", vec![1., 0.], 4., "diesel"),
+        create_job_with_goods("job2", vec![2., 0.], 3., "petrol"),
+        create_job_with_goods("job3", vec![3., 0.], 3., "petrol"),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+}
+
+#[test]
+fn can_skip_job_with_incompatible_goods() {
+    let problem = create_problem(vec![
+        create_job_with_goods("job1", vec![1., 0.], 1., "diesel"),
+        create_job_with_goods("job2", vec![2., 0.], 1., "water"),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let unassigned = solution.unassigned.expect("should have unassigned job");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job2");
+    assert_eq!(unassigned[0].reasons[0].code, 110);
+}
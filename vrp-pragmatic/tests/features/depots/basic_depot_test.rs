@@ -15,6 +15,7 @@ fn create_problem_with_depots(depots: Option<Vec<VehicleCargoPlace>>) -> Problem
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     }
@@ -40,6 +41,7 @@ fn can_assign_single_depot() {
                 distance: 14,
                 duration: 18,
                 times: Timing { driving: 14, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -50,7 +52,7 @@ fn can_assign_single_depot() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:03Z"),
                         0,
                     ),
@@ -58,7 +60,7 @@ fn can_assign_single_depot() {
                         "depot",
                         "depot",
                         (7., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:12Z"),
                         7,
                     ),
@@ -66,7 +68,7 @@ fn can_assign_single_depot() {
                         "job2",
                         "delivery",
                         (5., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:14Z", "1970-01-01T00:00:15Z"),
                         9,
                     ),
@@ -74,7 +76,7 @@ fn can_assign_single_depot() {
                         "job1",
                         "delivery",
                         (3., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:17Z", "1970-01-01T00:00:18Z"),
                         11
                     ),
@@ -82,7 +84,7 @@ fn can_assign_single_depot() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:21Z", "1970-01-01T00:00:21Z"),
                         14
                     )
@@ -92,6 +94,7 @@ fn can_assign_single_depot() {
                     distance: 14,
                     duration: 18,
                     times: Timing { driving: 14, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
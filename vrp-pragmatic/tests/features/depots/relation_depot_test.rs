@@ -31,6 +31,7 @@ fn can_use_depot_in_relation() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
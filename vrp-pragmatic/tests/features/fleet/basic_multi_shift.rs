@@ -39,10 +39,11 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         ..create_default_vehicle_shift()
                     },
                 ],
-                capacity: vec![1],
+                capacity: vec![1.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -58,6 +59,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 distance: 40,
                 duration: 42,
                 times: Timing { driving: 40, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![
                 Tour {
@@ -69,7 +71,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                             "departure",
                             "departure",
                             (0., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                             0
                         ),
@@ -77,7 +79,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                             "job1",
                             "delivery",
                             (10., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                             10
                         ),
@@ -85,7 +87,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                             "arrival",
                             "arrival",
                             (0., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:21Z", "1970-01-01T00:00:21Z"),
                             20
                         )
@@ -95,6 +97,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         distance: 20,
                         duration: 21,
                         times: Timing { driving: 20, serving: 1, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 },
                 Tour {
@@ -106,7 +109,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                             "departure",
                             "departure",
                             (0., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:01:40Z", "1970-01-01T00:01:40Z"),
                             0
                         ),
@@ -114,7 +117,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                             "job2",
                             "delivery",
                             (10., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:01:50Z", "1970-01-01T00:01:51Z"),
                             10
                         ),
@@ -122,7 +125,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                             "arrival",
                             "arrival",
                             (0., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:02:01Z", "1970-01-01T00:02:01Z"),
                             20
                         )
@@ -132,6 +135,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         distance: 20,
                         duration: 21,
                         times: Timing { driving: 20, serving: 1, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 }
             ],
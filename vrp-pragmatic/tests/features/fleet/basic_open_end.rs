@@ -12,6 +12,7 @@ fn can_use_vehicle_with_open_end() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -27,6 +28,7 @@ fn can_use_vehicle_with_open_end() {
                 distance: 1,
                 duration: 2,
                 times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -37,7 +39,7 @@ fn can_use_vehicle_with_open_end() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -45,7 +47,7 @@ fn can_use_vehicle_with_open_end() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1
                     )
@@ -55,6 +57,7 @@ fn can_use_vehicle_with_open_end() {
                     distance: 1,
                     duration: 2,
                     times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -0,0 +1,55 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem(demands: Vec<f64>, capacity: f64) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: demands
+                .into_iter()
+                .enumerate()
+                .map(|(idx, demand)| {
+                    create_delivery_job_with_demand(&format!("job{}", idx + 1), vec![idx as f64 + 1., 0.], vec![demand])
+                })
+                .collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string()],
+                shifts: vec![create_default_open_vehicle_shift()],
+                capacity: vec![capacity],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_use_decimal_demand_and_capacity() {
+    let problem = create_problem(vec![3.5, 2.25, 4.75], 10.5);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let loads = solution.tours[0].stops.iter().map(|stop| stop.load.clone()).collect::<Vec<_>>();
+    assert_eq!(loads.first(), Some(&vec![10.5]));
+    assert_eq!(loads.last(), Some(&vec![0.]));
+}
+
+#[test]
+fn can_unassign_job_exceeding_decimal_capacity() {
+    let problem = create_problem(vec![3.5, 2.25, 4.8], 10.5);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let unassigned = solution.unassigned.expect("should have unassigned job");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].reasons[0].code, 3);
+}
@@ -1,4 +1,6 @@
 mod basic_multi_shift;
 mod basic_open_end;
+mod decimal_dimens;
 mod multi_dimens;
+mod named_dimens;
 mod unreachable_jobs;
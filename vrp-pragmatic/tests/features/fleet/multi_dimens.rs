@@ -7,18 +7,19 @@ fn can_use_two_dimensions() {
     let problem = Problem {
         plan: Plan {
             jobs: vec![
-                create_delivery_job_with_demand("job1", vec![1., 0.], vec![0, 1]),
-                create_delivery_job_with_demand("job2", vec![2., 0.], vec![1, 0]),
+                create_delivery_job_with_demand("job1", vec![1., 0.], vec![0., 1.]),
+                create_delivery_job_with_demand("job2", vec![2., 0.], vec![1., 0.]),
             ],
             relations: None,
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                capacity: vec![1, 1],
+                capacity: vec![1., 1.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -34,6 +35,7 @@ fn can_use_two_dimensions() {
                 distance: 2,
                 duration: 4,
                 times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -44,7 +46,7 @@ fn can_use_two_dimensions() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        vec![1, 1],
+                        vec![1., 1.],
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -52,7 +54,7 @@ fn can_use_two_dimensions() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        vec![1, 0],
+                        vec![1., 0.],
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1
                     ),
@@ -60,7 +62,7 @@ fn can_use_two_dimensions() {
                         "job2",
                         "delivery",
                         (2., 0.),
-                        vec![0, 0],
+                        vec![0., 0.],
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
                         2
                     )
@@ -70,6 +72,7 @@ fn can_use_two_dimensions() {
                     distance: 2,
                     duration: 4,
                     times: Timing { driving: 2, serving: 2, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -80,14 +83,15 @@ fn can_use_two_dimensions() {
 #[test]
 fn can_unassign_due_to_dimension_mismatch() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0, 1])], relations: None },
+        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0., 1.])], relations: None },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                capacity: vec![1],
+                capacity: vec![1.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -103,6 +107,7 @@ fn can_unassign_due_to_dimension_mismatch() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -110,7 +115,8 @@ fn can_unassign_due_to_dimension_mismatch() {
                 reasons: vec![UnassignedJobReason {
                     code: 3,
                     description: "does not fit into any vehicle due to capacity".to_string()
-                }]
+                }],
+                details: None,
             }]),
             ..create_empty_solution()
         }
@@ -0,0 +1,75 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::collections::HashMap;
+
+fn to_named_load(load: Vec<(&str, f64)>) -> HashMap<String, f64> {
+    load.into_iter().map(|(name, value)| (name.to_string(), value)).collect()
+}
+
+fn create_delivery_job_with_named_demand(id: &str, location: Vec<f64>, demand: Vec<(&str, f64)>) -> Job {
+    let job = create_delivery_job(id, location);
+    let tasks = job.deliveries.unwrap().into_iter().map(|task| JobTask {
+        demand: None,
+        named_demand: Some(to_named_load(demand.clone())),
+        ..task
+    });
+
+    Job { deliveries: Some(tasks.collect()), ..create_job(id) }
+}
+
+fn create_problem(jobs: Vec<Job>, capacity: Vec<(&str, f64)>) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                capacity: vec![],
+                named_capacity: Some(to_named_load(capacity)),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: Some(vec!["weight".to_string(), "volume".to_string(), "pallets".to_string()]),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_use_named_dimensions() {
+    let problem = create_problem(
+        vec![
+            create_delivery_job_with_named_demand("job1", vec![1., 0.], vec![("volume", 1.)]),
+            create_delivery_job_with_named_demand("job2", vec![2., 0.], vec![("weight", 2.), ("pallets", 1.)]),
+        ],
+        vec![("weight", 2.), ("volume", 1.), ("pallets", 1.)],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let stops = &solution.tours[0].stops;
+    assert_eq!(stops[0].load, vec![2., 1., 1.]);
+    assert_eq!(stops[0].named_load, Some(to_named_load(vec![("weight", 2.), ("volume", 1.), ("pallets", 1.)])));
+    assert_eq!(
+        stops.last().unwrap().named_load,
+        Some(to_named_load(vec![("weight", 0.), ("volume", 0.), ("pallets", 0.)]))
+    );
+}
+
+#[test]
+fn can_unassign_job_exceeding_named_capacity() {
+    let problem = create_problem(
+        vec![create_delivery_job_with_named_demand("job1", vec![1., 0.], vec![("pallets", 2.)])],
+        vec![("weight", 10.), ("volume", 10.), ("pallets", 1.)],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    let unassigned = solution.unassigned.expect("should have unassigned job");
+    assert_eq!(unassigned[0].job_id, "job1");
+    assert_eq!(unassigned[0].reasons[0].code, 3);
+}
@@ -6,7 +6,11 @@ use crate::helpers::*;
 fn can_use_vehicle_with_open_end() {
     let problem = Problem {
         plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: Option::None },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     };
     let matrix = Matrix {
@@ -27,11 +31,13 @@ fn can_use_vehicle_with_open_end() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job1".to_string(),
-                reasons: vec![UnassignedJobReason { code: 100, description: "location unreachable".to_string() }]
+                reasons: vec![UnassignedJobReason { code: 100, description: "location unreachable".to_string() }],
+                details: None,
             }]),
             ..create_empty_solution()
         }
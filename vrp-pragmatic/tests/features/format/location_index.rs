@@ -23,6 +23,7 @@ fn create_test_problem() -> Problem {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     }
@@ -52,7 +53,8 @@ fn can_use_location_index() {
                 cost: 18.,
                 distance: 3,
                 duration: 5,
-                times: Timing { driving: 3, serving: 2, waiting: 0, break_time: 0 }
+                times: Timing { driving: 3, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -65,7 +67,7 @@ fn can_use_location_index() {
                             "departure",
                             "departure",
                             (0., 0.),
-                            2,
+                            2.,
                             ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                             0
                         )
@@ -76,7 +78,7 @@ fn can_use_location_index() {
                             "job2",
                             "delivery",
                             (0., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z"),
                             2
                         )
@@ -87,7 +89,7 @@ fn can_use_location_index() {
                             "job1",
                             "delivery",
                             (0., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:04Z", "1970-01-01T00:00:05Z"),
                             3
                         )
@@ -97,7 +99,8 @@ fn can_use_location_index() {
                     cost: 18.,
                     distance: 3,
                     duration: 5,
-                    times: Timing { driving: 3, serving: 2, waiting: 0, break_time: 0 }
+                    times: Timing { driving: 3, serving: 2, waiting: 0, break_time: 0 },
+                    value: None,
                 }
             }],
             unassigned: None,
@@ -20,11 +20,17 @@ fn can_limit_by_area() {
                             Location::new_coordinate(5., 5.),
                             Location::new_coordinate(-5., 5.),
                         ],
+                        inner_shapes: None,
                     }]),
+                    forbidden_areas: None,
+                    area_costs: None,
+                    max_jobs: None,
+                    max_stops: None,
                 }),
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -40,6 +46,7 @@ fn can_limit_by_area() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -47,7 +54,8 @@ fn can_limit_by_area() {
                 reasons: vec![UnassignedJobReason {
                     code: 106,
                     description: "cannot be assigned due to area constraint".to_string()
-                }]
+                }],
+                details: None,
             }]),
             ..create_empty_solution()
         }
@@ -0,0 +1,71 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::helpers::*;
+
+fn create_vehicle_with_area_cost(id: &str, fixed: f64, area_cost: Option<f64>) -> VehicleType {
+    VehicleType {
+        costs: VehicleCosts { fixed: Some(fixed), ..create_default_vehicle_costs() },
+        limits: area_cost.map(|cost| VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            allowed_areas: None,
+            forbidden_areas: None,
+            area_costs: Some(vec![AreaCost {
+                cost,
+                outer_shape: vec![
+                    Location::new_coordinate(5., -5.),
+                    Location::new_coordinate(15., -5.),
+                    Location::new_coordinate(15., 5.),
+                    Location::new_coordinate(5., 5.),
+                ],
+                inner_shapes: None,
+            }]),
+            max_jobs: None,
+            max_stops: None,
+        }),
+        ..create_default_vehicle(id)
+    }
+}
+
+#[test]
+fn can_prefer_vehicle_without_area_cost() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![10., 0.])], relations: Option::None },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_area_cost("vehicle1", 10., Some(100.)),
+                create_vehicle_with_area_cost("vehicle2", 20., None),
+            ],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours.first().unwrap().vehicle_id, "vehicle2_1");
+    assert_eq!(solution.statistic.cost, 61.);
+}
+
+#[test]
+fn can_report_area_cost_in_statistic() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![10., 0.])], relations: Option::None },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_area_cost("vehicle1", 10., Some(100.))],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.statistic.cost, 151.);
+}
@@ -0,0 +1,80 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format::Location;
+use crate::helpers::*;
+
+fn create_square(min: (f64, f64), max: (f64, f64)) -> Vec<Location> {
+    vec![
+        Location::new_coordinate(min.0, min.1),
+        Location::new_coordinate(max.0, min.1),
+        Location::new_coordinate(max.0, max.1),
+        Location::new_coordinate(min.0, max.1),
+    ]
+}
+
+fn create_problem_with_forbidden_area(inner_shapes: Option<Vec<Vec<Location>>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![10., 0.]), create_delivery_job("job2", vec![20., 0.])],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    allowed_areas: None,
+                    forbidden_areas: Some(vec![AreaLimit {
+                        priority: None,
+                        outer_shape: create_square((5., -5.), (15., 5.)),
+                        inner_shapes,
+                    }]),
+                    area_costs: None,
+                    max_jobs: None,
+                    max_stops: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_limit_by_forbidden_area() {
+    let problem = create_problem_with_forbidden_area(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(solution.tours.first().unwrap()),
+        vec![vec!["departure"], vec!["job2"], vec!["arrival"]]
+    );
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: 106,
+                description: "cannot be assigned due to area constraint".to_string()
+            }],
+            details: None,
+        }])
+    );
+}
+
+#[test]
+fn can_serve_job_inside_forbidden_area_hole() {
+    let problem = create_problem_with_forbidden_area(Some(vec![create_square((9., -1.), (11., 1.))]));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours.first().unwrap().stops.len(), 4);
+}
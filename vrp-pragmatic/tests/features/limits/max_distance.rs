@@ -8,10 +8,19 @@ fn can_limit_by_max_distance() {
         plan: Plan { jobs: vec![create_delivery_job("job1", vec![100., 0.])], relations: Option::None },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                limits: Some(VehicleLimits { max_distance: Some(99.), shift_time: None, allowed_areas: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(99.),
+                    shift_time: None,
+                    allowed_areas: None,
+                    forbidden_areas: None,
+                    area_costs: None,
+                    max_jobs: None,
+                    max_stops: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -33,6 +42,7 @@ fn can_limit_by_max_distance() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -40,7 +50,8 @@ fn can_limit_by_max_distance() {
                 reasons: vec![UnassignedJobReason {
                     code: 101,
                     description: "cannot be assigned due to max distance constraint of vehicle".to_string()
-                }]
+                }],
+                details: None,
             }]),
             ..create_empty_solution()
         }
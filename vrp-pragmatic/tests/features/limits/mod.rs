@@ -1,3 +1,6 @@
 mod area_allowance;
+mod area_cost;
+mod area_forbidden;
 mod max_distance;
 mod shift_time;
+mod tour_size;
@@ -4,7 +4,15 @@ use crate::helpers::*;
 
 fn create_vehicle_type_with_shift_time_limit(shift_time: f64) -> VehicleType {
     VehicleType {
-        limits: Some(VehicleLimits { max_distance: None, shift_time: Some(shift_time), allowed_areas: None }),
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: Some(shift_time),
+            allowed_areas: None,
+            forbidden_areas: None,
+            area_costs: None,
+            max_jobs: None,
+            max_stops: None,
+        }),
         ..create_default_vehicle_type()
     }
 }
@@ -16,6 +24,7 @@ fn can_limit_one_job_by_shift_time() {
         fleet: Fleet {
             vehicles: vec![create_vehicle_type_with_shift_time_limit(99.)],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -37,6 +46,7 @@ fn can_limit_one_job_by_shift_time() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -44,7 +54,8 @@ fn can_limit_one_job_by_shift_time() {
                 reasons: vec![UnassignedJobReason {
                     code: 102,
                     description: "cannot be assigned due to shift time constraint of vehicle".to_string()
-                }]
+                }],
+                details: None,
             }]),
             ..create_empty_solution()
         }
@@ -67,6 +78,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
         fleet: Fleet {
             vehicles: vec![create_vehicle_type_with_shift_time_limit(40.)],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -82,6 +94,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                 distance: 6,
                 duration: 36,
                 times: Timing { driving: 6, serving: 30, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -92,7 +105,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        3,
+                        3.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -100,7 +113,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                         "job3",
                         "delivery",
                         (3., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:13Z"),
                         3
                     ),
@@ -108,7 +121,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                         "job2",
                         "delivery",
                         (2., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:14Z", "1970-01-01T00:00:24Z"),
                         4
                     ),
@@ -116,7 +129,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:25Z", "1970-01-01T00:00:35Z"),
                         5
                     ),
@@ -124,7 +137,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:36Z", "1970-01-01T00:00:36Z"),
                         6
                     )
@@ -134,6 +147,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                     distance: 6,
                     duration: 36,
                     times: Timing { driving: 6, serving: 30, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             unassigned: Some(vec![
@@ -142,14 +156,16 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                     reasons: vec![UnassignedJobReason {
                         code: 102,
                         description: "cannot be assigned due to shift time constraint of vehicle".to_string()
-                    }]
+                    }],
+                    details: None,
                 },
                 UnassignedJob {
                     job_id: "job5".to_string(),
                     reasons: vec![UnassignedJobReason {
                         code: 102,
                         description: "cannot be assigned due to shift time constraint of vehicle".to_string()
-                    }]
+                    }],
+                    details: None,
                 }
             ]),
             ..create_empty_solution()
@@ -0,0 +1,68 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_test_problem(max_jobs: Option<usize>, max_stops: Option<usize>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![1., 0.]),
+                create_delivery_job("job3", vec![2., 0.]),
+                create_delivery_job("job4", vec![3., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    allowed_areas: None,
+                    forbidden_areas: None,
+                    area_costs: None,
+                    max_jobs,
+                    max_stops,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_unassigned_codes(solution: &Solution) -> Vec<i32> {
+    solution
+        .unassigned
+        .iter()
+        .flat_map(|unassigned| unassigned.iter())
+        .flat_map(|job| job.reasons.iter().map(|reason| reason.code))
+        .collect()
+}
+
+#[test]
+fn can_limit_by_max_jobs() {
+    let problem = create_test_problem(Some(3), None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]).iter().flatten().filter(|id| id.starts_with("job")).count(), 3);
+    assert_eq!(get_unassigned_codes(&solution), vec![112]);
+}
+
+#[test]
+fn can_limit_by_max_stops() {
+    let problem = create_test_problem(None, Some(2));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]).iter().flatten().filter(|id| id.starts_with("job")).count(), 3);
+    assert_eq!(get_unassigned_codes(&solution), vec![112]);
+    assert_eq!(solution.unassigned.as_ref().unwrap().first().unwrap().job_id, "job4");
+}
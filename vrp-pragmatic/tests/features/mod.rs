@@ -1,6 +1,8 @@
 //! This module contains feature tests: minimalistic tests which check features in isolation and combination.
 
+mod battery;
 mod breaks;
+mod compartments;
 mod depots;
 mod fleet;
 mod format;
@@ -11,5 +13,8 @@ mod priorities;
 mod relations;
 mod reload;
 mod skills;
+mod split;
+mod stability;
 mod timing;
+mod tour_order;
 mod work_balance;
@@ -10,15 +10,16 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                 create_delivery_job("simple", vec![1., 0.]),
                 create_multi_job(
                     "multi",
-                    vec![((2., 0.), 1., vec![1]), ((8., 0.), 1., vec![1])],
-                    vec![((6., 0.), 1., vec![2])],
+                    vec![((2., 0.), 1., vec![1.]), ((8., 0.), 1., vec![1.])],
+                    vec![((6., 0.), 1., vec![2.])],
                 ),
             ],
             relations: Option::None,
         },
         fleet: Fleet {
-            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2.])],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -34,6 +35,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                 distance: 16,
                 duration: 20,
                 times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -44,7 +46,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -52,7 +54,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                         "simple",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1
                     ),
@@ -60,7 +62,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                         "multi",
                         "pickup",
                         (2., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
                         2,
                         "p1"
@@ -69,7 +71,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                         "multi",
                         "pickup",
                         (8., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                         8,
                         "p2"
@@ -78,7 +80,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                         "multi",
                         "delivery",
                         (6., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:13Z", "1970-01-01T00:00:14Z"),
                         10,
                         "d1"
@@ -87,7 +89,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:20Z", "1970-01-01T00:00:20Z"),
                         16
                     )
@@ -97,6 +99,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                     distance: 16,
                     duration: 20,
                     times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -110,14 +113,15 @@ fn can_assign_multi_job_in_pickup_effective_way() {
         plan: Plan {
             jobs: vec![create_multi_job(
                 "multi",
-                vec![((4., 0.), 1., vec![1]), ((2., 0.), 1., vec![1])],
-                vec![((6., 0.), 1., vec![2])],
+                vec![((4., 0.), 1., vec![1.]), ((2., 0.), 1., vec![1.])],
+                vec![((6., 0.), 1., vec![2.])],
             )],
             relations: Option::None,
         },
         fleet: Fleet {
-            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2.])],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -133,6 +137,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                 distance: 12,
                 duration: 15,
                 times: Timing { driving: 12, serving: 3, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -143,7 +148,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -151,7 +156,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                         "multi",
                         "pickup",
                         (2., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z"),
                         2,
                         "p2"
@@ -160,7 +165,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                         "multi",
                         "pickup",
                         (4., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
                         4,
                         "p1"
@@ -169,7 +174,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                         "multi",
                         "delivery",
                         (6., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:08Z", "1970-01-01T00:00:09Z"),
                         6,
                         "d1"
@@ -178,7 +183,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:15Z", "1970-01-01T00:00:15Z"),
                         12
                     )
@@ -188,6 +193,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                     distance: 12,
                     duration: 15,
                     times: Timing { driving: 12, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
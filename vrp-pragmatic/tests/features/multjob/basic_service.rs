@@ -27,6 +27,7 @@ fn can_assign_service_job() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -42,6 +43,7 @@ fn can_assign_service_job() {
                 distance: 4,
                 duration: 7,
                 times: Timing { driving: 4, serving: 3, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -52,7 +54,7 @@ fn can_assign_service_job() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -60,7 +62,7 @@ fn can_assign_service_job() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1
                     ),
@@ -68,7 +70,7 @@ fn can_assign_service_job() {
                         "job2",
                         "service",
                         (2., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
                         2
                     ),
@@ -76,7 +78,7 @@ fn can_assign_service_job() {
                         "job3",
                         "pickup",
                         (3., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
                         3
                     ),
@@ -84,7 +86,7 @@ fn can_assign_service_job() {
                         "arrival",
                         "arrival",
                         (4., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:07Z", "1970-01-01T00:00:07Z"),
                         4
                     )
@@ -94,6 +96,7 @@ fn can_assign_service_job() {
                     distance: 4,
                     duration: 7,
                     times: Timing { driving: 4, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -9,20 +9,21 @@ fn can_handle_limited_capacity() {
             jobs: vec![
                 create_multi_job(
                     "multi_1",
-                    vec![((1., 0.), 1., vec![1]), ((2., 0.), 1., vec![1])],
-                    vec![((10., 0.), 1., vec![2])],
+                    vec![((1., 0.), 1., vec![1.]), ((2., 0.), 1., vec![1.])],
+                    vec![((10., 0.), 1., vec![2.])],
                 ),
                 create_multi_job(
                     "multi_2",
-                    vec![((3., 0.), 1., vec![1]), ((4., 0.), 1., vec![1])],
-                    vec![((11., 0.), 1., vec![2])],
+                    vec![((3., 0.), 1., vec![1.]), ((4., 0.), 1., vec![1.])],
+                    vec![((11., 0.), 1., vec![2.])],
                 ),
             ],
             relations: Option::None,
         },
         fleet: Fleet {
-            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2.])],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -38,6 +39,7 @@ fn can_handle_limited_capacity() {
                 distance: 36,
                 duration: 42,
                 times: Timing { driving: 36, serving: 6, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -48,7 +50,7 @@ fn can_handle_limited_capacity() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -56,7 +58,7 @@ fn can_handle_limited_capacity() {
                         "multi_1",
                         "pickup",
                         (1., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1,
                         "p1",
@@ -65,7 +67,7 @@ fn can_handle_limited_capacity() {
                         "multi_1",
                         "pickup",
                         (2., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
                         2,
                         "p2",
@@ -74,7 +76,7 @@ fn can_handle_limited_capacity() {
                         "multi_1",
                         "delivery",
                         (10., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:12Z", "1970-01-01T00:00:13Z"),
                         10,
                         "d1",
@@ -83,7 +85,7 @@ fn can_handle_limited_capacity() {
                         "multi_2",
                         "pickup",
                         (3., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:20Z", "1970-01-01T00:00:21Z"),
                         17,
                         "p1",
@@ -92,7 +94,7 @@ fn can_handle_limited_capacity() {
                         "multi_2",
                         "pickup",
                         (4., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:22Z", "1970-01-01T00:00:23Z"),
                         18,
                         "p2",
@@ -101,7 +103,7 @@ fn can_handle_limited_capacity() {
                         "multi_2",
                         "delivery",
                         (11., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:30Z", "1970-01-01T00:00:31Z"),
                         25,
                         "d1",
@@ -110,7 +112,7 @@ fn can_handle_limited_capacity() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:42Z", "1970-01-01T00:00:42Z"),
                         36,
                     )
@@ -120,6 +122,7 @@ fn can_handle_limited_capacity() {
                     distance: 36,
                     duration: 42,
                     times: Timing { driving: 36, serving: 6, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
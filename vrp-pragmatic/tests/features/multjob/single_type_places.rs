@@ -7,7 +7,7 @@ use crate::helpers::*;
 fn can_use_only_deliveries_as_static_demand() {
     let problem = Problem {
         plan: Plan {
-            jobs: vec![create_multi_job("job1", vec![], vec![((8., 0.), 2., vec![1]), ((2., 0.), 1., vec![1])])],
+            jobs: vec![create_multi_job("job1", vec![], vec![((8., 0.), 2., vec![1.]), ((2., 0.), 1., vec![1.])])],
             relations: None,
         },
         fleet: Fleet {
@@ -16,6 +16,7 @@ fn can_use_only_deliveries_as_static_demand() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -31,6 +32,7 @@ fn can_use_only_deliveries_as_static_demand() {
                 distance: 8,
                 duration: 11,
                 times: Timing { driving: 8, serving: 3, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -41,7 +43,7 @@ fn can_use_only_deliveries_as_static_demand() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -49,7 +51,7 @@ fn can_use_only_deliveries_as_static_demand() {
                         "job1",
                         "delivery",
                         (2., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z"),
                         2,
                         "d2"
@@ -58,7 +60,7 @@ fn can_use_only_deliveries_as_static_demand() {
                         "job1",
                         "delivery",
                         (8., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:09Z", "1970-01-01T00:00:11Z"),
                         8,
                         "d1"
@@ -69,6 +71,7 @@ fn can_use_only_deliveries_as_static_demand() {
                     distance: 8,
                     duration: 11,
                     times: Timing { driving: 8, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -80,7 +83,7 @@ fn can_use_only_deliveries_as_static_demand() {
 fn can_use_only_pickups_as_static_demand() {
     let problem = Problem {
         plan: Plan {
-            jobs: vec![create_multi_job("job1", vec![((8., 0.), 2., vec![1]), ((2., 0.), 1., vec![1])], vec![])],
+            jobs: vec![create_multi_job("job1", vec![((8., 0.), 2., vec![1.]), ((2., 0.), 1., vec![1.])], vec![])],
             relations: None,
         },
         fleet: Fleet {
@@ -96,6 +99,7 @@ fn can_use_only_pickups_as_static_demand() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -111,6 +115,7 @@ fn can_use_only_pickups_as_static_demand() {
                 distance: 10,
                 duration: 13,
                 times: Timing { driving: 10, serving: 3, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -121,7 +126,7 @@ fn can_use_only_pickups_as_static_demand() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -129,7 +134,7 @@ fn can_use_only_pickups_as_static_demand() {
                         "job1",
                         "pickup",
                         (2., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z"),
                         2,
                         "p2"
@@ -138,7 +143,7 @@ fn can_use_only_pickups_as_static_demand() {
                         "job1",
                         "pickup",
                         (8., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:09Z", "1970-01-01T00:00:11Z"),
                         8,
                         "p1"
@@ -147,7 +152,7 @@ fn can_use_only_pickups_as_static_demand() {
                         "arrival",
                         "arrival",
                         (10., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:13Z", "1970-01-01T00:00:13Z"),
                         10
                     ),
@@ -157,6 +162,7 @@ fn can_use_only_pickups_as_static_demand() {
                     distance: 10,
                     duration: 13,
                     times: Timing { driving: 10, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -8,14 +8,15 @@ fn can_unassign_multi_job_due_to_capacity() {
         plan: Plan {
             jobs: vec![create_multi_job(
                 "multi",
-                vec![((2., 0.), 1., vec![2]), ((8., 0.), 1., vec![1])],
-                vec![((6., 0.), 1., vec![3])],
+                vec![((2., 0.), 1., vec![2.]), ((8., 0.), 1., vec![1.])],
+                vec![((6., 0.), 1., vec![3.])],
             )],
             relations: Option::None,
         },
         fleet: Fleet {
-            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2.])],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -31,6 +32,7 @@ fn can_unassign_multi_job_due_to_capacity() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
@@ -38,7 +40,8 @@ fn can_unassign_multi_job_due_to_capacity() {
                 reasons: vec![UnassignedJobReason {
                     code: 3,
                     description: "does not fit into any vehicle due to capacity".to_string()
-                }]
+                }],
+                details: None,
             }]),
             ..create_empty_solution()
         }
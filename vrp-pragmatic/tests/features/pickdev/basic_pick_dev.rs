@@ -6,7 +6,11 @@ use crate::helpers::*;
 fn can_use_one_pickup_delivery_job_with_one_vehicle() {
     let problem = Problem {
         plan: Plan { jobs: vec![create_pickup_delivery_job("job1", vec![1., 0.], vec![2., 0.])], relations: None },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -21,6 +25,7 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                 distance: 4,
                 duration: 6,
                 times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -31,7 +36,7 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -39,7 +44,7 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                         "job1",
                         "pickup",
                         (1., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1,
                         "p1"
@@ -48,7 +53,7 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                         "job1",
                         "delivery",
                         (2., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
                         2,
                         "d1"
@@ -57,7 +62,7 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:06Z", "1970-01-01T00:00:06Z"),
                         4
                     )
@@ -67,6 +72,7 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                     distance: 4,
                     duration: 6,
                     times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
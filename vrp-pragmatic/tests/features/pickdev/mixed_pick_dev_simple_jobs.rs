@@ -13,7 +13,11 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
             ],
             relations: None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -28,6 +32,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                 distance: 8,
                 duration: 12,
                 times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -38,7 +43,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -46,7 +51,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                         "job2",
                         "pickup",
                         (2., 0.),
-                        3,
+                        3.,
                         ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z"),
                         2,
                         "p1"
@@ -55,7 +60,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                         "job3",
                         "delivery",
                         (4., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
                         4
                     ),
@@ -63,7 +68,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                         "job2",
                         "delivery",
                         (3., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                         5,
                         "d1"
@@ -72,7 +77,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                         7
                     ),
@@ -80,7 +85,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:12Z", "1970-01-01T00:00:12Z"),
                         8
                     )
@@ -90,6 +95,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                     distance: 8,
                     duration: 12,
                     times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 }
             }],
             ..create_empty_solution()
@@ -23,6 +23,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -38,6 +39,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 distance: 50,
                 duration: 54,
                 times: Timing { driving: 50, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -48,7 +50,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                         "departure",
                         "departure",
                         (10., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -56,7 +58,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                         "job1",
                         "pickup",
                         (20., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                         10,
                         "p1"
@@ -65,7 +67,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                         "job2",
                         "pickup",
                         (5., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:26Z", "1970-01-01T00:00:27Z"),
                         25,
                         "p1"
@@ -74,7 +76,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                         "job1",
                         "delivery",
                         (15., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:37Z", "1970-01-01T00:00:38Z"),
                         35,
                         "d1"
@@ -83,7 +85,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                         "job2",
                         "delivery",
                         (20., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:43Z", "1970-01-01T00:00:44Z"),
                         40,
                         "d1"
@@ -92,7 +94,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                         "arrival",
                         "arrival",
                         (10., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:54Z", "1970-01-01T00:00:54Z"),
                         50
                     )
@@ -102,6 +104,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                     distance: 50,
                     duration: 54,
                     times: Timing { driving: 50, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
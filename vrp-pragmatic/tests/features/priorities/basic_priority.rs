@@ -19,6 +19,7 @@ fn can_follow_priorities() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -34,6 +35,7 @@ fn can_follow_priorities() {
                 distance: 20,
                 duration: 23,
                 times: Timing { driving: 20, serving: 3, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -44,7 +46,7 @@ fn can_follow_priorities() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        3,
+                        3.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -52,7 +54,7 @@ fn can_follow_priorities() {
                         "job3",
                         "delivery",
                         (7., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                         7
                     ),
@@ -60,7 +62,7 @@ fn can_follow_priorities() {
                         "job2",
                         "delivery",
                         (5., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                         9
                     ),
@@ -68,7 +70,7 @@ fn can_follow_priorities() {
                         "job1",
                         "delivery",
                         (2., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:14Z", "1970-01-01T00:00:15Z"),
                         12
                     ),
@@ -76,7 +78,7 @@ fn can_follow_priorities() {
                         "arrival",
                         "arrival",
                         (10., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:23Z", "1970-01-01T00:00:23Z"),
                         20
                     )
@@ -86,6 +88,7 @@ fn can_follow_priorities() {
                     distance: 20,
                     duration: 23,
                     times: Timing { driving: 20, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -0,0 +1,127 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_prefer_job_with_higher_value() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { value: Some(100.), ..create_delivery_job("job1", vec![5., 0.]) },
+                Job { value: Some(10.), ..create_delivery_job("job2", vec![1., 0.]) },
+            ],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![1.])],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        objectives: Some(Objectives { primary: vec![MaximizeValue], secondary: Some(vec![MinimizeCost]) }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 31.,
+                distance: 10,
+                duration: 11,
+                times: Timing { driving: 10, serving: 1, waiting: 0, break_time: 0 },
+                value: Some(100.),
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1.,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (5., 0.),
+                        0.,
+                        ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                        5
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0.,
+                        ("1970-01-01T00:00:11Z", "1970-01-01T00:00:11Z"),
+                        10
+                    )
+                ],
+                statistic: Statistic {
+                    cost: 31.,
+                    distance: 10,
+                    duration: 11,
+                    times: Timing { driving: 10, serving: 1, waiting: 0, break_time: 0 },
+                    value: Some(100.),
+                },
+            }],
+            unassigned: Some(vec![UnassignedJob {
+                job_id: "job2".to_string(),
+                reasons: vec![UnassignedJobReason {
+                    code: 3,
+                    description: "does not fit into any vehicle due to capacity".to_string()
+                }],
+                details: None,
+            }]),
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_skip_job_when_serving_costs_more_than_its_value() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { value: Some(100.), ..create_delivery_job("job1", vec![1., 0.]) },
+                Job { value: Some(1.), ..create_delivery_job("job2", vec![100., 0.]) },
+                create_delivery_job("job3", vec![2., 0.]),
+            ],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![10.])],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        objectives: Some(Objectives { primary: vec![MaximizeValue], secondary: Some(vec![MinimizeCost]) }),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let mut job_ids = get_ids_from_tour(&solution.tours[0]).concat();
+    job_ids.sort();
+    assert_eq!(job_ids, vec!["arrival", "departure", "job1", "job3"]);
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job2".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: 107,
+                description: "cannot be served as serving cost exceeds job value".to_string()
+            }],
+            details: None,
+        }])
+    );
+}
@@ -1 +1,2 @@
 mod basic_priority;
+mod basic_value;
@@ -21,10 +21,11 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                capacity: vec![3],
+                capacity: vec![3.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -40,6 +41,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                 distance: 3,
                 duration: 6,
                 times: Timing { driving: 3, serving: 3, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -50,7 +52,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        3,
+                        3.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0,
                     ),
@@ -58,7 +60,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1,
                     ),
@@ -66,7 +68,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                         "job2",
                         "delivery",
                         (2., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
                         2,
                     ),
@@ -74,7 +76,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                         "job3",
                         "delivery",
                         (3., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
                         3,
                     )
@@ -84,6 +86,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                     distance: 3,
                     duration: 6,
                     times: Timing { driving: 3, serving: 3, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -30,7 +30,11 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                 },
             ]),
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -45,6 +49,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                 distance: 18,
                 duration: 25,
                 times: Timing { driving: 18, serving: 7, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -55,7 +60,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        7,
+                        7.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -63,7 +68,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                         "job4",
                         "delivery",
                         (4., 0.),
-                        6,
+                        6.,
                         ("1970-01-01T00:00:04Z", "1970-01-01T00:00:05Z"),
                         4
                     ),
@@ -71,7 +76,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                         "job2",
                         "delivery",
                         (2., 0.),
-                        5,
+                        5.,
                         ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                         6
                     ),
@@ -79,7 +84,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                         "job6",
                         "delivery",
                         (6., 0.),
-                        4,
+                        4.,
                         ("1970-01-01T00:00:12Z", "1970-01-01T00:00:13Z"),
                         10
                     ),
@@ -87,7 +92,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                         "job7",
                         "delivery",
                         (7., 0.),
-                        3,
+                        3.,
                         ("1970-01-01T00:00:14Z", "1970-01-01T00:00:15Z"),
                         11
                     ),
@@ -95,7 +100,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                         "job5",
                         "delivery",
                         (5., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:17Z", "1970-01-01T00:00:18Z"),
                         13
                     ),
@@ -103,7 +108,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                         "job3",
                         "delivery",
                         (3., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:20Z", "1970-01-01T00:00:21Z"),
                         15
                     ),
@@ -111,7 +116,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:23Z", "1970-01-01T00:00:24Z"),
                         17
                     ),
@@ -119,7 +124,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:25Z", "1970-01-01T00:00:25Z"),
                         18
                     )
@@ -129,6 +134,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                     distance: 18,
                     duration: 25,
                     times: Timing { driving: 18, serving: 7, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -30,7 +30,11 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                 },
             ]),
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -45,6 +49,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                 distance: 22,
                 duration: 29,
                 times: Timing { driving: 22, serving: 7, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -55,7 +60,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        7,
+                        7.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -63,7 +68,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                         "job4",
                         "delivery",
                         (4., 0.),
-                        6,
+                        6.,
                         ("1970-01-01T00:00:04Z", "1970-01-01T00:00:05Z"),
                         4
                     ),
@@ -71,7 +76,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                         "job2",
                         "delivery",
                         (2., 0.),
-                        5,
+                        5.,
                         ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                         6
                     ),
@@ -79,7 +84,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                         "job6",
                         "delivery",
                         (6., 0.),
-                        4,
+                        4.,
                         ("1970-01-01T00:00:12Z", "1970-01-01T00:00:13Z"),
                         10
                     ),
@@ -87,7 +92,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                         "job7",
                         "delivery",
                         (7., 0.),
-                        3,
+                        3.,
                         ("1970-01-01T00:00:14Z", "1970-01-01T00:00:15Z"),
                         11
                     ),
@@ -95,7 +100,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                         "job5",
                         "delivery",
                         (5., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:17Z", "1970-01-01T00:00:18Z"),
                         13
                     ),
@@ -103,7 +108,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:22Z", "1970-01-01T00:00:23Z"),
                         17
                     ),
@@ -111,7 +116,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                         "job3",
                         "delivery",
                         (3., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:25Z", "1970-01-01T00:00:26Z"),
                         19
                     ),
@@ -119,7 +124,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:29Z", "1970-01-01T00:00:29Z"),
                         22
                     )
@@ -129,6 +134,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                     distance: 22,
                     duration: 29,
                     times: Timing { driving: 22, serving: 7, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -181,10 +187,11 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
             vehicles: vec![VehicleType {
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 shifts: vec![create_default_open_vehicle_shift()],
-                capacity: vec![5],
+                capacity: vec![5.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -200,6 +207,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                 distance: 26,
                 duration: 34,
                 times: Timing { driving: 26, serving: 8, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![
                 Tour {
@@ -211,7 +219,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                             "departure",
                             "departure",
                             (0., 0.),
-                            4,
+                            4.,
                             ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                             0
                         ),
@@ -219,7 +227,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                             "job1",
                             "delivery",
                             (1., 0.),
-                            3,
+                            3.,
                             ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                             1
                         ),
@@ -227,7 +235,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                             "job6",
                             "delivery",
                             (6., 0.),
-                            2,
+                            2.,
                             ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                             6
                         ),
@@ -235,7 +243,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                             "job3",
                             "delivery",
                             (3., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:00:11Z", "1970-01-01T00:00:12Z"),
                             9
                         ),
@@ -243,7 +251,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                             "job7",
                             "delivery",
                             (7., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:16Z", "1970-01-01T00:00:17Z"),
                             13
                         )
@@ -253,6 +261,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         distance: 13,
                         duration: 17,
                         times: Timing { driving: 13, serving: 4, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 },
                 Tour {
@@ -264,7 +273,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                             "departure",
                             "departure",
                             (0., 0.),
-                            4,
+                            4.,
                             ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                             0
                         ),
@@ -272,7 +281,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                             "job2",
                             "delivery",
                             (2., 0.),
-                            3,
+                            3.,
                             ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z"),
                             2
                         ),
@@ -280,7 +289,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                             "job8",
                             "delivery",
                             (8., 0.),
-                            2,
+                            2.,
                             ("1970-01-01T00:00:09Z", "1970-01-01T00:00:10Z"),
                             8
                         ),
@@ -288,7 +297,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                             "job4",
                             "delivery",
                             (4., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:00:14Z", "1970-01-01T00:00:15Z"),
                             12
                         ),
@@ -296,7 +305,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                             "job5",
                             "delivery",
                             (5., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:16Z", "1970-01-01T00:00:17Z"),
                             13
                         )
@@ -306,6 +315,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         distance: 13,
                         duration: 17,
                         times: Timing { driving: 13, serving: 4, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 }
             ],
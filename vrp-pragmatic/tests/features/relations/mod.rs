@@ -3,3 +3,4 @@ mod mixed_strict_any;
 mod mixed_strict_sequence;
 mod strict_with_new_jobs;
 mod strict_with_old_jobs;
+mod with_plan_progress;
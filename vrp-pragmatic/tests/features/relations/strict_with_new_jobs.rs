@@ -36,10 +36,11 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
-                capacity: vec![5],
+                capacity: vec![5.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -55,6 +56,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                 distance: 42,
                 duration: 52,
                 times: Timing { driving: 42, serving: 10, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![
                 Tour {
@@ -66,7 +68,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "departure",
                             "departure",
                             (0., 0.),
-                            5,
+                            5.,
                             ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                             0
                         ),
@@ -74,7 +76,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "job1",
                             "delivery",
                             (1., 0.),
-                            4,
+                            4.,
                             ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                             1
                         ),
@@ -82,7 +84,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "job6",
                             "delivery",
                             (6., 0.),
-                            3,
+                            3.,
                             ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                             6
                         ),
@@ -90,7 +92,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "job4",
                             "delivery",
                             (4., 0.),
-                            2,
+                            2.,
                             ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                             8
                         ),
@@ -98,7 +100,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "job8",
                             "delivery",
                             (8., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:00:15Z", "1970-01-01T00:00:16Z"),
                             12
                         ),
@@ -106,7 +108,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "job9",
                             "delivery",
                             (9., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:17Z", "1970-01-01T00:00:18Z"),
                             13
                         ),
@@ -114,7 +116,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "arrival",
                             "arrival",
                             (0., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:27Z", "1970-01-01T00:00:27Z"),
                             22
                         )
@@ -124,6 +126,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         distance: 22,
                         duration: 27,
                         times: Timing { driving: 22, serving: 5, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 },
                 Tour {
@@ -135,7 +138,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "departure",
                             "departure",
                             (0., 0.),
-                            5,
+                            5.,
                             ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                             0
                         ),
@@ -143,7 +146,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "job2",
                             "delivery",
                             (2., 0.),
-                            4,
+                            4.,
                             ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z"),
                             2
                         ),
@@ -151,7 +154,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "job3",
                             "delivery",
                             (3., 0.),
-                            3,
+                            3.,
                             ("1970-01-01T00:00:04Z", "1970-01-01T00:00:05Z"),
                             3
                         ),
@@ -159,7 +162,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "job5",
                             "delivery",
                             (5., 0.),
-                            2,
+                            2.,
                             ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                             5
                         ),
@@ -167,7 +170,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "job7",
                             "delivery",
                             (7., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                             7
                         ),
@@ -175,7 +178,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "job10",
                             "delivery",
                             (10., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:14Z", "1970-01-01T00:00:15Z"),
                             10
                         ),
@@ -183,7 +186,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                             "arrival",
                             "arrival",
                             (0., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:25Z", "1970-01-01T00:00:25Z"),
                             20
                         )
@@ -193,6 +196,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         distance: 20,
                         duration: 25,
                         times: Timing { driving: 20, serving: 5, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 }
             ],
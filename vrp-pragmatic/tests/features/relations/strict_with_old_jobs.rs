@@ -34,10 +34,11 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
-                capacity: vec![5],
+                capacity: vec![5.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -53,6 +54,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                 distance: 34,
                 duration: 42,
                 times: Timing { driving: 34, serving: 8, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![
                 Tour {
@@ -64,7 +66,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             "departure",
                             "departure",
                             (0., 0.),
-                            4,
+                            4.,
                             ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                             0
                         ),
@@ -72,7 +74,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             "job1",
                             "delivery",
                             (1., 0.),
-                            3,
+                            3.,
                             ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                             1
                         ),
@@ -80,7 +82,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             "job6",
                             "delivery",
                             (6., 0.),
-                            2,
+                            2.,
                             ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                             6
                         ),
@@ -88,7 +90,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             "job4",
                             "delivery",
                             (4., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                             8
                         ),
@@ -96,7 +98,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             "job8",
                             "delivery",
                             (8., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:15Z", "1970-01-01T00:00:16Z"),
                             12
                         ),
@@ -104,7 +106,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             "arrival",
                             "arrival",
                             (0., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:24Z", "1970-01-01T00:00:24Z"),
                             20
                         )
@@ -114,6 +116,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         distance: 20,
                         duration: 24,
                         times: Timing { driving: 20, serving: 4, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 },
                 Tour {
@@ -125,7 +128,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             "departure",
                             "departure",
                             (0., 0.),
-                            4,
+                            4.,
                             ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                             0
                         ),
@@ -133,7 +136,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             "job2",
                             "delivery",
                             (2., 0.),
-                            3,
+                            3.,
                             ("1970-01-01T00:00:02Z", "1970-01-01T00:00:03Z"),
                             2
                         ),
@@ -141,7 +144,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             "job3",
                             "delivery",
                             (3., 0.),
-                            2,
+                            2.,
                             ("1970-01-01T00:00:04Z", "1970-01-01T00:00:05Z"),
                             3
                         ),
@@ -149,7 +152,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             "job5",
                             "delivery",
                             (5., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                             5
                         ),
@@ -157,7 +160,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             "job7",
                             "delivery",
                             (7., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                             7
                         ),
@@ -165,7 +168,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                             "arrival",
                             "arrival",
                             (0., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:18Z", "1970-01-01T00:00:18Z"),
                             14
                         )
@@ -175,6 +178,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         distance: 14,
                         duration: 18,
                         times: Timing { driving: 14, serving: 4, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 }
             ],
@@ -0,0 +1,52 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_solve_remaining_part_of_the_plan() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_pickup_delivery_job("job3", vec![3., 0.], vec![4., 0.]),
+                create_delivery_job("job4", vec![5., 0.]),
+                create_delivery_job("job5", vec![6., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+    let progress = PlanProgress {
+        vehicles: vec![VehicleProgress {
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: 0,
+            location: vec![3., 0.].to_loc(),
+            time: format_time(5.),
+            completed: Some(to_strings(vec!["job1", "job3"])),
+            committed: Some(to_strings(vec!["job5"])),
+            assigned: None,
+        }],
+    };
+    let problem = apply_progress(problem, &progress).expect("cannot apply progress");
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution.tours.iter().find(|tour| tour.vehicle_id == "my_vehicle_1").expect("no tour");
+    let ids = get_ids_from_tour(tour).into_iter().flatten().collect::<Vec<_>>();
+    assert_eq!(ids.first().map(|id| id.as_str()), Some("departure"));
+    assert_eq!(ids.get(1).map(|id| id.as_str()), Some("job5"));
+    assert!(ids.contains(&"job3".to_string()));
+    assert!(!ids.contains(&"job1".to_string()));
+    assert_eq!(tour.stops.first().unwrap().time.departure, format_time(5.));
+}
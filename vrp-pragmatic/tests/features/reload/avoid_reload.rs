@@ -11,8 +11,8 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                 create_delivery_job("simple", vec![1., 0.]),
                 create_multi_job(
                     "multi",
-                    vec![((2., 0.), 1., vec![1]), ((8., 0.), 1., vec![1])],
-                    vec![((6., 0.), 1., vec![2])],
+                    vec![((2., 0.), 1., vec![1.]), ((8., 0.), 1., vec![1.])],
+                    vec![((6., 0.), 1., vec![2.])],
                 ),
             ],
             relations: Option::None,
@@ -35,10 +35,11 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                         tag: None,
                     }]),
                 }],
-                capacity: vec![2],
+                capacity: vec![2.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -54,6 +55,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                 distance: 16,
                 duration: 20,
                 times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -64,7 +66,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -72,7 +74,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                         "simple",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1
                     ),
@@ -80,7 +82,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                         "multi",
                         "pickup",
                         (2., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
                         2,
                         "p1"
@@ -89,7 +91,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                         "multi",
                         "pickup",
                         (8., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                         8,
                         "p2"
@@ -98,7 +100,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                         "multi",
                         "delivery",
                         (6., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:13Z", "1970-01-01T00:00:14Z"),
                         10,
                         "d1"
@@ -107,7 +109,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:20Z", "1970-01-01T00:00:20Z"),
                         16
                     )
@@ -117,6 +119,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload() {
                     distance: 16,
                     duration: 20,
                     times: Timing { driving: 16, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -23,8 +23,7 @@ can_use_vehicle_with_two_tours_and_two_jobs! {
                     reasons: vec![UnassignedJobReason {
                         code: 3,
                         description: "does not fit into any vehicle due to capacity".to_string()
-                    }]
-                }
+                    }], details: None, }
              ])),
 }
 
@@ -49,10 +48,11 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         tag: None,
                     }]),
                 }],
-                capacity: vec![1],
+                capacity: vec![1.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -68,6 +68,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -78,7 +79,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         "departure",
                         "departure",
                         (0., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -86,7 +87,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         "job1",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1
                     ),
@@ -94,7 +95,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         "reload",
                         "reload",
                         (0., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:05Z"),
                         2
                     ),
@@ -102,7 +103,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         "job2",
                         "delivery",
                         (2., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                         4
                     ),
@@ -110,7 +111,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:10Z"),
                         6
                     ),
@@ -120,6 +121,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             unassigned,
@@ -42,10 +42,11 @@ fn can_use_reloads_with_different_locations() {
                         },
                     ]),
                 }],
-                capacity: vec![2],
+                capacity: vec![2.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -61,6 +62,7 @@ fn can_use_reloads_with_different_locations() {
                 distance: 38,
                 duration: 47,
                 times: Timing { driving: 38, serving: 9, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -71,7 +73,7 @@ fn can_use_reloads_with_different_locations() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -79,7 +81,7 @@ fn can_use_reloads_with_different_locations() {
                         "job1",
                         "delivery",
                         (10., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                         10
                     ),
@@ -87,7 +89,7 @@ fn can_use_reloads_with_different_locations() {
                         "job2",
                         "delivery",
                         (11., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:12Z", "1970-01-01T00:00:13Z"),
                         11
                     ),
@@ -95,7 +97,7 @@ fn can_use_reloads_with_different_locations() {
                         "reload",
                         "reload",
                         (12., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:14Z", "1970-01-01T00:00:16Z"),
                         12,
                         "close"
@@ -104,7 +106,7 @@ fn can_use_reloads_with_different_locations() {
                         "job3",
                         "delivery",
                         (20., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:24Z", "1970-01-01T00:00:25Z"),
                         20
                     ),
@@ -112,7 +114,7 @@ fn can_use_reloads_with_different_locations() {
                         "job4",
                         "delivery",
                         (21., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:26Z", "1970-01-01T00:00:27Z"),
                         21
                     ),
@@ -120,7 +122,7 @@ fn can_use_reloads_with_different_locations() {
                         "reload",
                         "reload",
                         (33., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:39Z", "1970-01-01T00:00:41Z"),
                         33,
                         "far"
@@ -129,7 +131,7 @@ fn can_use_reloads_with_different_locations() {
                         "job5",
                         "delivery",
                         (30., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:44Z", "1970-01-01T00:00:45Z"),
                         36
                     ),
@@ -137,7 +139,7 @@ fn can_use_reloads_with_different_locations() {
                         "arrival",
                         "arrival",
                         (32., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:47Z", "1970-01-01T00:00:47Z"),
                         38
                     ),
@@ -147,6 +149,7 @@ fn can_use_reloads_with_different_locations() {
                     distance: 38,
                     duration: 47,
                     times: Timing { driving: 38, serving: 9, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
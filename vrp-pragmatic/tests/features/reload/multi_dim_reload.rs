@@ -8,8 +8,8 @@ fn can_use_multi_dim_capacity() {
     let problem = Problem {
         plan: Plan {
             jobs: vec![
-                create_delivery_job_with_demand("job1", vec![1., 0.], vec![1, 1]),
-                create_delivery_job_with_demand("job2", vec![2., 0.], vec![1, 1]),
+                create_delivery_job_with_demand("job1", vec![1., 0.], vec![1., 1.]),
+                create_delivery_job_with_demand("job2", vec![2., 0.], vec![1., 1.]),
             ],
             relations: None,
         },
@@ -31,10 +31,11 @@ fn can_use_multi_dim_capacity() {
                         tag: None,
                     }]),
                 }],
-                capacity: vec![1, 1],
+                capacity: vec![1., 1.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -50,6 +51,7 @@ fn can_use_multi_dim_capacity() {
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -60,7 +62,7 @@ fn can_use_multi_dim_capacity() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        vec![1, 1],
+                        vec![1., 1.],
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -68,7 +70,7 @@ fn can_use_multi_dim_capacity() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        vec![0, 0],
+                        vec![0., 0.],
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1
                     ),
@@ -76,7 +78,7 @@ fn can_use_multi_dim_capacity() {
                         "reload",
                         "reload",
                         (0., 0.),
-                        vec![1, 1],
+                        vec![1., 1.],
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:05Z"),
                         2
                     ),
@@ -84,7 +86,7 @@ fn can_use_multi_dim_capacity() {
                         "job2",
                         "delivery",
                         (2., 0.),
-                        vec![0, 0],
+                        vec![0., 0.],
                         ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                         4
                     ),
@@ -92,7 +94,7 @@ fn can_use_multi_dim_capacity() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        vec![0, 0],
+                        vec![0., 0.],
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:10Z"),
                         6
                     ),
@@ -102,6 +104,7 @@ fn can_use_multi_dim_capacity() {
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -14,8 +14,8 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 create_delivery_job("simple3", vec![7., 0.]),
                 create_multi_job(
                     "multi",
-                    vec![((2., 0.), 1., vec![1]), ((8., 0.), 1., vec![1])],
-                    vec![((9., 0.), 1., vec![2])],
+                    vec![((2., 0.), 1., vec![1.]), ((8., 0.), 1., vec![1.])],
+                    vec![((9., 0.), 1., vec![2.])],
                 ),
             ],
             relations: Option::None,
@@ -38,10 +38,11 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         tag: None,
                     }]),
                 }],
-                capacity: vec![2],
+                capacity: vec![2.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -57,6 +58,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 distance: 14,
                 duration: 22,
                 times: Timing { driving: 14, serving: 8, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -67,7 +69,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0,
                     ),
@@ -75,7 +77,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         "simple1",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1,
                     ),
@@ -83,7 +85,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         "reload",
                         "reload",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:05Z"),
                         2,
                     ),
@@ -91,7 +93,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         "simple2",
                         "delivery",
                         (3., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:08Z", "1970-01-01T00:00:09Z"),
                         5,
                     ),
@@ -99,7 +101,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         "multi",
                         "pickup",
                         (2., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                         6,
                         "p1",
@@ -108,7 +110,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         "simple3",
                         "delivery",
                         (7., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:16Z", "1970-01-01T00:00:17Z"),
                         11,
                     ),
@@ -116,7 +118,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         "multi",
                         "pickup",
                         (8., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:18Z", "1970-01-01T00:00:19Z"),
                         12,
                         "p2",
@@ -125,7 +127,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         "multi",
                         "delivery",
                         (9., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:20Z", "1970-01-01T00:00:21Z"),
                         13,
                         "d1",
@@ -134,7 +136,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         "arrival",
                         "arrival",
                         (10., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:22Z", "1970-01-01T00:00:22Z"),
                         14,
                     )
@@ -144,6 +146,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                     distance: 14,
                     duration: 22,
                     times: Timing { driving: 14, serving: 8, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -158,11 +161,11 @@ fn can_properly_handle_load_without_capacity_violation() {
             jobs: vec![
                 create_pickup_delivery_job_with_params(
                     "job1",
-                    vec![2],
+                    vec![2.],
                     (vec![52., 0.], 10., vec![]),
                     (vec![1., 0.], 12., vec![]),
                 ),
-                create_pickup_job_with_demand("job2", vec![67., 0.], vec![2]),
+                create_pickup_job_with_demand("job2", vec![67., 0.], vec![2.]),
             ],
             relations: None,
         },
@@ -186,10 +189,11 @@ fn can_properly_handle_load_without_capacity_violation() {
                     ]),
                     ..create_default_vehicle_shift()
                 }],
-                capacity: vec![2],
+                capacity: vec![2.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
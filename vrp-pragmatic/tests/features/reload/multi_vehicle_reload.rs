@@ -29,10 +29,11 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                         tag: None,
                     }]),
                 }],
-                capacity: vec![1],
+                capacity: vec![1.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -48,6 +49,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -58,7 +60,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0,
                     ),
@@ -66,7 +68,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1,
                     ),
@@ -74,7 +76,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                         "reload",
                         "reload",
                         (0., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:05Z"),
                         2,
                     ),
@@ -82,7 +84,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                         "job2",
                         "delivery",
                         (2., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                         4,
                     ),
@@ -90,7 +92,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:10Z"),
                         6,
                     ),
@@ -100,6 +102,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -34,10 +34,11 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         tag: None,
                     }]),
                 }],
-                capacity: vec![1],
+                capacity: vec![1.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -53,6 +54,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                 distance: 6,
                 duration: 12,
                 times: Timing { driving: 6, serving: 6, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -63,7 +65,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -71,7 +73,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         "d1",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                         1
                     ),
@@ -79,7 +81,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         "p1",
                         "pickup",
                         (2., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
                         2
                     ),
@@ -87,7 +89,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         "reload",
                         "reload",
                         (3., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:05Z", "1970-01-01T00:00:07Z"),
                         3
                     ),
@@ -95,7 +97,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         "d2",
                         "delivery",
                         (4., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:08Z", "1970-01-01T00:00:09Z"),
                         4
                     ),
@@ -103,7 +105,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         "p2",
                         "pickup",
                         (5., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                         5
                     ),
@@ -111,7 +113,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         "arrival",
                         "arrival",
                         (6., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:12Z", "1970-01-01T00:00:12Z"),
                         6
                     ),
@@ -121,6 +123,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                     distance: 6,
                     duration: 12,
                     times: Timing { driving: 6, serving: 6, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             unassigned: Some(vec![UnassignedJob {
@@ -129,6 +132,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                     code: 3,
                     description: "does not fit into any vehicle due to capacity".to_string()
                 }],
+                details: None,
             }]),
             ..create_empty_solution()
         }
@@ -21,6 +21,7 @@ fn can_wait_for_job_start() {
                 },
             ],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -36,6 +37,7 @@ fn can_wait_for_job_start() {
                 distance: 18,
                 duration: 19,
                 times: Timing { driving: 18, serving: 1, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_with_skill_1".to_string(),
@@ -46,7 +48,7 @@ fn can_wait_for_job_start() {
                         "departure",
                         "departure",
                         (10., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -54,7 +56,7 @@ fn can_wait_for_job_start() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:09Z", "1970-01-01T00:00:10Z"),
                         9
                     ),
@@ -62,7 +64,7 @@ fn can_wait_for_job_start() {
                         "arrival",
                         "arrival",
                         (10., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:19Z", "1970-01-01T00:00:19Z"),
                         18
                     )
@@ -72,6 +74,7 @@ fn can_wait_for_job_start() {
                     distance: 18,
                     duration: 19,
                     times: Timing { driving: 18, serving: 1, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
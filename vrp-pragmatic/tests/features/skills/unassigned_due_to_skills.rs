@@ -12,6 +12,7 @@ fn can_have_unassigned_due_to_missing_vehicle_skill() {
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("vehicle_without_skill")],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -27,11 +28,13 @@ fn can_have_unassigned_due_to_missing_vehicle_skill() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job1".to_string(),
-                reasons: vec![UnassignedJobReason { code: 1, description: "cannot serve required skill".to_string() }]
+                reasons: vec![UnassignedJobReason { code: 1, description: "cannot serve required skill".to_string() }],
+                details: None,
             }]),
             ..create_empty_solution()
         }
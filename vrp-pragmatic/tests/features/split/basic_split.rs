@@ -0,0 +1,56 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem(split: Option<bool>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { split, ..create_delivery_job_with_demand("job1", vec![1., 0.], vec![25.]) },
+                create_delivery_job("job2", vec![2., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string(), "my_vehicle_3".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_split_job_exceeding_vehicle_capacity() {
+    let problem = create_problem(Some(true));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 3);
+    let demands = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| activity.job_id == "job1")
+        .map(|activity| activity.demand.clone().expect("split job activity should have demand"))
+        .collect::<Vec<_>>();
+    assert_eq!(demands.len(), 3);
+    assert_eq!(demands.iter().map(|demand| demand[0]).sum::<f64>(), 25.);
+}
+
+#[test]
+fn can_skip_job_exceeding_vehicle_capacity_without_split() {
+    let problem = create_problem(None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.as_ref().map(|jobs| jobs.len()), Some(1));
+    assert_eq!(solution.unassigned.unwrap().first().unwrap().job_id, "job1");
+}
@@ -0,0 +1,93 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_reference_tour(vehicle_id: &str, jobs: Vec<(&str, f64)>) -> Tour {
+    let time = ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z");
+    let stops = std::iter::once(create_stop_with_activity("departure", "departure", (0., 0.), 2., time, 0))
+        .chain(
+            jobs.into_iter().map(|(job_id, lat)| create_stop_with_activity(job_id, "delivery", (lat, 0.), 1., time, 0)),
+        )
+        .chain(std::iter::once(create_stop_with_activity("arrival", "arrival", (0., 0.), 0., time, 0)))
+        .collect();
+
+    Tour {
+        vehicle_id: vehicle_id.to_string(),
+        type_id: "my_vehicle".to_string(),
+        shift_index: 0,
+        stops,
+        statistic: Statistic::default(),
+    }
+}
+
+fn get_job_ids(solution: &Solution, vehicle_id: &str) -> Vec<String> {
+    let tour = solution.tours.iter().find(|tour| tour.vehicle_id == vehicle_id).expect("cannot find tour");
+
+    get_ids_from_tour(tour).into_iter().flatten().filter(|id| id.starts_with("job")).collect()
+}
+
+fn create_problem(objectives: Objectives) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+                create_delivery_job("job4", vec![4., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    }
+}
+
+fn create_reference() -> Solution {
+    Solution {
+        tours: vec![
+            create_reference_tour("my_vehicle_1", vec![("job3", 3.), ("job1", 1.)]),
+            create_reference_tour("my_vehicle_2", vec![("job4", 4.), ("job2", 2.)]),
+        ],
+        ..create_empty_solution()
+    }
+}
+
+#[test]
+fn can_keep_reference_assignment_and_order() {
+    let problem = create_problem(Objectives {
+        primary: vec![MinimizeUnassignedJobs, MinimizeDeviation { options: None }],
+        secondary: Some(vec![MinimizeCost]),
+    });
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_reference(problem, Some(vec![matrix]), &create_reference(), 100);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert_eq!(get_job_ids(&solution, "my_vehicle_1"), vec!["job3".to_string(), "job1".to_string()]);
+    assert_eq!(get_job_ids(&solution, "my_vehicle_2"), vec!["job4".to_string(), "job2".to_string()]);
+}
+
+#[test]
+fn can_prefer_cost_over_reference_when_deviation_is_secondary() {
+    let problem = create_problem(Objectives {
+        primary: vec![MinimizeUnassignedJobs, MinimizeCost],
+        secondary: Some(vec![MinimizeDeviation { options: None }]),
+    });
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_reference(problem, Some(vec![matrix]), &create_reference(), 100);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].stops.iter().flat_map(|stop| stop.activities.iter()).count(), 6);
+}
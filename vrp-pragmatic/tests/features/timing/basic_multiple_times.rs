@@ -15,7 +15,11 @@ fn can_use_multiple_times() {
             ],
             relations: Option::None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -30,6 +34,7 @@ fn can_use_multiple_times() {
                 distance: 100,
                 duration: 130,
                 times: Timing { driving: 100, serving: 0, waiting: 30, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -40,7 +45,7 @@ fn can_use_multiple_times() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        5,
+                        5.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -48,7 +53,7 @@ fn can_use_multiple_times() {
                         "job1",
                         "delivery",
                         (10., 0.),
-                        4,
+                        4.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:10Z"),
                         10
                     ),
@@ -56,7 +61,7 @@ fn can_use_multiple_times() {
                         "job2",
                         "delivery",
                         (20., 0.),
-                        3,
+                        3.,
                         ("1970-01-01T00:00:20Z", "1970-01-01T00:00:20Z"),
                         20
                     ),
@@ -64,7 +69,7 @@ fn can_use_multiple_times() {
                         "job4",
                         "delivery",
                         (40., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:40Z", "1970-01-01T00:00:40Z"),
                         40
                     ),
@@ -72,7 +77,7 @@ fn can_use_multiple_times() {
                         "job5",
                         "delivery",
                         (50., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:50Z", "1970-01-01T00:00:50Z"),
                         50
                     ),
@@ -80,7 +85,7 @@ fn can_use_multiple_times() {
                         "job3",
                         "delivery",
                         (30., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:01:10Z", "1970-01-01T00:01:40Z"),
                         70
                     ),
@@ -88,7 +93,7 @@ fn can_use_multiple_times() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:02:10Z", "1970-01-01T00:02:10Z"),
                         100
                     )
@@ -98,6 +103,7 @@ fn can_use_multiple_times() {
                     distance: 100,
                     duration: 130,
                     times: Timing { driving: 100, serving: 0, waiting: 30, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -13,7 +13,11 @@ fn can_wait_for_job_start() {
             ],
             relations: Option::None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -28,6 +32,7 @@ fn can_wait_for_job_start() {
                 distance: 4,
                 duration: 12,
                 times: Timing { driving: 4, serving: 0, waiting: 8, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -38,7 +43,7 @@ fn can_wait_for_job_start() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -46,7 +51,7 @@ fn can_wait_for_job_start() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:01Z", "1970-01-01T00:00:01Z"),
                         1
                     ),
@@ -54,7 +59,7 @@ fn can_wait_for_job_start() {
                         "job2",
                         "delivery",
                         (2., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:02Z", "1970-01-01T00:00:10Z"),
                         2
                     ),
@@ -62,7 +67,7 @@ fn can_wait_for_job_start() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:12Z", "1970-01-01T00:00:12Z"),
                         4
                     )
@@ -72,6 +77,7 @@ fn can_wait_for_job_start() {
                     distance: 4,
                     duration: 12,
                     times: Timing { driving: 4, serving: 0, waiting: 8, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -86,7 +92,11 @@ fn can_skip_initial_waiting() {
             jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], vec![(10, 20)], 10.)],
             relations: None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -101,6 +111,7 @@ fn can_skip_initial_waiting() {
                 distance: 2,
                 duration: 12,
                 times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -111,7 +122,7 @@ fn can_skip_initial_waiting() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:09Z"),
                         0
                     ),
@@ -119,7 +130,7 @@ fn can_skip_initial_waiting() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:20Z"),
                         1
                     ),
@@ -127,7 +138,7 @@ fn can_skip_initial_waiting() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:21Z", "1970-01-01T00:00:21Z"),
                         2
                     )
@@ -137,6 +148,7 @@ fn can_skip_initial_waiting() {
                     distance: 2,
                     duration: 12,
                     times: Timing { driving: 2, serving: 10, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -164,6 +176,7 @@ fn can_consider_latest_departure_time() {
                 ..create_default_vehicle("my_vehicle")
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -179,6 +192,7 @@ fn can_consider_latest_departure_time() {
                 distance: 2,
                 duration: 16,
                 times: Timing { driving: 2, serving: 10, waiting: 4, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -189,7 +203,7 @@ fn can_consider_latest_departure_time() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:05Z"),
                         0
                     ),
@@ -197,7 +211,7 @@ fn can_consider_latest_departure_time() {
                         "job1",
                         "delivery",
                         (1., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:06Z", "1970-01-01T00:00:20Z"),
                         1
                     ),
@@ -205,7 +219,7 @@ fn can_consider_latest_departure_time() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:21Z", "1970-01-01T00:00:21Z"),
                         2
                     )
@@ -215,6 +229,89 @@ fn can_consider_latest_departure_time() {
                     distance: 2,
                     duration: 16,
                     times: Timing { driving: 2, serving: 10, waiting: 4, break_time: 0 },
+                    value: None,
+                },
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_compress_intermediate_waiting() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 9)], 0.),
+                create_delivery_job_with_times("job2", vec![2., 0.], vec![(10, 20)], 0.),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 18.,
+                distance: 4,
+                duration: 4,
+                times: Timing { driving: 4, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        2.,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:08Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        1.,
+                        ("1970-01-01T00:00:09Z", "1970-01-01T00:00:09Z"),
+                        1
+                    ),
+                    create_stop_with_activity(
+                        "job2",
+                        "delivery",
+                        (2., 0.),
+                        0.,
+                        ("1970-01-01T00:00:10Z", "1970-01-01T00:00:10Z"),
+                        2
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0.,
+                        ("1970-01-01T00:00:12Z", "1970-01-01T00:00:12Z"),
+                        4
+                    )
+                ],
+                statistic: Statistic {
+                    cost: 18.,
+                    distance: 4,
+                    duration: 4,
+                    times: Timing { driving: 4, serving: 0, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
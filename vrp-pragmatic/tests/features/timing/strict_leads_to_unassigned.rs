@@ -15,7 +15,11 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
             ],
             relations: Option::None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -30,6 +34,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 distance: 80,
                 duration: 80,
                 times: Timing { driving: 80, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -40,7 +45,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        4,
+                        4.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -48,7 +53,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                         "job1",
                         "delivery",
                         (10., 0.),
-                        3,
+                        3.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:10Z"),
                         10
                     ),
@@ -56,7 +61,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                         "job2",
                         "delivery",
                         (20., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:20Z", "1970-01-01T00:00:20Z"),
                         20
                     ),
@@ -64,7 +69,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                         "job3",
                         "delivery",
                         (30., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:30Z", "1970-01-01T00:00:30Z"),
                         30
                     ),
@@ -72,7 +77,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                         "job4",
                         "delivery",
                         (40., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:40Z", "1970-01-01T00:00:40Z"),
                         40
                     ),
@@ -80,7 +85,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:01:20Z", "1970-01-01T00:01:20Z"),
                         80
                     ),
@@ -90,6 +95,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                     distance: 80,
                     duration: 80,
                     times: Timing { driving: 80, serving: 0, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             unassigned: Some(vec![UnassignedJob {
@@ -97,7 +103,8 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 reasons: vec![UnassignedJobReason {
                     code: 2,
                     description: "cannot be visited within time window".to_string()
-                }]
+                }],
+                details: None,
             }]),
             ..create_empty_solution()
         },
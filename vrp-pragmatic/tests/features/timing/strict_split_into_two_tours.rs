@@ -21,6 +21,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -36,6 +37,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                 distance: 140,
                 duration: 200,
                 times: Timing { driving: 140, serving: 50, waiting: 10, break_time: 0 },
+                value: None,
             },
             tours: vec![
                 Tour {
@@ -47,7 +49,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                             "departure",
                             "departure",
                             (0., 0.),
-                            3,
+                            3.,
                             ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                             0,
                         ),
@@ -55,7 +57,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                             "job4",
                             "delivery",
                             (40., 0.),
-                            2,
+                            2.,
                             ("1970-01-01T00:00:40Z", "1970-01-01T00:00:50Z"),
                             40,
                         ),
@@ -63,7 +65,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                             "job5",
                             "delivery",
                             (50., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:01:00Z", "1970-01-01T00:01:10Z"),
                             50,
                         ),
@@ -71,7 +73,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                             "job3",
                             "delivery",
                             (30., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:01:30Z", "1970-01-01T00:01:50Z"),
                             70,
                         ),
@@ -79,7 +81,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                             "arrival",
                             "arrival",
                             (0., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:02:20Z", "1970-01-01T00:02:20Z"),
                             100,
                         ),
@@ -89,6 +91,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                         distance: 100,
                         duration: 140,
                         times: Timing { driving: 100, serving: 30, waiting: 10, break_time: 0 },
+                        value: None,
                     },
                 },
                 Tour {
@@ -100,7 +103,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                             "departure",
                             "departure",
                             (0., 0.),
-                            2,
+                            2.,
                             ("1970-01-01T00:00:00Z", "1970-01-01T00:00:30Z"),
                             0,
                         ),
@@ -108,7 +111,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                             "job2",
                             "delivery",
                             (20., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:00:50Z", "1970-01-01T00:01:00Z"),
                             20,
                         ),
@@ -116,7 +119,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                             "job1",
                             "delivery",
                             (10., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:01:10Z", "1970-01-01T00:01:20Z"),
                             30,
                         ),
@@ -124,7 +127,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                             "arrival",
                             "arrival",
                             (0., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:01:30Z", "1970-01-01T00:01:30Z"),
                             40,
                         ),
@@ -134,6 +137,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                         distance: 40,
                         duration: 60,
                         times: Timing { driving: 40, serving: 20, waiting: 0, break_time: 0 },
+                        value: None,
                     },
                 },
             ],
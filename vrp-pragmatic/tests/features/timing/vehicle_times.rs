@@ -0,0 +1,77 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_have_unassigned_due_to_vehicle_times() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_vehicle_times("job1", vec![10., 0.], vec!["my_vehicle"], vec![(0, 5)])],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 0.,
+                distance: 0,
+                duration: 0,
+                times: Timing { driving: 0, serving: 0, waiting: 0, break_time: 0 },
+                value: None,
+            },
+            tours: vec![],
+            unassigned: Some(vec![UnassignedJob {
+                job_id: "job1".to_string(),
+                reasons: vec![UnassignedJobReason {
+                    code: 108,
+                    description: "cannot be visited within vehicle specific time window".to_string()
+                }],
+                details: None,
+            }]),
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_use_vehicle_without_time_restriction() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_vehicle_times("job1", vec![10., 0.], vec!["truck"], vec![(0, 5)]),
+                create_delivery_job("job2", vec![5., 0.]),
+            ],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("truck"),
+                VehicleType {
+                    costs: VehicleCosts { fixed: Some(100.), distance: 2., time: 2. },
+                    ..create_default_vehicle("van")
+                },
+            ],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours.first().unwrap().vehicle_id, "van_1");
+}
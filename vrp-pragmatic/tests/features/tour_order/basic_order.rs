@@ -0,0 +1,81 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_job_with_order(id: &str, location: Vec<f64>, order: JobOrder) -> Job {
+    Job { order: Some(order), ..create_delivery_job(id, location) }
+}
+
+fn create_problem(jobs: Vec<Job>) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: Option::None },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_serve_jobs_first_and_last() {
+    let problem = create_problem(vec![
+        create_delivery_job("job1", vec![1., 0.]),
+        create_job_with_order("job2", vec![2., 0.], JobOrder::Position(JobOrderPosition::First)),
+        create_job_with_order("job3", vec![3., 0.], JobOrder::Position(JobOrderPosition::Last)),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_ids_from_tour(solution.tours.first().unwrap()),
+        vec![vec!["departure"], vec!["job2"], vec!["job1"], vec!["job3"], vec!["arrival"]]
+    );
+}
+
+#[test]
+fn can_follow_job_ranks() {
+    let problem = create_problem(vec![
+        create_job_with_order("job1", vec![1., 0.], JobOrder::Rank(2)),
+        create_job_with_order("job2", vec![2., 0.], JobOrder::Rank(3)),
+        create_job_with_order("job3", vec![3., 0.], JobOrder::Rank(1)),
+    ]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_ids_from_tour(solution.tours.first().unwrap()),
+        vec![vec!["departure"], vec!["job3"], vec!["job1"], vec!["job2"], vec!["arrival"]]
+    );
+}
+
+#[test]
+fn can_follow_job_ranks_with_explicit_objective() {
+    let problem = Problem {
+        objectives: Some(Objectives {
+            primary: vec![Objective::MinimizeUnassignedJobs, Objective::MinimizeRankViolations],
+            secondary: Some(vec![Objective::MinimizeCost]),
+        }),
+        ..create_problem(vec![
+            create_job_with_order("job1", vec![1., 0.], JobOrder::Rank(2)),
+            create_job_with_order("job2", vec![2., 0.], JobOrder::Rank(3)),
+            create_job_with_order("job3", vec![3., 0.], JobOrder::Rank(1)),
+        ])
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_ids_from_tour(solution.tours.first().unwrap()),
+        vec![vec!["departure"], vec!["job3"], vec!["job1"], vec!["job2"], vec!["arrival"]]
+    );
+}
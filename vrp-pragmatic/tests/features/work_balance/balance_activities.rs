@@ -38,18 +38,19 @@ fn can_balance_activities_with_threshold_impl(threshold: Option<f64>, expected_l
                 VehicleType {
                     vehicle_ids: vec!["my_vehicle1".to_string()],
                     shifts: vec![create_default_open_vehicle_shift()],
-                    capacity: vec![4],
+                    capacity: vec![4.],
                     ..create_default_vehicle_type()
                 },
                 VehicleType {
                     type_id: "my_vehicle2".to_string(),
                     vehicle_ids: vec!["my_vehicle2".to_string()],
                     shifts: vec![create_default_vehicle_shift_with_locations((3., 0.), (3., 0.))],
-                    capacity: vec![4],
+                    capacity: vec![4.],
                     ..create_default_vehicle_type()
                 },
             ],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         objectives: Some(Objectives {
             primary: vec![BalanceActivities { options: Some(BalanceOptions { threshold, tolerance: None }) }],
@@ -40,7 +40,7 @@ prop_compose! {
     pub fn pickup_delivery_prototype(
         pickup_place: impl Strategy<Value = JobPlace>,
         delivery_place: impl Strategy<Value = JobPlace>,
-        demand_proto: impl Strategy<Value = Option<Vec<i32>>>,
+        demand_proto: impl Strategy<Value = Option<Vec<f64>>>,
         priority_proto: impl Strategy<Value = Option<i32>>,
         skills_proto: impl Strategy<Value = Option<Vec<String>>>
     )
@@ -54,15 +54,19 @@ prop_compose! {
        Job {
             id: Uuid::new_v4().to_string(),
             pickups: Some(vec![
-             JobTask { places: vec![pickup], demand: demand.clone(), tag: Some("p1".to_owned())}
+             JobTask { places: vec![pickup], demand: demand.clone(), tag: Some("p1".to_owned()), named_demand: None }
             ]),
             deliveries: Some(vec![
-             JobTask { places: vec![delivery], demand: demand.clone(), tag: Some("d1".to_owned())}
+             JobTask { places: vec![delivery], demand: demand.clone(), tag: Some("d1".to_owned()), named_demand: None }
             ]),
             replacements: None,
             services: None,
             priority,
+            value: None,
             skills,
+            order: None,
+            split: None,
+            goods: None,
         }
     }
 }
@@ -101,7 +105,11 @@ prop_compose! {
             replacements,
             services,
             priority,
+            value: None,
             skills,
+            order: None,
+            split: None,
+            goods: None,
         }
     }
 }
@@ -109,7 +117,7 @@ prop_compose! {
 prop_compose! {
     pub fn job_task_prototype(
         places: impl Strategy<Value = JobPlace>,
-        demand_proto: impl Strategy<Value = Option<Vec<i32>>>,
+        demand_proto: impl Strategy<Value = Option<Vec<f64>>>,
         tags: impl Strategy<Value = Option<String>>,
     )
     (
@@ -117,7 +125,7 @@ prop_compose! {
      demand in demand_proto,
      tag in tags
     ) -> JobTask {
-       JobTask { places: vec![place], demand, tag}
+       JobTask { places: vec![place], demand, tag, named_demand: None }
     }
 }
 
@@ -132,14 +140,14 @@ prop_compose! {
      duration in durations,
      times in time_windows
     ) -> JobPlace {
-      JobPlace { times, location, duration}
+      JobPlace { times, location, duration, vehicle_times: None }
     }
 }
 
 prop_compose! {
     /// Generates one dimensional demand in range.
-    pub fn generate_simple_demand(range: Range<i32>)(demand in range) -> Option<Vec<i32>> {
-        Some(vec![demand])
+    pub fn generate_simple_demand(range: Range<i32>)(demand in range) -> Option<Vec<f64>> {
+        Some(vec![demand as f64])
     }
 }
 
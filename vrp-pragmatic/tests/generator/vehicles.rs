@@ -8,7 +8,7 @@ prop_compose! {
     pub fn generate_vehicle(
         amount_proto: Range<usize>,
         profile_proto: impl Strategy<Value = String>,
-        capacity_proto: impl Strategy<Value = Vec<i32>>,
+        capacity_proto: impl Strategy<Value = Vec<f64>>,
         costs_proto: impl Strategy<Value = VehicleCosts>,
         skills_proto: impl Strategy<Value = Option<Vec<String>>>,
         limits_proto: impl Strategy<Value = Option<VehicleLimits>>,
@@ -33,6 +33,9 @@ prop_compose! {
             capacity,
             skills,
             limits,
+            compartments: None,
+            named_capacity: None,
+            battery: None,
         }
     }
 }
@@ -125,7 +128,7 @@ prop_compose! {
      vehicles in vehicles_proto,
      profiles in profiles_proto
     ) -> Fleet {
-        Fleet { vehicles, profiles }
+        Fleet { vehicles, profiles, dimensions: None }
     }
 }
 
@@ -158,7 +161,7 @@ prop_compose! {
 
 prop_compose! {
     /// Generates one dimensional capacity in range.
-    pub fn generate_simple_capacity(range: Range<i32>)(capacity in range) -> Vec<i32> {
-        vec![capacity]
+    pub fn generate_simple_capacity(range: Range<i32>)(capacity in range) -> Vec<f64> {
+        vec![capacity as f64]
     }
 }
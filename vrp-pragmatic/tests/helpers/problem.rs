@@ -4,11 +4,11 @@ use crate::format_time;
 use crate::helpers::ToLocation;
 
 pub fn create_job_place(location: Vec<f64>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1. }
+    JobPlace { times: None, location: location.to_loc(), duration: 1., vehicle_times: None }
 }
 
 pub fn create_task(location: Vec<f64>) -> JobTask {
-    JobTask { places: vec![create_job_place(location)], demand: Some(vec![1]), tag: None }
+    JobTask { places: vec![create_job_place(location)], demand: Some(vec![1.]), tag: None, named_demand: None }
 }
 
 pub fn create_job(id: &str) -> Job {
@@ -19,7 +19,11 @@ pub fn create_job(id: &str) -> Job {
         replacements: None,
         services: None,
         priority: None,
+        value: None,
         skills: None,
+        order: None,
+        split: None,
+        goods: None,
     }
 }
 
@@ -35,7 +39,7 @@ pub fn create_delivery_job_with_skills(id: &str, location: Vec<f64>, skills: Vec
     Job { skills: Some(skills), ..create_delivery_job(id, location) }
 }
 
-pub fn create_delivery_job_with_demand(id: &str, location: Vec<f64>, demand: Vec<i32>) -> Job {
+pub fn create_delivery_job_with_demand(id: &str, location: Vec<f64>, demand: Vec<f64>) -> Job {
     Job { deliveries: Some(vec![JobTask { demand: Some(demand), ..create_task(location) }]), ..create_job(id) }
 }
 
@@ -43,8 +47,9 @@ pub fn create_delivery_job_with_duration(id: &str, location: Vec<f64>, duration:
     Job {
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace { duration, ..create_job_place(location) }],
-            demand: Some(vec![1]),
+            demand: Some(vec![1.]),
             tag: None,
+            named_demand: None,
         }]),
         ..create_job(id)
     }
@@ -54,8 +59,33 @@ pub fn create_delivery_job_with_times(id: &str, location: Vec<f64>, times: Vec<(
     Job {
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace { duration, times: convert_times(&times), ..create_job_place(location) }],
-            demand: Some(vec![1]),
+            demand: Some(vec![1.]),
             tag: None,
+            named_demand: None,
+        }]),
+        ..create_job(id)
+    }
+}
+
+pub fn create_delivery_job_with_vehicle_times(
+    id: &str,
+    location: Vec<f64>,
+    type_ids: Vec<&str>,
+    times: Vec<(i32, i32)>,
+) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace {
+                vehicle_times: Some(vec![JobVehicleTimes {
+                    type_ids: Some(type_ids.into_iter().map(|id| id.to_string()).collect()),
+                    profiles: None,
+                    times: convert_times(&times).unwrap_or_default(),
+                }]),
+                ..create_job_place(location)
+            }],
+            demand: Some(vec![1.]),
+            tag: None,
+            named_demand: None,
         }]),
         ..create_job(id)
     }
@@ -65,7 +95,7 @@ pub fn create_pickup_job(id: &str, location: Vec<f64>) -> Job {
     Job { pickups: Some(vec![create_task(location.clone())]), ..create_job(id) }
 }
 
-pub fn create_pickup_job_with_demand(id: &str, location: Vec<f64>, demand: Vec<i32>) -> Job {
+pub fn create_pickup_job_with_demand(id: &str, location: Vec<f64>, demand: Vec<f64>) -> Job {
     Job { pickups: Some(vec![JobTask { demand: Some(demand), ..create_task(location) }]), ..create_job(id) }
 }
 
@@ -87,7 +117,7 @@ pub fn create_pickup_delivery_job(id: &str, pickup_location: Vec<f64>, delivery_
 
 pub fn create_pickup_delivery_job_with_params(
     id: &str,
-    demand: Vec<i32>,
+    demand: Vec<f64>,
     pickup: (Vec<f64>, f64, Vec<(i32, i32)>),
     delivery: (Vec<f64>, f64, Vec<(i32, i32)>),
 ) -> Job {
@@ -100,6 +130,7 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand.clone()),
             tag: Some("p1".to_string()),
+            named_demand: None,
         }]),
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace {
@@ -109,6 +140,7 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand.clone()),
             tag: Some("d1".to_string()),
+            named_demand: None,
         }]),
 
         ..create_job(id)
@@ -118,9 +150,15 @@ pub fn create_pickup_delivery_job_with_params(
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1. }],
-            demand: Some(vec![1]),
+            places: vec![JobPlace {
+                times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+                vehicle_times: None,
+            }],
+            demand: Some(vec![1.]),
             tag: None,
+            named_demand: None,
         }]),
         ..create_job(id)
     }
@@ -128,10 +166,10 @@ pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
 
 pub fn create_multi_job(
     id: &str,
-    pickups: Vec<((f64, f64), f64, Vec<i32>)>,
-    deliveries: Vec<((f64, f64), f64, Vec<i32>)>,
+    pickups: Vec<((f64, f64), f64, Vec<f64>)>,
+    deliveries: Vec<((f64, f64), f64, Vec<f64>)>,
 ) -> Job {
-    let create_tasks = |tasks: Vec<((f64, f64), f64, Vec<i32>)>, prefix: &str| {
+    let create_tasks = |tasks: Vec<((f64, f64), f64, Vec<f64>)>, prefix: &str| {
         let tasks = tasks
             .into_iter()
             .enumerate()
@@ -139,6 +177,7 @@ pub fn create_multi_job(
                 places: vec![JobPlace { duration, ..create_job_place(vec![location.0, location.1]) }],
                 demand: Some(demand),
                 tag: Some(format!("{}{}", prefix, i + 1)),
+                named_demand: None,
             })
             .collect::<Vec<_>>();
 
@@ -189,10 +228,10 @@ pub fn create_default_vehicle_type() -> VehicleType {
 }
 
 pub fn create_default_vehicle(id: &str) -> VehicleType {
-    create_vehicle_with_capacity(id, vec![10])
+    create_vehicle_with_capacity(id, vec![10.])
 }
 
-pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType {
+pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<f64>) -> VehicleType {
     VehicleType {
         type_id: id.to_string(),
         vehicle_ids: vec![format!("{}_1", id)],
@@ -202,6 +241,9 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         capacity,
         skills: None,
         limits: None,
+        compartments: None,
+        named_capacity: None,
+        battery: None,
     }
 }
 
@@ -212,7 +254,7 @@ pub fn create_default_profiles() -> Vec<Profile> {
 pub fn create_empty_problem() -> Problem {
     Problem {
         plan: Plan { jobs: vec![], relations: None },
-        fleet: Fleet { vehicles: vec![], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![], profiles: vec![], dimensions: None },
         objectives: None,
         config: None,
     }
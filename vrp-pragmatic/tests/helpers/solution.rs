@@ -7,7 +7,7 @@ pub fn create_stop_with_activity(
     id: &str,
     activity_type: &str,
     location: (f64, f64),
-    load: i32,
+    load: f64,
     time: (&str, &str),
     distance: i64,
 ) -> Stop {
@@ -18,7 +18,7 @@ pub fn create_stop_with_activity_md(
     id: &str,
     activity_type: &str,
     location: (f64, f64),
-    load: Vec<i32>,
+    load: Vec<f64>,
     time: (&str, &str),
     distance: i64,
 ) -> Stop {
@@ -29,7 +29,7 @@ pub fn create_stop_with_activity_with_tag(
     id: &str,
     activity_type: &str,
     location: (f64, f64),
-    load: i32,
+    load: f64,
     time: (&str, &str),
     distance: i64,
     job_tag: &str,
@@ -41,7 +41,7 @@ fn create_stop_with_activity_impl(
     id: &str,
     activity_type: &str,
     location: (f64, f64),
-    load: Vec<i32>,
+    load: Vec<f64>,
     time: (&str, &str),
     distance: i64,
     job_tag: Option<String>,
@@ -57,7 +57,9 @@ fn create_stop_with_activity_impl(
             location: None,
             time: None,
             job_tag,
+            demand: None,
         }],
+        named_load: None,
     }
 }
 
@@ -1,12 +1,14 @@
 use crate::checker::CheckerContext;
 use crate::format::problem::{Matrix, PragmaticProblem, Problem};
-use crate::format::solution::{create_solution, Solution};
+use crate::format::solution::{create_solution, read_init_solution, serialize_solution, Solution};
 use std::cmp::Ordering::Less;
+use std::io::{BufReader, BufWriter};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::Problem as CoreProblem;
 use vrp_core::models::Solution as CoreSolution;
 use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::objectives::set_stability_reference;
 use vrp_core::solver::selection::NaiveSelection;
 use vrp_core::solver::RefinementContext;
 use vrp_core::solver::{Builder, DominancePopulation};
@@ -51,18 +53,43 @@ pub fn solve_with_metaheuristic_and_iterations_without_check(
 
 pub fn solve(problem: Problem, matrices: Option<Vec<Matrix>>, generations: usize, perform_check: bool) -> Solution {
     get_core_solution(problem, matrices, perform_check, |problem: Arc<CoreProblem>| {
-        let (solution, _, _) = Builder::new(problem)
-            .with_max_generations(Some(generations))
-            .with_selection(Arc::new(NaiveSelection::new(2)))
-            .build()
-            .unwrap_or_else(|err| panic!("cannot build solver: {}", err))
-            .solve()
-            .unwrap_or_else(|err| panic!("cannot solve the problem: {}", err));
-
-        solution
+        solve_core_problem(problem, generations)
     })
 }
 
+/// Runs solver with default metaheuristic using given solution as a reference for plan stability objective.
+pub fn solve_with_reference(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    reference: &Solution,
+    generations: usize,
+) -> Solution {
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    serialize_solution(writer, reference).expect("cannot serialize reference solution");
+
+    get_core_solution(problem, matrices, true, |problem: Arc<CoreProblem>| {
+        let reference = read_init_solution(BufReader::new(buffer.as_bytes()), problem.clone())
+            .unwrap_or_else(|err| panic!("cannot read reference solution: {}", err));
+        set_stability_reference(problem.as_ref(), &reference)
+            .unwrap_or_else(|err| panic!("cannot set reference solution: {}", err));
+
+        solve_core_problem(problem, generations)
+    })
+}
+
+fn solve_core_problem(problem: Arc<CoreProblem>, generations: usize) -> CoreSolution {
+    let (solution, _, _) = Builder::new(problem)
+        .with_max_generations(Some(generations))
+        .with_selection(Arc::new(NaiveSelection::new(2)))
+        .build()
+        .unwrap_or_else(|err| panic!("cannot build solver: {}", err))
+        .solve()
+        .unwrap_or_else(|err| panic!("cannot solve the problem: {}", err));
+
+    solution
+}
+
 fn get_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>) -> Arc<CoreProblem> {
     Arc::new(
         if let Some(matrices) = matrices { (problem, matrices).read_pragmatic() } else { problem.read_pragmatic() }
@@ -21,6 +21,7 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -126,13 +127,14 @@ fn check_jobs_impl(
             .filter(|(_, t)| **t == tgt)
             .map(|(idx, _)| JobTask {
                 places: vec![],
-                demand: if tgt != "service" { Some(vec![1]) } else { None },
+                demand: if tgt != "service" { Some(vec![1.]) } else { None },
                 tag: Some(format!("{}{}", tgt, idx)),
+                named_demand: None,
             })
             .collect()
     };
 
-    let create_stop = |stop: (&str, &str)| create_stop_with_activity(stop.0, stop.1, (0., 0.), 0, ("", ""), 0);
+    let create_stop = |stop: (&str, &str)| create_stop_with_activity(stop.0, stop.1, (0., 0.), 0., ("", ""), 0);
 
     let problem = Problem {
         plan: Plan {
@@ -145,12 +147,20 @@ fn check_jobs_impl(
                     replacements: Some(create_tasks("replacement", &tasks)),
                     services: Some(create_tasks("service", &tasks)),
                     priority: None,
+                    value: None,
                     skills: None,
+                    order: None,
+                    split: None,
+                    goods: None,
                 })
                 .collect(),
             relations: None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     };
     let solution = Solution {
@@ -166,7 +176,10 @@ fn check_jobs_impl(
             })
             .collect(),
         unassigned: Some(
-            unassigned.into_iter().map(|job| UnassignedJob { job_id: job.to_string(), reasons: vec![] }).collect(),
+            unassigned
+                .into_iter()
+                .map(|job| UnassignedJob { job_id: job.to_string(), reasons: vec![], details: None })
+                .collect(),
         ),
         ..create_empty_solution()
     };
@@ -183,7 +196,11 @@ fn can_detect_time_window_violation() {
             jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], vec![(1, 2)], 1.)],
             relations: None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     };
     let solution = Solution {
@@ -192,6 +209,7 @@ fn can_detect_time_window_violation() {
             distance: 2,
             duration: 3,
             times: Timing { driving: 2, serving: 1, waiting: 0, break_time: 0 },
+            value: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -202,7 +220,7 @@ fn can_detect_time_window_violation() {
                     "departure",
                     "departure",
                     (0., 0.),
-                    1,
+                    1.,
                     ("1970-01-01T00:00:02Z", "1970-01-01T00:00:02Z"),
                     0,
                 ),
@@ -210,7 +228,7 @@ fn can_detect_time_window_violation() {
                     "job1",
                     "delivery",
                     (1., 0.),
-                    0,
+                    0.,
                     ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
                     1,
                 ),
@@ -218,7 +236,7 @@ fn can_detect_time_window_violation() {
                     "arrival",
                     "arrival",
                     (0., 0.),
-                    0,
+                    0.,
                     ("1970-01-01T00:00:05Z", "1970-01-01T00:00:05Z"),
                     2,
                 ),
@@ -228,6 +246,7 @@ fn can_detect_time_window_violation() {
                 distance: 2,
                 duration: 3,
                 times: Timing { driving: 2, serving: 1, waiting: 0, break_time: 0 },
+                value: None,
             },
         }],
         ..create_empty_solution()
@@ -238,3 +257,69 @@ fn can_detect_time_window_violation() {
 
     assert_eq!(result, Err("cannot match activities to jobs: job1:<no tag>".to_owned()));
 }
+
+parameterized_test! {can_check_vehicle_times, (vehicle_times, expected_result), {
+    can_check_vehicle_times_impl(vehicle_times, expected_result);
+}}
+
+can_check_vehicle_times! {
+    case_01: (("my_vehicle", (0, 10)), Ok(())),
+    case_02: (("my_vehicle", (5, 10)), Err(())),
+    case_03: (("other_vehicle", (5, 10)), Ok(())),
+}
+
+fn can_check_vehicle_times_impl(vehicle_times: (&str, (i32, i32)), expected_result: Result<(), ()>) {
+    let (type_id, time) = vehicle_times;
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_vehicle_times("job1", vec![1., 0.], vec![type_id], vec![time])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type(), create_default_vehicle("other_vehicle")],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1.,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    0.,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0.,
+                    ("1970-01-01T00:00:03Z", "1970-01-01T00:00:03Z"),
+                    2,
+                ),
+            ],
+            ..create_empty_tour()
+        }],
+        ..create_empty_solution()
+    };
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+
+    let result = check_vehicle_times(&CheckerContext::new(core_problem, problem, None, solution));
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
@@ -0,0 +1,93 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::models::examples::create_example_problem;
+
+parameterized_test! {can_check_battery, (initial, load_consumption, rate, expected_result), {
+    can_check_battery_impl(initial, load_consumption, rate, expected_result);
+}}
+
+can_check_battery! {
+    case01: (None, Some(0.5), 1., Ok(())),
+    case02: (Some(5.), Some(0.5), 1., Err("battery is depleted before stop 1, vehicle id 'my_vehicle_1', shift index: 0".to_owned())),
+    case03: (Some(5.), None, 1., Ok(())),
+    case04: (Some(5.), None, 0.5, Err("battery is depleted before stop 3, vehicle id 'my_vehicle_1', shift index: 0".to_owned())),
+}
+
+fn can_check_battery_impl(
+    initial: Option<f64>,
+    load_consumption: Option<f64>,
+    rate: f64,
+    expected_result: Result<(), String>,
+) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", vec![4., 0.], vec![2.])], relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                battery: Some(VehicleBattery {
+                    capacity: 10.,
+                    initial,
+                    consumption: 1.,
+                    load_consumption,
+                    stations: Some(vec![VehicleChargingStation {
+                        location: vec![5., 0.].to_loc(),
+                        duration: 5.,
+                        rate,
+                        times: None,
+                        tag: None,
+                    }]),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    2.,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (4., 0.),
+                    0.,
+                    ("1970-01-01T00:00:04Z", "1970-01-01T00:00:05Z"),
+                    4,
+                ),
+                create_stop_with_activity(
+                    "charging",
+                    "charging",
+                    (5., 0.),
+                    0.,
+                    ("1970-01-01T00:00:06Z", "1970-01-01T00:00:11Z"),
+                    5,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (10., 0.),
+                    0.,
+                    ("1970-01-01T00:00:16Z", "1970-01-01T00:00:16Z"),
+                    10,
+                ),
+            ],
+            ..create_empty_tour()
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = check_battery(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected_result);
+}
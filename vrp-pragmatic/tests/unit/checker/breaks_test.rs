@@ -92,10 +92,11 @@ fn can_check_breaks_impl(
                     breaks: Some(vec![VehicleBreak { time: break_times, duration: 2.0, locations: None }]),
                     reloads: None,
                 }],
-                capacity: vec![5],
+                capacity: vec![5.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -106,6 +107,7 @@ fn can_check_breaks_impl(
         location: None,
         time: Some(Interval { start: "1970-01-01T00:00:03Z".to_string(), end: "1970-01-01T00:00:04Z".to_string() }),
         job_tag: None,
+        demand: None,
     }];
     if has_break {
         activities.push(Activity {
@@ -114,6 +116,7 @@ fn can_check_breaks_impl(
             location: None,
             time: Some(Interval { start: "1970-01-01T00:00:04Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
             job_tag: None,
+            demand: None,
         });
     }
 
@@ -123,6 +126,7 @@ fn can_check_breaks_impl(
             distance: 4,
             duration: 8,
             times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 2 },
+            value: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -133,7 +137,7 @@ fn can_check_breaks_impl(
                     "departure",
                     "departure",
                     (0., 0.),
-                    2,
+                    2.,
                     ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                     0,
                 ),
@@ -141,7 +145,7 @@ fn can_check_breaks_impl(
                     "job1",
                     "delivery",
                     (1., 0.),
-                    1,
+                    1.,
                     ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                     5,
                 ),
@@ -152,14 +156,15 @@ fn can_check_breaks_impl(
                         departure: "1970-01-01T00:00:06Z".to_string(),
                     },
                     distance: 2,
-                    load: vec![0],
+                    load: vec![0.],
                     activities,
+                    named_load: None,
                 },
                 create_stop_with_activity(
                     "arrival",
                     "arrival",
                     (0., 0.),
-                    0,
+                    0.,
                     ("1970-01-01T00:00:08Z", "1970-01-01T00:00:08Z"),
                     4,
                 ),
@@ -169,6 +174,7 @@ fn can_check_breaks_impl(
                 distance: 4,
                 duration: 8,
                 times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 2 },
+                value: None,
             },
         }],
         violations,
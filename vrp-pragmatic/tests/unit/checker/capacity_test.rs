@@ -8,20 +8,20 @@ parameterized_test! {can_check_load, (stop_loads, expected_result), {
 }}
 
 can_check_load! {
-    case00: ( vec![1, 1, 3, 1, 2, 1, 0], Ok(())),
+    case00: ( vec![1., 1., 3., 1., 2., 1., 0.], Ok(())),
 
-    case01: ( vec![1, 2, 3, 1, 2, 1, 0], Err("Load mismatch at stop 1 in tour 'my_vehicle_1'".to_owned())),
-    case02: ( vec![1, 1, 2, 1, 2, 1, 0], Err("Load mismatch at stops 2, 3 in tour 'my_vehicle_1'".to_owned())),
-    case03: ( vec![1, 1, 3, 2, 2, 1, 0], Err("Load mismatch at stop 3 in tour 'my_vehicle_1'".to_owned())),
-    case04: ( vec![1, 1, 3, 1, 1, 1, 0], Err("Load mismatch at stop 4 in tour 'my_vehicle_1'".to_owned())),
-    case05: ( vec![1, 1, 3, 1, 2, 2, 0], Err("Load mismatch at stop 5 in tour 'my_vehicle_1'".to_owned())),
+    case01: ( vec![1., 2., 3., 1., 2., 1., 0.], Err("Load mismatch at stop 1 in tour 'my_vehicle_1'".to_owned())),
+    case02: ( vec![1., 1., 2., 1., 2., 1., 0.], Err("Load mismatch at stops 2, 3 in tour 'my_vehicle_1'".to_owned())),
+    case03: ( vec![1., 1., 3., 2., 2., 1., 0.], Err("Load mismatch at stop 3 in tour 'my_vehicle_1'".to_owned())),
+    case04: ( vec![1., 1., 3., 1., 1., 1., 0.], Err("Load mismatch at stop 4 in tour 'my_vehicle_1'".to_owned())),
+    case05: ( vec![1., 1., 3., 1., 2., 2., 0.], Err("Load mismatch at stop 5 in tour 'my_vehicle_1'".to_owned())),
 
-    case06_1: ( vec![10, 1, 3, 1, 2, 1, 0], Err("Load exceeds capacity in tour 'my_vehicle_1'".to_owned())),
-    case06_2: ( vec![1, 1, 30, 1, 2, 1, 0], Err("Load exceeds capacity in tour 'my_vehicle_1'".to_owned())),
-    case06_3: ( vec![1, 1, 3, 1, 20, 1, 0], Err("Load exceeds capacity in tour 'my_vehicle_1'".to_owned())),
+    case06_1: ( vec![10., 1., 3., 1., 2., 1., 0.], Err("Load exceeds capacity in tour 'my_vehicle_1'".to_owned())),
+    case06_2: ( vec![1., 1., 30., 1., 2., 1., 0.], Err("Load exceeds capacity in tour 'my_vehicle_1'".to_owned())),
+    case06_3: ( vec![1., 1., 3., 1., 20., 1., 0.], Err("Load exceeds capacity in tour 'my_vehicle_1'".to_owned())),
 }
 
-fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>) {
+fn can_check_load_impl(stop_loads: Vec<f64>, expected_result: Result<(), String>) {
     let problem = Problem {
         plan: Plan {
             jobs: vec![
@@ -51,10 +51,11 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                         tag: None,
                     }]),
                 }],
-                capacity: vec![5],
+                capacity: vec![5.],
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -64,6 +65,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
             distance: 1,
             duration: 2,
             times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+            value: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -93,6 +95,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                             location: None,
                             time: None,
                             job_tag: None,
+                            demand: None,
                         },
                         Activity {
                             job_id: "job5".to_string(),
@@ -100,8 +103,10 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                             location: None,
                             time: None,
                             job_tag: Some("p1".to_string()),
+                            demand: None,
                         },
                     ],
+                    named_load: None,
                 },
                 Stop {
                     location: vec![0., 0.].to_loc(),
@@ -117,7 +122,9 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                         location: None,
                         time: None,
                         job_tag: None,
+                        demand: None,
                     }],
+                    named_load: None,
                 },
                 Stop {
                     location: vec![2., 0.].to_loc(),
@@ -137,6 +144,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                                 end: "1970-01-01T00:00:09Z".to_string(),
                             }),
                             job_tag: None,
+                            demand: None,
                         },
                         Activity {
                             job_id: "job3".to_string(),
@@ -147,8 +155,10 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                                 end: "1970-01-01T00:00:10Z".to_string(),
                             }),
                             job_tag: None,
+                            demand: None,
                         },
                     ],
+                    named_load: None,
                 },
                 create_stop_with_activity(
                     "job4",
@@ -181,6 +191,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                 distance: 1,
                 duration: 2,
                 times: Timing { driving: 1, serving: 1, waiting: 0, break_time: 0 },
+                value: None,
             },
         }],
         ..create_empty_solution()
@@ -11,6 +11,7 @@ fn create_test_problem(limits: Option<VehicleLimits>) -> Problem {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     }
@@ -60,10 +61,156 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), String>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, shift_time, allowed_areas: None }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance,
+        shift_time,
+        allowed_areas: None,
+        forbidden_areas: None,
+        area_costs: None,
+        max_jobs: None,
+        max_stops: None,
+    }));
     let solution = create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() });
 
     let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
 
     assert_eq!(result, expected);
 }
+
+parameterized_test! {can_check_area_limits, (allowed, forbidden, expected_result), {
+    let expected_result = if expected_result {
+        Ok(())
+    } else {
+        Err("area limit violation, job 'job1' is served outside allowed or inside forbidden area, \
+             vehicle id 'some_real_vehicle', shift index: 0"
+            .to_string())
+    };
+    can_check_area_limits_impl(allowed, forbidden, expected_result);
+}}
+
+can_check_area_limits! {
+    case_01: (Some((0., 2.)), None, true),
+    case_02: (Some((2., 4.)), None, false),
+    case_03: (None, Some((0., 2.)), false),
+    case_04: (None, Some((2., 4.)), true),
+    case_05: (Some((0., 2.)), Some((0., 2.)), false),
+}
+
+fn can_check_area_limits_impl(
+    allowed: Option<(f64, f64)>,
+    forbidden: Option<(f64, f64)>,
+    expected: Result<(), String>,
+) {
+    let create_areas = |range: Option<(f64, f64)>| {
+        range.map(|(min, max)| {
+            vec![AreaLimit {
+                priority: None,
+                outer_shape: vec![
+                    Location::new_coordinate(min, -1.),
+                    Location::new_coordinate(max, -1.),
+                    Location::new_coordinate(max, 1.),
+                    Location::new_coordinate(min, 1.),
+                ],
+                inner_shapes: None,
+            }]
+        })
+    };
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        allowed_areas: create_areas(allowed),
+        forbidden_areas: create_areas(forbidden),
+        area_costs: None,
+        max_jobs: None,
+        max_stops: None,
+    }));
+    let mut solution = create_test_solution(Statistic::default());
+    solution.tours.first_mut().unwrap().stops = vec![
+        create_stop_with_activity(
+            "departure",
+            "departure",
+            (0., 0.),
+            1.,
+            ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+            0,
+        ),
+        create_stop_with_activity(
+            "job1",
+            "delivery",
+            (1., 0.),
+            0.,
+            ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+            1,
+        ),
+    ];
+
+    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_tour_size_limits, (max_jobs, max_stops, expected_result), {
+    can_check_tour_size_limits_impl(max_jobs, max_stops, expected_result);
+}}
+
+can_check_tour_size_limits! {
+    case_01: (Some(3), None, Ok(())),
+    case_02: (Some(2), None, Err("max jobs limit violation, expected: not more than 2, got: 3, vehicle id 'some_real_vehicle', shift index: 0".to_string())),
+    case_03: (None, Some(2), Ok(())),
+    case_04: (None, Some(1), Err("max stops limit violation, expected: not more than 1, got: 2, vehicle id 'some_real_vehicle', shift index: 0".to_string())),
+}
+
+fn can_check_tour_size_limits_impl(max_jobs: Option<usize>, max_stops: Option<usize>, expected: Result<(), String>) {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        allowed_areas: None,
+        forbidden_areas: None,
+        area_costs: None,
+        max_jobs,
+        max_stops,
+    }));
+    let mut solution = create_test_solution(Statistic::default());
+    let mut stop = create_stop_with_activity(
+        "job1",
+        "delivery",
+        (1., 0.),
+        0.,
+        ("1970-01-01T00:00:01Z", "1970-01-01T00:00:03Z"),
+        1,
+    );
+    let mut activity = stop.activities.first().unwrap().clone();
+    activity.job_id = "job2".to_string();
+    stop.activities.push(activity);
+    solution.tours.first_mut().unwrap().stops = vec![
+        create_stop_with_activity(
+            "departure",
+            "departure",
+            (0., 0.),
+            3.,
+            ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+            0,
+        ),
+        stop,
+        create_stop_with_activity(
+            "job3",
+            "delivery",
+            (2., 0.),
+            0.,
+            ("1970-01-01T00:00:04Z", "1970-01-01T00:00:05Z"),
+            2,
+        ),
+        create_stop_with_activity(
+            "arrival",
+            "arrival",
+            (0., 0.),
+            0.,
+            ("1970-01-01T00:00:07Z", "1970-01-01T00:00:07Z"),
+            4,
+        ),
+    ];
+
+    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
+
+    assert_eq!(result, expected);
+}
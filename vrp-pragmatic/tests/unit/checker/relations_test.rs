@@ -105,11 +105,15 @@ mod single {
                             tag: None,
                         }]),
                     }],
-                    capacity: vec![5],
+                    capacity: vec![5.],
                     skills: None,
                     limits: None,
+                    compartments: None,
+                    named_capacity: None,
+                    battery: None,
                 }],
                 profiles: create_default_profiles(),
+                dimensions: None,
             },
             ..create_empty_problem()
         };
@@ -119,6 +123,7 @@ mod single {
                 distance: 16,
                 duration: 25,
                 times: Timing { driving: 16, serving: 9, waiting: 0, break_time: 2 },
+                value: None,
             },
             tours: vec![
                 VehicleTour {
@@ -130,7 +135,7 @@ mod single {
                             "departure",
                             "departure",
                             (0., 0.),
-                            2,
+                            2.,
                             ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                             0,
                         ),
@@ -138,7 +143,7 @@ mod single {
                             "job1",
                             "delivery",
                             (1., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                             1,
                         ),
@@ -149,7 +154,7 @@ mod single {
                                 departure: "1970-01-01T00:00:06Z".to_string(),
                             },
                             distance: 2,
-                            load: vec![0],
+                            load: vec![0.],
                             activities: vec![
                                 Activity {
                                     job_id: "job2".to_string(),
@@ -157,6 +162,7 @@ mod single {
                                     location: None,
                                     time: None,
                                     job_tag: None,
+                                    demand: None,
                                 },
                                 Activity {
                                     job_id: "break".to_string(),
@@ -164,14 +170,16 @@ mod single {
                                     location: None,
                                     time: None,
                                     job_tag: None,
+                                    demand: None,
                                 },
                             ],
+                            named_load: None,
                         },
                         create_stop_with_activity(
                             "job3",
                             "pickup",
                             (3., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:00:07Z", "1970-01-01T00:00:08Z"),
                             3,
                         ),
@@ -179,7 +187,7 @@ mod single {
                             "reload",
                             "reload",
                             (0., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:00:11Z", "1970-01-01T00:00:13Z"),
                             6,
                         ),
@@ -187,7 +195,7 @@ mod single {
                             "job4",
                             "delivery",
                             (4., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:17Z", "1970-01-01T00:00:18Z"),
                             10,
                         ),
@@ -195,7 +203,7 @@ mod single {
                             "job5",
                             "pickup",
                             (5., 0.),
-                            1,
+                            1.,
                             ("1970-01-01T00:00:19Z", "1970-01-01T00:00:20Z"),
                             11,
                         ),
@@ -203,7 +211,7 @@ mod single {
                             "arrival",
                             "arrival",
                             (0., 0.),
-                            0,
+                            0.,
                             ("1970-01-01T00:00:25Z", "1970-01-01T00:00:25Z"),
                             16,
                         ),
@@ -213,6 +221,7 @@ mod single {
                         distance: 16,
                         duration: 25,
                         times: Timing { driving: 16, serving: 9, waiting: 0, break_time: 2 },
+                        value: None,
                     },
                 },
                 VehicleTour {
@@ -232,3 +241,96 @@ mod single {
         assert_eq!(result, expected_result);
     }
 }
+
+mod order {
+    use super::*;
+    use vrp_core::models::examples::create_example_problem;
+    use JobOrderPosition::{First, Last};
+
+    parameterized_test! {can_check_tour_order, (orders, expected_result), {
+        can_check_tour_order_impl(orders, expected_result);
+    }}
+
+    can_check_tour_order! {
+        case_01: ((None, None, None), Ok(())),
+        case_02: ((Some(First), None, Some(Last)), Ok(())),
+        case_03: ((Some(First), Some(First), None), Ok(())),
+        case_04: ((None, Some(First), None), Err(())),
+        case_05: ((None, Some(Last), None), Err(())),
+        case_06: ((Some(Last), None, Some(First)), Err(())),
+    }
+
+    fn can_check_tour_order_impl(
+        orders: (Option<JobOrderPosition>, Option<JobOrderPosition>, Option<JobOrderPosition>),
+        expected_result: Result<(), ()>,
+    ) {
+        let create_job = |id: &str, location: f64, order: Option<JobOrderPosition>| Job {
+            order: order.map(JobOrder::Position),
+            ..create_delivery_job(id, vec![location, 0.])
+        };
+        let problem = Problem {
+            plan: Plan {
+                jobs: vec![
+                    create_job("job1", 1., orders.0),
+                    create_job("job2", 2., orders.1),
+                    create_job("job3", 3., orders.2),
+                ],
+                relations: None,
+            },
+            fleet: Fleet {
+                vehicles: vec![create_default_vehicle_type()],
+                profiles: create_default_profiles(),
+                dimensions: None,
+            },
+            ..create_empty_problem()
+        };
+        let solution = Solution {
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        3.,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0,
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        2.,
+                        ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                        1,
+                    ),
+                    create_stop_with_activity(
+                        "job2",
+                        "delivery",
+                        (2., 0.),
+                        1.,
+                        ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                        2,
+                    ),
+                    create_stop_with_activity(
+                        "job3",
+                        "delivery",
+                        (3., 0.),
+                        0.,
+                        ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                        3,
+                    ),
+                ],
+                ..create_empty_tour()
+            }],
+            ..create_empty_solution()
+        };
+
+        let result =
+            check_relations(&CheckerContext::new(create_example_problem(), problem, None, solution)).map_err(|_| ());
+
+        assert_eq!(result, expected_result);
+    }
+}
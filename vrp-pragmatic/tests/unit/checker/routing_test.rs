@@ -9,7 +9,11 @@ fn create_test_problem() -> Problem {
             jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
             relations: None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     }
 }
@@ -20,6 +24,7 @@ fn create_test_statistic() -> Statistic {
         distance: 4,
         duration: 6,
         times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
+        value: None,
     }
 }
 
@@ -36,7 +41,7 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                     "departure",
                     "departure",
                     (0., 0.),
-                    2,
+                    2.,
                     ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                     0,
                 ),
@@ -44,33 +49,37 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                     location: vec![1., 0.].to_loc(),
                     time: Schedule { arrival: format_time(first.0), departure: "1970-01-01T00:00:02Z".to_string() },
                     distance: first.1,
-                    load: vec![1],
+                    load: vec![1.],
                     activities: vec![Activity {
                         job_id: "job1".to_string(),
                         activity_type: "delivery".to_string(),
                         location: None,
                         time: None,
                         job_tag: None,
+                        demand: None,
                     }],
+                    named_load: None,
                 },
                 Stop {
                     location: vec![2., 0.].to_loc(),
                     time: Schedule { arrival: format_time(second.0), departure: "1970-01-01T00:00:04Z".to_string() },
                     distance: second.1,
-                    load: vec![0],
+                    load: vec![0.],
                     activities: vec![Activity {
                         job_id: "job2".to_string(),
                         activity_type: "delivery".to_string(),
                         location: Some(vec![2., 0.].to_loc()),
                         time: None,
                         job_tag: None,
+                        demand: None,
                     }],
+                    named_load: None,
                 },
                 create_stop_with_activity(
                     "arrival",
                     "arrival",
                     (0., 0.),
-                    0,
+                    0.,
                     (format_time(third.0).as_str(), "1970-01-01T00:00:06Z"),
                     third.1,
                 ),
@@ -0,0 +1,215 @@
+use crate::constraints::{Battery, ChargingModule};
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::constraints::{ConstraintPipeline, TOTAL_DISTANCE_KEY};
+use vrp_core::construction::heuristics::{ActivityContext, RegistryContext, RouteContext, RouteState, SolutionContext};
+use vrp_core::models::common::*;
+use vrp_core::models::problem::{Fleet, Job, Single, TransportCost};
+use vrp_core::models::solution::Registry;
+
+struct TestTransportCost {}
+
+impl TransportCost for TestTransportCost {
+    fn duration(&self, _: Profile, from: Location, to: Location, _: Timestamp) -> Duration {
+        (if to > from { to - from } else { from - to }) as f64
+    }
+
+    fn distance(&self, _: Profile, from: Location, to: Location, _: Timestamp) -> Distance {
+        (if to > from { to - from } else { from - to }) as f64
+    }
+}
+
+fn create_fleet(capacity: f64, load_consumption: f64) -> Fleet {
+    let mut vehicle = test_vehicle("v1");
+    vehicle.dimens.set_value("shift_index", 0_usize);
+    vehicle.dimens.set_value("battery", Battery { capacity, initial: capacity, consumption: 1., load_consumption });
+
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_module() -> ChargingModule<SingleDimLoad> {
+    ChargingModule::new(Arc::new(TestTransportCost {}), Arc::new(|load| load.value as f64), 1)
+}
+
+fn create_job(id: &str, location: Location, delivery: i32) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_id(id);
+    single.dimens.set_demand(Demand {
+        pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+        delivery: (SingleDimLoad::new(delivery), SingleDimLoad::default()),
+    });
+
+    Arc::new(single)
+}
+
+fn create_charging(location: Location, amount: f64) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(location));
+    single.places.first_mut().unwrap().duration = amount;
+    single.dimens.set_id("charging");
+    single.dimens.set_value("type", "charging".to_string());
+    single.dimens.set_value("vehicle_id", "v1".to_string());
+    single.dimens.set_value("shift_index", 0_usize);
+    single.dimens.set_value("charging_rate", 1.);
+
+    Arc::new(single)
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (capacity, load_consumption, target_location, is_violation), {
+    can_evaluate_activity_insertion_impl(capacity, load_consumption, target_location, is_violation);
+}}
+
+can_evaluate_activity_insertion! {
+    case01: (12., 0., 6, false),
+    case02: (12., 0., 7, true),
+    case03: (16., 0.5, 6, true),
+    case04: (23., 0.5, 6, false),
+}
+
+fn can_evaluate_activity_insertion_impl(
+    capacity: f64,
+    load_consumption: f64,
+    target_location: Location,
+    is_violation: bool,
+) {
+    let fleet = create_fleet(capacity, load_consumption);
+    let mut route_ctx = RouteContext {
+        route: Arc::new(create_route_with_activities(
+            &fleet,
+            "v1",
+            vec![create_activity_with_job_at_location(create_job("job1", 5, 2), 5)],
+        )),
+        state: Arc::new(RouteState::default()),
+    };
+    let target = create_activity_with_job_at_location(create_job("job2", target_location, 2), target_location);
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(create_module()));
+    pipeline.accept_route_state(&mut route_ctx);
+
+    let result = pipeline.evaluate_hard_activity(
+        &route_ctx,
+        &ActivityContext {
+            index: 1,
+            prev: route_ctx.route.tour.get(1).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(2),
+        },
+    );
+
+    assert_eq!(result.is_some(), is_violation);
+}
+
+parameterized_test! {can_remove_redundant_charging, (capacity, charging_removed), {
+    can_remove_redundant_charging_impl(capacity, charging_removed);
+}}
+
+can_remove_redundant_charging! {
+    case01: (10., true),
+    case02: (5., false),
+}
+
+fn can_remove_redundant_charging_impl(capacity: f64, charging_removed: bool) {
+    let fleet = create_fleet(capacity, 0.);
+    let mut solution_ctx = SolutionContext {
+        required: vec![],
+        ignored: vec![],
+        unassigned: Default::default(),
+        locked: Default::default(),
+        state: Default::default(),
+        routes: vec![RouteContext {
+            route: Arc::new(create_route_with_activities(
+                &fleet,
+                "v1",
+                vec![
+                    create_activity_with_job_at_location(create_charging(2, 5.), 2),
+                    create_activity_with_job_at_location(create_job("job1", 3, 0), 3),
+                ],
+            )),
+            state: Arc::new(RouteState::default()),
+        }],
+        registry: RegistryContext::new(Registry::new(&fleet)),
+    };
+
+    ConstraintPipeline::default().add_module(Box::new(create_module())).accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.ignored.len(), if charging_removed { 1 } else { 0 });
+    assert_eq!(solution_ctx.routes.first().unwrap().route.tour.job_count(), if charging_removed { 1 } else { 2 });
+}
+
+parameterized_test! {can_offer_charging_only_when_range_is_not_enough, (unassigned_code, expected), {
+    can_offer_charging_only_when_range_is_not_enough_impl(unassigned_code, expected);
+}}
+
+can_offer_charging_only_when_range_is_not_enough! {
+    case01: (None, false),
+    case02: (Some(1), true),
+    case03: (Some(2), false),
+}
+
+fn can_offer_charging_only_when_range_is_not_enough_impl(unassigned_code: Option<i32>, expected: bool) {
+    let fleet = create_fleet(10., 0.);
+    let job = Job::Single(create_job("job1", 3, 0));
+    let charging = Job::Single(create_charging(2, 5.));
+    let mut solution_ctx = SolutionContext {
+        required: vec![Job::Single(create_job("job2", 4, 0))],
+        ignored: vec![charging.clone()],
+        unassigned: unassigned_code
+            .map(|code| vec![(Job::Single(create_job("job3", 9, 0)), code)].into_iter().collect())
+            .unwrap_or_default(),
+        locked: Default::default(),
+        state: Default::default(),
+        routes: vec![RouteContext {
+            route: Arc::new(create_route_with_activities(
+                &fleet,
+                "v1",
+                vec![create_activity_with_job_at_location(job.as_single().unwrap().clone(), 3)],
+            )),
+            state: Arc::new(RouteState::default()),
+        }],
+        registry: RegistryContext::new(Registry::new(&fleet)),
+    };
+
+    ConstraintPipeline::default().add_module(Box::new(create_module())).accept_insertion(&mut solution_ctx, 0, &job);
+
+    assert_eq!(solution_ctx.required.contains(&charging), expected);
+    assert_eq!(solution_ctx.ignored.contains(&charging), !expected);
+}
+
+parameterized_test! {can_prefer_charging_insertion, (distance, expected), {
+    can_prefer_charging_insertion_impl(distance, expected);
+}}
+
+can_prefer_charging_insertion! {
+    case01: (0., -400.),
+    case02: (10., -440.),
+}
+
+fn can_prefer_charging_insertion_impl(distance: f64, expected: f64) {
+    let fleet = create_fleet(10., 0.);
+    let mut route_ctx = RouteContext {
+        route: Arc::new(create_route_with_activities(&fleet, "v1", vec![])),
+        state: Arc::new(RouteState::default()),
+    };
+    route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, distance);
+    let solution_ctx = SolutionContext {
+        required: vec![],
+        ignored: vec![],
+        unassigned: Default::default(),
+        locked: Default::default(),
+        state: Default::default(),
+        routes: vec![],
+        registry: RegistryContext::new(Registry::new(&fleet)),
+    };
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(create_module()));
+
+    let charging = pipeline.evaluate_soft_route(&solution_ctx, &route_ctx, &Job::Single(create_charging(2, 5.)));
+    let job = pipeline.evaluate_soft_route(&solution_ctx, &route_ctx, &Job::Single(create_job("job1", 3, 0)));
+
+    assert_eq!(charging, expected);
+    assert_eq!(job, 0.);
+}
@@ -0,0 +1,145 @@
+use crate::constraints::{TourSize, TourSizeModule};
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::constraints::ConstraintPipeline;
+use vrp_core::construction::heuristics::{ActivityContext, RegistryContext, RouteContext, RouteState, SolutionContext};
+use vrp_core::models::common::*;
+use vrp_core::models::problem::{Fleet, Job, Single};
+use vrp_core::models::solution::Registry;
+
+fn create_fleet(max_jobs: Option<usize>, max_stops: Option<usize>) -> Fleet {
+    let mut vehicle = test_vehicle("v1");
+    vehicle.dimens.set_value("tour_size", TourSize { max_jobs, max_stops });
+
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_job(id: &str, location: Location) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_id(id);
+
+    Arc::new(single)
+}
+
+fn create_break(location: Location) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_id("break");
+    single.dimens.set_value("type", "break".to_string());
+    single.dimens.set_value("vehicle_id", "v1".to_string());
+    single.dimens.set_value("shift_index", 0_usize);
+
+    Arc::new(single)
+}
+
+fn create_route_ctx(fleet: &Fleet, locations: Vec<Location>) -> RouteContext {
+    let activities = locations
+        .into_iter()
+        .enumerate()
+        .map(|(idx, location)| {
+            create_activity_with_job_at_location(create_job(&format!("job{}", idx), location), location)
+        })
+        .collect();
+
+    let mut route_ctx = RouteContext {
+        route: Arc::new(create_route_with_activities(fleet, "v1", activities)),
+        state: Arc::new(RouteState::default()),
+    };
+
+    ConstraintPipeline::default().add_module(Box::new(TourSizeModule::new(1))).accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+fn create_solution_ctx(fleet: &Fleet) -> SolutionContext {
+    SolutionContext {
+        required: vec![],
+        ignored: vec![],
+        unassigned: Default::default(),
+        locked: Default::default(),
+        state: Default::default(),
+        routes: vec![],
+        registry: RegistryContext::new(Registry::new(fleet)),
+    }
+}
+
+parameterized_test! {can_evaluate_job_insertion, (max_jobs, is_break, is_violation), {
+    can_evaluate_job_insertion_impl(max_jobs, is_break, is_violation);
+}}
+
+can_evaluate_job_insertion! {
+    case01: (Some(3), false, true),
+    case02: (Some(4), false, false),
+    case03: (Some(3), true, false),
+    case04: (None, false, false),
+}
+
+fn can_evaluate_job_insertion_impl(max_jobs: Option<usize>, is_break: bool, is_violation: bool) {
+    let fleet = create_fleet(max_jobs, None);
+    let route_ctx = create_route_ctx(&fleet, vec![1, 1, 2]);
+    let job = Job::Single(if is_break { create_break(3) } else { create_job("job", 3) });
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(TourSizeModule::new(1)));
+
+    let result = pipeline.evaluate_hard_route(&create_solution_ctx(&fleet), &route_ctx, &job);
+
+    assert_eq!(result.is_some(), is_violation);
+}
+
+parameterized_test! {can_evaluate_activity_insertion, (max_stops, index, target_location, is_violation), {
+    can_evaluate_activity_insertion_impl(max_stops, index, target_location, is_violation);
+}}
+
+can_evaluate_activity_insertion! {
+    case01: (2, 1, 1, false),
+    case02: (2, 3, 2, false),
+    case03: (2, 3, 3, true),
+    case04: (3, 3, 3, false),
+    case05: (3, 1, 3, true),
+    case06: (4, 1, 3, false),
+}
+
+fn can_evaluate_activity_insertion_impl(max_stops: usize, index: usize, target_location: Location, is_violation: bool) {
+    let fleet = create_fleet(None, Some(max_stops));
+    let route_ctx = create_route_ctx(&fleet, vec![1, 1, 2]);
+    let target = create_activity_with_job_at_location(create_job("job", target_location), target_location);
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(TourSizeModule::new(1)));
+
+    let result = pipeline.evaluate_hard_activity(
+        &route_ctx,
+        &ActivityContext {
+            index,
+            prev: route_ctx.route.tour.get(index).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(index + 1),
+        },
+    );
+
+    assert_eq!(result.is_some(), is_violation);
+}
+
+#[test]
+fn can_skip_vehicle_specific_activity_insertion() {
+    let fleet = create_fleet(None, Some(2));
+    let route_ctx = create_route_ctx(&fleet, vec![1, 2]);
+    let target = create_activity_with_job_at_location(create_break(3), 3);
+    let mut pipeline = ConstraintPipeline::default();
+    pipeline.add_module(Box::new(TourSizeModule::new(1)));
+
+    let result = pipeline.evaluate_hard_activity(
+        &route_ctx,
+        &ActivityContext {
+            index: 2,
+            prev: route_ctx.route.tour.get(2).unwrap(),
+            target: &target,
+            next: route_ctx.route.tour.get(3),
+        },
+    );
+
+    assert!(result.is_none());
+}
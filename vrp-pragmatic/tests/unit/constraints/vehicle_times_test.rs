@@ -0,0 +1,131 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{Distance, Duration, IdDimension};
+use vrp_core::models::problem::{Fleet, SimpleActivityCost, Single};
+
+struct TestTransportCost {}
+
+impl TransportCost for TestTransportCost {
+    fn duration(&self, _: Profile, from: Location, to: Location, _: Timestamp) -> Duration {
+        (if to > from { to - from } else { from - to }) as f64
+    }
+
+    fn distance(&self, _: Profile, from: Location, to: Location, _: Timestamp) -> Distance {
+        (if to > from { to - from } else { from - to }) as f64
+    }
+}
+
+fn create_fleet() -> Fleet {
+    Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(test_vehicle("v1"))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    )
+}
+
+fn create_job(location: Location, times: Vec<(f64, f64)>) -> Arc<Single> {
+    let mut single = create_single_with_location(Some(location));
+    single.dimens.set_id("job1");
+    single.dimens.set_value(
+        "vehicle_times",
+        vec![(
+            location,
+            vec![VehicleTimes {
+                type_ids: vec!["v1".to_string()].into_iter().collect(),
+                profiles: HashSet::default(),
+                times: times.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect(),
+            }],
+        )],
+    );
+
+    Arc::new(single)
+}
+
+fn create_route_ctx(fleet: &Fleet, job: Arc<Single>, location: Location) -> RouteContext {
+    RouteContext {
+        route: Arc::new(create_route_with_activities(
+            fleet,
+            "v1",
+            vec![create_activity_with_job_at_location(job, location)],
+        )),
+        state: Arc::new(RouteState::default()),
+    }
+}
+
+fn create_module() -> VehicleTimesModule {
+    VehicleTimesModule::new(Arc::new(SimpleActivityCost::default()), Arc::new(TestTransportCost {}), 1)
+}
+
+#[test]
+fn can_narrow_time_window_from_original_one_every_time() {
+    let fleet = create_fleet();
+    let module = create_module();
+    let mut route_ctx = create_route_ctx(&fleet, create_job(10, vec![(0., 50.), (100., 200.)]), 10);
+
+    module.accept_route_state(&mut route_ctx);
+    assert_eq!(route_ctx.route.tour.get(1).unwrap().place.time, TimeWindow::new(0., 50.));
+
+    route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = 120.;
+    module.accept_route_state(&mut route_ctx);
+    assert_eq!(route_ctx.route.tour.get(1).unwrap().place.time, TimeWindow::new(100., 200.));
+
+    route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = 20.;
+    module.accept_route_state(&mut route_ctx);
+    assert_eq!(route_ctx.route.tour.get(1).unwrap().place.time, TimeWindow::new(0., 50.));
+}
+
+parameterized_test! {can_restrict_time_window_for_vehicle_arrival, (departure, expected), {
+    can_restrict_time_window_for_vehicle_arrival_impl(departure, expected);
+}}
+
+can_restrict_time_window_for_vehicle_arrival! {
+    case01_early_arrival: (0., (20., 30.)),
+    case02_in_time_arrival: (15., (20., 30.)),
+    case03_late_arrival: (40., (20., 30.)),
+}
+
+fn can_restrict_time_window_for_vehicle_arrival_impl(departure: f64, expected: (f64, f64)) {
+    let fleet = create_fleet();
+    let module = create_module();
+    let mut route_ctx = create_route_ctx(&fleet, create_job(10, vec![(20., 30.)]), 10);
+    route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = departure;
+
+    module.accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.route.tour.get(1).unwrap().place.time, TimeWindow::new(expected.0, expected.1));
+}
+
+parameterized_test! {can_evaluate_vehicle_arrival, (departure, expected), {
+    can_evaluate_vehicle_arrival_impl(departure, expected);
+}}
+
+can_evaluate_vehicle_arrival! {
+    case01_early_arrival: (0., None),
+    case02_in_time_arrival: (15., None),
+    case03_late_arrival: (40., Some(1)),
+}
+
+fn can_evaluate_vehicle_arrival_impl(departure: f64, expected: Option<i32>) {
+    let fleet = create_fleet();
+    let module = create_module();
+    let mut route_ctx = create_route_ctx(&fleet, create_job(10, vec![(20., 30.)]), 10);
+    route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = departure;
+    let target = create_activity_with_job_at_location(create_job(10, vec![(20., 30.)]), 10);
+    let constraint = module
+        .get_constraints()
+        .filter_map(|constraint| match constraint {
+            ConstraintVariant::HardActivity(constraint) => Some(constraint.clone()),
+            _ => None,
+        })
+        .next()
+        .unwrap();
+
+    let result = constraint.evaluate_activity(
+        &route_ctx,
+        &ActivityContext { index: 0, prev: route_ctx.route.tour.get(0).unwrap(), target: &target, next: None },
+    );
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
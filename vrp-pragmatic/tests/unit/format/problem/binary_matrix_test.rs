@@ -0,0 +1,143 @@
+use super::*;
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::format_time;
+use crate::helpers::*;
+
+fn to_binary(matrix: &Matrix, value_type: BinaryValueType) -> Vec<u8> {
+    let mut buffer = vec![];
+    serialize_binary_matrix(matrix, value_type, &mut buffer).expect("cannot serialize matrix");
+
+    buffer
+}
+
+fn get_values(values: &Arc<dyn MatrixValues>) -> Vec<f64> {
+    (0..values.len()).map(|idx| values.value(idx)).collect()
+}
+
+parameterized_test! {can_serialize_and_deserialize_matrix, value_type, {
+    can_serialize_and_deserialize_matrix_impl(value_type);
+}}
+
+can_serialize_and_deserialize_matrix! {
+    case01: BinaryValueType::U32,
+    case02: BinaryValueType::F32,
+}
+
+fn can_serialize_and_deserialize_matrix_impl(value_type: BinaryValueType) {
+    let matrix = Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: vec![0, 2, 3, 0],
+        distances: vec![0, 4, 5, 0],
+        error_codes: Some(vec![0, 0, 1, 0]),
+    };
+
+    let binary = deserialize_binary_matrix(to_binary(&matrix, value_type)).expect("cannot deserialize matrix");
+
+    assert_eq!(binary.profile, Some("car".to_string()));
+    assert_eq!(binary.size, 2);
+    assert!(binary.has_unreachable);
+    assert_eq!(get_values(binary.durations.as_ref().unwrap()), vec![0., 2., -1., 0.]);
+    assert_eq!(get_values(&binary.distances), vec![0., 4., -1., 0.]);
+}
+
+#[test]
+fn can_use_distance_only_mode_when_durations_are_same() {
+    let matrix = create_matrix(vec![0, 1, 1, 0]);
+
+    let buffer = to_binary(&matrix, BinaryValueType::U32);
+    let binary = deserialize_binary_matrix(buffer.clone()).expect("cannot deserialize matrix");
+
+    assert!(binary.durations.is_none());
+    assert!(!binary.has_unreachable);
+    assert_eq!(get_values(&binary.distances), vec![0., 1., 1., 0.]);
+    assert_eq!(buffer.len(), 16 + "car".len() + 4 * 4);
+}
+
+parameterized_test! {can_detect_invalid_binary_matrix, (bytes, expected), {
+    can_detect_invalid_binary_matrix_impl(bytes, expected);
+}}
+
+can_detect_invalid_binary_matrix! {
+    case01: (b"JSON".to_vec(), "unknown magic bytes"),
+    case02: (b"VRPM".to_vec(), "unexpected end of header"),
+    case03: (vec![b'V', b'R', b'P', b'M', 2, 0, 0, 0], "unsupported version: 2"),
+    case04: (vec![b'V', b'R', b'P', b'M', 1, 3, 0, 0], "unsupported value type: 3"),
+    case05: (vec![b'V', b'R', b'P', b'M', 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
+             "data size does not match amount of locations"),
+    case06: (vec![b'V', b'R', b'P', b'M', 1, 0, 1, 0, 255, 255, 255, 255, 0, 0, 0, 0],
+             "amount of locations is too big"),
+}
+
+fn can_detect_invalid_binary_matrix_impl(bytes: Vec<u8>, expected: &str) {
+    let result = deserialize_binary_matrix(bytes).err().expect("should fail");
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].code, "E0001");
+    assert_eq!(result[0].action, format!("check binary matrix: '{}'", expected));
+}
+
+#[test]
+fn can_reject_values_which_do_not_fit_u32() {
+    let matrix = create_matrix(vec![0, -2, 1, 0]);
+    let mut buffer = vec![];
+
+    let result = serialize_binary_matrix(&matrix, BinaryValueType::U32, &mut buffer);
+
+    assert_eq!(result, Err("value '-2' cannot be stored as u32".to_string()));
+}
+
+#[test]
+fn can_read_problem_with_binary_matrix() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_index("job1", 0), create_delivery_job_with_index("job2", 1)],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: Location::Reference { index: 2 },
+                    },
+                    end: None,
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix =
+        Matrix { travel_times: vec![0, 2, 3, 2, 0, 4, 3, 4, 0], ..create_matrix(vec![0, 5, 6, 5, 0, 7, 6, 7, 0]) };
+    let binary = deserialize_binary_matrix(to_binary(&matrix, BinaryValueType::U32)).unwrap();
+
+    let core_problem = (problem, vec![binary]).read_pragmatic().expect("cannot read problem");
+
+    assert_eq!(core_problem.transport.duration(0, 0, 2, 0.), 3.);
+    assert_eq!(core_problem.transport.distance(0, 0, 2, 0.), 6.);
+    assert_eq!(core_problem.jobs.size(), 2);
+}
+
+#[test]
+fn can_validate_problem_with_binary_matrix() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_index("job1", 3)], relations: None },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+    let binary = deserialize_binary_matrix(to_binary(&create_matrix(vec![0, 1, 1, 0]), BinaryValueType::U32)).unwrap();
+
+    let errors = (problem, vec![binary]).read_pragmatic().err().expect("should fail");
+
+    assert!(errors.iter().any(|err| err.code == "E1505"));
+}
@@ -32,6 +32,7 @@ fn create_problem(profiles: &[&str]) -> Problem {
                 .iter()
                 .map(|p| Profile { name: p.to_string(), profile_type: "car".to_string(), speed: None })
                 .collect(),
+            dimensions: None,
         },
         ..create_empty_problem()
     }
@@ -97,7 +98,7 @@ can_create_transport_costs_negative_cases! {
 fn can_create_transport_costs_negative_cases_impl(profiles: &[&str], matrices: &[Matrix], res_err: &str) {
     let problem = create_problem(profiles);
 
-    let result = create_transport_costs(&problem, matrices);
+    let result = create_transport_costs(&problem, matrices, false);
 
     assert_eq!(result.err(), Some(res_err.to_string()));
 }
@@ -154,10 +155,12 @@ fn can_create_transport_costs_positive_cases_impl(
 ) {
     let problem = create_problem(profiles);
 
-    let transport = create_transport_costs(&problem, matrices).unwrap();
+    vec![false, true].into_iter().for_each(|use_compact_matrices| {
+        let transport = create_transport_costs(&problem, matrices, use_compact_matrices).unwrap();
 
-    probes.iter().for_each(|&(profile, timestamp, distance)| {
-        let result = transport.distance(profile, 0, 1, timestamp);
-        assert_eq!(result, distance);
+        probes.iter().for_each(|&(profile, timestamp, distance)| {
+            let result = transport.distance(profile, 0, 1, timestamp);
+            assert_eq!(result, distance);
+        });
     });
 }
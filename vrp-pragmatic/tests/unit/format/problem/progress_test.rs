@@ -0,0 +1,249 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_test_problem(vehicle_ids: Vec<&str>, breaks: Option<Vec<VehicleBreak>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_pickup_delivery_job("job3", vec![3., 0.], vec![4., 0.]),
+                create_delivery_job("job4", vec![5., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: to_strings(vehicle_ids),
+                shifts: vec![VehicleShift { breaks, ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_vehicle_progress(vehicle_id: &str, time: f64, completed: Vec<&str>) -> VehicleProgress {
+    VehicleProgress {
+        vehicle_id: vehicle_id.to_string(),
+        shift_index: 0,
+        location: vec![2., 0.].to_loc(),
+        time: format_time(time),
+        completed: Some(to_strings(completed)),
+        committed: None,
+        assigned: None,
+    }
+}
+
+fn get_job_ids(problem: &Problem) -> Vec<String> {
+    problem.plan.jobs.iter().map(|job| job.id.clone()).collect()
+}
+
+#[test]
+fn can_deserialize_progress() {
+    let progress = r#"
+    {
+      "vehicles": [
+        {
+          "vehicleId": "v1",
+          "location": { "lat": 1.0, "lng": 0.0 },
+          "time": "2020-07-04T10:00:00Z",
+          "completed": ["job1", "break"],
+          "committed": ["job2"]
+        }
+      ]
+    }
+    "#;
+
+    let progress = deserialize_progress(BufReader::new(progress.as_bytes())).expect("cannot deserialize");
+
+    assert_eq!(progress.vehicles.len(), 1);
+    let vehicle = progress.vehicles.first().unwrap();
+    assert_eq!(vehicle.vehicle_id, "v1");
+    assert_eq!(vehicle.shift_index, 0);
+    assert_eq!(vehicle.completed, Some(to_strings(vec!["job1", "break"])));
+    assert_eq!(vehicle.committed, Some(to_strings(vec!["job2"])));
+    assert!(vehicle.assigned.is_none());
+}
+
+#[test]
+fn can_remove_completed_jobs_and_move_shift_start() {
+    let problem = create_test_problem(vec!["my_vehicle_1"], None);
+    let progress = PlanProgress { vehicles: vec![create_vehicle_progress("my_vehicle_1", 10., vec!["job1", "job2"])] };
+
+    let problem = apply_progress(problem, &progress).expect("cannot apply progress");
+
+    assert_eq!(get_job_ids(&problem), vec!["job3", "job4"]);
+    assert!(problem.plan.relations.is_none());
+    assert_eq!(problem.fleet.vehicles.len(), 1);
+    let start = &problem.fleet.vehicles[0].shifts[0].start;
+    assert_eq!(start.earliest, format_time(10.));
+    assert_eq!(start.latest, Some(format_time(10.)));
+    assert_eq!(start.location.to_lat_lng(), (2., 0.));
+}
+
+#[test]
+fn can_keep_delivery_of_job_with_completed_pickup() {
+    let problem = create_test_problem(vec!["my_vehicle_1"], None);
+    let progress = PlanProgress { vehicles: vec![create_vehicle_progress("my_vehicle_1", 10., vec!["job3"])] };
+
+    let problem = apply_progress(problem, &progress).expect("cannot apply progress");
+
+    let job = problem.plan.jobs.iter().find(|job| job.id == "job3").unwrap();
+    assert!(job.pickups.is_none());
+    assert_eq!(job.deliveries.as_ref().map(|tasks| tasks.len()), Some(1));
+
+    let relations = problem.plan.relations.expect("no relations");
+    assert_eq!(relations.len(), 1);
+    assert!(matches!(relations[0].type_field, RelationType::Any));
+    assert_eq!(relations[0].jobs, to_strings(vec!["job3"]));
+    assert_eq!(relations[0].vehicle_id, "my_vehicle_1");
+}
+
+#[test]
+fn can_keep_goods_of_completed_pickup_job_on_board() {
+    let mut problem = create_test_problem(vec!["my_vehicle_1"], None);
+    problem.plan.jobs.push(create_pickup_job("job5", vec![6., 0.]));
+    let progress = PlanProgress {
+        vehicles: vec![VehicleProgress {
+            committed: Some(to_strings(vec!["job2"])),
+            ..create_vehicle_progress("my_vehicle_1", 10., vec!["job1", "job5"])
+        }],
+    };
+
+    let problem = apply_progress(problem, &progress).expect("cannot apply progress");
+
+    let job = problem.plan.jobs.iter().find(|job| job.id == "job5").expect("no job with goods on board");
+    let pickup = job.pickups.as_ref().and_then(|tasks| tasks.first()).unwrap();
+    assert_eq!(pickup.demand, Some(vec![1.]));
+    assert_eq!(pickup.places.len(), 1);
+    assert_eq!(pickup.places[0].location.to_lat_lng(), (2., 0.));
+    assert_eq!(pickup.places[0].duration, 0.);
+    assert!(pickup.places[0].times.is_none());
+
+    let relations = problem.plan.relations.expect("no relations");
+    assert_eq!(relations.len(), 1);
+    assert!(matches!(relations[0].type_field, RelationType::Strict));
+    assert_eq!(relations[0].jobs, to_strings(vec!["departure", "job5", "job2"]));
+}
+
+#[test]
+fn can_lock_committed_and_assigned_jobs() {
+    let problem = create_test_problem(vec!["my_vehicle_1"], None);
+    let progress = PlanProgress {
+        vehicles: vec![VehicleProgress {
+            committed: Some(to_strings(vec!["job2"])),
+            assigned: Some(to_strings(vec!["job2", "job4"])),
+            ..create_vehicle_progress("my_vehicle_1", 10., vec!["job1"])
+        }],
+    };
+
+    let problem = apply_progress(problem, &progress).expect("cannot apply progress");
+
+    let relations = problem.plan.relations.expect("no relations");
+    assert_eq!(relations.len(), 2);
+    assert!(matches!(relations[0].type_field, RelationType::Strict));
+    assert_eq!(relations[0].jobs, to_strings(vec!["departure", "job2"]));
+    assert_eq!(relations[0].shift_index, Some(0));
+    assert!(matches!(relations[1].type_field, RelationType::Any));
+    assert_eq!(relations[1].jobs, to_strings(vec!["job4"]));
+}
+
+#[test]
+fn can_remove_completed_jobs_from_relations() {
+    let mut problem = create_test_problem(vec!["my_vehicle_1"], None);
+    problem.plan.relations = Some(vec![
+        Relation {
+            type_field: RelationType::Sequence,
+            jobs: to_strings(vec!["departure", "job1", "job2"]),
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: None,
+        },
+        Relation {
+            type_field: RelationType::Any,
+            jobs: to_strings(vec!["job4"]),
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: None,
+        },
+    ]);
+    let progress = PlanProgress { vehicles: vec![create_vehicle_progress("my_vehicle_1", 10., vec!["job1", "job2"])] };
+
+    let problem = apply_progress(problem, &progress).expect("cannot apply progress");
+
+    let relations = problem.plan.relations.expect("no relations");
+    assert_eq!(relations.len(), 1);
+    assert_eq!(relations[0].jobs, to_strings(vec!["job4"]));
+}
+
+#[test]
+fn can_split_vehicle_type_with_multiple_vehicles() {
+    let problem = create_test_problem(vec!["v1", "v2"], None);
+    let progress = PlanProgress { vehicles: vec![create_vehicle_progress("v2", 10., vec![])] };
+
+    let problem = apply_progress(problem, &progress).expect("cannot apply progress");
+
+    let vehicles = &problem.fleet.vehicles;
+    assert_eq!(vehicles.len(), 2);
+    assert_eq!(vehicles[0].vehicle_ids, to_strings(vec!["v1"]));
+    assert_eq!(vehicles[0].shifts[0].start.earliest, format_time(0.));
+    assert_eq!(vehicles[1].type_id, "my_vehicle_v2");
+    assert_eq!(vehicles[1].vehicle_ids, to_strings(vec!["v2"]));
+    assert_eq!(vehicles[1].shifts[0].start.earliest, format_time(10.));
+}
+
+parameterized_test! {can_update_breaks, (time, completed, expected), {
+    can_update_breaks_impl(time, completed, expected);
+}}
+
+can_update_breaks! {
+    case01_offset_not_started: (10., vec![], Some(vec![90., 110.])),
+    case02_offset_started: (105., vec![], Some(vec![0., 15.])),
+    case03_offset_passed: (130., vec![], None),
+    case04_offset_taken: (50., vec!["break"], None),
+}
+
+fn can_update_breaks_impl(time: f64, completed: Vec<&str>, expected: Option<Vec<f64>>) {
+    let breaks = Some(vec![VehicleBreak {
+        time: VehicleBreakTime::TimeOffset(vec![100., 120.]),
+        duration: 10.,
+        locations: None,
+    }]);
+    let problem = create_test_problem(vec!["my_vehicle_1"], breaks);
+    let progress = PlanProgress { vehicles: vec![create_vehicle_progress("my_vehicle_1", time, completed)] };
+
+    let problem = apply_progress(problem, &progress).expect("cannot apply progress");
+
+    let breaks = problem.fleet.vehicles[0].shifts[0].breaks.as_ref();
+    let actual = breaks.map(|breaks| match &breaks[0].time {
+        VehicleBreakTime::TimeOffset(offset) => offset.clone(),
+        VehicleBreakTime::TimeWindow(_) => unreachable!(),
+    });
+    assert_eq!(actual, expected);
+}
+
+parameterized_test! {can_detect_invalid_progress, (vehicle_id, completed, expected), {
+    can_detect_invalid_progress_impl(vehicle_id, completed, expected);
+}}
+
+can_detect_invalid_progress! {
+    case01_unknown_vehicle: ("v2", vec![], "cannot find vehicle 'v2' with shift index 0"),
+    case02_unknown_job: ("my_vehicle_1", vec!["job6"], "unknown job id in plan progress: 'job6'"),
+    case03_partial_pickups: ("my_vehicle_1", vec!["job5"],
+        "cannot re-plan partially completed job 'job5': only completed pickups are supported"),
+}
+
+fn can_detect_invalid_progress_impl(vehicle_id: &str, completed: Vec<&str>, expected: &str) {
+    let mut problem = create_test_problem(vec!["my_vehicle_1"], None);
+    problem.plan.jobs.push(create_multi_job(
+        "job5",
+        vec![((1., 0.), 1., vec![1.]), ((2., 0.), 1., vec![1.])],
+        vec![((3., 0.), 1., vec![2.])],
+    ));
+    let progress = PlanProgress { vehicles: vec![create_vehicle_progress(vehicle_id, 10., completed)] };
+
+    let result = apply_progress(problem, &progress);
+
+    assert_eq!(result.err(), Some(expected.to_string()));
+}
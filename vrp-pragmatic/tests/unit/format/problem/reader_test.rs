@@ -69,14 +69,20 @@ fn can_read_complex_problem() {
                             ]),
                             location: vec![52.48325, 13.4436].to_loc(),
                             duration: 100.0,
+                            vehicle_times: None,
                         }],
-                        demand: Some(vec![0, 1]),
+                        demand: Some(vec![0., 1.]),
                         tag: Some("my_delivery".to_string()),
+                        named_demand: None,
                     }]),
                     replacements: None,
                     services: None,
                     priority: None,
+                    value: None,
                     skills: Some(vec!["unique".to_string()]),
+                    order: None,
+                    split: None,
+                    goods: None,
                 },
                 Job {
                     id: "pickup_delivery_job".to_string(),
@@ -88,9 +94,11 @@ fn can_read_complex_problem() {
                             ]]),
                             location: vec![52.48300, 13.4420].to_loc(),
                             duration: 110.0,
+                            vehicle_times: None,
                         }],
-                        demand: Some(vec![2]),
+                        demand: Some(vec![2.]),
                         tag: None,
+                        named_demand: None,
                     }]),
                     deliveries: Some(vec![JobTask {
                         places: vec![JobPlace {
@@ -100,14 +108,20 @@ fn can_read_complex_problem() {
                             ]]),
                             location: vec![52.48325, 13.4436].to_loc(),
                             duration: 120.0,
+                            vehicle_times: None,
                         }],
-                        demand: Some(vec![2]),
+                        demand: Some(vec![2.]),
                         tag: None,
+                        named_demand: None,
                     }]),
                     replacements: None,
                     services: None,
                     priority: None,
+                    value: None,
                     skills: None,
+                    order: None,
+                    split: None,
+                    goods: None,
                 },
                 Job {
                     id: "pickup_job".to_string(),
@@ -120,15 +134,21 @@ fn can_read_complex_problem() {
                             ]]),
                             location: vec![52.48321, 13.4438].to_loc(),
                             duration: 90.0,
+                            vehicle_times: None,
                         }],
-                        demand: Some(vec![3]),
+                        demand: Some(vec![3.]),
                         tag: None,
+                        named_demand: None,
                     }]),
                     deliveries: None,
                     replacements: None,
                     services: None,
                     priority: None,
+                    value: None,
                     skills: Some(vec!["unique2".to_string()]),
+                    order: None,
+                    split: None,
+                    goods: None,
                 },
             ],
             relations: Option::None,
@@ -161,11 +181,23 @@ fn can_read_complex_problem() {
                     }]),
                     reloads: None,
                 }],
-                capacity: vec![10, 1],
+                capacity: vec![10., 1.],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
-                limits: Some(VehicleLimits { max_distance: Some(123.1), shift_time: Some(100.), allowed_areas: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(123.1),
+                    shift_time: Some(100.),
+                    allowed_areas: None,
+                    forbidden_areas: None,
+                    area_costs: None,
+                    max_jobs: None,
+                    max_stops: None,
+                }),
+                compartments: None,
+                named_capacity: None,
+                battery: None,
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         objectives: None,
         config: None,
@@ -293,6 +325,7 @@ fn can_create_approximation_matrices() {
                 FormatProfile { name: "car3".to_string(), profile_type: "car".to_string(), speed: Some(5.) },
                 FormatProfile { name: "car4".to_string(), profile_type: "car".to_string(), speed: None },
             ],
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -310,3 +343,105 @@ fn can_create_approximation_matrices() {
         assert_eq!(matrix.travel_times, &[0, duration, duration, 0]);
     }
 }
+
+parameterized_test! {can_get_load_scale, (demand, capacity, expected), {
+    can_get_load_scale_impl(demand, capacity, expected);
+}}
+
+can_get_load_scale! {
+    case01: (vec![1., 2.], vec![10.], 1.),
+    case02: (vec![1.5, 2.], vec![10.], 10.),
+    case03: (vec![1., 2.], vec![10.25], 100.),
+    case04: (vec![12.7, 0.125], vec![10.], 1000.),
+    case05: (vec![0.00001], vec![10.], 1000.),
+}
+
+fn can_get_load_scale_impl(demand: Vec<f64>, capacity: Vec<f64>, expected: f64) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: demand
+                .into_iter()
+                .enumerate()
+                .map(|(idx, demand)| {
+                    create_delivery_job_with_demand(&format!("job{}", idx), vec![1., 0.], vec![demand])
+                })
+                .collect(),
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", capacity)],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+
+    assert_eq!(get_load_scale(&problem), expected);
+}
+
+parameterized_test! {can_limit_job_neighbours_with_options, (full_job_index_threshold, expected), {
+    can_limit_job_neighbours_with_options_impl(full_job_index_threshold, expected);
+}}
+
+can_limit_job_neighbours_with_options! {
+    case01: (Some(2), 1),
+    case02: (Some(3), 2),
+    case03: (None, 2),
+}
+
+fn can_limit_job_neighbours_with_options_impl(full_job_index_threshold: Option<usize>, expected: usize) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+    let options = ReaderOptions { full_job_index_threshold, max_job_neighbours: 1, ..ReaderOptions::default() };
+
+    let problem = problem.read_pragmatic_with_options(&options).ok().unwrap();
+
+    let job = get_job(0, problem.jobs.as_ref());
+    assert_eq!(problem.jobs.neighbors(0, &job, 0.).count(), expected);
+}
+
+parameterized_test! {can_size_split_job_parts_by_smallest_compatible_capacity, (capacities, expected), {
+    can_size_split_job_parts_by_smallest_compatible_capacity_impl(capacities, expected);
+}}
+
+can_size_split_job_parts_by_smallest_compatible_capacity! {
+    case01: (vec![vec![10.]], Some(3)),
+    case02: (vec![vec![10.], vec![5.]], Some(5)),
+    case03: (vec![vec![10.], vec![0.]], Some(3)),
+    case04: (vec![vec![30.]], None),
+}
+
+fn can_size_split_job_parts_by_smallest_compatible_capacity_impl(capacities: Vec<Vec<f64>>, expected: Option<i32>) {
+    let job = Job { split: Some(true), ..create_delivery_job_with_demand("job1", vec![1., 0.], vec![25.]) };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job.clone()], relations: None },
+        fleet: Fleet {
+            vehicles: capacities
+                .into_iter()
+                .enumerate()
+                .map(|(idx, capacity)| create_vehicle_with_capacity(format!("v{}", idx).as_str(), capacity))
+                .collect(),
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = get_split_task(&problem, &job).map(|(_, _, parts)| parts);
+
+    assert_eq!(result, expected);
+}
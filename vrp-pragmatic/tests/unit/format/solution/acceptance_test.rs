@@ -0,0 +1,113 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+use std::io::{BufReader, BufWriter};
+use std::sync::Arc;
+
+fn create_test_problem(jobs: Vec<Job>) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: None },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn evaluate_new_jobs(new_jobs: Vec<Job>, job_ids: Vec<&str>) -> Result<Vec<JobAcceptance>, String> {
+    let jobs = vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])];
+    let problem = create_test_problem(jobs.clone());
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let problem = create_test_problem(jobs.into_iter().chain(new_jobs.into_iter()).collect());
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new(
+        (problem, vec![matrix]).read_pragmatic().unwrap_or_else(|err| panic!("cannot read core problem: {:?}", err)),
+    );
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    serialize_solution(writer, &solution).expect("cannot serialize test solution");
+    let core_solution = read_init_solution(BufReader::new(buffer.as_bytes()), core_problem.clone())?;
+
+    evaluate_job_acceptance(core_problem, core_solution, to_strings(job_ids).as_slice())
+}
+
+#[test]
+fn can_evaluate_feasible_insertion() {
+    let result = evaluate_new_jobs(vec![create_delivery_job("job3", vec![3., 0.])], vec!["job3"])
+        .expect("cannot evaluate acceptance");
+
+    assert_eq!(
+        result,
+        vec![JobAcceptance {
+            job_id: "job3".to_string(),
+            feasible: true,
+            insertion: Some(JobInsertion {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                cost: 3.,
+                activities: vec![InsertedActivity {
+                    activity_type: "delivery".to_string(),
+                    tag: None,
+                    index: 3,
+                    location: vec![3., 0.].to_loc(),
+                    arrival: format_time(5.),
+                }],
+            }),
+            reasons: None,
+        }]
+    );
+}
+
+#[test]
+fn can_evaluate_infeasible_insertion() {
+    let result =
+        evaluate_new_jobs(vec![create_delivery_job_with_demand("job3", vec![3., 0.], vec![11.])], vec!["job3"])
+            .expect("cannot evaluate acceptance");
+
+    assert_eq!(result.len(), 1);
+    assert!(!result[0].feasible);
+    assert!(result[0].insertion.is_none());
+    assert_eq!(
+        result[0].reasons,
+        Some(vec![UnassignedJobReason {
+            code: 3,
+            description: "does not fit into any vehicle due to capacity".to_string()
+        }])
+    );
+}
+
+#[test]
+fn can_evaluate_multiple_jobs_independently() {
+    let new_jobs = vec![create_delivery_job("job3", vec![3., 0.]), create_delivery_job("job4", vec![3., 0.])];
+
+    let result = evaluate_new_jobs(new_jobs, vec!["job3", "job4"]).expect("cannot evaluate acceptance");
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].insertion.as_ref().map(|insertion| insertion.cost), Some(3.));
+    assert_eq!(result[1].insertion.as_ref().map(|insertion| insertion.cost), Some(3.));
+}
+
+parameterized_test! {can_detect_invalid_job_ids, (job_id, expected), {
+    can_detect_invalid_job_ids_impl(job_id, expected);
+}}
+
+can_detect_invalid_job_ids! {
+    case01_unknown: ("job5", "cannot find job with id 'job5'"),
+    case02_assigned: ("job1", "job 'job1' is already assigned"),
+}
+
+fn can_detect_invalid_job_ids_impl(job_id: &str, expected: &str) {
+    let result = evaluate_new_jobs(vec![], vec![job_id]);
+
+    assert_eq!(result.err(), Some(expected.to_string()));
+}
@@ -0,0 +1,193 @@
+use super::*;
+use crate::format::problem::*;
+use crate::format::solution::{Statistic, Timing};
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job_with_times("job2", vec![2., 0.], vec![(0, 10)], 1.),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_solution() -> Solution {
+    Solution {
+        tours: vec![
+            Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        2.,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0,
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        1.,
+                        ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                        1,
+                    ),
+                    create_stop_with_activity(
+                        "job2",
+                        "delivery",
+                        (2., 0.),
+                        0.,
+                        ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
+                        2,
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0.,
+                        ("1970-01-01T00:00:06Z", "1970-01-01T00:00:06Z"),
+                        4,
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 20.,
+                    distance: 4,
+                    duration: 6,
+                    times: Timing { driving: 4, serving: 2, waiting: 0, break_time: 0 },
+                    value: None,
+                },
+            },
+            Tour {
+                vehicle_id: "my_vehicle_2".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1.,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0,
+                    ),
+                    create_stop_with_activity(
+                        "job3",
+                        "delivery",
+                        (3., 0.),
+                        0.,
+                        ("1970-01-01T00:00:05Z", "1970-01-01T00:00:06Z"),
+                        3,
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0.,
+                        ("1970-01-01T00:00:09Z", "1970-01-01T00:00:09Z"),
+                        6,
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 22.,
+                    distance: 6,
+                    duration: 9,
+                    times: Timing { driving: 6, serving: 1, waiting: 2, break_time: 0 },
+                    value: None,
+                },
+            },
+        ],
+        ..create_empty_solution()
+    }
+}
+
+#[test]
+fn can_analyze_tours() {
+    let analytics = analyze_solution(&create_test_problem(), &create_test_solution()).expect("cannot analyze");
+
+    assert_eq!(analytics.dimensions, vec!["dimension_0".to_string()]);
+    assert_eq!(analytics.tours.len(), 2);
+
+    let tour = &analytics.tours[0];
+    assert_eq!(tour.jobs, 2);
+    assert_eq!(tour.utilization, vec![0.2]);
+    assert_eq!(tour.waiting_ratio, 0.);
+    assert_eq!(tour.idle_ratio, 0.);
+    assert_eq!(tour.empty_distance_ratio, 0.5);
+    assert_eq!(tour.jobs_per_hour, 1200.);
+    assert_eq!(
+        tour.stops.iter().map(|stop| stop.time_window_slack).collect::<Vec<_>>(),
+        vec![None, None, Some(7), None]
+    );
+
+    let tour = &analytics.tours[1];
+    assert_eq!(tour.jobs, 1);
+    assert_eq!(tour.waiting_ratio, 2. / 9.);
+    assert_eq!(tour.idle_ratio, 2. / 9.);
+    assert_eq!(tour.empty_distance_ratio, 0.5);
+}
+
+#[test]
+fn can_calculate_balance() {
+    let analytics = analyze_solution(&create_test_problem(), &create_test_solution()).expect("cannot analyze");
+
+    assert_eq!(analytics.balance.jobs.mean, 1.5);
+    assert!((analytics.balance.jobs.cv - 0.4714).abs() < 0.0001);
+    assert_eq!(analytics.balance.distance.mean, 5.);
+    assert!((analytics.balance.utilization.mean - 0.15).abs() < 1E-9);
+}
+
+#[test]
+fn can_return_error_for_unknown_vehicle_type() {
+    let mut solution = create_test_solution();
+    solution.tours[0].type_id = "unknown".to_string();
+
+    let result = analyze_solution(&create_test_problem(), &solution);
+
+    assert_eq!(result, Err("cannot find vehicle type 'unknown'".to_string()));
+}
+
+#[test]
+fn can_return_error_for_invalid_time() {
+    let mut solution = create_test_solution();
+    solution.tours[0].stops[1].time.arrival = "invalid".to_string();
+
+    let result = analyze_solution(&create_test_problem(), &solution);
+
+    assert!(result.err().map_or(false, |err| err.starts_with("cannot parse time 'invalid'")));
+}
+
+#[test]
+fn can_write_analytics_as_csv() {
+    let analytics = analyze_solution(&create_test_problem(), &create_test_solution()).expect("cannot analyze");
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    serialize_analytics_as_csv(writer, &analytics).expect("cannot write csv");
+
+    assert_eq!(
+        buffer.lines().collect::<Vec<_>>(),
+        vec![
+            "vehicle_id,shift_index,jobs,distance,duration,utilization_dimension_0,waiting_ratio,idle_ratio,\
+             empty_distance_ratio,jobs_per_hour,min_time_window_slack",
+            "my_vehicle_1,0,2,4,6,0.2000,0.0000,0.0000,0.5000,1200.0000,7",
+            "my_vehicle_2,0,1,6,9,0.1000,0.2222,0.2222,0.5000,400.0000,",
+        ]
+    );
+}
@@ -0,0 +1,74 @@
+use super::*;
+use crate::format::solution::Interval;
+use crate::helpers::*;
+
+fn get_csv(solution: &Solution) -> Vec<String> {
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    serialize_solution_as_csv(writer, solution).expect("cannot serialize solution as csv");
+
+    buffer.lines().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn can_write_one_row_per_activity() {
+    let mut stop = create_stop_with_activity(
+        "job1",
+        "delivery",
+        (1., 0.),
+        1.,
+        ("1970-01-01T00:00:01Z", "1970-01-01T00:00:03Z"),
+        1,
+    );
+    stop.activities.push(Activity {
+        job_id: "job,2".to_string(),
+        activity_type: "pickup".to_string(),
+        location: Some(Location::new_reference(3)),
+        time: Some(Interval { start: "1970-01-01T00:00:02Z".to_string(), end: "1970-01-01T00:00:03Z".to_string() }),
+        job_tag: Some("tag".to_string()),
+        demand: None,
+    });
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1.,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                stop,
+            ],
+            statistic: Default::default(),
+        }],
+        ..create_empty_solution()
+    };
+
+    let rows = get_csv(&solution);
+
+    assert_eq!(
+        rows,
+        vec![
+            CSV_HEADER.to_string(),
+            "my_vehicle_1,my_vehicle,0,0,departure,departure,,0,0,,1970-01-01T00:00:00Z,1970-01-01T00:00:00Z,,,1,0"
+                .to_string(),
+            "my_vehicle_1,my_vehicle,0,1,job1,delivery,,1,0,,1970-01-01T00:00:01Z,1970-01-01T00:00:03Z,,,1,1"
+                .to_string(),
+            "my_vehicle_1,my_vehicle,0,1,\"job,2\",pickup,tag,,,3,1970-01-01T00:00:01Z,1970-01-01T00:00:03Z,\
+             1970-01-01T00:00:02Z,1970-01-01T00:00:03Z,1,1"
+                .to_string(),
+        ]
+    );
+}
+
+#[test]
+fn can_write_only_header_for_empty_solution() {
+    let rows = get_csv(&create_empty_solution());
+
+    assert_eq!(rows, vec![CSV_HEADER.to_string()]);
+}
@@ -0,0 +1,109 @@
+use super::*;
+use crate::format::solution::UnassignedJob;
+use crate::helpers::*;
+
+fn create_tour(vehicle_id: &str, jobs: Vec<&str>, cost: f64, distance: i64) -> Tour {
+    let time = ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z");
+    let stops = std::iter::once(create_stop_with_activity("departure", "departure", (0., 0.), 0., time, 0))
+        .chain(
+            jobs.into_iter()
+                .enumerate()
+                .map(|(idx, job_id)| create_stop_with_activity(job_id, "delivery", (idx as f64, 0.), 0., time, 0)),
+        )
+        .collect();
+
+    Tour {
+        vehicle_id: vehicle_id.to_string(),
+        type_id: "my_vehicle".to_string(),
+        stops,
+        statistic: Statistic { cost, distance, ..Statistic::default() },
+        ..create_empty_tour()
+    }
+}
+
+fn create_solution(tours: Vec<Tour>, unassigned: Vec<&str>) -> Solution {
+    let cost = tours.iter().map(|tour| tour.statistic.cost).sum();
+    let distance = tours.iter().map(|tour| tour.statistic.distance).sum();
+
+    Solution {
+        statistic: Statistic { cost, distance, ..Statistic::default() },
+        tours,
+        unassigned: if unassigned.is_empty() {
+            None
+        } else {
+            Some(
+                unassigned
+                    .into_iter()
+                    .map(|job_id| UnassignedJob { job_id: job_id.to_string(), reasons: vec![], details: None })
+                    .collect(),
+            )
+        },
+        ..create_empty_solution()
+    }
+}
+
+#[test]
+fn can_detect_no_changes() {
+    let solution = create_solution(vec![create_tour("v1", vec!["job1", "job2"], 10., 5)], vec!["job3"]);
+
+    let diff = compare_solutions(&solution, &solution.clone());
+
+    assert!(diff.is_empty());
+    assert!(diff.tours.is_empty());
+    assert_eq!(diff.to_string().lines().last(), Some("solutions are the same"));
+}
+
+#[test]
+fn can_detect_reordered_jobs() {
+    let original = create_solution(vec![create_tour("v1", vec!["job1", "job2", "job3"], 10., 5)], vec![]);
+    let new = create_solution(vec![create_tour("v1", vec!["job2", "job1", "job3"], 12., 6)], vec![]);
+
+    let diff = compare_solutions(&original, &new);
+
+    assert_eq!(diff.tours.len(), 1);
+    assert_eq!(diff.tours[0].status, TourStatus::Changed);
+    assert!(diff.tours[0].reordered);
+    assert!(diff.tours[0].added_jobs.is_empty());
+    assert!(diff.tours[0].removed_jobs.is_empty());
+    assert_eq!(diff.statistic.cost, ValueChange { before: 10., after: 12., delta: 2. });
+    assert_eq!(diff.statistic.distance, ValueChange { before: 5, after: 6, delta: 1 });
+    assert!(diff.moved_jobs.is_empty());
+}
+
+#[test]
+fn can_detect_moved_and_unassigned_jobs() {
+    let original = create_solution(
+        vec![create_tour("v1", vec!["job1", "job2"], 10., 5), create_tour("v2", vec!["job3"], 5., 2)],
+        vec!["job4"],
+    );
+    let new = create_solution(
+        vec![create_tour("v1", vec!["job1", "job4"], 9., 5), create_tour("v3", vec!["job2"], 4., 2)],
+        vec!["job3"],
+    );
+
+    let diff = compare_solutions(&original, &new);
+
+    assert_eq!(
+        diff.tours.iter().map(|tour| (tour.vehicle_id.as_str(), tour.status.clone())).collect::<Vec<_>>(),
+        vec![("v1", TourStatus::Changed), ("v2", TourStatus::Removed), ("v3", TourStatus::Added)]
+    );
+    assert_eq!(diff.tours[0].added_jobs, vec!["job4".to_string()]);
+    assert_eq!(diff.tours[0].removed_jobs, vec!["job2".to_string()]);
+    assert!(!diff.tours[0].reordered);
+    assert_eq!(diff.tours[1].statistic.cost, ValueChange { before: 5., after: 0., delta: -5. });
+    assert_eq!(
+        diff.moved_jobs,
+        vec![JobMove {
+            job_id: "job2".to_string(),
+            from: vec![TourRef { vehicle_id: "v1".to_string(), shift_index: 0 }],
+            to: vec![TourRef { vehicle_id: "v3".to_string(), shift_index: 0 }],
+        }]
+    );
+    assert_eq!(diff.newly_unassigned, vec!["job3".to_string()]);
+    assert_eq!(diff.newly_assigned, vec!["job4".to_string()]);
+
+    let text = diff.to_string();
+    assert!(text.contains("job2: v1 (shift 0) -> v3 (shift 0)"));
+    assert!(text.contains("newly unassigned jobs: job3"));
+    assert!(text.contains("total cost: 15.00 -> 13.00 (-2.00)"));
+}
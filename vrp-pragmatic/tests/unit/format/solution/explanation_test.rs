@@ -0,0 +1,153 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use std::io::{BufReader, BufWriter};
+use std::sync::Arc;
+
+fn create_reason(code: i32, description: &str) -> UnassignedJobReason {
+    UnassignedJobReason { code, description: description.to_string() }
+}
+
+fn get_explained_unassigned_jobs(problem: Problem) -> Vec<UnassignedJob> {
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix.clone()]));
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().expect("cannot read core problem"));
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    serialize_solution(writer, &solution).expect("cannot serialize test solution");
+    let core_solution =
+        read_init_solution(BufReader::new(buffer.as_bytes()), core_problem.clone()).expect("cannot read core solution");
+
+    let solution = explain_unassigned_jobs(core_problem, core_solution, solution);
+
+    solution.unassigned.expect("no unassigned jobs")
+}
+
+#[test]
+fn can_explain_unassigned_job_per_vehicle() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                Job {
+                    skills: Some(vec!["fridge".to_string()]),
+                    ..create_delivery_job_with_demand("job2", vec![2., 0.], vec![5.])
+                },
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    type_id: "small".to_string(),
+                    vehicle_ids: vec!["small_1".to_string()],
+                    skills: Some(vec!["fridge".to_string()]),
+                    ..create_vehicle_with_capacity("small", vec![2.])
+                },
+                VehicleType {
+                    type_id: "big".to_string(),
+                    vehicle_ids: vec!["big_1".to_string()],
+                    ..create_vehicle_with_capacity("big", vec![10.])
+                },
+            ],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let unassigned = get_explained_unassigned_jobs(problem);
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job2");
+    assert_eq!(
+        unassigned[0].details,
+        Some(vec![
+            UnassignedJobDetail {
+                vehicle_id: "big_1".to_string(),
+                shift_index: 0,
+                reasons: vec![create_reason(1, "cannot serve required skill")],
+            },
+            UnassignedJobDetail {
+                vehicle_id: "small_1".to_string(),
+                shift_index: 0,
+                reasons: vec![create_reason(3, "does not fit into any vehicle due to capacity")],
+            },
+        ])
+    );
+}
+
+#[test]
+fn can_explain_all_activity_violations() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job_with_times("job2", vec![10., 0.], vec![(0, 1)], 1.),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: Some(5.),
+                    shift_time: None,
+                    allowed_areas: None,
+                    forbidden_areas: None,
+                    area_costs: None,
+                    max_jobs: None,
+                    max_stops: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let unassigned = get_explained_unassigned_jobs(problem);
+
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job2");
+    let mut codes = unassigned[0].details.as_ref().expect("no details")[0]
+        .reasons
+        .iter()
+        .map(|reason| reason.code)
+        .collect::<Vec<_>>();
+    codes.sort();
+    assert_eq!(codes, vec![2, 101]);
+}
+
+#[test]
+fn can_explain_unassigned_split_job() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                split: Some(true),
+                skills: Some(vec!["fridge".to_string()]),
+                ..create_delivery_job_with_demand("job1", vec![1., 0.], vec![4.])
+            }],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2.])],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let unassigned = get_explained_unassigned_jobs(problem);
+
+    assert!(!unassigned.is_empty());
+    assert!(unassigned.iter().all(|job| job.job_id == "job1"));
+    assert_eq!(
+        unassigned[0].details,
+        Some(vec![UnassignedJobDetail {
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: 0,
+            reasons: vec![create_reason(1, "cannot serve required skill")],
+        }])
+    );
+}
@@ -14,8 +14,8 @@ fn create_basic_problem(breaks: Option<Vec<VehicleBreak>>) -> Problem {
                 create_delivery_job("job1", vec![1., 0.]),
                 create_multi_job(
                     "job2",
-                    vec![((2., 0.), 1., vec![1]), ((3., 0.), 1., vec![1])],
-                    vec![((4., 0.), 1., vec![2])],
+                    vec![((2., 0.), 1., vec![1.]), ((3., 0.), 1., vec![1.])],
+                    vec![((4., 0.), 1., vec![2.])],
                 ),
                 create_pickup_job("job3", vec![5., 0.]),
             ],
@@ -28,6 +28,7 @@ fn create_basic_problem(breaks: Option<Vec<VehicleBreak>>) -> Problem {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     }
@@ -48,6 +49,7 @@ fn create_unassigned_jobs(job_ids: &[&str]) -> Option<Vec<UnassignedJob>> {
             .map(|job_id| UnassignedJob {
                 job_id: job_id.to_string(),
                 reasons: vec![UnassignedJobReason { code: 0, description: "unknown".to_string() }],
+                details: None,
             })
             .collect(),
     )
@@ -89,6 +91,7 @@ fn can_read_basic_init_solution() {
             distance: 8,
             duration: 14,
             times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 2 },
+            value: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -99,7 +102,7 @@ fn can_read_basic_init_solution() {
                     "departure",
                     "departure",
                     (0., 0.),
-                    1,
+                    1.,
                     ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                     0,
                 ),
@@ -107,7 +110,7 @@ fn can_read_basic_init_solution() {
                     "job1",
                     "delivery",
                     (1., 0.),
-                    0,
+                    0.,
                     ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
                     1,
                 ),
@@ -115,7 +118,7 @@ fn can_read_basic_init_solution() {
                     "job2",
                     "pickup",
                     (2., 0.),
-                    1,
+                    1.,
                     ("1970-01-01T00:00:03Z", "1970-01-01T00:00:04Z"),
                     2,
                     "p1",
@@ -127,7 +130,7 @@ fn can_read_basic_init_solution() {
                         departure: "1970-01-01T00:00:08Z".to_string(),
                     },
                     distance: 3,
-                    load: vec![2],
+                    load: vec![2.],
                     activities: vec![
                         Activity {
                             job_id: "job2".to_string(),
@@ -138,6 +141,7 @@ fn can_read_basic_init_solution() {
                                 end: "1970-01-01T00:00:06Z".to_string(),
                             }),
                             job_tag: Some("p2".to_owned()),
+                            demand: None,
                         },
                         Activity {
                             job_id: "break".to_string(),
@@ -148,14 +152,16 @@ fn can_read_basic_init_solution() {
                                 end: "1970-01-01T00:00:08Z".to_string(),
                             }),
                             job_tag: None,
+                            demand: None,
                         },
                     ],
+                    named_load: None,
                 },
                 create_stop_with_activity_with_tag(
                     "job2",
                     "delivery",
                     (4., 0.),
-                    0,
+                    0.,
                     ("1970-01-01T00:00:09Z", "1970-01-01T00:00:10Z"),
                     4,
                     "d1",
@@ -164,7 +170,7 @@ fn can_read_basic_init_solution() {
                     "arrival",
                     "arrival",
                     (0., 0.),
-                    0,
+                    0.,
                     ("1970-01-01T00:00:14Z", "1970-01-01T00:00:14Z"),
                     8,
                 ),
@@ -174,6 +180,7 @@ fn can_read_basic_init_solution() {
                 distance: 8,
                 duration: 14,
                 times: Timing { driving: 8, serving: 4, waiting: 0, break_time: 2 },
+                value: None,
             },
         }],
         unassigned: create_unassigned_jobs(&["job3"]),
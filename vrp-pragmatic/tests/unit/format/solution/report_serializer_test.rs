@@ -0,0 +1,145 @@
+use super::*;
+use crate::format::problem::*;
+use crate::format::solution::{UnassignedJob, UnassignedJobReason};
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 1000)], 60.),
+                create_delivery_job_with_times("job2", vec![2., 0.], vec![(300, 1000)], 60.),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_solution() -> Solution {
+    Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    2.,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    1.,
+                    ("1970-01-01T00:01:00Z", "1970-01-01T00:02:00Z"),
+                    1,
+                ),
+                create_stop_with_activity(
+                    "job2",
+                    "delivery",
+                    (2., 0.),
+                    0.,
+                    ("1970-01-01T00:03:00Z", "1970-01-01T00:06:00Z"),
+                    2,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0.,
+                    ("1970-01-01T00:08:00Z", "1970-01-01T00:08:00Z"),
+                    4,
+                ),
+            ],
+            statistic: Default::default(),
+        }],
+        unassigned: Some(vec![UnassignedJob {
+            job_id: "job3".to_string(),
+            reasons: vec![UnassignedJobReason { code: 3, description: "does not fit".to_string() }],
+            details: None,
+        }]),
+        ..create_empty_solution()
+    }
+}
+
+fn write_report(problem: &Problem, solution: &Solution, is_html: bool) -> String {
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+
+    if is_html {
+        serialize_solution_as_html(writer, problem, solution).expect("cannot write html");
+    } else {
+        serialize_solution_as_svg(writer, problem, solution).expect("cannot write svg");
+    }
+
+    buffer
+}
+
+#[test]
+fn can_write_solution_as_svg() {
+    let svg = write_report(&create_test_problem(), &create_test_solution(), false);
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.ends_with("</svg>\n"));
+    assert_eq!(svg.matches("<polyline").count(), 1);
+    assert_eq!(svg.matches("width=\"12\" height=\"12\"").count(), 2);
+    assert!(svg.contains("<title>unassigned: job3</title>"));
+    assert_eq!(svg.matches("<title>driving</title>").count(), 3);
+    assert_eq!(svg.matches("<title>waiting: ").count(), 1);
+    assert!(svg.contains("<title>waiting: 2 min</title>"));
+    assert!(svg.contains("<title>job2 (delivery)&#10;1970-01-01T00:05:00Z - 1970-01-01T00:06:00Z</title>"));
+}
+
+#[test]
+fn can_write_solution_as_html() {
+    let html = write_report(&create_test_problem(), &create_test_solution(), true);
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert_eq!(html.matches("<svg ").count(), 2);
+    assert!(html.contains("<h2>Unassigned jobs</h2>"));
+    assert!(html.contains("<tr><td>job3</td><td>does not fit</td></tr>"));
+}
+
+#[test]
+fn can_skip_map_for_location_indices() {
+    let mut solution = create_test_solution();
+    solution.tours[0].stops[1].location = Location::Reference { index: 1 };
+
+    let svg = write_report(&create_test_problem(), &solution, false);
+
+    assert!(svg.contains("map cannot be used with location indices"));
+    assert!(!svg.contains("<polyline"));
+    assert!(svg.contains("<title>waiting: 2 min</title>"));
+}
+
+#[test]
+fn can_return_error_for_invalid_time() {
+    let mut solution = create_test_solution();
+    solution.tours[0].stops[1].time.arrival = "invalid".to_string();
+
+    [true, false].iter().for_each(|is_html| {
+        let mut buffer = String::new();
+        let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+        let problem = create_test_problem();
+
+        let result = if *is_html {
+            serialize_solution_as_html(writer, &problem, &solution)
+        } else {
+            serialize_solution_as_svg(writer, &problem, &solution)
+        };
+
+        let error = result.err().expect("should return error");
+        assert!(error.to_string().starts_with("cannot parse time 'invalid'"));
+    });
+}
@@ -9,7 +9,11 @@ fn can_create_solution() {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -24,6 +28,7 @@ fn can_create_solution() {
                 distance: 20,
                 duration: 22,
                 times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -34,7 +39,7 @@ fn can_create_solution() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -42,7 +47,7 @@ fn can_create_solution() {
                         "job2",
                         "delivery",
                         (10., 0.),
-                        1,
+                        1.,
                         ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
                         10
                     ),
@@ -50,7 +55,7 @@ fn can_create_solution() {
                         "job1",
                         "delivery",
                         (5., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:16Z", "1970-01-01T00:00:17Z"),
                         15
                     ),
@@ -58,7 +63,7 @@ fn can_create_solution() {
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:22Z", "1970-01-01T00:00:22Z"),
                         20
                     )
@@ -68,6 +73,7 @@ fn can_create_solution() {
                     distance: 20,
                     duration: 22,
                     times: Timing { driving: 20, serving: 2, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
@@ -82,7 +88,11 @@ fn can_merge_activities_in_one_stop() {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![5., 0.])],
             relations: Option::None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -97,6 +107,7 @@ fn can_merge_activities_in_one_stop() {
                 distance: 10,
                 duration: 12,
                 times: Timing { driving: 10, serving: 2, waiting: 0, break_time: 0 },
+                value: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -107,7 +118,7 @@ fn can_merge_activities_in_one_stop() {
                         "departure",
                         "departure",
                         (0., 0.),
-                        2,
+                        2.,
                         ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
                         0
                     ),
@@ -118,7 +129,7 @@ fn can_merge_activities_in_one_stop() {
                             departure: "1970-01-01T00:00:07Z".to_string()
                         },
                         distance: 5,
-                        load: vec![0],
+                        load: vec![0.],
                         activities: vec![
                             Activity {
                                 job_id: "job2".to_string(),
@@ -128,7 +139,8 @@ fn can_merge_activities_in_one_stop() {
                                     start: "1970-01-01T00:00:05Z".to_string(),
                                     end: "1970-01-01T00:00:06Z".to_string()
                                 }),
-                                job_tag: None
+                                job_tag: None,
+                                demand: None,
                             },
                             Activity {
                                 job_id: "job1".to_string(),
@@ -138,15 +150,17 @@ fn can_merge_activities_in_one_stop() {
                                     start: "1970-01-01T00:00:06Z".to_string(),
                                     end: "1970-01-01T00:00:07Z".to_string()
                                 }),
-                                job_tag: None
+                                job_tag: None,
+                                demand: None,
                             }
-                        ]
+                        ],
+                        named_load: None,
                     },
                     create_stop_with_activity(
                         "arrival",
                         "arrival",
                         (0., 0.),
-                        0,
+                        0.,
                         ("1970-01-01T00:00:12Z", "1970-01-01T00:00:12Z"),
                         10
                     )
@@ -156,9 +170,26 @@ fn can_merge_activities_in_one_stop() {
                     distance: 10,
                     duration: 12,
                     times: Timing { driving: 10, serving: 2, waiting: 0, break_time: 0 },
+                    value: None,
                 },
             }],
             ..create_empty_solution()
         }
     );
 }
+
+#[test]
+fn can_write_load_without_fractional_part_as_integer() {
+    let stop = create_stop_with_activity_md(
+        "job1",
+        "delivery",
+        (1., 0.),
+        vec![1., 2.5],
+        ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+        1,
+    );
+
+    let json = serde_json::to_string(&stop).expect("cannot serialize stop");
+
+    assert!(json.contains(r#""load":[1,2.5]"#));
+}
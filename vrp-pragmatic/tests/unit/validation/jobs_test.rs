@@ -22,7 +22,7 @@ can_detect_reserved_ids! {
 fn can_detect_reserved_ids_impl(job_id: String, expected: Option<&str>) {
     let problem = Problem {
         plan: Plan { jobs: vec![create_delivery_job(job_id.as_str(), vec![1., 0.])], relations: None },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![], dimensions: None },
         ..create_empty_problem()
     };
 
@@ -46,7 +46,11 @@ fn can_detect_empty_job() {
                 replacements: None,
                 services: None,
                 priority: None,
+                value: None,
                 skills: None,
+                order: None,
+                split: None,
+                goods: None,
             }],
             relations: None,
         },
@@ -73,7 +77,10 @@ fn can_detect_negative_duration() {
 #[test]
 fn can_detect_negative_demand() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0, -1])], relations: None },
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![0., -1.])],
+            relations: None,
+        },
         ..create_empty_problem()
     };
 
@@ -81,3 +88,203 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+#[test]
+fn can_detect_negative_value() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                Job { value: Some(-1.), ..create_delivery_job("job2", vec![1., 0.]) },
+            ],
+            relations: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1108_negative_value(&ValidationContext::new(&problem, None)).err();
+
+    assert_result("E1108", "job2", result);
+}
+
+parameterized_test! {can_detect_invalid_vehicle_times, (type_ids, times, expected), {
+    can_detect_invalid_vehicle_times_impl(type_ids, times, expected);
+}}
+
+can_detect_invalid_vehicle_times! {
+    case01: (vec!["vehicle"], vec![(0, 10)], None),
+    case02: (vec![], vec![(0, 10)], Some("job1")),
+    case03: (vec!["unknown"], vec![(0, 10)], Some("job1")),
+    case04: (vec!["vehicle"], vec![(10, 0)], Some("job1")),
+    case05: (vec!["vehicle"], vec![(0, 10), (5, 20)], Some("job1")),
+    case06: (vec!["vehicle"], vec![], Some("job1")),
+}
+
+fn can_detect_invalid_vehicle_times_impl(type_ids: Vec<&str>, times: Vec<(i32, i32)>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_vehicle_times("job1", vec![1., 0.], type_ids, times)],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("vehicle")],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1109_vehicle_times(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1109", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_split_jobs, (job, has_relation, expected), {
+    can_detect_invalid_split_jobs_impl(job, has_relation, expected);
+}}
+
+can_detect_invalid_split_jobs! {
+    case01: (create_delivery_job("job1", vec![1., 0.]), false, None),
+    case02: (create_delivery_job("job1", vec![1., 0.]), true, Some("job1")),
+    case03: (create_pickup_delivery_job("job1", vec![1., 0.], vec![2., 0.]), false, Some("job1")),
+    case04: (Job { deliveries: Some(vec![JobTask { demand: None, ..create_task(vec![1., 0.]) }]), ..create_job("job1") }, false, Some("job1")),
+    case05: (create_service_job("job1", vec![1., 0.]), false, Some("job1")),
+}
+
+fn can_detect_invalid_split_jobs_impl(job: Job, has_relation: bool, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { split: Some(true), ..job }],
+            relations: if has_relation {
+                Some(vec![Relation {
+                    type_field: RelationType::Any,
+                    jobs: vec!["job1".to_string()],
+                    vehicle_id: "vehicle_1".to_string(),
+                    shift_index: None,
+                }])
+            } else {
+                None
+            },
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("vehicle")],
+            profiles: create_default_profiles(),
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1110_split_jobs(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1110", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_split_job_part_id_collision, (job_id, expected), {
+    can_detect_split_job_part_id_collision_impl(job_id, expected);
+}}
+
+can_detect_split_job_part_id_collision! {
+    case01: ("job2", None),
+    case02: ("job1_split_2", Some("job1_split_2")),
+    case03: ("job1_split_part", None),
+    case04: ("job3_split_1", None),
+}
+
+fn can_detect_split_job_part_id_collision_impl(job_id: &str, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { split: Some(true), ..create_delivery_job("job1", vec![1., 0.]) },
+                create_delivery_job(job_id, vec![2., 0.]),
+            ],
+            relations: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1110_split_jobs(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1110", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+fn to_named_load(load: Vec<(&str, f64)>) -> Option<HashMap<String, f64>> {
+    Some(load.into_iter().map(|(name, value)| (name.to_string(), value)).collect())
+}
+
+parameterized_test! {can_detect_invalid_named_demand, (dimensions, demand, named_demand, expected), {
+    can_detect_invalid_named_demand_impl(dimensions, demand, named_demand, expected);
+}}
+
+can_detect_invalid_named_demand! {
+    case01: (Some(vec!["weight", "volume"]), None, vec![("weight", 1.)], None),
+    case02: (None, None, vec![("weight", 1.)], Some("job1")),
+    case03: (Some(vec!["weight"]), None, vec![("volume", 1.)], Some("job1")),
+    case04: (Some(vec!["weight", "volume"]), Some(vec![1., 0.]), vec![("weight", 1.)], None),
+    case05: (Some(vec!["weight", "volume"]), Some(vec![2., 0.]), vec![("weight", 1.)], Some("job1")),
+}
+
+fn can_detect_invalid_named_demand_impl(
+    dimensions: Option<Vec<&str>>,
+    demand: Option<Vec<f64>>,
+    named_demand: Vec<(&str, f64)>,
+    expected: Option<&str>,
+) {
+    let task = JobTask { demand, named_demand: to_named_load(named_demand), ..create_task(vec![1., 0.]) };
+    let problem = apply_named_dimensions(Problem {
+        plan: Plan { jobs: vec![Job { deliveries: Some(vec![task]), ..create_job("job1") }], relations: None },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("vehicle")],
+            profiles: create_default_profiles(),
+            dimensions: dimensions.map(|dimensions| dimensions.into_iter().map(|name| name.to_string()).collect()),
+        },
+        ..create_empty_problem()
+    });
+
+    let result = check_e1111_named_demand(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1111", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_demand_precision, (demand, expected), {
+    can_detect_invalid_demand_precision_impl(demand, expected);
+}}
+
+can_detect_invalid_demand_precision! {
+    case01: (vec![1.], None),
+    case02: (vec![1.125], None),
+    case03: (vec![1.1234], Some("job1")),
+    case04: (vec![3E9], Some("job1")),
+    case05: (vec![3E6, 0.5], None),
+    case06: (vec![3E6, 0.125], Some("job1")),
+}
+
+fn can_detect_invalid_demand_precision_impl(demand: Vec<f64>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", vec![1., 0.], demand)], relations: None },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1112_demand_precision(&ValidationContext::new(&problem, None)).err();
+
+    if let Some(action) = expected {
+        assert_result("E1112", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
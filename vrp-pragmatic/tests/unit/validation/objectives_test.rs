@@ -1,6 +1,6 @@
 use super::*;
 use crate::format::problem::Objective::*;
-use crate::helpers::create_empty_problem;
+use crate::helpers::{create_delivery_job, create_empty_problem};
 
 fn min_cost() -> Objective {
     MinimizeCost
@@ -89,3 +89,28 @@ fn can_detect_missing_cost_objective_impl(objectives: Option<Objectives>, expect
 
     assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1602".to_string()));
 }
+
+parameterized_test! {can_detect_missing_rank_objective, (order, objectives, expected), {
+    can_detect_missing_rank_objective_impl(order, objectives, expected);
+}}
+
+can_detect_missing_rank_objective! {
+    case01: (Some(JobOrder::Rank(1)), vec![min_cost()], Some(())),
+    case02: (Some(JobOrder::Rank(1)), vec![MinimizeRankViolations, min_cost()], None),
+    case03: (Some(JobOrder::Position(JobOrderPosition::First)), vec![min_cost()], None),
+    case04: (None, vec![min_cost()], None),
+}
+
+fn can_detect_missing_rank_objective_impl(order: Option<JobOrder>, primary: Vec<Objective>, expected: Option<()>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { order, ..create_delivery_job("job1", vec![1., 0.]) }], relations: None },
+        objectives: Some(Objectives { primary, secondary: None }),
+        ..create_empty_problem()
+    };
+    let ctx = ValidationContext::new(&problem, None);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1603_no_rank_objective(&ctx, &objectives);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1603".to_string()));
+}
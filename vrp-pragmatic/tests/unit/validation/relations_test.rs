@@ -51,7 +51,7 @@ fn can_detect_relation_errors_impl(
                 shift_index,
             }]),
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![], dimensions: None },
         ..create_empty_problem()
     };
 
@@ -96,7 +96,7 @@ fn can_detect_multi_place_time_window_jobs_impl(relation_type: RelationType, exp
                 shift_index: None,
             }]),
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![], dimensions: None },
         ..create_empty_problem()
     };
 
@@ -142,6 +142,7 @@ fn can_detect_multi_vehicle_assignment_impl(relations: Vec<(&str, &str)>, expect
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("car"), create_default_vehicle("truck")],
             profiles: vec![],
+            dimensions: None,
         },
         ..create_empty_problem()
     };
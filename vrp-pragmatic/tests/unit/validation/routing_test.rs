@@ -11,6 +11,7 @@ fn can_detect_duplicates() {
                 Profile { name: "my_vehicle".to_string(), profile_type: "car".to_string(), speed: None },
                 Profile { name: "my_vehicle".to_string(), profile_type: "truck".to_string(), speed: None },
             ],
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -23,7 +24,8 @@ fn can_detect_duplicates() {
 
 #[test]
 fn can_detect_empty_profiles() {
-    let problem = Problem { fleet: Fleet { vehicles: vec![], profiles: vec![] }, ..create_empty_problem() };
+    let problem =
+        Problem { fleet: Fleet { vehicles: vec![], profiles: vec![], dimensions: None }, ..create_empty_problem() };
     let ctx = ValidationContext::new(&problem, None);
 
     let result = check_e1501_empty_profiles(&ctx);
@@ -77,11 +79,17 @@ fn can_detect_limit_areas_with_indices() {
                             Location::new_coordinate(5., 5.),
                             Location::new_coordinate(-5., 5.),
                         ],
+                        inner_shapes: None,
                     }]),
+                    forbidden_areas: None,
+                    area_costs: None,
+                    max_jobs: None,
+                    max_stops: None,
                 }),
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            dimensions: None,
         },
         ..create_empty_problem()
     };
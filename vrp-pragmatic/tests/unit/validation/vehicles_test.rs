@@ -31,12 +31,66 @@ fn can_detect_invalid_area_impl(allowed_shapes: Option<Vec<Vec<Location>>>, expe
                     max_distance: None,
                     shift_time: None,
                     allowed_areas: allowed_shapes.map(|shapes| {
-                        shapes.into_iter().map(|shape| AreaLimit { priority: None, outer_shape: shape }).collect()
+                        shapes
+                            .into_iter()
+                            .map(|shape| AreaLimit { priority: None, outer_shape: shape, inner_shapes: None })
+                            .collect()
                     }),
+                    forbidden_areas: None,
+                    area_costs: None,
+                    max_jobs: None,
+                    max_stops: None,
                 }),
                 ..create_default_vehicle_type()
             }],
             profiles: vec![],
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1305_vehicle_limit_area_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|_| "E1305".to_string()));
+}
+
+parameterized_test! {can_detect_invalid_forbidden_area_and_cost, (inner_shape, cost, expected), {
+    can_detect_invalid_forbidden_area_and_cost_impl(inner_shape, cost, expected);
+}}
+
+can_detect_invalid_forbidden_area_and_cost! {
+    case01: (None, 1., None),
+    case02: (Some(vec![coord(0.1, 0.1), coord(0.1, 0.2), coord(0.2, 0.2)]), 1., None),
+    case03: (Some(vec![coord(0.1, 0.1), coord(0.1, 0.2)]), 1., Some(())),
+    case04: (None, -1., Some(())),
+}
+
+fn can_detect_invalid_forbidden_area_and_cost_impl(
+    inner_shape: Option<Vec<Location>>,
+    cost: f64,
+    expected: Option<()>,
+) {
+    let outer_shape = vec![coord(0., 0.), coord(0., 1.), coord(1., 1.), coord(1., 0.)];
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    allowed_areas: None,
+                    forbidden_areas: Some(vec![AreaLimit {
+                        priority: None,
+                        outer_shape: outer_shape.clone(),
+                        inner_shapes: inner_shape.map(|shape| vec![shape]),
+                    }]),
+                    area_costs: Some(vec![AreaCost { cost, outer_shape, inner_shapes: None }]),
+                    max_jobs: None,
+                    max_stops: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -82,6 +136,7 @@ fn can_detect_invalid_depots_impl(depots: &[(f64, Option<Vec<Vec<String>>>)], ex
                 ..create_default_vehicle_type()
             }],
             profiles: vec![],
+            dimensions: None,
         },
         ..create_empty_problem()
     };
@@ -90,3 +145,179 @@ fn can_detect_invalid_depots_impl(depots: &[(f64, Option<Vec<Vec<String>>>)], ex
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_compartments, (capacity, goods, expected), {
+    can_detect_invalid_compartments_impl(capacity, goods, expected);
+}}
+
+can_detect_invalid_compartments! {
+    case01: (vec![5.], vec!["goods1"], None),
+    case02: (vec![5.], vec![], Some("E1307".to_string())),
+    case03: (vec![], vec!["goods1"], Some("E1307".to_string())),
+    case04: (vec![5., 1.], vec!["goods1"], Some("E1307".to_string())),
+    case05: (vec![-1.], vec!["goods1"], Some("E1307".to_string())),
+}
+
+fn can_detect_invalid_compartments_impl(capacity: Vec<f64>, goods: Vec<&str>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                compartments: Some(vec![VehicleCompartment {
+                    capacity,
+                    goods: goods.into_iter().map(|goods| goods.to_string()).collect(),
+                }]),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1307_vehicle_compartments_are_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+fn to_dimensions(dimensions: Option<Vec<&str>>) -> Option<Vec<String>> {
+    dimensions.map(|dimensions| dimensions.into_iter().map(|name| name.to_string()).collect())
+}
+
+parameterized_test! {can_detect_invalid_capacity_dimensions, (dimensions, expected), {
+    can_detect_invalid_capacity_dimensions_impl(dimensions, expected);
+}}
+
+can_detect_invalid_capacity_dimensions! {
+    case01: (None, None),
+    case02: (Some(vec!["weight", "volume"]), None),
+    case03: (Some(vec![]), Some("E1308".to_string())),
+    case04: (Some(vec!["weight", "weight"]), Some("E1308".to_string())),
+    case05: (Some(vec!["weight", ""]), Some("E1308".to_string())),
+}
+
+fn can_detect_invalid_capacity_dimensions_impl(dimensions: Option<Vec<&str>>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet { vehicles: vec![], profiles: vec![], dimensions: to_dimensions(dimensions) },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1308_capacity_dimensions_are_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_named_capacity, (dimensions, capacity, named_capacity, expected), {
+    can_detect_invalid_named_capacity_impl(dimensions, capacity, named_capacity, expected);
+}}
+
+can_detect_invalid_named_capacity! {
+    case01: (None, vec![10.], None, None),
+    case02: (None, vec![], None, Some("E1309".to_string())),
+    case03: (None, vec![], Some(vec![("weight", 10.)]), Some("E1309".to_string())),
+    case04: (Some(vec!["weight", "volume"]), vec![], Some(vec![("weight", 10.), ("volume", 5.)]), None),
+    case05: (Some(vec!["weight", "volume"]), vec![], Some(vec![("weight", 10.)]), Some("E1309".to_string())),
+    case06: (Some(vec!["weight"]), vec![], Some(vec![("weight", 10.), ("volume", 5.)]), Some("E1309".to_string())),
+    case07: (Some(vec!["weight", "volume"]), vec![10., 5.], None, None),
+    case08: (Some(vec!["weight", "volume"]), vec![10.], None, Some("E1309".to_string())),
+    case09: (Some(vec!["weight"]), vec![5.], Some(vec![("weight", 10.)]), Some("E1309".to_string())),
+}
+
+fn can_detect_invalid_named_capacity_impl(
+    dimensions: Option<Vec<&str>>,
+    capacity: Vec<f64>,
+    named_capacity: Option<Vec<(&str, f64)>>,
+    expected: Option<String>,
+) {
+    let problem = apply_named_dimensions(Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                capacity,
+                named_capacity: named_capacity
+                    .map(|capacity| capacity.into_iter().map(|(name, value)| (name.to_string(), value)).collect()),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+            dimensions: to_dimensions(dimensions),
+        },
+        ..create_empty_problem()
+    });
+
+    let result = check_e1309_vehicle_named_capacity_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_battery, (capacity, initial, consumption, rate, expected), {
+    can_detect_invalid_battery_impl(capacity, initial, consumption, rate, expected);
+}}
+
+can_detect_invalid_battery! {
+    case01: (10., None, 1., 1., None),
+    case02: (10., Some(5.), 1., 1., None),
+    case03: (0., None, 1., 1., Some("E1310".to_string())),
+    case04: (10., Some(11.), 1., 1., Some("E1310".to_string())),
+    case05: (10., None, -1., 1., Some("E1310".to_string())),
+    case06: (10., None, 1., 0., Some("E1310".to_string())),
+}
+
+fn can_detect_invalid_battery_impl(
+    capacity: f64,
+    initial: Option<f64>,
+    consumption: f64,
+    rate: f64,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                battery: Some(VehicleBattery {
+                    capacity,
+                    initial,
+                    consumption,
+                    load_consumption: None,
+                    stations: Some(vec![VehicleChargingStation {
+                        location: vec![1., 0.].to_loc(),
+                        duration: 10.,
+                        rate,
+                        times: None,
+                        tag: None,
+                    }]),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_vehicle_battery_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_capacity_precision, (capacity, expected), {
+    can_detect_invalid_capacity_precision_impl(capacity, expected);
+}}
+
+can_detect_invalid_capacity_precision! {
+    case01: (vec![10.], None),
+    case02: (vec![10.5], None),
+    case03: (vec![10.0001], Some("E1311".to_string())),
+    case04: (vec![3E9], Some("E1311".to_string())),
+}
+
+fn can_detect_invalid_capacity_precision_impl(capacity: Vec<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("vehicle", capacity)],
+            profiles: vec![],
+            dimensions: None,
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1311_vehicle_capacity_precision(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}